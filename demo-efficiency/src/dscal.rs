@@ -0,0 +1,112 @@
+#![allow(non_upper_case_globals)]
+
+use ndarray::prelude::*;
+use blas_array2::prelude::*;
+use std::time::Instant;
+use rand::{thread_rng, Rng};
+
+pub fn bench_dscal() {
+    let mut a = Array2::<f64>::zeros((n, n));
+
+    // random initialize
+    a.mapv_inplace(| _ | thread_rng().gen());
+    println!("random initialize finished");
+
+    bench_blas_array2_contiguous(2, &mut a);
+    bench_blas_array2_contiguous(10, &mut a);
+    bench_blas_array2_strided(2, &mut a);
+    bench_blas_array2_strided(10, &mut a);
+    bench_ndarray(2, &mut a);
+    bench_ndarray(10, &mut a);
+}
+
+/// matrix dimension (n x n)
+static n: usize = 8192;
+
+/// scaling factor; alternates with its inverse so values stay bounded
+static alpha: f64 = 1.000_001;
+
+/// memory traffic of one full-matrix scaling (read + write, in GB)
+static mem_count: f64 = (2 * n * n * 8) as f64 / 1.0e9;
+
+fn bench_blas_array2_contiguous(
+    ntest: usize,
+    a: &mut Array2<f64>,
+) {
+    let mut time_list: Vec<f64> = vec![];
+
+    for itest in 0..ntest {
+        let now = Instant::now();
+
+        let factor = if itest % 2 == 0 { alpha } else { 1.0 / alpha };
+        scale(factor, a.view_mut()).unwrap();
+
+        let elapsed = now.elapsed();
+        time_list.push(elapsed.as_secs_f64());
+    }
+
+    let time_sum: f64 = time_list.iter().sum();
+    let time_avg: f64 = time_sum / ntest as f64;
+    let time_std: f64 = (time_list.iter().map(|x| (x - time_avg).powi(2)).sum::<f64>() / ntest as f64).sqrt();
+    if ntest > 2 {
+        println!("== bench_blas_array2_contiguous ==");
+        println!("time for all tests: {:.3} sec, {:.1} GB/s", time_sum, ntest as f64 * mem_count / time_sum);
+        println!("time for one test : {:.3} ± {:.3} msec", time_avg * 1000., time_std * 1000.);
+        println!("a_sum: {:.6}", a.sum());
+    }
+}
+
+fn bench_blas_array2_strided(
+    ntest: usize,
+    a: &mut Array2<f64>,
+) {
+    let mut time_list: Vec<f64> = vec![];
+
+    for itest in 0..ntest {
+        let now = Instant::now();
+
+        // half the columns: not contiguous, falls back to one SCAL per row
+        let factor = if itest % 2 == 0 { alpha } else { 1.0 / alpha };
+        scale(factor, a.slice_mut(s![.., ..;2])).unwrap();
+
+        let elapsed = now.elapsed();
+        time_list.push(elapsed.as_secs_f64());
+    }
+
+    let time_sum: f64 = time_list.iter().sum();
+    let time_avg: f64 = time_sum / ntest as f64;
+    let time_std: f64 = (time_list.iter().map(|x| (x - time_avg).powi(2)).sum::<f64>() / ntest as f64).sqrt();
+    if ntest > 2 {
+        println!("== bench_blas_array2_strided ==");
+        println!("time for all tests: {:.3} sec, {:.1} GB/s", time_sum, ntest as f64 * mem_count / 2.0 / time_sum);
+        println!("time for one test : {:.3} ± {:.3} msec", time_avg * 1000., time_std * 1000.);
+        println!("a_sum: {:.6}", a.sum());
+    }
+}
+
+fn bench_ndarray(
+    ntest: usize,
+    a: &mut Array2<f64>,
+) {
+    let mut time_list: Vec<f64> = vec![];
+
+    for itest in 0..ntest {
+        let now = Instant::now();
+
+        let factor = if itest % 2 == 0 { alpha } else { 1.0 / alpha };
+        a.mapv_inplace(|x| x * factor);
+
+        let elapsed = now.elapsed();
+        time_list.push(elapsed.as_secs_f64());
+    }
+
+    let time_sum: f64 = time_list.iter().sum();
+    let time_avg: f64 = time_sum / ntest as f64;
+    let time_std: f64 = (time_list.iter().map(|x| (x - time_avg).powi(2)).sum::<f64>() / ntest as f64).sqrt();
+    if ntest > 2 {
+        println!("== bench_ndarray ==");
+        println!("time for all tests: {:.3} sec, {:.1} GB/s", time_sum, ntest as f64 * mem_count / time_sum);
+        println!("time for one test : {:.3} ± {:.3} msec", time_avg * 1000., time_std * 1000.);
+        println!("a_sum: {:.6}", a.sum());
+    }
+}
@@ -0,0 +1,82 @@
+#![allow(non_upper_case_globals)]
+
+use ndarray::prelude::*;
+use blas_array2::prelude::*;
+use std::time::Instant;
+use rand::{thread_rng, Rng};
+
+pub fn bench_extract() {
+    let mut a = Array2::<f64>::zeros((n, n));
+
+    // random initialize
+    a.mapv_inplace(| _ | thread_rng().gen());
+    println!("random initialize finished");
+
+    bench_blas_array2(2, &a);
+    bench_blas_array2(10, &a);
+    bench_ndarray(2, &a);
+    bench_ndarray(10, &a);
+}
+
+/// matrix dimension (n x n)
+static n: usize = 8192;
+
+/// memory traffic of extracting the (n/2 x n/2) sub-block of every other row and middle half of columns
+/// (read + write, in GB)
+static mem_count: f64 = (2 * (n / 2) * (n / 2) * 8) as f64 / 1.0e9;
+
+fn bench_blas_array2(
+    ntest: usize,
+    a: &Array2<f64>,
+) {
+    let mut time_list: Vec<f64> = vec![];
+    let mut b_sum = 0.0;
+
+    for _ in 0..ntest {
+        let now = Instant::now();
+
+        let b = extract_block(a.slice(s![..;2, ..]), 0..n / 2, n / 4..3 * n / 4).unwrap();
+
+        let elapsed = now.elapsed();
+        time_list.push(elapsed.as_secs_f64());
+        b_sum = b.sum();
+    }
+
+    let time_sum: f64 = time_list.iter().sum();
+    let time_avg: f64 = time_sum / ntest as f64;
+    let time_std: f64 = (time_list.iter().map(|x| (x - time_avg).powi(2)).sum::<f64>() / ntest as f64).sqrt();
+    if ntest > 2 {
+        println!("== bench_blas_array2 ==");
+        println!("time for all tests: {:.3} sec, {:.1} GB/s", time_sum, ntest as f64 * mem_count / time_sum);
+        println!("time for one test : {:.3} ± {:.3} msec", time_avg * 1000., time_std * 1000.);
+        println!("b_sum: {:.6}", b_sum);
+    }
+}
+
+fn bench_ndarray(
+    ntest: usize,
+    a: &Array2<f64>,
+) {
+    let mut time_list: Vec<f64> = vec![];
+    let mut b_sum = 0.0;
+
+    for _ in 0..ntest {
+        let now = Instant::now();
+
+        let b = a.slice(s![..;2, n / 4..3 * n / 4]).to_owned();
+
+        let elapsed = now.elapsed();
+        time_list.push(elapsed.as_secs_f64());
+        b_sum = b.sum();
+    }
+
+    let time_sum: f64 = time_list.iter().sum();
+    let time_avg: f64 = time_sum / ntest as f64;
+    let time_std: f64 = (time_list.iter().map(|x| (x - time_avg).powi(2)).sum::<f64>() / ntest as f64).sqrt();
+    if ntest > 2 {
+        println!("== bench_ndarray ==");
+        println!("time for all tests: {:.3} sec, {:.1} GB/s", time_sum, ntest as f64 * mem_count / time_sum);
+        println!("time for one test : {:.3} ± {:.3} msec", time_avg * 1000., time_std * 1000.);
+        println!("b_sum: {:.6}", b_sum);
+    }
+}
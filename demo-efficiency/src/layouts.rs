@@ -0,0 +1,72 @@
+#![allow(non_upper_case_globals)]
+
+use ndarray::prelude::*;
+use blas_array2::prelude::*;
+use blas_array2::util::{copy_stats, reset_copy_stats};
+use std::time::Instant;
+use rand::{thread_rng, Rng};
+
+/// matrix dimension (n x n)
+static n: usize = 1024;
+/// number of repeated runs for each layout combination
+static ntest: usize = 5;
+
+pub fn bench_layouts() {
+    let mut a = Array2::<f64>::zeros((n, n));
+    let mut b = Array2::<f64>::zeros((n, n));
+    a.mapv_inplace(| _ | thread_rng().gen());
+    b.mapv_inplace(| _ | thread_rng().gen());
+    let a_f = a.t().as_standard_layout().reversed_axes().into_owned();
+    let b_f = b.t().as_standard_layout().reversed_axes().into_owned();
+    println!("random initialize finished");
+
+    println!("== bench_dgemm_layouts ==");
+    for (a_name, a) in [("C", a.view()), ("F", a_f.view())] {
+        for (b_name, b) in [("C", b.view()), ("F", b_f.view())] {
+            for c_name in ["C", "F"] {
+                let mut c = match c_name {
+                    "C" => Array2::<f64>::zeros((n, n)),
+                    _ => Array2::<f64>::zeros((n, n).f()),
+                };
+                let plan = DGEMM::default().a(a).b(b).c(c.view_mut()).plan().unwrap();
+                let (time_avg, copies, elements) = time_runs(|| {
+                    DGEMM::default().a(a).b(b).c(c.view_mut()).run().unwrap();
+                });
+                println!(
+                    "a: {a_name}, b: {b_name}, c: {c_name} | {:.3} msec | planned copies: {:?} | copies: {copies}, elements: {elements}",
+                    time_avg * 1000., plan.copies
+                );
+            }
+        }
+    }
+
+    println!("== bench_dsyrk_layouts ==");
+    for (a_name, a) in [("C", a.view()), ("F", a_f.view())] {
+        for c_name in ["C", "F"] {
+            let mut c = match c_name {
+                "C" => Array2::<f64>::zeros((n, n)),
+                _ => Array2::<f64>::zeros((n, n).f()),
+            };
+            let plan = DSYRK::default().a(a).c(c.view_mut()).plan().unwrap();
+            let (time_avg, copies, elements) = time_runs(|| {
+                DSYRK::default().a(a).c(c.view_mut()).run().unwrap();
+            });
+            println!(
+                "a: {a_name}, c: {c_name} | {:.3} msec | planned copies: {:?} | copies: {copies}, elements: {elements}",
+                time_avg * 1000., plan.copies
+            );
+        }
+    }
+}
+
+/// Average time (in sec) and copy counters (per run) of `ntest` runs.
+fn time_runs(mut f: impl FnMut()) -> (f64, usize, usize) {
+    reset_copy_stats();
+    let now = Instant::now();
+    for _ in 0..ntest {
+        f();
+    }
+    let time_avg = now.elapsed().as_secs_f64() / ntest as f64;
+    let stats = copy_stats();
+    (time_avg, stats.copies / ntest, stats.elements / ntest)
+}
@@ -2,11 +2,20 @@
 
 mod dgemm;
 mod dsyrk;
+mod dscal;
+mod extract;
+mod layouts;
 
 fn main() {
     println!(">>> bench_dgemm <<<");
     dgemm::bench_dgemm();
     println!(">>> bench_dsyrk <<<");
     dsyrk::bench_dsyrk();
+    println!(">>> bench_dscal <<<");
+    dscal::bench_dscal();
+    println!(">>> bench_extract <<<");
+    extract::bench_extract();
+    println!(">>> bench_layouts <<<");
+    layouts::bench_layouts();
 }
 
@@ -0,0 +1,38 @@
+//! Writing BLAS output in place into arrays held in shared ownership.
+//!
+//! Output views of BLAS wrappers are ordinary `ArrayViewMut2`, so the output may live behind an `Arc` as
+//! long as it is uniquely owned while being written. This example is a library, so it does not require a
+//! BLAS backend to link. Check by
+//! ```bash
+//! cargo build --example arc_output
+//! ```
+
+use std::sync::Arc;
+
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+/// $\mathbf{C} \leftarrow \mathbf{A} \mathbf{B}$ into `Arc<Array2>`, then share the result.
+///
+/// `Arc::get_mut` only succeeds while no other clone of the `Arc` exists.
+pub fn gemm_into_arc(
+    a: ArrayView2<f64>,
+    b: ArrayView2<f64>,
+    c: &mut Arc<Array2<f64>>,
+) -> Result<Arc<Array2<f64>>, BLASError> {
+    let c_mut = Arc::get_mut(c).ok_or(BLASError::RuntimeError("output is shared".into()))?;
+    GEMM::default().a(a).b(b).c(c_mut.view_mut()).run()?;
+    Ok(Arc::clone(c))
+}
+
+/// $\mathbf{C} \leftarrow \mathbf{A} \mathbf{A}^T$ (lower triangle) into `ArcArray`, then share the result.
+///
+/// `view_mut` of `ArcArray` makes the data unique first, which is a no-op (no copy) when it is already
+/// uniquely owned.
+pub fn syrk_into_arc_array(
+    a: ArrayView2<f64>,
+    c: &mut ArcArray<f64, Ix2>,
+) -> Result<ArcArray<f64, Ix2>, BLASError> {
+    SYRK::default().a(a).c(c.view_mut()).run()?;
+    Ok(c.clone())
+}
@@ -0,0 +1,31 @@
+//! Compile check of core Level-1/2/3 wrappers in `#![no_std]` (with `alloc`) environment.
+//!
+//! This example is a library, so it does not require panic handler or global allocator. Check by
+//! ```bash
+//! cargo build --example no_std --no-default-features
+//! ```
+
+#![no_std]
+
+extern crate alloc;
+
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+/// Level-1: $\mathbf{y} \leftarrow \alpha \mathbf{x} + \mathbf{y}$, and returns $\Vert \mathbf{y} \Vert_2$.
+pub fn level1(x: ArrayView1<f64>, y: ArrayViewMut1<f64>) -> Result<f64, BLASError> {
+    let y = AXPY::default().x(x).y(y).alpha(2.0).run()?.into_owned();
+    NRM2::default().x(y.view()).run()
+}
+
+/// Level-2: $\mathbf{y} = \mathbf{A} \mathbf{x}$.
+pub fn level2(a: ArrayView2<c64>, x: ArrayView1<c64>) -> Result<Array1<c64>, BLASError> {
+    Ok(GEMV::default().a(a).x(x).run()?.into_owned())
+}
+
+/// Level-3: $\mathbf{C} = \mathbf{A} \mathbf{B}^\dagger$ and $\mathbf{A} \mathbf{A}^T$.
+pub fn level3(a: ArrayView2<c64>, b: ArrayView2<c64>) -> Result<(Array2<c64>, Array2<c64>), BLASError> {
+    let c = GEMM::default().a(a).b(b).transb('C').run()?.into_owned();
+    let s = SYRK::default().a(a).run()?.into_owned();
+    Ok((c, s))
+}
@@ -52,6 +52,7 @@ where
         if n == 0 {
             return Ok(F::RealFloat::zero());
         } else {
+            crate::record::blas_record!("ASUM", dims: [n], flags: [], scalars: [], arrays: [x]);
             return unsafe { Ok(F::asum(&n, x_ptr, &incx)) };
         }
     }
@@ -62,12 +63,16 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std, derive(Clone))]
 pub struct ASUM_<'x, F>
 where
     F: ASUMNum,
 {
     pub x: ArrayView1<'x, F>,
+
+    /// Raw increment override of `x`; `x` should be contiguous if this is set.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub incx: Option<blas_int>,
 }
 
 impl<'x, F> ASUM_<'x, F>
@@ -75,9 +80,10 @@ where
     F: ASUMNum,
 {
     pub fn driver(self) -> Result<ASUM_Driver<'x, F>, BLASError> {
-        let Self { x } = self;
-        let incx = x.stride_of(Axis(0));
-        let n = x.len_of(Axis(0));
+        let Self { x, incx } = self;
+        // BLAS gives zero for negative increment; the result does not depend on order of elements
+        let x = if x.stride_of(Axis(0)) < 0 { x.slice_move(s![..;-1]) } else { x };
+        let (n, incx) = get_len_inc_array1(&x, incx)?;
         let driver = ASUM_Driver { n: n.try_into()?, x, incx: incx.try_into()? };
         return Ok(driver);
     }
@@ -95,6 +101,7 @@ impl<'x, F> ASUM<'x, F>
 where
     F: ASUMNum,
 {
+    #[must_use = "the computed result is dropped if unused"]
     pub fn run(self) -> Result<F::RealFloat, BLASError> {
         self.build()?.driver()?.run_blas()
     }
@@ -0,0 +1,152 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region BLAS func */
+
+pub trait AXPYNum: BLASFloat {
+    unsafe fn axpy(
+        n: *const blas_int,
+        alpha: *const Self,
+        x: *const Self,
+        incx: *const blas_int,
+        y: *mut Self,
+        incy: *const blas_int,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl AXPYNum for $type {
+            unsafe fn axpy(
+                n: *const blas_int,
+                alpha: *const Self,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *mut Self,
+                incy: *const blas_int,
+            ) {
+                ffi::$func(n, alpha, x, incx, y, incy);
+            }
+        }
+    };
+}
+
+impl_func!(f32, saxpy_);
+impl_func!(f64, daxpy_);
+impl_func!(c32, caxpy_);
+impl_func!(c64, zaxpy_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct AXPY_Driver<'x, 'y, F>
+where
+    F: AXPYNum,
+{
+    n: blas_int,
+    alpha: F,
+    x: ArrayView1<'x, F>,
+    incx: blas_int,
+    y: ArrayOut1<'y, F>,
+    incy: blas_int,
+}
+
+impl<'x, 'y, F> BLASDriver<'y, F, Ix1> for AXPY_Driver<'x, 'y, F>
+where
+    F: AXPYNum,
+{
+    fn run_blas(self) -> Result<ArrayOut1<'y, F>, BLASError> {
+        let Self { n, alpha, x, incx, mut y, incy } = self;
+
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok(y);
+        }
+
+        // BLAS starts from the end of vector for negative increments
+        let x_ptr = unsafe { x.as_ptr().offset(((n - 1) * incx.min(0)) as isize) };
+        let y_ptr = unsafe { y.get_data_mut_ptr().offset(((n - 1) * incy.min(0)) as isize) };
+        crate::record::blas_record!("AXPY", dims: [n], flags: [], scalars: [alpha], arrays: [x, y]);
+        unsafe {
+            F::axpy(&n, &alpha, x_ptr, &incx, y_ptr, &incy);
+        }
+        Ok(y)
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct AXPY_<'x, 'y, F>
+where
+    F: AXPYNum,
+{
+    pub x: ArrayView1<'x, F>,
+    pub y: ArrayViewMut1<'y, F>,
+
+    #[builder(setter(custom), default = "F::one()")]
+    pub alpha: F,
+    /// Raw increment override of `x`; `x` should be contiguous if this is set.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub incx: Option<blas_int>,
+    /// Raw increment override of `y`; `y` should be contiguous if this is set.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub incy: Option<blas_int>,
+}
+
+impl_builder_clone!(AXPY_Builder<'x, 'y>, AXPYNum, [x, alpha, incx, incy], [y]);
+impl_builder_scalar!(AXPY_Builder<'x, 'y>, AXPYNum, [alpha]);
+
+impl<'x, 'y, F> BLASBuilder_<'y, F, Ix1> for AXPY_<'x, 'y, F>
+where
+    F: AXPYNum,
+{
+    fn driver(self) -> Result<AXPY_Driver<'x, 'y, F>, BLASError> {
+        let Self { x, y, alpha, incx, incy } = self;
+
+        // initialize intent(hide)
+        let (n, incx) = get_len_inc_array1(&x, incx)?;
+        let (ny, incy) = get_len_inc_array1(&y.view(), incy)?;
+
+        // perform check
+        blas_assert_eq!(n, ny, InvalidDim)?;
+
+        // finalize
+        let driver = AXPY_Driver {
+            n: n.try_into()?,
+            alpha,
+            x,
+            incx: incx.try_into()?,
+            y: ArrayOut1::ViewMut(y),
+            incy: incy.try_into()?,
+        };
+        Ok(driver)
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type AXPY<'x, 'y, F> = AXPY_Builder<'x, 'y, F>;
+pub type SAXPY<'x, 'y> = AXPY<'x, 'y, f32>;
+pub type DAXPY<'x, 'y> = AXPY<'x, 'y, f64>;
+pub type CAXPY<'x, 'y> = AXPY<'x, 'y, c32>;
+pub type ZAXPY<'x, 'y> = AXPY<'x, 'y, c64>;
+
+impl<'x, 'y, F> BLASBuilder<'y, F, Ix1> for AXPY_Builder<'x, 'y, F>
+where
+    F: AXPYNum,
+{
+    fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
+        self.build()?.driver()?.run_blas()
+    }
+}
+
+/* #endregion */
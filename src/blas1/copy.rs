@@ -0,0 +1,145 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region BLAS func */
+
+pub trait COPYNum: BLASFloat {
+    unsafe fn copy(
+        n: *const blas_int,
+        x: *const Self,
+        incx: *const blas_int,
+        y: *mut Self,
+        incy: *const blas_int,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl COPYNum for $type {
+            unsafe fn copy(
+                n: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *mut Self,
+                incy: *const blas_int,
+            ) {
+                ffi::$func(n, x, incx, y, incy);
+            }
+        }
+    };
+}
+
+impl_func!(f32, scopy_);
+impl_func!(f64, dcopy_);
+impl_func!(c32, ccopy_);
+impl_func!(c64, zcopy_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct COPY_Driver<'x, 'y, F>
+where
+    F: COPYNum,
+{
+    n: blas_int,
+    x: ArrayView1<'x, F>,
+    incx: blas_int,
+    y: ArrayOut1<'y, F>,
+    incy: blas_int,
+}
+
+impl<'x, 'y, F> BLASDriver<'y, F, Ix1> for COPY_Driver<'x, 'y, F>
+where
+    F: COPYNum,
+{
+    fn run_blas(self) -> Result<ArrayOut1<'y, F>, BLASError> {
+        let Self { n, x, incx, mut y, incy } = self;
+
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok(y);
+        }
+
+        // BLAS starts from the end of vector for negative increments
+        let x_ptr = unsafe { x.as_ptr().offset(((n - 1) * incx.min(0)) as isize) };
+        let y_ptr = unsafe { y.get_data_mut_ptr().offset(((n - 1) * incy.min(0)) as isize) };
+        crate::record::blas_record!("COPY", dims: [n], flags: [], scalars: [], arrays: [x, y]);
+        unsafe {
+            F::copy(&n, x_ptr, &incx, y_ptr, &incy);
+        }
+        Ok(y)
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct COPY_<'x, 'y, F>
+where
+    F: COPYNum,
+{
+    pub x: ArrayView1<'x, F>,
+    pub y: ArrayViewMut1<'y, F>,
+
+    /// Raw increment override of `x`; `x` should be contiguous if this is set.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub incx: Option<blas_int>,
+    /// Raw increment override of `y`; `y` should be contiguous if this is set.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub incy: Option<blas_int>,
+}
+
+impl_builder_clone!(COPY_Builder<'x, 'y>, COPYNum, [x, incx, incy], [y]);
+
+impl<'x, 'y, F> BLASBuilder_<'y, F, Ix1> for COPY_<'x, 'y, F>
+where
+    F: COPYNum,
+{
+    fn driver(self) -> Result<COPY_Driver<'x, 'y, F>, BLASError> {
+        let Self { x, y, incx, incy } = self;
+
+        // initialize intent(hide)
+        let (n, incx) = get_len_inc_array1(&x, incx)?;
+        let (ny, incy) = get_len_inc_array1(&y.view(), incy)?;
+
+        // perform check
+        blas_assert_eq!(n, ny, InvalidDim)?;
+
+        // finalize
+        let driver = COPY_Driver {
+            n: n.try_into()?,
+            x,
+            incx: incx.try_into()?,
+            y: ArrayOut1::ViewMut(y),
+            incy: incy.try_into()?,
+        };
+        Ok(driver)
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type COPY<'x, 'y, F> = COPY_Builder<'x, 'y, F>;
+pub type SCOPY<'x, 'y> = COPY<'x, 'y, f32>;
+pub type DCOPY<'x, 'y> = COPY<'x, 'y, f64>;
+pub type CCOPY<'x, 'y> = COPY<'x, 'y, c32>;
+pub type ZCOPY<'x, 'y> = COPY<'x, 'y, c64>;
+
+impl<'x, 'y, F> BLASBuilder<'y, F, Ix1> for COPY_Builder<'x, 'y, F>
+where
+    F: COPYNum,
+{
+    fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
+        self.build()?.driver()?.run_blas()
+    }
+}
+
+/* #endregion */
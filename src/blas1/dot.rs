@@ -0,0 +1,228 @@
+use crate::ffi::{self, blas_int, c_char};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::{One, Zero};
+
+/* #region BLAS func */
+
+pub trait DOTNum: BLASFloat {
+    unsafe fn dot(
+        n: *const blas_int,
+        x: *const Self,
+        incx: *const blas_int,
+        y: *const Self,
+        incy: *const blas_int,
+    ) -> Self;
+
+    /// Conjugated dot product.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`DOTNum::dot`], and additionally `incx` should be one for complex types.
+    unsafe fn dotc(
+        n: *const blas_int,
+        x: *const Self,
+        incx: *const blas_int,
+        y: *const Self,
+        incy: *const blas_int,
+    ) -> Self;
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl DOTNum for $type {
+            unsafe fn dot(
+                n: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *const Self,
+                incy: *const blas_int,
+            ) -> Self {
+                ffi::$func(n, x, incx, y, incy)
+            }
+
+            unsafe fn dotc(
+                n: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *const Self,
+                incy: *const blas_int,
+            ) -> Self {
+                ffi::$func(n, x, incx, y, incy)
+            }
+        }
+    };
+}
+
+impl_func!(f32, sdot_);
+impl_func!(f64, ddot_);
+
+// Complex dot functions return the result by value or through a pointer depending on the BLAS library (and
+// compiler of it), so they can't be called portably. Unconjugated dot is computed as GEMV of `x` viewed as an
+// 1 x n matrix (leading dimension `incx`, which should be positive) instead; conjugated dot is GEMV with
+// conjugate transpose of `x` viewed as an n x 1 matrix, which requires unit increment of `x`.
+macro_rules! impl_func_complex {
+    ($type: ty, $func: ident) => {
+        impl DOTNum for $type {
+            unsafe fn dot(
+                n: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *const Self,
+                incy: *const blas_int,
+            ) -> Self {
+                let (trans, m, lda) = ('N' as c_char, 1 as blas_int, (*incx).max(1));
+                let (alpha, beta) = (<$type>::one(), <$type>::zero());
+                let mut res = <$type>::zero();
+                ffi::$func(&trans, &m, n, &alpha, x, &lda, y, incy, &beta, &mut res, &m);
+                res
+            }
+
+            unsafe fn dotc(
+                n: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *const Self,
+                incy: *const blas_int,
+            ) -> Self {
+                let _ = incx;
+                let (trans, one, lda) = ('C' as c_char, 1 as blas_int, (*n).max(1));
+                let (alpha, beta) = (<$type>::one(), <$type>::zero());
+                let mut res = <$type>::zero();
+                ffi::$func(&trans, n, &one, &alpha, x, &lda, y, incy, &beta, &mut res, &one);
+                res
+            }
+        }
+    };
+}
+
+impl_func_complex!(c32, cgemv_);
+impl_func_complex!(c64, zgemv_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct DOT_Driver<'x, 'y, F>
+where
+    F: DOTNum,
+{
+    n: blas_int,
+    x: ArrayView1<'x, F>,
+    incx: blas_int,
+    y: ArrayView1<'y, F>,
+    incy: blas_int,
+    conjx: bool,
+}
+
+impl<'x, 'y, F> DOT_Driver<'x, 'y, F>
+where
+    F: DOTNum,
+{
+    pub fn run_blas(self) -> Result<F, BLASError> {
+        let Self { n, x, incx, y, incy, conjx } = self;
+        if n == 0 {
+            return Ok(F::zero());
+        }
+
+        // BLAS starts from the end of vector for negative increments
+        let x_ptr = unsafe { x.as_ptr().offset(((n - 1) * incx.min(0)) as isize) };
+        let y_ptr = unsafe { y.as_ptr().offset(((n - 1) * incy.min(0)) as isize) };
+        crate::record::blas_record!("DOT", dims: [n], flags: [], scalars: [], arrays: [x, y]);
+        match conjx {
+            false => unsafe { Ok(F::dot(&n, x_ptr, &incx, y_ptr, &incy)) },
+            true => unsafe { Ok(F::dotc(&n, x_ptr, &incx, y_ptr, &incy)) },
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+/// Dot product $\boldsymbol{x}^\mathrm{T} \boldsymbol{y}$, or $\boldsymbol{x}^\dagger \boldsymbol{y}$ with `conjx`.
+///
+/// By default, like ndarray's `.dot()`, no complex conjugate is applied to either vector.
+/// Broadcasted (zero-stride) `x` or `y` is copied before calling BLAS, which does not accept zero increment.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std, derive(Clone))]
+pub struct DOT_<'x, 'y, F>
+where
+    F: DOTNum,
+{
+    pub x: ArrayView1<'x, F>,
+    pub y: ArrayView1<'y, F>,
+
+    /// Raw increment override of `x`; `x` should be contiguous if this is set.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub incx: Option<blas_int>,
+    /// Raw increment override of `y`; `y` should be contiguous if this is set.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub incy: Option<blas_int>,
+    /// Conjugate `x` (DOTC); for complex types, `x` is copied if it is not unit-strided.
+    #[builder(setter(into), default = "false")]
+    pub conjx: bool,
+}
+
+impl<'x, 'y, F> DOT_<'x, 'y, F>
+where
+    F: DOTNum,
+{
+    pub fn driver(self) -> Result<DOT_Driver<'x, 'y, F>, BLASError> {
+        let Self { x, y, incx, incy, conjx } = self;
+
+        // result does not depend on order of pairs, so reverse both if `x` has negative increment
+        let (x, y) = match incx.is_none() && x.stride_of(Axis(0)) < 0 {
+            true => (x.slice_move(s![..;-1]), y.slice_move(s![..;-1])),
+            false => (x, y),
+        };
+
+        // initialize intent(hide)
+        let (n, incx) = get_len_inc_array1(&x, incx)?;
+        let (ny, incy) = get_len_inc_array1(&y, incy)?;
+
+        // perform check
+        blas_assert_eq!(n, ny, InvalidDim)?;
+        if conjx && F::is_complex() && n > 1 {
+            blas_assert!(incx == 1, InvalidStride, "conjugated dot requires unit increment of x")?;
+        }
+
+        // finalize
+        let driver =
+            DOT_Driver { n: n.try_into()?, x, incx: incx.try_into()?, y, incy: incy.try_into()?, conjx };
+        Ok(driver)
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type DOT<'x, 'y, F> = DOT_Builder<'x, 'y, F>;
+pub type SDOT<'x, 'y> = DOT<'x, 'y, f32>;
+pub type DDOT<'x, 'y> = DOT<'x, 'y, f64>;
+pub type CDOTU<'x, 'y> = DOT<'x, 'y, c32>;
+pub type ZDOTU<'x, 'y> = DOT<'x, 'y, c64>;
+
+impl<'x, 'y, F> DOT<'x, 'y, F>
+where
+    F: DOTNum,
+{
+    #[must_use = "the computed result is dropped if unused"]
+    pub fn run(self) -> Result<F, BLASError> {
+        let obj = self.build()?;
+        // broadcasted (zero-stride) vectors are not accepted by BLAS, and conjugated dot of complex types
+        // requires unit-strided `x`
+        let stride_x = obj.x.stride_of(Axis(0));
+        let copy_x = obj.incx.is_none()
+            && obj.x.len() > 1
+            && (stride_x == 0 || (obj.conjx && F::is_complex() && stride_x.abs() != 1));
+        let copy_y = obj.incy.is_none() && obj.y.len() > 1 && obj.y.stride_of(Axis(0)) == 0;
+        let x = if copy_x { obj.x.to_seq_layout()? } else { CowArray::from(obj.x.view()) };
+        let y = if copy_y { obj.y.to_seq_layout()? } else { CowArray::from(obj.y.view()) };
+        DOT_ { x: x.view(), y: y.view(), ..obj }.driver()?.run_blas()
+    }
+}
+
+/* #endregion */
@@ -2,6 +2,7 @@ use crate::ffi::{self, blas_int};
 use crate::util::*;
 use derive_builder::Builder;
 use ndarray::prelude::*;
+use num_traits::{Float, One};
 
 /* #region BLAS func */
 
@@ -51,6 +52,7 @@ where
             return Ok(0);
         } else {
             // 0-index for C/Rust v.s. 1-index for Fortran
+            crate::record::blas_record!("IAMAX", dims: [n], flags: [], scalars: [], arrays: [x]);
             return unsafe { Ok((F::iamax(&n, x_ptr, &incx) - 1).try_into()?) };
         }
     }
@@ -60,13 +62,36 @@ where
 
 /* #region BLAS builder */
 
+/// Handling of NaN elements in IAMAX.
+///
+/// Behavior of BLAS `i?amax` with NaN elements is implementation-defined, so NaNs are handled by a
+/// pre-scan in Rust; BLAS is only called when no NaN is present.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NanPolicy {
+    /// Return index of the first NaN element (default).
+    #[default]
+    Propagate,
+    /// Skip NaN elements; index 0 is returned if all elements are NaN.
+    Ignore,
+}
+
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std, derive(Clone))]
 pub struct IAMAX_<'x, F>
 where
     F: IAMAXNum,
 {
     pub x: ArrayView1<'x, F>,
+
+    /// Raw increment override of `x`; `x` should be contiguous if this is set.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub incx: Option<blas_int>,
+
+    /// Handling of NaN elements, see [`NanPolicy`].
+    ///
+    /// For complex numbers, an element is NaN if either its real or imaginary part is NaN.
+    #[builder(setter(into), default = "NanPolicy::Propagate")]
+    pub nan_policy: NanPolicy,
 }
 
 impl<'x, F> IAMAX_<'x, F>
@@ -74,12 +99,42 @@ where
     F: IAMAXNum,
 {
     pub fn driver(self) -> Result<IAMAX_Driver<'x, F>, BLASError> {
-        let Self { x } = self;
-        let incx = x.stride_of(Axis(0));
-        let n = x.len_of(Axis(0));
+        let Self { x, incx, .. } = self;
+        let (n, incx) = get_len_inc_array1(&x, incx)?;
         let driver = IAMAX_Driver { n: n.try_into()?, x, incx: incx.try_into()? };
         return Ok(driver);
     }
+
+    /// Result by [`NanPolicy`] if any element is NaN; `None` if no NaN is present.
+    pub fn nan_scan(&self) -> Result<Option<usize>, BLASError> {
+        let is_nan = |v: F| F::real(v).is_nan() || F::imag(v).is_nan();
+        // elements that BLAS would see
+        let x = match self.incx {
+            Some(inc) => {
+                get_len_inc_array1(&self.x, Some(inc))?;
+                self.x.slice(s![..;inc as usize])
+            },
+            None => self.x.view(),
+        };
+        let Some(first_nan) = x.iter().position(|&v| is_nan(v)) else {
+            return Ok(None);
+        };
+        match self.nan_policy {
+            NanPolicy::Propagate => Ok(Some(first_nan)),
+            NanPolicy::Ignore => {
+                // same measure as BLAS: |re| + |im|, and first index of maximum
+                let mut idx_max = 0;
+                let mut val_max = -F::RealFloat::one();
+                for (idx, &v) in x.iter().enumerate().filter(|&(_, &v)| !is_nan(v)) {
+                    let val = F::real(v).abs() + F::imag(v).abs();
+                    if val > val_max {
+                        (idx_max, val_max) = (idx, val);
+                    }
+                }
+                Ok(Some(idx_max))
+            },
+        }
+    }
 }
 
 /* #region BLAS wrapper */
@@ -94,8 +149,13 @@ impl<'x, F> IAMAX<'x, F>
 where
     F: IAMAXNum,
 {
+    #[must_use = "the computed result is dropped if unused"]
     pub fn run(self) -> Result<usize, BLASError> {
-        self.build()?.driver()?.run_blas()
+        let obj = self.build()?;
+        if let Some(idx) = obj.nan_scan()? {
+            return Ok(idx);
+        }
+        obj.driver()?.run_blas()
     }
 }
 
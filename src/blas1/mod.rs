@@ -1,3 +1,8 @@
 pub mod asum;
+pub mod axpy;
+pub mod copy;
+pub mod dot;
 pub mod iamax;
 pub mod nrm2;
+pub mod rot;
+pub mod scal;
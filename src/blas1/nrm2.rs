@@ -55,6 +55,7 @@ where
         if n == 0 {
             return Ok(F::RealFloat::zero());
         } else {
+            crate::record::blas_record!("NRM2", dims: [n], flags: [], scalars: [], arrays: [x]);
             return unsafe { Ok(F::nrm2(&n, x_ptr, &incx)) };
         }
     }
@@ -65,12 +66,16 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std, derive(Clone))]
 pub struct NRM2_<'x, F>
 where
     F: NRM2Num,
 {
     pub x: ArrayView1<'x, F>,
+
+    /// Raw increment override of `x`; `x` should be contiguous if this is set.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub incx: Option<blas_int>,
 }
 
 impl<'x, F> NRM2_<'x, F>
@@ -78,9 +83,10 @@ where
     F: NRM2Num,
 {
     pub fn driver(self) -> Result<NRM2_Driver<'x, F>, BLASError> {
-        let Self { x } = self;
-        let incx = x.stride_of(Axis(0));
-        let n = x.len_of(Axis(0));
+        let Self { x, incx } = self;
+        // BLAS gives zero for negative increment; the result does not depend on order of elements
+        let x = if x.stride_of(Axis(0)) < 0 { x.slice_move(s![..;-1]) } else { x };
+        let (n, incx) = get_len_inc_array1(&x, incx)?;
         let driver = NRM2_Driver { n: n.try_into()?, x, incx: incx.try_into()? };
         return Ok(driver);
     }
@@ -98,6 +104,7 @@ impl<'x, F> NRM2<'x, F>
 where
     F: NRM2Num,
 {
+    #[must_use = "the computed result is dropped if unused"]
     pub fn run(self) -> Result<F::RealFloat, BLASError> {
         self.build()?.driver()?.run_blas()
     }
@@ -0,0 +1,184 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::Zero;
+
+/* #region BLAS func */
+
+pub trait ROTNum: BLASFloat {
+    unsafe fn rot(
+        n: *const blas_int,
+        x: *mut Self,
+        incx: *const blas_int,
+        y: *mut Self,
+        incy: *const blas_int,
+        c: *const Self::RealFloat,
+        s: *const Self::RealFloat,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl ROTNum for $type {
+            unsafe fn rot(
+                n: *const blas_int,
+                x: *mut Self,
+                incx: *const blas_int,
+                y: *mut Self,
+                incy: *const blas_int,
+                c: *const Self::RealFloat,
+                s: *const Self::RealFloat,
+            ) {
+                ffi::$func(n, x, incx, y, incy, c, s);
+            }
+        }
+    };
+}
+
+impl_func!(f32, srot_);
+impl_func!(f64, drot_);
+impl_func!(c32, csrot_);
+impl_func!(c64, zdrot_);
+
+pub trait ROTGNum: BLASFloat {
+    unsafe fn rotg(a: *mut Self, b: *mut Self, c: *mut Self::RealFloat, s: *mut Self);
+}
+
+macro_rules! impl_rotg {
+    ($type: ty, $func: ident) => {
+        impl ROTGNum for $type {
+            unsafe fn rotg(a: *mut Self, b: *mut Self, c: *mut Self::RealFloat, s: *mut Self) {
+                ffi::$func(a, b, c, s);
+            }
+        }
+    };
+}
+
+impl_rotg!(f32, srotg_);
+impl_rotg!(f64, drotg_);
+impl_rotg!(c32, crotg_);
+impl_rotg!(c64, zrotg_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct ROT_Driver<'x, 'y, F>
+where
+    F: ROTNum,
+{
+    n: blas_int,
+    x: ArrayViewMut1<'x, F>,
+    incx: blas_int,
+    y: ArrayViewMut1<'y, F>,
+    incy: blas_int,
+    c: F::RealFloat,
+    s: F::RealFloat,
+}
+
+impl<'x, 'y, F> ROT_Driver<'x, 'y, F>
+where
+    F: ROTNum,
+{
+    pub fn run_blas(self) -> Result<(), BLASError> {
+        let Self { n, mut x, incx, mut y, incy, c, s } = self;
+
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok(());
+        }
+
+        // BLAS starts from the end of vector for negative increments
+        let x_ptr = unsafe { x.as_mut_ptr().offset(((n - 1) * incx.min(0)) as isize) };
+        let y_ptr = unsafe { y.as_mut_ptr().offset(((n - 1) * incy.min(0)) as isize) };
+        crate::record::blas_record!("ROT", dims: [n], flags: [], scalars: [c, s], arrays: [x, y]);
+        unsafe {
+            F::rot(&n, x_ptr, &incx, y_ptr, &incy, &c, &s);
+        }
+        Ok(())
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+/// Plane rotation $x_i \leftarrow c x_i + s y_i$, $y_i \leftarrow c y_i - s x_i$, in-place on `x` and `y`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct ROT_<'x, 'y, F>
+where
+    F: ROTNum,
+{
+    pub x: ArrayViewMut1<'x, F>,
+    pub y: ArrayViewMut1<'y, F>,
+
+    #[builder(setter(into))]
+    pub c: F::RealFloat,
+    #[builder(setter(into))]
+    pub s: F::RealFloat,
+}
+
+impl_builder_clone!(ROT_Builder<'x, 'y>, ROTNum, [c, s], [x, y]);
+
+impl<'x, 'y, F> ROT_<'x, 'y, F>
+where
+    F: ROTNum,
+{
+    pub fn driver(self) -> Result<ROT_Driver<'x, 'y, F>, BLASError> {
+        let Self { x, y, c, s } = self;
+
+        // initialize intent(hide)
+        let (n, incx) = get_len_inc_array1(&x.view(), None)?;
+        let (ny, incy) = get_len_inc_array1(&y.view(), None)?;
+
+        // perform check
+        blas_assert_eq!(n, ny, InvalidDim)?;
+        check_zero_stride(&x.view())?;
+        check_zero_stride(&y.view())?;
+
+        // finalize
+        let driver =
+            ROT_Driver { n: n.try_into()?, x, incx: incx.try_into()?, y, incy: incy.try_into()?, c, s };
+        Ok(driver)
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type ROT<'x, 'y, F> = ROT_Builder<'x, 'y, F>;
+pub type SROT<'x, 'y> = ROT<'x, 'y, f32>;
+pub type DROT<'x, 'y> = ROT<'x, 'y, f64>;
+pub type CSROT<'x, 'y> = ROT<'x, 'y, c32>;
+pub type ZDROT<'x, 'y> = ROT<'x, 'y, c64>;
+
+impl<'x, 'y, F> ROT<'x, 'y, F>
+where
+    F: ROTNum,
+{
+    pub fn run(self) -> Result<(), BLASError> {
+        self.build()?.driver()?.run_blas()
+    }
+}
+
+/// Construct Givens rotation by ROTG, such that
+/// $\begin{pmatrix} c & s \\ -\bar s & c \end{pmatrix} \begin{pmatrix} a \\ b \end{pmatrix} = \begin{pmatrix} r \\ 0 \end{pmatrix}$.
+///
+/// Returns `(r, c, s)`.
+pub fn rotg<F>(a: F, b: F) -> (F, F::RealFloat, F)
+where
+    F: ROTGNum,
+{
+    let (mut a, mut b) = (a, b);
+    let mut c = F::RealFloat::zero();
+    let mut s = F::zero();
+    unsafe {
+        F::rotg(&mut a, &mut b, &mut c, &mut s);
+    }
+    (a, c, s)
+}
+
+/* #endregion */
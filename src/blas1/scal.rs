@@ -0,0 +1,121 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region BLAS func */
+
+pub trait SCALNum: BLASFloat {
+    unsafe fn scal(n: *const blas_int, alpha: *const Self, x: *mut Self, incx: *const blas_int);
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl SCALNum for $type {
+            unsafe fn scal(n: *const blas_int, alpha: *const Self, x: *mut Self, incx: *const blas_int) {
+                ffi::$func(n, alpha, x, incx);
+            }
+        }
+    };
+}
+
+impl_func!(f32, sscal_);
+impl_func!(f64, dscal_);
+impl_func!(c32, cscal_);
+impl_func!(c64, zscal_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct SCAL_Driver<'x, F>
+where
+    F: SCALNum,
+{
+    n: blas_int,
+    alpha: F,
+    x: ArrayOut1<'x, F>,
+    incx: blas_int,
+}
+
+impl<'x, F> BLASDriver<'x, F, Ix1> for SCAL_Driver<'x, F>
+where
+    F: SCALNum,
+{
+    fn run_blas(self) -> Result<ArrayOut1<'x, F>, BLASError> {
+        let Self { n, alpha, mut x, incx } = self;
+
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok(x);
+        }
+
+        // BLAS starts from the end of vector for negative increments
+        let x_ptr = unsafe { x.get_data_mut_ptr().offset(((n - 1) * incx.min(0)) as isize) };
+        crate::record::blas_record!("SCAL", dims: [n], flags: [], scalars: [alpha], arrays: [x]);
+        unsafe {
+            F::scal(&n, &alpha, x_ptr, &incx);
+        }
+        Ok(x)
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct SCAL_<'x, F>
+where
+    F: SCALNum,
+{
+    pub x: ArrayViewMut1<'x, F>,
+
+    #[builder(setter(custom), default = "F::one()")]
+    pub alpha: F,
+    /// Raw increment override of `x`; `x` should be contiguous if this is set.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub incx: Option<blas_int>,
+}
+
+impl_builder_clone!(SCAL_Builder<'x>, SCALNum, [alpha, incx], [x]);
+impl_builder_scalar!(SCAL_Builder<'x>, SCALNum, [alpha]);
+
+impl<'x, F> BLASBuilder_<'x, F, Ix1> for SCAL_<'x, F>
+where
+    F: SCALNum,
+{
+    fn driver(self) -> Result<SCAL_Driver<'x, F>, BLASError> {
+        let Self { x, alpha, incx } = self;
+
+        // initialize intent(hide)
+        let (n, incx) = get_len_inc_array1(&x.view(), incx)?;
+
+        // finalize
+        let driver =
+            SCAL_Driver { n: n.try_into()?, alpha, x: ArrayOut1::ViewMut(x), incx: incx.try_into()? };
+        Ok(driver)
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type SCAL<'x, F> = SCAL_Builder<'x, F>;
+pub type SSCAL<'x> = SCAL<'x, f32>;
+pub type DSCAL<'x> = SCAL<'x, f64>;
+pub type CSCAL<'x> = SCAL<'x, c32>;
+pub type ZSCAL<'x> = SCAL<'x, c64>;
+
+impl<'x, F> BLASBuilder<'x, F, Ix1> for SCAL_Builder<'x, F>
+where
+    F: SCALNum,
+{
+    fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
+        self.build()?.driver()?.run_blas()
+    }
+}
+
+/* #endregion */
@@ -1,3 +1,4 @@
+use crate::blas2::gemv::{GEMVNum, GEMV};
 use crate::ffi::{self, blas_int, c_char};
 use crate::util::*;
 use derive_builder::Builder;
@@ -87,10 +88,14 @@ where
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
-        if n == 0 {
+        if y.view().is_empty() {
+            return Ok(y);
+        } else if m == 0 || n == 0 {
+            scale_by_beta(y.view_mut(), beta);
             return Ok(y);
         }
 
+        crate::record::blas_record!("GBMV", dims: [m, n, kl, ku], flags: [trans], scalars: [alpha, beta], arrays: [a, x, y]);
         unsafe {
             F::gbmv(&trans, &m, &n, &kl, &ku, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
         }
@@ -115,27 +120,43 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub y: Option<ArrayViewMut1<'y, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
-    #[builder(setter(into), default = "F::zero()")]
+    #[builder(setter(custom), default = "F::zero()")]
     pub beta: F,
     #[builder(setter(into), default = "BLASNoTrans")]
     pub trans: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(
+    GBMV_Builder<'a, 'x, 'y>,
+    GBMVNum,
+    [a, x, m, kl, alpha, beta, trans, layout, check_finite],
+    [y]
+);
+impl_builder_with_lda!(GBMV_Builder<'a, 'x, 'y>, GBMVNum);
+impl_builder_trans_str!(GBMV_Builder<'a, 'x, 'y>, GBMVNum, [trans => trans_str]);
+impl_builder_scalar!(GBMV_Builder<'a, 'x, 'y>, GBMVNum, [alpha, beta]);
+
 impl<'a, 'x, 'y, F> BLASBuilder_<'y, F, Ix1> for GBMV_<'a, 'x, 'y, F>
 where
     F: GBMVNum,
 {
     fn driver(self) -> Result<GBMV_Driver<'a, 'x, 'y, F>, BLASError> {
-        let Self { a, x, m, kl, y, alpha, beta, trans, layout } = self;
+        let Self { a, x, m, kl, y, alpha, beta, trans, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
-        assert!(layout == Some(BLASLayout::ColMajor));
+        blas_assert!(
+            layout_a.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
 
         // initialize intent(hide)
         let (k, n) = a.dim();
@@ -144,7 +165,7 @@ where
 
         // perform check
         blas_assert!(k > kl, InvalidDim)?;
-        blas_assert!(m >= k, InvalidDim)?;
+        blas_assert!(m >= k || m == 0 || n == 0, InvalidDim)?;
         let ku = k - 1 - kl;
         match trans {
             BLASNoTrans => blas_assert_eq!(x.len_of(Axis(0)), n, InvalidDim)?,
@@ -206,9 +227,19 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         // initialize
-        let GBMV_ { a, x, m, kl, y, alpha, beta, trans, layout } = self.build()?;
+        let GBMV_ { a, x, m, kl, y, alpha, beta, trans, layout, check_finite } = self.build()?;
+        check_zero_stride(&a)?;
+        check_zero_stride(&x)?;
+        if let Some(y) = y.as_ref() {
+            check_zero_stride(&y.view())?;
+        }
+        if check_finite {
+            check_finite_scalar(&[alpha, beta])?;
+            check_finite_array(&a)?;
+            check_finite_array(&x)?;
+        }
 
-        let layout_a = get_layout_array2(&a);
+        let layout_a = get_layout_array2_strict(&a);
         let layout = match layout {
             Some(layout) => layout,
             None => match layout_a {
@@ -222,7 +253,18 @@ where
         if layout == BLASColMajor {
             // F-contiguous
             let a_cow = a.to_col_layout()?;
-            let obj = GBMV_ { a: a_cow.view(), x, m, kl, y, alpha, beta, trans, layout: Some(BLASColMajor) };
+            let obj = GBMV_ {
+                a: a_cow.view(),
+                x,
+                m,
+                kl,
+                y,
+                alpha,
+                beta,
+                trans,
+                layout: Some(BLASColMajor),
+                check_finite,
+            };
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
@@ -243,6 +285,7 @@ where
                         beta,
                         trans: BLASTrans,
                         layout: Some(BLASColMajor),
+                        check_finite,
                     };
                     return obj.driver()?.run_blas();
                 },
@@ -258,6 +301,7 @@ where
                         beta,
                         trans: BLASNoTrans,
                         layout: Some(BLASColMajor),
+                        check_finite,
                     };
                     return obj.driver()?.run_blas();
                 },
@@ -278,6 +322,7 @@ where
                         beta: F::conj(beta),
                         trans: BLASNoTrans,
                         layout: Some(BLASColMajor),
+                        check_finite,
                     };
                     let mut y = obj.driver()?.run_blas()?;
                     y.view_mut().mapv_inplace(F::conj);
@@ -289,4 +334,50 @@ where
     }
 }
 
+impl<'a, 'x, 'y, F> GBMV_Builder<'a, 'x, 'y, F>
+where
+    F: GBMVNum + GEMVNum,
+{
+    /// Run by densifying band matrix `a` (see [`densify_band`]) and calling GEMV instead of GBMV.
+    ///
+    /// This is meant for debugging, or for BLAS backends that misbehave on band routines; it costs dense
+    /// memory and flops.
+    pub fn run_dense_fallback(self) -> Result<ArrayOut1<'y, F>, BLASError> {
+        // initialize
+        let GBMV_ { a, x, m, kl, y, alpha, beta, trans, layout, check_finite } = self.build()?;
+        check_zero_stride(&a)?;
+
+        let layout_a = get_layout_array2_strict(&a);
+        let layout = match layout {
+            Some(layout) => layout,
+            None => match layout_a {
+                BLASLayout::Sequential => BLASColMajor,
+                BLASRowMajor => BLASRowMajor,
+                BLASColMajor => BLASColMajor,
+                _ => blas_raise!(InvalidFlag, "Without defining layout, this function checks layout of input matrix `a` but it is not contiguous.")?,
+            }
+        };
+
+        let a_dense = match layout {
+            BLASColMajor => {
+                let k = a.len_of(Axis(0));
+                blas_assert!(k > kl, InvalidDim)?;
+                densify_band(&a, kl, k - 1 - kl, m, a.len_of(Axis(1)))?
+            },
+            _ => {
+                // row-major band storage of A is col-major band storage of A^T
+                let k = a.len_of(Axis(1));
+                blas_assert!(k > kl, InvalidDim)?;
+                densify_band(&a.t(), k - 1 - kl, kl, m, a.len_of(Axis(0)))?.reversed_axes()
+            },
+        };
+        let obj = GEMV::default().a(a_dense.view()).x(x).alpha(alpha).beta(beta).trans(trans);
+        let obj = obj.check_finite(check_finite);
+        match y {
+            Some(y) => obj.y(y).run(),
+            None => obj.run(),
+        }
+    }
+}
+
 /* #endregion */
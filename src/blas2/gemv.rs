@@ -1,6 +1,6 @@
 use crate::ffi::{self, blas_int, c_char};
 use crate::util::*;
-use derive_builder::Builder;
+use derive_builder::{Builder, UninitializedFieldError};
 use ndarray::prelude::*;
 
 /* #region BLAS func */
@@ -76,15 +76,22 @@ where
     fn run_blas(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         let Self { trans, m, n, alpha, a, lda, x, incx, beta, mut y, incy } = self;
         let a_ptr = a.as_ptr();
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
-        if m == 0 || n == 0 {
+        if y.view().is_empty() {
+            return Ok(y);
+        } else if m == 0 || n == 0 {
+            scale_by_beta(y.view_mut(), beta);
             return Ok(y);
         }
 
+        // BLAS starts from the end of vector for negative increments
+        let x_ptr = unsafe { x.as_ptr().offset((x.len() as isize - 1) * incx.min(0) as isize) };
+        let y_len = y.view().len() as isize;
+        let y_ptr = unsafe { y.get_data_mut_ptr().offset((y_len - 1) * incy.min(0) as isize) };
+
+        crate::record::blas_record!("GEMV", dims: [m, n], flags: [trans], scalars: [alpha, beta], arrays: [a, x, y]);
         unsafe {
             F::gemv(&trans, &m, &n, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
         }
@@ -103,16 +110,50 @@ where
     F: GEMVNum,
 {
     pub a: ArrayView2<'a, F>,
+    /// Vector `x`; matrix-shaped vector (`n x 1` or `1 x n`) is also accepted by setter, see [`AsVector`].
+    #[builder(
+        setter(custom),
+        field(
+            ty = "Option<Result<ArrayView1<'x, F>, BLASError>>",
+            build = "self.x.ok_or(UninitializedFieldError::new(\"x\"))??"
+        )
+    )]
     pub x: ArrayView1<'x, F>,
 
-    #[builder(setter(into, strip_option), default = "None")]
+    /// Vector `y`; matrix-shaped vector (`n x 1` or `1 x n`) is also accepted by setter, see [`AsVectorMut`].
+    #[builder(
+        setter(custom),
+        field(ty = "Option<Result<ArrayViewMut1<'y, F>, BLASError>>", build = "self.y.transpose()?")
+    )]
     pub y: Option<ArrayViewMut1<'y, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
-    #[builder(setter(into), default = "F::zero()")]
+    #[builder(setter(custom), default = "F::zero()")]
     pub beta: F,
     #[builder(setter(into), default = "BLASNoTrans")]
     pub trans: BLASTranspose,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
+}
+
+impl_builder_clone!(GEMV_Builder<'a, 'x, 'y>, GEMVNum, [a, x, alpha, beta, trans, check_finite], [y]);
+impl_builder_with_lda!(GEMV_Builder<'a, 'x, 'y>, GEMVNum);
+impl_builder_trans_str!(GEMV_Builder<'a, 'x, 'y>, GEMVNum, [trans => trans_str]);
+impl_builder_scalar!(GEMV_Builder<'a, 'x, 'y>, GEMVNum, [alpha, beta]);
+
+impl<'a, 'x, 'y, F> GEMV_Builder<'a, 'x, 'y, F>
+where
+    F: GEMVNum,
+{
+    pub fn x(mut self, x: impl AsVector<'x, F>) -> Self {
+        self.x = Some(x.into_vector());
+        self
+    }
+
+    pub fn y(mut self, y: impl AsVectorMut<'y, F>) -> Self {
+        self.y = Some(y.into_vector_mut());
+        self
+    }
 }
 
 impl<'a, 'x, 'y, F> BLASBuilder_<'y, F, Ix1> for GEMV_<'a, 'x, 'y, F>
@@ -120,11 +161,15 @@ where
     F: GEMVNum,
 {
     fn driver(self) -> Result<GEMV_Driver<'a, 'x, 'y, F>, BLASError> {
-        let Self { a, x, y, alpha, beta, trans } = self;
+        let Self { a, x, y, alpha, beta, trans, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
+        blas_assert!(
+            layout_a.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
 
         // initialize intent(hide)
         let (m, n) = a.dim();
@@ -191,6 +236,16 @@ where
     fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.a)?;
+        check_zero_stride(&obj.x)?;
+        if let Some(y) = obj.y.as_ref() {
+            check_zero_stride(&y.view())?;
+        }
+        if obj.check_finite {
+            check_finite_scalar(&[obj.alpha, obj.beta])?;
+            check_finite_array(&obj.a)?;
+            check_finite_array(&obj.x)?;
+        }
 
         let layout_a = get_layout_array2(&obj.a);
 
@@ -225,6 +280,7 @@ where
                         y,
                         alpha: F::conj(obj.alpha),
                         beta: F::conj(obj.beta),
+                        check_finite: obj.check_finite,
                     };
                     let mut y = obj.driver()?.run_blas()?;
                     y.view_mut().mapv_inplace(F::conj);
@@ -69,8 +69,6 @@ where
 {
     fn run_blas(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         let Self { m, n, alpha, x, incx, y, incy, mut a, lda } = self;
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.as_ptr();
         let a_ptr = a.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
@@ -79,6 +77,11 @@ where
             return Ok(a.clone_to_view_mut());
         }
 
+        // BLAS starts from the end of vector for negative increments
+        let x_ptr = unsafe { x.as_ptr().offset((x.len() as isize - 1) * incx.min(0) as isize) };
+        let y_ptr = unsafe { y.as_ptr().offset((y.len() as isize - 1) * incy.min(0) as isize) };
+
+        crate::record::blas_record!("GER", dims: [m, n], flags: [], scalars: [alpha], arrays: [x, y, a]);
         unsafe {
             F::ger(&m, &n, &alpha, x_ptr, &incx, y_ptr, &incy, a_ptr, &lda);
         }
@@ -101,16 +104,21 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub a: Option<ArrayViewMut2<'a, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(GER_Builder<'x, 'y, 'a>, GERNum, [x, y, alpha, check_finite], [a]);
+impl_builder_scalar!(GER_Builder<'x, 'y, 'a>, GERNum, [alpha]);
+
 impl<'x, 'y, 'a, F> BLASBuilder_<'a, F, Ix2> for GER_<'x, 'y, 'a, F>
 where
     F: GERNum,
 {
     fn driver(self) -> Result<GER_Driver<'x, 'y, 'a, F>, BLASError> {
-        let Self { x, y, a, alpha } = self;
+        let Self { x, y, a, alpha, .. } = self;
 
         // initialize intent(hide)
         let incx = x.stride_of(Axis(0));
@@ -166,6 +174,16 @@ where
     fn run(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.x)?;
+        check_zero_stride(&obj.y)?;
+        if let Some(a) = obj.a.as_ref() {
+            check_zero_stride(&a.view())?;
+        }
+        if obj.check_finite {
+            check_finite_scalar(&[obj.alpha])?;
+            check_finite_array(&obj.x)?;
+            check_finite_array(&obj.y)?;
+        }
 
         if obj.a.as_ref().map(|a| a.view().is_fpref()) == Some(true) {
             // F-contiguous
@@ -68,8 +68,6 @@ where
 {
     fn run_blas(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         let Self { m, n, alpha, x, incx, y, incy, mut a, lda } = self;
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.as_ptr();
         let a_ptr = a.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
@@ -78,6 +76,11 @@ where
             return Ok(a.clone_to_view_mut());
         }
 
+        // BLAS starts from the end of vector for negative increments
+        let x_ptr = unsafe { x.as_ptr().offset((x.len() as isize - 1) * incx.min(0) as isize) };
+        let y_ptr = unsafe { y.as_ptr().offset((y.len() as isize - 1) * incy.min(0) as isize) };
+
+        crate::record::blas_record!("GERC", dims: [m, n], flags: [], scalars: [alpha], arrays: [x, y, a]);
         unsafe {
             F::gerc(&m, &n, &alpha, x_ptr, &incx, y_ptr, &incy, a_ptr, &lda);
         }
@@ -100,16 +103,21 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub a: Option<ArrayViewMut2<'a, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(GERC_Builder<'x, 'y, 'a>, BLASFloat, [x, y, alpha, check_finite], [a]);
+impl_builder_scalar!(GERC_Builder<'x, 'y, 'a>, BLASFloat, [alpha]);
+
 impl<'x, 'y, 'a, F> BLASBuilder_<'a, F, Ix2> for GERC_<'x, 'y, 'a, F>
 where
     F: GERCNum,
 {
     fn driver(self) -> Result<GERC_Driver<'x, 'y, 'a, F>, BLASError> {
-        let Self { x, y, a, alpha } = self;
+        let Self { x, y, a, alpha, .. } = self;
 
         // initialize intent(hide)
         let incx = x.stride_of(Axis(0));
@@ -163,6 +171,16 @@ where
     fn run(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.x)?;
+        check_zero_stride(&obj.y)?;
+        if let Some(a) = obj.a.as_ref() {
+            check_zero_stride(&a.view())?;
+        }
+        if obj.check_finite {
+            check_finite_scalar(&[obj.alpha])?;
+            check_finite_array(&obj.x)?;
+            check_finite_array(&obj.y)?;
+        }
 
         if obj.a.as_ref().map(|a| a.view().is_fpref()) == Some(true) {
             // F-contiguous
@@ -171,7 +189,7 @@ where
             // C-contiguous
             let a = obj.a.map(|a| a.reversed_axes());
             let y = obj.y.mapv(F::conj);
-            let obj = GER_ { a, x: y.view(), y: obj.x, alpha: obj.alpha };
+            let obj = GER_ { a, x: y.view(), y: obj.x, alpha: obj.alpha, check_finite: obj.check_finite };
             let a = obj.driver()?.run_blas()?;
             return Ok(a.reversed_axes());
         }
@@ -1,3 +1,4 @@
+use crate::blas2::hemv::{HEMVNum, HEMV};
 use crate::ffi::{self, blas_int, c_char};
 use crate::util::*;
 use derive_builder::Builder;
@@ -85,6 +86,7 @@ where
             return Ok(y);
         }
 
+        crate::record::blas_record!("HBMV", dims: [n, k], flags: [uplo], scalars: [alpha, beta], arrays: [a, x, y]);
         unsafe {
             F::hbmv(&uplo, &n, &k, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
         }
@@ -97,7 +99,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct HBMV_<'a, 'x, 'y, F>
 where
     F: HBMVNum,
@@ -107,16 +109,23 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub y: Option<ArrayViewMut1<'y, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
-    #[builder(setter(into), default = "F::zero()")]
+    #[builder(setter(custom), default = "F::zero()")]
     pub beta: F,
     #[builder(setter(into), default = "BLASUpper")]
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(HBMV_Builder<'a, 'x, 'y>, HBMVNum, [a, x, alpha, beta, uplo, layout, check_finite], [y]);
+impl_builder_with_lda!(HBMV_Builder<'a, 'x, 'y>, HBMVNum);
+impl_builder_validate_uplo!(HBMV_Builder<'a, 'x, 'y>, HBMVNum);
+impl_builder_scalar!(HBMV_Builder<'a, 'x, 'y>, HBMVNum, [alpha, beta]);
+
 impl<'a, 'x, 'y, F> BLASBuilder_<'y, F, Ix1> for HBMV_<'a, 'x, 'y, F>
 where
     F: HBMVNum,
@@ -126,8 +135,12 @@ where
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
-        assert!(layout == Some(BLASLayout::ColMajor));
+        blas_assert!(
+            layout_a.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
 
         // initialize intent(hide)
         let (k_, n) = a.dim();
@@ -184,8 +197,18 @@ where
     fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.a)?;
+        check_zero_stride(&obj.x)?;
+        if let Some(y) = obj.y.as_ref() {
+            check_zero_stride(&y.view())?;
+        }
+        if obj.check_finite {
+            check_finite_scalar(&[obj.alpha, obj.beta])?;
+            check_finite_array(&obj.a)?;
+            check_finite_array(&obj.x)?;
+        }
 
-        let layout_a = get_layout_array2(&obj.a);
+        let layout_a = get_layout_array2_strict(&obj.a);
         let layout = get_layout_row_preferred(&[obj.layout, Some(layout_a)], &[]);
 
         if layout == BLASColMajor {
@@ -223,4 +246,31 @@ where
     }
 }
 
+impl<'a, 'x, 'y, F> HBMV_Builder<'a, 'x, 'y, F>
+where
+    F: HBMVNum + HEMVNum,
+{
+    /// Run by densifying band matrix `a` (see [`densify_band`]) and calling HEMV (SYMV for real types)
+    /// instead of the band routine.
+    ///
+    /// This is meant for debugging, or for BLAS backends that misbehave on band routines; it costs dense
+    /// memory and flops.
+    pub fn run_dense_fallback(self) -> Result<ArrayOut1<'y, F>, BLASError> {
+        // initialize
+        let obj = self.build()?;
+        check_zero_stride(&obj.a)?;
+
+        let layout_a = get_layout_array2_strict(&obj.a);
+        let layout = get_layout_row_preferred(&[obj.layout, Some(layout_a)], &[]);
+
+        let a_dense = densify_band_triangle(&obj.a, obj.uplo, layout)?;
+        let hemv = HEMV::default().a(a_dense.view()).x(obj.x).alpha(obj.alpha).beta(obj.beta).uplo(obj.uplo);
+        let hemv = hemv.check_finite(obj.check_finite);
+        match obj.y {
+            Some(y) => hemv.y(y).run(),
+            None => hemv.run(),
+        }
+    }
+}
+
 /* #endregion */
@@ -82,6 +82,7 @@ where
             return Ok(y);
         }
 
+        crate::record::blas_record!("HEMV", dims: [n], flags: [uplo], scalars: [alpha, beta], arrays: [a, x, y]);
         unsafe {
             F::hemv(&uplo, &n, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
         }
@@ -94,7 +95,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct HEMV_<'a, 'x, 'y, F>
 where
     F: BLASFloat,
@@ -104,14 +105,33 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub y: Option<ArrayViewMut1<'y, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
-    #[builder(setter(into), default = "F::zero()")]
+    #[builder(setter(custom), default = "F::zero()")]
     pub beta: F,
     #[builder(setter(into), default = "BLASUpper")]
     pub uplo: BLASUpLo,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
+    /// Pick `uplo` by the triangle of `a` that holds data, when the other strict triangle is exactly zero.
+    ///
+    /// This guards against silently wrong results when data is stored in the triangle opposite to `uplo`.
+    /// The override is reported by feature `trace`.
+    #[builder(setter(into), default = "false")]
+    pub auto_uplo_from_data: bool,
 }
 
+impl_builder_clone!(
+    HEMV_Builder<'a, 'x, 'y>,
+    BLASFloat,
+    [a, x, alpha, beta, uplo, check_finite, auto_uplo_from_data],
+    [y]
+);
+impl_builder_with_lda!(HEMV_Builder<'a, 'x, 'y>, BLASFloat);
+impl_builder_validate_uplo!(HEMV_Builder<'a, 'x, 'y>, BLASFloat);
+impl_builder_symmetric!(HEMV_Builder<'a, 'x, 'y>, BLASFloat);
+impl_builder_scalar!(HEMV_Builder<'a, 'x, 'y>, BLASFloat, [alpha, beta]);
+
 impl<'a, 'x, 'y, F> BLASBuilder_<'y, F, Ix1> for HEMV_<'a, 'x, 'y, F>
 where
     F: HEMVNum,
@@ -121,7 +141,11 @@ where
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
+        blas_assert!(
+            layout_a.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
 
         // initialize intent(hide)
         let (n_, n) = a.dim();
@@ -175,7 +199,20 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         // initialize
-        let obj = self.build()?;
+        let mut obj = self.build()?;
+        check_zero_stride(&obj.a)?;
+        check_zero_stride(&obj.x)?;
+        if let Some(y) = obj.y.as_ref() {
+            check_zero_stride(&y.view())?;
+        }
+        if obj.auto_uplo_from_data {
+            obj.uplo = uplo_from_data(&obj.a, obj.uplo);
+        }
+        if obj.check_finite {
+            check_finite_scalar(&[obj.alpha, obj.beta])?;
+            check_finite_array(&obj.a)?;
+            check_finite_array(&obj.x)?;
+        }
 
         let layout_a = get_layout_array2(&obj.a);
 
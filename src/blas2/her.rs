@@ -73,6 +73,7 @@ where
             return Ok(a.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("HER", dims: [n], flags: [uplo], scalars: [alpha], arrays: [x, a]);
         unsafe {
             F::her(&uplo, &n, &alpha, x_ptr, &incx, a_ptr, &lda);
         }
@@ -85,7 +86,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct HER_<'x, 'a, F>
 where
     F: HERNum,
@@ -98,8 +99,13 @@ where
     pub alpha: F::RealFloat,
     #[builder(setter(into), default = "BLASUpper")]
     pub uplo: BLASUpLo,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(HER_Builder<'x, 'a>, HERNum, [x, alpha, uplo, check_finite], [a]);
+impl_builder_validate_uplo!(HER_Builder<'x, 'a>, HERNum);
+
 impl<'x, 'a, F> BLASBuilder_<'a, F, Ix2> for HER_<'x, 'a, F>
 where
     F: HERNum,
@@ -157,6 +163,14 @@ where
     fn run(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.x)?;
+        if let Some(a) = obj.a.as_ref() {
+            check_zero_stride(&a.view())?;
+        }
+        if obj.check_finite {
+            check_finite_scalar(&[obj.alpha])?;
+            check_finite_array(&obj.x)?;
+        }
 
         if obj.a.as_ref().map(|a| a.view().is_fpref()) == Some(true) {
             // F-contiguous
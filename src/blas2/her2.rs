@@ -79,6 +79,7 @@ where
             return Ok(a.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("HER2", dims: [n], flags: [uplo], scalars: [alpha], arrays: [x, y, a]);
         unsafe {
             F::syr2(&uplo, &n, &alpha, x_ptr, &incx, y_ptr, &incy, a_ptr, &lda);
         }
@@ -91,7 +92,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct HER2_<'x, 'y, 'a, F>
 where
     F: BLASFloat,
@@ -101,12 +102,18 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub a: Option<ArrayViewMut2<'a, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
     #[builder(setter(into), default = "BLASUpper")]
     pub uplo: BLASUpLo,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(HER2_Builder<'x, 'y, 'a>, BLASFloat, [x, y, alpha, uplo, check_finite], [a]);
+impl_builder_validate_uplo!(HER2_Builder<'x, 'y, 'a>, BLASFloat);
+impl_builder_scalar!(HER2_Builder<'x, 'y, 'a>, BLASFloat, [alpha]);
+
 impl<'x, 'y, 'a, F> BLASBuilder_<'a, F, Ix2> for HER2_<'x, 'y, 'a, F>
 where
     F: HER2Num,
@@ -172,6 +179,16 @@ where
     fn run(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.x)?;
+        check_zero_stride(&obj.y)?;
+        if let Some(a) = obj.a.as_ref() {
+            check_zero_stride(&a.view())?;
+        }
+        if obj.check_finite {
+            check_finite_scalar(&[obj.alpha])?;
+            check_finite_array(&obj.x)?;
+            check_finite_array(&obj.y)?;
+        }
 
         if obj.a.as_ref().map(|a| a.view().is_fpref()) == Some(true) {
             // F-contiguous
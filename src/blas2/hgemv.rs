@@ -0,0 +1,75 @@
+use crate::blas2::gemv::GEMV;
+use crate::util::*;
+use derive_builder::Builder;
+use half::f16;
+use ndarray::{prelude::*, Zip};
+
+/* #region BLAS builder */
+
+/// Half-precision matrix-vector multiply, accumulated in single precision.
+///
+/// $\mathbf{y} = \alpha \mathrm{op} (\mathbf{A}) \mathbf{x} + \beta \mathbf{y}$, computed by widening
+/// $\mathbf{A}$, $\mathbf{x}$ (and $\mathbf{y}$ for nonzero $\beta$) to `f32`, calling SGEMV, and rounding the
+/// result back to `f16`. So sums are accumulated in `f32`, and only the final result is rounded to `f16`;
+/// intermediate values may exceed the range of `f16` as long as the result does not. `f16` does not implement
+/// [`BLASFloat`], since BLAS has no half-precision routines.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct HGEMV_<'a, 'x, 'y> {
+    pub a: ArrayView2<'a, f16>,
+    pub x: ArrayView1<'x, f16>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub y: Option<ArrayViewMut1<'y, f16>>,
+    #[builder(setter(into), default = "1.0")]
+    pub alpha: f32,
+    #[builder(setter(into), default = "0.0")]
+    pub beta: f32,
+    #[builder(setter(into), default = "BLASNoTrans")]
+    pub trans: BLASTranspose,
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type HGEMV<'a, 'x, 'y> = HGEMV_Builder<'a, 'x, 'y>;
+
+impl<'a, 'x, 'y> BLASBuilder<'y, f16, Ix1> for HGEMV_Builder<'a, 'x, 'y> {
+    fn run(self) -> Result<ArrayOut1<'y, f16>, BLASError> {
+        // initialize
+        let HGEMV_ { a, x, y, alpha, beta, trans } = self.build()?;
+
+        // widen to f32; `y` is only read for nonzero beta
+        let a_f32 = a.mapv(f16::to_f32);
+        let x_f32 = x.mapv(f16::to_f32);
+        let y_f32 = match (&y, beta == 0.0) {
+            (Some(y), false) => Some(y.mapv(f16::to_f32)),
+            _ => None,
+        };
+
+        let builder = GEMV::default().a(a_f32.view()).x(x_f32.view()).alpha(alpha).beta(beta).trans(trans);
+        let y_calc = match y_f32 {
+            Some(mut y_f32) => {
+                builder.y(y_f32.view_mut()).run()?;
+                y_f32
+            },
+            None => builder.run()?.into_owned(),
+        };
+
+        // optional intent(out)
+        let mut y = match y {
+            Some(y) => {
+                blas_assert_eq!(y.dim(), y_calc.dim(), InvalidDim)?;
+                ArrayOut1::ViewMut(y)
+            },
+            None => ArrayOut1::Owned(Array1::from_elem(y_calc.dim(), f16::ZERO)),
+        };
+
+        // narrow back to f16
+        Zip::from(y.view_mut()).and(&y_calc).for_each(|y, &v| *y = f16::from_f32(v));
+        Ok(y)
+    }
+}
+
+/* #endregion */
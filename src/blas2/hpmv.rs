@@ -79,6 +79,7 @@ where
             return Ok(y);
         }
 
+        crate::record::blas_record!("HPMV", dims: [n], flags: [uplo], scalars: [alpha, beta], arrays: [ap, x, y]);
         unsafe {
             F::hpmv(&uplo, &n, &alpha, ap_ptr, x_ptr, &incx, &beta, y_ptr, &incy);
         }
@@ -91,7 +92,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct HPMV_<'a, 'x, 'y, F>
 where
     F: HPMVNum,
@@ -101,16 +102,22 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub y: Option<ArrayViewMut1<'y, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
-    #[builder(setter(into), default = "F::zero()")]
+    #[builder(setter(custom), default = "F::zero()")]
     pub beta: F,
     #[builder(setter(into), default = "BLASUpper")]
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(HPMV_Builder<'a, 'x, 'y>, HPMVNum, [ap, x, alpha, beta, uplo, layout, check_finite], [y]);
+impl_builder_validate_uplo!(HPMV_Builder<'a, 'x, 'y>, HPMVNum);
+impl_builder_scalar!(HPMV_Builder<'a, 'x, 'y>, HPMVNum, [alpha, beta]);
+
 impl<'a, 'x, 'y, F> BLASBuilder_<'y, F, Ix1> for HPMV_<'a, 'x, 'y, F>
 where
     F: HPMVNum,
@@ -119,9 +126,10 @@ where
         let Self { ap, x, y, alpha, beta, uplo, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
+        // packed array is passed to BLAS without increment, so it must be contiguous
         let incap = ap.stride_of(Axis(0));
-        assert!(incap <= 1);
-        assert_eq!(layout, Some(BLASColMajor));
+        blas_assert!(ap.len() <= 1 || incap == 1, InvalidStride, "packed array `ap` should be contiguous")?;
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
 
         // initialize intent(hide)
         let np = ap.len_of(Axis(0));
@@ -174,6 +182,16 @@ where
     fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.ap)?;
+        check_zero_stride(&obj.x)?;
+        if let Some(y) = obj.y.as_ref() {
+            check_zero_stride(&y.view())?;
+        }
+        if obj.check_finite {
+            check_finite_scalar(&[obj.alpha, obj.beta])?;
+            check_finite_array(&obj.ap)?;
+            check_finite_array(&obj.x)?;
+        }
 
         let layout = obj.layout.unwrap_or(BLASRowMajor);
 
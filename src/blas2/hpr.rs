@@ -70,6 +70,7 @@ where
             return Ok(ap.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("HPR", dims: [n], flags: [uplo], scalars: [alpha], arrays: [x, ap]);
         unsafe {
             F::hpr(&uplo, &n, &alpha, x_ptr, &incx, ap_ptr);
         }
@@ -82,7 +83,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct HPR_<'x, 'a, F>
 where
     F: HPRNum,
@@ -97,8 +98,13 @@ where
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(HPR_Builder<'x, 'a>, HPRNum, [x, alpha, uplo, layout, check_finite], [ap]);
+impl_builder_validate_uplo!(HPR_Builder<'x, 'a>, HPRNum);
+
 impl<'x, 'a, F> BLASBuilder_<'a, F, Ix1> for HPR_<'x, 'a, F>
 where
     F: HPRNum,
@@ -111,7 +117,7 @@ where
         let n = x.len_of(Axis(0));
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
 
         // prepare output
         let ap = match ap {
@@ -151,6 +157,14 @@ where
     fn run(self) -> Result<ArrayOut1<'a, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.x)?;
+        if let Some(ap) = obj.ap.as_ref() {
+            check_zero_stride(&ap.view())?;
+        }
+        if obj.check_finite {
+            check_finite_scalar(&[obj.alpha])?;
+            check_finite_array(&obj.x)?;
+        }
 
         if obj.layout == Some(BLASColMajor) {
             // F-contiguous
@@ -76,6 +76,7 @@ where
             return Ok(ap.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("HPR2", dims: [n], flags: [uplo], scalars: [alpha], arrays: [x, y, ap]);
         unsafe {
             F::hpr2(&uplo, &n, &alpha, x_ptr, &incx, y_ptr, &incy, ap_ptr);
         }
@@ -88,7 +89,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct HPR2_<'x, 'y, 'a, F>
 where
     F: HPR2Num,
@@ -98,14 +99,20 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub ap: Option<ArrayViewMut1<'a, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
     #[builder(setter(into), default = "BLASUpper")]
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(HPR2_Builder<'x, 'y, 'a>, HPR2Num, [x, y, alpha, uplo, layout, check_finite], [ap]);
+impl_builder_validate_uplo!(HPR2_Builder<'x, 'y, 'a>, HPR2Num);
+impl_builder_scalar!(HPR2_Builder<'x, 'y, 'a>, HPR2Num, [alpha]);
+
 impl<'x, 'y, 'a, F> BLASBuilder_<'a, F, Ix1> for HPR2_<'x, 'y, 'a, F>
 where
     F: HPR2Num,
@@ -119,7 +126,7 @@ where
         let n = x.len_of(Axis(0));
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
 
         // check optional
         blas_assert_eq!(y.len_of(Axis(0)), n, InvalidDim)?;
@@ -170,6 +177,16 @@ where
     fn run(self) -> Result<ArrayOut1<'a, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.x)?;
+        check_zero_stride(&obj.y)?;
+        if let Some(ap) = obj.ap.as_ref() {
+            check_zero_stride(&ap.view())?;
+        }
+        if obj.check_finite {
+            check_finite_scalar(&[obj.alpha])?;
+            check_finite_array(&obj.x)?;
+            check_finite_array(&obj.y)?;
+        }
 
         if obj.layout == Some(BLASColMajor) {
             // F-contiguous
@@ -15,3 +15,7 @@ pub mod tpmv;
 pub mod tpsv;
 pub mod trmv;
 pub mod trsv;
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+pub mod hgemv;
@@ -1,3 +1,4 @@
+use crate::blas2::trmv::{TRMVNum, TRMV};
 use crate::ffi::{self, blas_int, c_char};
 use crate::util::*;
 use derive_builder::Builder;
@@ -78,6 +79,7 @@ where
             return Ok(x);
         }
 
+        crate::record::blas_record!("TBMV", dims: [n, k], flags: [uplo, trans, diag], scalars: [], arrays: [a, x]);
         unsafe {
             F::tbmv(&uplo, &trans, &diag, &n, &k, a_ptr, &lda, x_ptr, &incx);
         }
@@ -90,7 +92,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct TBMV_<'a, 'x, F>
 where
     F: TBMVNum,
@@ -108,17 +110,26 @@ where
     pub layout: Option<BLASLayout>,
 }
 
+impl_builder_clone!(TBMV_Builder<'a, 'x>, TBMVNum, [a, uplo, trans, diag, layout], [x]);
+impl_builder_with_lda!(TBMV_Builder<'a, 'x>, TBMVNum);
+impl_builder_validate_uplo!(TBMV_Builder<'a, 'x>, TBMVNum);
+impl_builder_trans_str!(TBMV_Builder<'a, 'x>, TBMVNum, [trans => trans_str]);
+
 impl<'a, 'x, F> BLASBuilder_<'x, F, Ix1> for TBMV_<'a, 'x, F>
 where
     F: TBMVNum,
 {
     fn driver(self) -> Result<TBMV_Driver<'a, 'x, F>, BLASError> {
-        let Self { a, x, uplo, trans, diag, layout } = self;
+        let Self { a, x, uplo, trans, diag, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
-        assert!(layout == Some(BLASLayout::ColMajor));
+        blas_assert!(
+            layout_a.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
 
         // initialize intent(hide)
         let (k_, n) = a.dim();
@@ -166,8 +177,10 @@ where
     fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.a)?;
+        check_zero_stride(&obj.x.view())?;
 
-        let layout_a = get_layout_array2(&obj.a);
+        let layout_a = get_layout_array2_strict(&obj.a);
         let layout = get_layout_row_preferred(&[obj.layout, Some(layout_a)], &[]);
 
         if layout == BLASColMajor {
@@ -223,4 +236,25 @@ where
     }
 }
 
+impl<'a, 'x, F> TBMV_Builder<'a, 'x, F>
+where
+    F: TBMVNum + TRMVNum,
+{
+    /// Run by densifying band matrix `a` (see [`densify_band`]) and calling TRMV instead of TBMV.
+    ///
+    /// This is meant for debugging, or for BLAS backends that misbehave on band routines; it costs dense
+    /// memory and flops.
+    pub fn run_dense_fallback(self) -> Result<ArrayOut1<'x, F>, BLASError> {
+        // initialize
+        let obj = self.build()?;
+        check_zero_stride(&obj.a)?;
+
+        let layout_a = get_layout_array2_strict(&obj.a);
+        let layout = get_layout_row_preferred(&[obj.layout, Some(layout_a)], &[]);
+
+        let a_dense = densify_band_triangle(&obj.a, obj.uplo, layout)?;
+        TRMV::default().a(a_dense.view()).x(obj.x).uplo(obj.uplo).trans(obj.trans).diag(obj.diag).run()
+    }
+}
+
 /* #endregion */
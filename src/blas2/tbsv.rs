@@ -78,6 +78,7 @@ where
             return Ok(x);
         }
 
+        crate::record::blas_record!("TBSV", dims: [n, k], flags: [uplo, trans, diag], scalars: [], arrays: [a, x]);
         unsafe {
             F::tbsv(&uplo, &trans, &diag, &n, &k, a_ptr, &lda, x_ptr, &incx);
         }
@@ -90,7 +91,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct TBSV_<'a, 'x, F>
 where
     F: TBSVNum,
@@ -108,6 +109,11 @@ where
     pub layout: Option<BLASLayout>,
 }
 
+impl_builder_clone!(TBSV_Builder<'a, 'x>, TBSVNum, [a, uplo, trans, diag, layout], [x]);
+impl_builder_with_lda!(TBSV_Builder<'a, 'x>, TBSVNum);
+impl_builder_validate_uplo!(TBSV_Builder<'a, 'x>, TBSVNum);
+impl_builder_trans_str!(TBSV_Builder<'a, 'x>, TBSVNum, [trans => trans_str]);
+
 impl<'a, 'x, F> BLASBuilder_<'x, F, Ix1> for TBSV_<'a, 'x, F>
 where
     F: TBSVNum,
@@ -117,8 +123,12 @@ where
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
-        assert!(layout == Some(BLASLayout::ColMajor));
+        blas_assert!(
+            layout_a.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
 
         // initialize intent(hide)
         let (k_, n) = a.dim();
@@ -166,8 +176,10 @@ where
     fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.a)?;
+        check_zero_stride(&obj.x.view())?;
 
-        let layout_a = get_layout_array2(&obj.a);
+        let layout_a = get_layout_array2_strict(&obj.a);
         let layout = get_layout_row_preferred(&[obj.layout, Some(layout_a)], &[]);
 
         if layout == BLASColMajor {
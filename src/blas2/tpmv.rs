@@ -72,6 +72,7 @@ where
             return Ok(x);
         }
 
+        crate::record::blas_record!("TPMV", dims: [n], flags: [uplo, trans, diag], scalars: [], arrays: [ap, x]);
         unsafe {
             F::tpmv(&uplo, &trans, &diag, &n, ap_ptr, x_ptr, &incx);
         }
@@ -84,7 +85,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct TPMV_<'a, 'x, F>
 where
     F: TPMVNum,
@@ -100,19 +101,30 @@ where
     pub diag: BLASDiag,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    /// Element order of `ap`, if it does not follow the BLAS packing order of `uplo` (the default).
+    ///
+    /// When this disagrees with `uplo`, `ap` is repacked into the order of `uplo` before calling BLAS.
+    /// See [`PackedOrder`].
+    #[builder(setter(into, strip_option), default = "None")]
+    pub packed_order: Option<PackedOrder>,
 }
 
+impl_builder_clone!(TPMV_Builder<'a, 'x>, TPMVNum, [ap, uplo, trans, diag, layout, packed_order], [x]);
+impl_builder_validate_uplo!(TPMV_Builder<'a, 'x>, TPMVNum);
+impl_builder_trans_str!(TPMV_Builder<'a, 'x>, TPMVNum, [trans => trans_str]);
+
 impl<'a, 'x, F> BLASBuilder_<'x, F, Ix1> for TPMV_<'a, 'x, F>
 where
     F: TPMVNum,
 {
     fn driver(self) -> Result<TPMV_Driver<'a, 'x, F>, BLASError> {
-        let Self { ap, x, uplo, trans, diag, layout } = self;
+        let Self { ap, x, uplo, trans, diag, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
+        // packed array is passed to BLAS without increment, so it must be contiguous
         let incap = ap.stride_of(Axis(0));
-        assert!(incap <= 1);
-        assert_eq!(layout, Some(BLASColMajor));
+        blas_assert!(ap.len() <= 1 || incap == 1, InvalidStride, "packed array `ap` should be contiguous")?;
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
 
         // initialize intent(hide)
         let np = ap.len_of(Axis(0));
@@ -156,8 +168,17 @@ where
     fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.ap)?;
+        check_zero_stride(&obj.x.view())?;
 
         let layout = obj.layout.unwrap_or(BLASRowMajor);
+        let ap_repacked = match obj.packed_order {
+            Some(order) if !order.matches(obj.uplo) => {
+                CowArray::from(packed_reorder(&obj.ap, obj.x.len_of(Axis(0)), obj.uplo, layout)?)
+            },
+            _ => CowArray::from(obj.ap.view()),
+        };
+        let obj = TPMV_ { ap: ap_repacked.view(), packed_order: None, ..obj };
 
         if layout == BLASColMajor {
             // F-contiguous
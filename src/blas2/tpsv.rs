@@ -72,6 +72,7 @@ where
             return Ok(x);
         }
 
+        crate::record::blas_record!("TPSV", dims: [n], flags: [uplo, trans, diag], scalars: [], arrays: [ap, x]);
         unsafe {
             F::tpsv(&uplo, &trans, &diag, &n, ap_ptr, x_ptr, &incx);
         }
@@ -84,7 +85,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct TPSV_<'a, 'x, F>
 where
     F: TPSVNum,
@@ -100,19 +101,40 @@ where
     pub diag: BLASDiag,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    /// Element order of `ap`, if it does not follow the BLAS packing order of `uplo` (the default).
+    ///
+    /// When this disagrees with `uplo`, `ap` is repacked into the order of `uplo` before calling BLAS.
+    /// See [`PackedOrder`].
+    #[builder(setter(into, strip_option), default = "None")]
+    pub packed_order: Option<PackedOrder>,
+    /// Report (by feature `trace`) diagonal elements of `ap` with magnitude below `eps * max |diagonal|`
+    /// before solving, which indicate an ill-conditioned (or singular) triangular matrix. This does not
+    /// change the result.
+    #[builder(setter(into), default = "false")]
+    pub warn_ill_conditioned: bool,
 }
 
+impl_builder_clone!(
+    TPSV_Builder<'a, 'x>,
+    TPSVNum,
+    [ap, uplo, trans, diag, layout, packed_order, warn_ill_conditioned],
+    [x]
+);
+impl_builder_validate_uplo!(TPSV_Builder<'a, 'x>, TPSVNum);
+impl_builder_trans_str!(TPSV_Builder<'a, 'x>, TPSVNum, [trans => trans_str]);
+
 impl<'a, 'x, F> BLASBuilder_<'x, F, Ix1> for TPSV_<'a, 'x, F>
 where
     F: TPSVNum,
 {
     fn driver(self) -> Result<TPSV_Driver<'a, 'x, F>, BLASError> {
-        let Self { ap, x, uplo, trans, diag, layout } = self;
+        let Self { ap, x, uplo, trans, diag, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
+        // packed array is passed to BLAS without increment, so it must be contiguous
         let incap = ap.stride_of(Axis(0));
-        assert!(incap <= 1);
+        blas_assert!(ap.len() <= 1 || incap == 1, InvalidStride, "packed array `ap` should be contiguous")?;
 
         // initialize intent(hide)
         let np = ap.len_of(Axis(0));
@@ -156,8 +178,22 @@ where
     fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.ap)?;
+        check_zero_stride(&obj.x.view())?;
+        let n = obj.x.len_of(Axis(0));
+        blas_assert_eq!(obj.ap.len(), n * (n + 1) / 2, InvalidDim)?;
 
         let layout = obj.layout.unwrap_or(BLASRowMajor);
+        let ap_repacked = match obj.packed_order {
+            Some(order) if !order.matches(obj.uplo) => {
+                CowArray::from(packed_reorder(&obj.ap, n, obj.uplo, layout)?)
+            },
+            _ => CowArray::from(obj.ap.view()),
+        };
+        let obj = TPSV_ { ap: ap_repacked.view(), packed_order: None, ..obj };
+        if obj.warn_ill_conditioned && obj.diag == BLASNonUnit {
+            warn_small_diagonal(packed_diagonal(&obj.ap, n, obj.uplo, layout).view());
+        }
 
         if layout == BLASColMajor {
             // F-contiguous
@@ -75,6 +75,7 @@ where
             return Ok(x);
         }
 
+        crate::record::blas_record!("TRMV", dims: [n], flags: [uplo, trans, diag], scalars: [], arrays: [a, x]);
         unsafe {
             F::trmv(&uplo, &trans, &diag, &n, a_ptr, &lda, x_ptr, &incx);
         }
@@ -87,7 +88,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct TRMV_<'a, 'x, F>
 where
     F: TRMVNum,
@@ -103,6 +104,12 @@ where
     pub diag: BLASDiag,
 }
 
+impl_builder_clone!(TRMV_Builder<'a, 'x>, TRMVNum, [a, uplo, trans, diag], [x]);
+impl_builder_with_lda!(TRMV_Builder<'a, 'x>, TRMVNum);
+impl_builder_validate_uplo!(TRMV_Builder<'a, 'x>, TRMVNum);
+impl_builder_triangular!(TRMV_Builder<'a, 'x>, TRMVNum);
+impl_builder_trans_str!(TRMV_Builder<'a, 'x>, TRMVNum, [trans => trans_str]);
+
 impl<'a, 'x, F> BLASBuilder_<'x, F, Ix1> for TRMV_<'a, 'x, F>
 where
     F: TRMVNum,
@@ -112,7 +119,11 @@ where
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
+        blas_assert!(
+            layout_a.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
 
         // initialize intent(hide)
         let (n, n_) = a.dim();
@@ -158,6 +169,8 @@ where
     fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.a)?;
+        check_zero_stride(&obj.x.view())?;
 
         let layout_a = get_layout_array2(&obj.a);
 
@@ -75,6 +75,7 @@ where
             return Ok(x);
         }
 
+        crate::record::blas_record!("TRSV", dims: [n], flags: [uplo, trans, diag], scalars: [], arrays: [a, x]);
         unsafe {
             F::trsv(&uplo, &trans, &diag, &n, a_ptr, &lda, x_ptr, &incx);
         }
@@ -87,7 +88,7 @@ where
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct TRSV_<'a, 'x, F>
 where
     F: TRSVNum,
@@ -101,18 +102,33 @@ where
     pub trans: BLASTranspose,
     #[builder(setter(into), default = "BLASNonUnit")]
     pub diag: BLASDiag,
+    /// Report (by feature `trace`) diagonal elements of `a` with magnitude below `eps * max |diagonal|`
+    /// before solving, which indicate an ill-conditioned (or singular) triangular matrix. This does not
+    /// change the result.
+    #[builder(setter(into), default = "false")]
+    pub warn_ill_conditioned: bool,
 }
 
+impl_builder_clone!(TRSV_Builder<'a, 'x>, TRSVNum, [a, uplo, trans, diag, warn_ill_conditioned], [x]);
+impl_builder_with_lda!(TRSV_Builder<'a, 'x>, TRSVNum);
+impl_builder_validate_uplo!(TRSV_Builder<'a, 'x>, TRSVNum);
+impl_builder_triangular!(TRSV_Builder<'a, 'x>, TRSVNum);
+impl_builder_trans_str!(TRSV_Builder<'a, 'x>, TRSVNum, [trans => trans_str]);
+
 impl<'a, 'x, F> BLASBuilder_<'x, F, Ix1> for TRSV_<'a, 'x, F>
 where
     F: TRSVNum,
 {
     fn driver(self) -> Result<TRSV_Driver<'a, 'x, F>, BLASError> {
-        let Self { a, x, uplo, trans, diag } = self;
+        let Self { a, x, uplo, trans, diag, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
+        blas_assert!(
+            layout_a.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
 
         // initialize intent(hide)
         let (n, n_) = a.dim();
@@ -158,6 +174,11 @@ where
     fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
         // initialize
         let obj = self.build()?;
+        check_zero_stride(&obj.a)?;
+        check_zero_stride(&obj.x.view())?;
+        if obj.warn_ill_conditioned && obj.diag == BLASNonUnit {
+            warn_small_diagonal(obj.a.diag());
+        }
 
         let layout_a = get_layout_array2(&obj.a);
 
@@ -193,4 +214,27 @@ where
     }
 }
 
+impl<'a, 'x, F> TRSV_Builder<'a, 'x, F>
+where
+    F: TRSVNum,
+{
+    /// Solve $\mathrm{op}(\mathbf{A}) \mathbf{x} = \mathbf{b}$ into a new vector, without mutating `b`.
+    ///
+    /// Options other than `x` (which should not be set) are taken from this builder.
+    pub fn solve(self, b: ArrayView1<F>) -> Result<Array1<F>, BLASError> {
+        blas_assert!(self.x.is_none(), InvalidFlag, "x should not be set when solving into a new vector")?;
+        let mut x = b.to_owned();
+        let builder = TRSV_Builder {
+            a: self.a,
+            x: Some(x.view_mut()),
+            uplo: self.uplo,
+            trans: self.trans,
+            diag: self.diag,
+            warn_ill_conditioned: self.warn_ill_conditioned,
+        };
+        builder.run()?;
+        Ok(x)
+    }
+}
+
 /* #endregion */
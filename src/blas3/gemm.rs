@@ -1,7 +1,17 @@
+extern crate alloc;
+
+use crate::blas3::hemm::HEMM;
+use crate::blas3::symm::{SYMMNum, SYMM};
+use crate::blas3::syrk::{SYRKNum, SYRK};
+use crate::blasext::elementwise::scale;
 use crate::ffi::{self, blas_int, c_char};
+use crate::reference;
 use crate::util::*;
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
 use derive_builder::Builder;
 use ndarray::prelude::*;
+use num_traits::Zero;
 
 /* #region BLAS func */
 
@@ -54,6 +64,61 @@ impl_func!(c64, zgemm_);
 
 /* #endregion */
 
+/* #region GEMMDispatchNum */
+
+/// Float types of which GEMM wrapper can dispatch products with symmetric or hermitian `a` to SYMM or HEMM.
+pub trait GEMMDispatchNum: GEMMNum + SYMMNum {
+    /// Hermitian-times-general product for [`GEMM_::a_hermitian`]; this is HEMM for complex types, and SYMM
+    /// for real types (where hermitian is the same as symmetric).
+    #[allow(clippy::too_many_arguments)]
+    fn hermitian_mm<'a, 'b, 'c>(
+        a: ArrayView2<'a, Self>,
+        b: ArrayView2<'b, Self>,
+        c: Option<ArrayViewMut2<'c, Self>>,
+        alpha: Self,
+        beta: Self,
+        uplo: BLASUpLo,
+        layout: Option<BLASLayout>,
+        check_finite: bool,
+    ) -> Result<ArrayOut2<'c, Self>, BLASError>;
+}
+
+macro_rules! impl_dispatch {
+    ($type: ty, $hemm: ident) => {
+        impl GEMMDispatchNum for $type {
+            fn hermitian_mm<'a, 'b, 'c>(
+                a: ArrayView2<'a, Self>,
+                b: ArrayView2<'b, Self>,
+                c: Option<ArrayViewMut2<'c, Self>>,
+                alpha: Self,
+                beta: Self,
+                uplo: BLASUpLo,
+                layout: Option<BLASLayout>,
+                check_finite: bool,
+            ) -> Result<ArrayOut2<'c, Self>, BLASError> {
+                let builder =
+                    $hemm::default().a(a).b(b).alpha(alpha).beta(beta).uplo(uplo).check_finite(check_finite);
+                let builder = match c {
+                    Some(c) => builder.c(c),
+                    None => builder,
+                };
+                let builder = match layout {
+                    Some(layout) => builder.layout(layout),
+                    None => builder,
+                };
+                builder.run()
+            }
+        }
+    };
+}
+
+impl_dispatch!(f32, SYMM);
+impl_dispatch!(f64, SYMM);
+impl_dispatch!(c32, HEMM);
+impl_dispatch!(c64, HEMM);
+
+/* #endregion */
+
 /* #region BLAS driver */
 
 pub struct GEMM_Driver<'a, 'b, 'c, F>
@@ -90,14 +155,11 @@ where
         if m == 0 || n == 0 {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
-            if beta == F::zero() {
-                c.view_mut().fill(F::zero());
-            } else if beta != F::one() {
-                c.view_mut().mapv_inplace(|v| v * beta);
-            }
+            scale_by_beta(c.view_mut(), beta);
             return Ok(c.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("GEMM", dims: [m, n, k], flags: [transa, transb], scalars: [alpha, beta], arrays: [a, b, c]);
         unsafe {
             F::gemm(&transa, &transb, &m, &n, &k, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
         }
@@ -105,12 +167,325 @@ where
     }
 }
 
+impl<'a, 'b, 'c, F> GEMM_Driver<'a, 'b, 'c, F>
+where
+    F: GEMMNum,
+{
+    /// Dimensions `(m, n, k)` passed to BLAS.
+    pub fn dims(&self) -> (blas_int, blas_int, blas_int) {
+        (self.m, self.n, self.k)
+    }
+
+    /// Scalars `(alpha, beta)` passed to BLAS.
+    pub fn scalars(&self) -> (F, F) {
+        (self.alpha, self.beta)
+    }
+
+    /// View of input matrix `a` passed to BLAS.
+    pub fn a_view(&self) -> ArrayView2<'_, F> {
+        self.a.view()
+    }
+
+    /// View of input matrix `b` passed to BLAS.
+    pub fn b_view(&self) -> ArrayView2<'_, F> {
+        self.b.view()
+    }
+
+    /// View of output matrix `c` passed to BLAS.
+    pub fn c_view(&self) -> ArrayView2<'_, F> {
+        self.c.view()
+    }
+
+    /// Leading dimension of `a` passed to BLAS.
+    pub fn lda(&self) -> blas_int {
+        self.lda
+    }
+
+    /// Leading dimension of `b` passed to BLAS.
+    pub fn ldb(&self) -> blas_int {
+        self.ldb
+    }
+
+    /// Leading dimension of `c` passed to BLAS.
+    pub fn ldc(&self) -> blas_int {
+        self.ldc
+    }
+}
+
+/* #endregion */
+
+/* #region shape hint */
+
+/// Shape class of a GEMM product, as an advisory hint for [`GEMM_::shape_hint`].
+///
+/// The class describes $\mathrm{op}(\mathbf{A})$ of shape `m x k`: tall-skinny if `m` is much larger than
+/// `k`, short-wide if `k` is much larger than `m`, and square otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShapeClass {
+    /// Detect from dimensions.
+    #[default]
+    Auto,
+    TallSkinny,
+    ShortWide,
+    Square,
+}
+
+impl ShapeClass {
+    /// Ratio of dimensions above which a product is considered tall-skinny or short-wide.
+    pub const RATIO: usize = 4;
+
+    /// Shape class of $\mathrm{op}(\mathbf{A})$ of shape `m x k`.
+    pub fn detect(m: usize, k: usize) -> Self {
+        if m >= Self::RATIO * k.max(1) {
+            ShapeClass::TallSkinny
+        } else if k >= Self::RATIO * m.max(1) {
+            ShapeClass::ShortWide
+        } else {
+            ShapeClass::Square
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region structured dispatch */
+
+/// Product $\alpha \mathbf{A} \mathbf{B} + \beta \mathbf{C}$ with symmetric (or hermitian) `a` stored in the
+/// given triangle.
+type StructuredFn<F> = for<'a, 'b, 'c> fn(
+    ArrayView2<'a, F>,
+    ArrayView2<'b, F>,
+    Option<ArrayViewMut2<'c, F>>,
+    F,
+    F,
+    BLASUpLo,
+    Option<BLASLayout>,
+    bool,
+) -> Result<ArrayOut2<'c, F>, BLASError>;
+
+/// Gram product $\alpha \mathrm{op}(\mathbf{A}) \mathrm{op}(\mathbf{A})^T$ with the given SYRK transpose flag.
+type GramFn<F> = for<'a, 'c> fn(
+    ArrayView2<'a, F>,
+    Option<ArrayViewMut2<'c, F>>,
+    F,
+    BLASTranspose,
+    Option<BLASLayout>,
+    bool,
+) -> Result<ArrayOut2<'c, F>, BLASError>;
+
+/// Plan of [`GramFn`].
+type GramPlanFn<F> = fn(&ArrayView2<F>, BLASTranspose, Option<BLASLayout>) -> Result<BlasPlan, BLASError>;
+
+/// Routines that GEMM dispatches products of special structure to.
+///
+/// [`run`](BLASBuilder::run) of GEMM only requires [`GEMMNum`]. Options that dispatch to other routines have
+/// setters with further bounds, and these setters record the routines here; by default nothing is dispatched.
+#[derive(Clone, Copy)]
+pub struct GEMMDispatch<F> {
+    symmetric_mm: Option<StructuredFn<F>>,
+    hermitian_mm: Option<StructuredFn<F>>,
+    gram: Option<(GramFn<F>, GramPlanFn<F>)>,
+}
+
+impl<F> Default for GEMMDispatch<F> {
+    fn default() -> Self {
+        Self { symmetric_mm: None, hermitian_mm: None, gram: None }
+    }
+}
+
+/// Symmetric-times-general product by SYMM.
+#[allow(clippy::too_many_arguments)]
+fn symm_mm<'a, 'b, 'c, F>(
+    a: ArrayView2<'a, F>,
+    b: ArrayView2<'b, F>,
+    c: Option<ArrayViewMut2<'c, F>>,
+    alpha: F,
+    beta: F,
+    uplo: BLASUpLo,
+    layout: Option<BLASLayout>,
+    check_finite: bool,
+) -> Result<ArrayOut2<'c, F>, BLASError>
+where
+    F: SYMMNum,
+{
+    let builder = SYMM::default().a(a).b(b).alpha(alpha).beta(beta).uplo(uplo).check_finite(check_finite);
+    let builder = match c {
+        Some(c) => builder.c(c),
+        None => builder,
+    };
+    let builder = match layout {
+        Some(layout) => builder.layout(layout),
+        None => builder,
+    };
+    builder.run()
+}
+
+/// Gram product by SYRK, with the other triangle filled.
+fn syrk_gram<'a, 'c, F>(
+    a: ArrayView2<'a, F>,
+    c: Option<ArrayViewMut2<'c, F>>,
+    alpha: F,
+    trans: BLASTranspose,
+    layout: Option<BLASLayout>,
+    check_finite: bool,
+) -> Result<ArrayOut2<'c, F>, BLASError>
+where
+    F: SYRKNum,
+{
+    let builder = SYRK::default().a(a).alpha(alpha).uplo(BLASLower).trans(trans).check_finite(check_finite);
+    let builder = match layout {
+        Some(layout) => builder.layout(layout),
+        None => builder,
+    };
+    let mut c = match c {
+        Some(c) => builder.c(c).run()?,
+        None => builder.run()?,
+    };
+    symmetrize_lower(c.view_mut());
+    Ok(c)
+}
+
+fn syrk_gram_plan<F>(
+    a: &ArrayView2<F>,
+    trans: BLASTranspose,
+    layout: Option<BLASLayout>,
+) -> Result<BlasPlan, BLASError>
+where
+    F: SYRKNum,
+{
+    let builder = SYRK::default().a(a.view()).uplo(BLASLower).trans(trans);
+    let builder = match layout {
+        Some(layout) => builder.layout(layout),
+        None => builder,
+    };
+    builder.plan()
+}
+
+/* #endregion */
+
+/* #region output pool */
+
+/// Reusable output buffer for a sequence of GEMM calls with changing shapes.
+///
+/// The pool owns a flat buffer and hands out matrix views of the requested shape into it; the buffer
+/// grows only when the requested number of elements exceeds its capacity. Pass it to GEMM by
+/// [`output_pool`](GEMM_Builder::output_pool).
+#[derive(Debug, Clone, Default)]
+pub struct GemmOutputPool<F> {
+    buffer: Vec<F>,
+    allocations: usize,
+}
+
+impl<F> GemmOutputPool<F>
+where
+    F: Clone + Zero,
+{
+    /// Create an empty pool.
+    pub fn new() -> Self {
+        Self { buffer: Vec::new(), allocations: 0 }
+    }
+
+    /// Create a pool holding `capacity` elements up front.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut pool = Self::new();
+        pool.reserve(capacity);
+        pool
+    }
+
+    /// Number of elements the pool can hold without reallocation.
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Number of times the buffer has been (re)allocated.
+    pub fn allocations(&self) -> usize {
+        self.allocations
+    }
+
+    fn reserve(&mut self, size: usize) {
+        if size > self.buffer.len() {
+            self.buffer = alloc::vec![F::zero(); size];
+            self.allocations += 1;
+        }
+    }
+
+    /// Mutable view of shape `(m, n)` in the given layout into the buffer; contents are unspecified.
+    ///
+    /// Number of elements `m * n` overflowing `usize` gives [`BLASError::InvalidDim`].
+    pub fn view_mut(
+        &mut self,
+        (m, n): (usize, usize),
+        layout: BLASLayout,
+    ) -> Result<ArrayViewMut2<'_, F>, BLASError> {
+        let size = match m.checked_mul(n) {
+            Some(size) => size,
+            None => blas_raise!(InvalidDim, "number of elements of output overflows usize")?,
+        };
+        self.reserve(size);
+        let data = &mut self.buffer[..size];
+        match layout {
+            BLASColMajor => Ok(ArrayViewMut2::from_shape((m, n).f(), data).unwrap()),
+            _ => Ok(ArrayViewMut2::from_shape((m, n), data).unwrap()),
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region accumulator */
+
+/// Persistent owned output $\mathbf{C}$ for accumulating products $\mathbf{C} \mathrel{+}= \alpha
+/// \mathbf{A}_i \mathbf{B}_i$ over a loop.
+///
+/// Each [`gemm_add`](Accumulator::gemm_add) runs GEMM with `beta = 1` in place on the internal buffer, so
+/// no output is allocated or returned per call. Transposed operands can be passed as `a.t()`; GEMM handles
+/// their layout.
+#[derive(Debug, Clone)]
+pub struct Accumulator<F> {
+    c: Array2<F>,
+}
+
+impl<F> Accumulator<F>
+where
+    F: GEMMNum,
+{
+    /// Accumulator of shape `(m, n)` starting from zero, stored in the given layout.
+    pub fn zeros((m, n): (usize, usize), layout: BLASLayout) -> Self {
+        let c = match layout {
+            BLASColMajor => Array2::zeros((m, n).f()),
+            _ => Array2::zeros((m, n)),
+        };
+        Self { c }
+    }
+
+    /// Accumulator starting from the given matrix.
+    pub fn from_array(c: Array2<F>) -> Self {
+        Self { c }
+    }
+
+    /// Add $\alpha \mathbf{A} \mathbf{B}$ to the accumulated result.
+    pub fn gemm_add(&mut self, a: ArrayView2<F>, b: ArrayView2<F>, alpha: F) -> Result<(), BLASError> {
+        GEMM::default().a(a).b(b).c(self.c.view_mut()).alpha(alpha).beta(F::one()).run()?;
+        Ok(())
+    }
+
+    /// View of the accumulated result.
+    pub fn view(&self) -> ArrayView2<'_, F> {
+        self.c.view()
+    }
+
+    /// Take the accumulated result.
+    pub fn into_inner(self) -> Array2<F> {
+        self.c
+    }
+}
+
 /* #endregion */
 
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct GEMM_<'a, 'b, 'c, F>
 where
     F: GEMMNum,
@@ -118,11 +493,16 @@ where
     pub a: ArrayView2<'a, F>,
     pub b: ArrayView2<'b, F>,
 
-    #[builder(setter(into, strip_option), default = "None")]
+    /// Output matrix updated in place; newly allocated if not given.
+    ///
+    /// Any mutable view is accepted, so output held in shared ownership can be written in place, such as
+    /// by `Arc::get_mut` on `Arc<Array2<F>>`, or `view_mut` on an `ArcArray` (which only copies data if it
+    /// is not uniquely owned). See `examples/arc_output.rs`.
+    #[builder(setter(custom), default = "None")]
     pub c: Option<ArrayViewMut2<'c, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
-    #[builder(setter(into), default = "F::zero()")]
+    #[builder(setter(custom), default = "F::zero()")]
     pub beta: F,
     #[builder(setter(into), default = "BLASNoTrans")]
     pub transa: BLASTranspose,
@@ -130,18 +510,340 @@ where
     pub transb: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
+    /// Treat all matrices as the given layout, instead of inferring it from strides.
+    ///
+    /// This is an escape hatch for arrays with degenerate dimensions (such as `shape = [1, n]` with
+    /// non-unit strides), where stride inference may not recognize a layout BLAS can actually handle, and
+    /// explicit copy is performed. Only strides of the contiguous axis and leading dimension are checked;
+    /// the wrapper trusts the tag otherwise, so a tag that does not match how data is meant to be read
+    /// gives wrong result.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub assume_layout: Option<BLASLayout>,
+    /// Copy `a` or `b` to a temporary if its memory overlaps with `c`, instead of raising
+    /// [`BLASError::Aliasing`].
+    #[builder(setter(into), default = "false")]
+    pub allow_aliasing_copy: bool,
+    /// Treat `a` as symmetric with data stored in the given triangle, and dispatch to SYMM.
+    ///
+    /// Only `transa` of `N` or `T` (both give the same matrix) and `transb` of `N` are accepted. The setter
+    /// requires [`GEMMDispatchNum`].
+    #[builder(setter(custom), default = "None")]
+    pub a_symmetric: Option<BLASUpLo>,
+    /// Treat `a` as hermitian with data stored in the given triangle, and dispatch to HEMM (SYMM for real
+    /// types).
+    ///
+    /// Only `transa` of `N` or `C` (both give the same matrix) and `transb` of `N` are accepted. The setter
+    /// requires [`GEMMDispatchNum`].
+    #[builder(setter(custom), default = "None")]
+    pub a_hermitian: Option<BLASUpLo>,
+    /// Write output into a view of the given [`GemmOutputPool`], instead of allocating a new matrix.
+    ///
+    /// Can not be used together with `c`. Stale contents of the pool are zeroed before use if `beta` is
+    /// nonzero, so the result is the same as owned output.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub output_pool: Option<&'c mut GemmOutputPool<F>>,
+    /// Advisory hint of shape class of $\mathrm{op}(\mathbf{A})$, used to select the routine.
+    ///
+    /// Gram products ($\mathbf{A}^T \mathbf{A}$ or $\mathbf{A} \mathbf{A}^T$, with `a` and `b` the same
+    /// array) are computed by SYRK, which performs half of the flops, followed by filling the other
+    /// triangle. This is selected unless the class is tall-skinny, where the filling costs as much as the
+    /// computation saved; it also requires `beta` to be zero or output to be allocated, and `assume_layout`
+    /// not given. Other products always use GEMM. The hint never affects the result beyond rounding.
+    ///
+    /// Dispatch to SYRK is only enabled by giving this hint (`Auto` included), whose setter requires
+    /// [`SYRKNum`]; otherwise GEMM is always used.
+    #[builder(setter(custom), default = "ShapeClass::Auto")]
+    pub shape_hint: ShapeClass,
+    /// Cross-check output against the naive [`reference::gemm`] with tolerance `(rtol, atol)`, panicking with
+    /// diagnostics on mismatch (see [`reference::assert_allclose`]).
+    ///
+    /// This is meant for debugging suspicious results (backend bugs or layout handling errors), and is
+    /// expensive for large matrices. Not supported with `a_symmetric` or `a_hermitian`.
+    #[builder(setter(custom), default = "None")]
+    pub verify: Option<(F::RealFloat, F::RealFloat)>,
+    /// Whether `c` refers to uninitialized memory; set by [`c_uninit`](GEMM_Builder::c_uninit).
+    #[builder(setter(custom), default = "false")]
+    pub c_uninit: bool,
+    /// Return the logical transpose $\mathbf{C}^T$ instead of $\mathbf{C}$, by reversing axes of output.
+    ///
+    /// For owned output this is zero-cost (only strides change). For a user-given `c`, the data written is
+    /// still $\mathbf{C}$ in the memory of `c`; the returned view is the transpose of `c`, so logical
+    /// position `(i, j)` of output corresponds to physical element `(j, i)` of `c`.
+    #[builder(setter(into), default = "false")]
+    pub transpose_output: bool,
+    /// Split dimensions larger than the range of BLAS integer into chunks, instead of failing.
+    ///
+    /// For 32-bit integer BLAS (without feature `ilp64`), $m$, $n$ or $k$ exceeding `c_int::MAX` is computed
+    /// by tiling the product into sub-blocks within range, each by a separate GEMM call; chunks along $k$
+    /// are accumulated into output. See [`run_chunked`](GEMM_Builder::run_chunked). Not supported with
+    /// `a_symmetric`, `a_hermitian` or `output_pool` when chunking is actually needed.
+    #[builder(setter(into), default = "false")]
+    pub chunk_large_dims: bool,
+    /// Scan `a` for exact symmetry (or hermiticity), and if found, dispatch to SYMM (or HEMM) as
+    /// [`a_symmetric`](GEMM_::a_symmetric) (or [`a_hermitian`](GEMM_::a_hermitian)) would.
+    ///
+    /// The scan compares each off-diagonal pair once, stopping at the first mismatch; this is $O(m^2)$ memory
+    /// reads against $O(m^2 n)$ flops of the product, so it pays off unless `b` has few columns. Elements are
+    /// compared exactly (without tolerance), so dispatch never changes the result beyond rounding. Only
+    /// applies to square `a` with `transb` of `N` and without `assume_layout`; it is skipped if `a_symmetric`
+    /// or `a_hermitian` is given, or if the product is dispatched to SYRK as a gram product.
+    /// [`plan`](GEMM_Builder::plan) reports the dispatched routine. The setter requires [`GEMMDispatchNum`].
+    #[builder(setter(custom), default = "false")]
+    pub auto_detect_symmetry: bool,
+    /// Routines of special structure, recorded by setters of options that dispatch to them.
+    #[builder(setter(custom), default = "GEMMDispatch::default()")]
+    pub dispatch: GEMMDispatch<F>,
+}
+
+impl_builder_clone!(
+    GEMM_Builder<'a, 'b, 'c>,
+    GEMMNum,
+    [
+        a,
+        b,
+        alpha,
+        beta,
+        transa,
+        transb,
+        layout,
+        check_finite,
+        assume_layout,
+        allow_aliasing_copy,
+        a_symmetric,
+        a_hermitian,
+        shape_hint,
+        verify,
+        transpose_output,
+        chunk_large_dims,
+        auto_detect_symmetry,
+        dispatch
+    ],
+    [c, output_pool, c_uninit]
+);
+impl_builder_with_lda!(GEMM_Builder<'a, 'b, 'c>, GEMMNum);
+impl<'a, 'b, 'c, F> GEMM_Builder<'a, 'b, 'c, F>
+where
+    F: GEMMNum,
+{
+    /// Output matrix updated in place; see [`GEMM_::c`].
+    ///
+    /// This replaces output given before, including uninitialized memory by [`c_uninit`](Self::c_uninit).
+    pub fn c<VALUE: Into<ArrayViewMut2<'c, F>>>(mut self, value: VALUE) -> Self {
+        self.c = Some(Some(value.into()));
+        self.c_uninit = None;
+        self
+    }
+
+    /// Use uninitialized memory at `ptr` as output `c` of shape `(m, n)`, stored contiguously in `layout`.
+    ///
+    /// The memory is fully initialized after a successful [`run`](BLASBuilder::run), and the returned
+    /// array is a view into it. Since uninitialized memory must not be read, `beta` must be zero; otherwise
+    /// building fails with [`BLASError::InvalidFlag`]. Option `layout`, if also given, must agree with the
+    /// layout of `c`, so that `c` is never copied.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must be non-null, properly aligned, and valid for writes of `m * n` elements of `F` for the
+    ///   whole lifetime `'c`;
+    /// - the memory must not be accessed through any other pointer or reference during lifetime `'c`;
+    /// - if `run` returns an error, the memory may be partially written and must still be treated as
+    ///   uninitialized.
+    pub unsafe fn c_uninit(
+        mut self,
+        ptr: *mut MaybeUninit<F>,
+        (m, n): (usize, usize),
+        layout: BLASLayout,
+    ) -> Self {
+        let shape = match layout {
+            BLASColMajor => (m, n).f(),
+            _ => (m, n).into_shape(),
+        };
+        self.c = Some(Some(ArrayViewMut2::from_shape_ptr(shape, ptr.cast::<F>())));
+        self.c_uninit = Some(true);
+        self
+    }
+
+    /// Cross-check output against naive reference; see [`GEMM_::verify`].
+    pub fn verify(mut self, rtol: F::RealFloat, atol: F::RealFloat) -> Self {
+        self.verify = Some(Some((rtol, atol)));
+        self
+    }
+
+    /// Structure of `a` found by [`GEMM_::auto_detect_symmetry`]: `Some(false)` if dispatched as symmetric,
+    /// `Some(true)` as hermitian, and `None` if not found or not applicable.
+    fn detect_symmetry(&self) -> Option<bool> {
+        if self.auto_detect_symmetry != Some(true)
+            || self.a_symmetric.flatten().is_some()
+            || self.a_hermitian.flatten().is_some()
+            || self.assume_layout.flatten().is_some()
+        {
+            return None;
+        }
+        let (a, b) = (self.a.as_ref()?, self.b.as_ref()?);
+        let transa = self.transa.unwrap_or(BLASNoTrans);
+        let transb = self.transb.unwrap_or(BLASNoTrans);
+        if transb != BLASNoTrans || a.len_of(Axis(0)) != a.len_of(Axis(1)) {
+            return None;
+        }
+        // gram product is already dispatched to SYRK
+        let c_given = self.c.as_ref().is_some_and(|c| c.is_some());
+        let beta = self.beta.unwrap_or(F::zero());
+        let shape_hint = self.shape_hint.unwrap_or_default();
+        let gram = self.dispatch.is_some_and(|d| d.gram.is_some());
+        if gram
+            && gram_trans::<F>(a, b, transa, transb, shape_hint).is_some()
+            && (!c_given || beta == F::zero())
+        {
+            return None;
+        }
+        match transa {
+            BLASNoTrans if is_symmetric_exact(a, false) => Some(false),
+            BLASNoTrans if F::is_complex() && is_symmetric_exact(a, true) => Some(true),
+            BLASTrans if is_symmetric_exact(a, false) => Some(false),
+            BLASConjTrans if is_symmetric_exact(a, true) => Some(true),
+            _ => None,
+        }
+    }
+
+    fn validate(&self) -> Result<(), BLASError> {
+        if self.c_uninit == Some(true) {
+            let beta = self.beta.unwrap_or(F::zero());
+            blas_assert!(beta == F::zero(), InvalidFlag, "c_uninit requires beta to be zero")?;
+            if let (Some(Some(layout)), Some(Some(c))) = (self.layout, self.c.as_ref()) {
+                let c_pref = match layout {
+                    BLASColMajor => c.view().is_fpref(),
+                    _ => c.view().is_cpref(),
+                };
+                blas_assert!(c_pref, InvalidFlag, "layout does not agree with c_uninit")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl_builder_trans_str!(GEMM_Builder<'a, 'b, 'c>, GEMMNum, [transa => transa_str, transb => transb_str]);
+impl_builder_scalar!(GEMM_Builder<'a, 'b, 'c>, GEMMNum, [alpha, beta]);
+
+impl<'a, 'b, 'c, F> GEMM_Builder<'a, 'b, 'c, F>
+where
+    F: GEMMDispatchNum,
+{
+    /// Treat `a` as symmetric, dispatching to SYMM; see [`GEMM_::a_symmetric`].
+    pub fn a_symmetric<VALUE: Into<BLASUpLo>>(mut self, value: VALUE) -> Self {
+        let mut dispatch = self.dispatch.unwrap_or_default();
+        dispatch.symmetric_mm = Some(symm_mm::<F>);
+        self.a_symmetric = Some(Some(value.into()));
+        self.dispatch = Some(dispatch);
+        self
+    }
+
+    /// Treat `a` as hermitian, dispatching to HEMM (SYMM for real types); see [`GEMM_::a_hermitian`].
+    pub fn a_hermitian<VALUE: Into<BLASUpLo>>(mut self, value: VALUE) -> Self {
+        let mut dispatch = self.dispatch.unwrap_or_default();
+        dispatch.hermitian_mm = Some(F::hermitian_mm);
+        self.a_hermitian = Some(Some(value.into()));
+        self.dispatch = Some(dispatch);
+        self
+    }
+
+    /// Scan `a` for symmetry, dispatching to SYMM (or HEMM) if found; see [`GEMM_::auto_detect_symmetry`].
+    pub fn auto_detect_symmetry<VALUE: Into<bool>>(mut self, value: VALUE) -> Self {
+        let mut dispatch = self.dispatch.unwrap_or_default();
+        dispatch.symmetric_mm = Some(symm_mm::<F>);
+        dispatch.hermitian_mm = Some(F::hermitian_mm);
+        self.auto_detect_symmetry = Some(value.into());
+        self.dispatch = Some(dispatch);
+        self
+    }
+}
+
+impl<'a, 'b, 'c, F> GEMM_Builder<'a, 'b, 'c, F>
+where
+    F: GEMMNum + SYRKNum,
+{
+    /// Shape class hint, enabling dispatch of gram products to SYRK; see [`GEMM_::shape_hint`].
+    pub fn shape_hint<VALUE: Into<ShapeClass>>(mut self, value: VALUE) -> Self {
+        let mut dispatch = self.dispatch.unwrap_or_default();
+        dispatch.gram = Some((syrk_gram::<F>, syrk_gram_plan::<F>));
+        self.shape_hint = Some(value.into());
+        self.dispatch = Some(dispatch);
+        self
+    }
 }
 
+macro_rules! impl_gemm_into_complex_real_part {
+    ($type: ty, $complex: ty) => {
+        impl<'a, 'b, 'c> GEMM_Builder<'a, 'b, 'c, $type> {
+            /// Accumulate the real product into the real part of complex `c`.
+            ///
+            /// This computes $\mathrm{Re}(\mathbf{C}) = \alpha \mathbf{A} \mathbf{B} + \beta \mathrm{Re}(\mathbf{C})$,
+            /// and imaginary part of `c` is left unchanged. The real components of `c` are viewed in place
+            /// (with stride 2, since complex numbers are stored interleaved), so BLAS cannot write to them
+            /// directly; the product is computed into a temporary, which is then copied back.
+            pub fn into_complex_real_part(self, c: ArrayViewMut2<'c, $complex>) -> Self {
+                self.c(c.split_complex().re)
+            }
+
+            /// Accumulate the real product into complex `c`, with complex `beta`.
+            ///
+            /// This computes $\mathbf{C} = \alpha \mathbf{A} \mathbf{B} + \beta \mathbf{C}$ with real $\alpha$,
+            /// $\mathbf{A}$, $\mathbf{B}$ and complex $\beta$, $\mathbf{C}$ (such as $\mathbf{C} = \mathbf{A}
+            /// \mathbf{B} + i \mathbf{C}$), which real GEMM can't perform by itself. The real product is computed
+            /// first; then `c` is scaled by `beta` with complex SCAL, and the product is added to its real part.
+            /// `c` is not modified if the product fails.
+            ///
+            /// Output `c` and `beta` are given here, so they must not be set on the builder.
+            pub fn run_into_complex(
+                self,
+                mut c: ArrayViewMut2<'c, $complex>,
+                beta: impl Into<$complex>,
+            ) -> Result<ArrayOut2<'c, $complex>, BLASError> {
+                let beta = beta.into();
+                blas_assert!(
+                    self.c.as_ref().map_or(true, |c| c.is_none()),
+                    InvalidFlag,
+                    "run_into_complex does not accept c set on builder"
+                )?;
+                blas_assert!(
+                    self.beta.is_none(),
+                    InvalidFlag,
+                    "run_into_complex does not accept beta set on builder"
+                )?;
+
+                let ab = self.run()?;
+                blas_assert_eq!(ab.view().dim(), c.dim(), InvalidDim)?;
+                match beta.is_zero() {
+                    true => c.fill(<$complex>::zero()),
+                    false => scale(beta, c.view_mut())?,
+                }
+                c.view_mut().split_complex().re.zip_mut_with(&ab.view(), |c, &ab| *c += ab);
+                Ok(ArrayOut2::ViewMut(c))
+            }
+        }
+    };
+}
+
+impl_gemm_into_complex_real_part!(f32, c32);
+impl_gemm_into_complex_real_part!(f64, c64);
+
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for GEMM_<'a, 'b, 'c, F>
 where
     F: GEMMNum,
 {
     fn driver(self) -> Result<GEMM_Driver<'a, 'b, 'c, F>, BLASError> {
-        let Self { a, b, c, alpha, beta, transa, transb, layout } = self;
+        let Self { a, b, c, alpha, beta, transa, transb, layout, assume_layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref() && b.is_fpref());
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
+        if assume_layout.is_none() {
+            blas_assert!(
+                a.is_fpref() && b.is_fpref(),
+                InvalidStride,
+                "only col-major arrays are accepted in inner driver"
+            )?;
+        }
 
         // initialize intent(hide)
         let (m, k) = match transa {
@@ -154,8 +856,8 @@ where
             BLASTrans | BLASConjTrans => b.len_of(Axis(0)),
             _ => blas_invalid!(transb)?,
         };
-        let lda = a.stride_of(Axis(1));
-        let ldb = b.stride_of(Axis(1));
+        let lda = leading_dim(&a);
+        let ldb = leading_dim(&b);
 
         // perform check
         match transb {
@@ -168,7 +870,9 @@ where
         let c = match c {
             Some(c) => {
                 blas_assert_eq!(c.dim(), (m, n), InvalidDim)?;
-                if c.view().is_fpref() {
+                // non-contiguous output is computed into a contiguous buffer, then assigned back to `c`
+                let c_contiguous = get_layout_array2(&c.view()) != BLASLayout::NonContiguous;
+                if c_contiguous && (assume_layout.is_some() || c.view().is_fpref()) {
                     ArrayOut2::ViewMut(c)
                 } else {
                     let c_buffer = c.view().to_col_layout()?.into_owned();
@@ -177,7 +881,7 @@ where
             },
             None => ArrayOut2::Owned(Array2::zeros((m, n).f())),
         };
-        let ldc = c.view().stride_of(Axis(1));
+        let ldc = leading_dim(&c.view());
 
         // finalize
         let driver = GEMM_Driver {
@@ -199,10 +903,103 @@ where
     }
 }
 
+/// Leading dimension of col-major matrix `x` to be passed to BLAS.
+///
+/// Single-column views (such as sliced blocks) may have zero stride of columns in ndarray. That stride never
+/// addresses an element, so it is raised to the smallest valid leading dimension; other strides are kept as is.
+fn leading_dim<F>(x: &ArrayView2<F>) -> isize {
+    match x.len_of(Axis(1)) {
+        1 => x.stride_of(Axis(1)).max(x.len_of(Axis(0)).max(1) as isize),
+        _ => x.stride_of(Axis(1)),
+    }
+}
+
 /* #endregion */
 
 /* #region BLAS wrapper */
 
+/// SYRK transpose flag if $\mathrm{op}(\mathbf{A}) \mathrm{op}(\mathbf{B})$ is a gram product that
+/// should be computed by SYRK under the given shape hint.
+fn gram_trans<F>(
+    a: &ArrayView2<F>,
+    b: &ArrayView2<F>,
+    transa: BLASTranspose,
+    transb: BLASTranspose,
+    shape_hint: ShapeClass,
+) -> Option<BLASTranspose>
+where
+    F: BLASFloat,
+{
+    if a.as_ptr() != b.as_ptr() || a.dim() != b.dim() || a.strides() != b.strides() {
+        return None;
+    }
+    // SYRK does not conjugate, so conjugate transpose is only the same for real types
+    let is_trans = |t| t == BLASTrans || (t == BLASConjTrans && !F::is_complex());
+    let (trans, m, k) = match (transa, transb) {
+        (BLASNoTrans, t) if is_trans(t) => (BLASNoTrans, a.len_of(Axis(0)), a.len_of(Axis(1))),
+        (t, BLASNoTrans) if is_trans(t) => (BLASTrans, a.len_of(Axis(1)), a.len_of(Axis(0))),
+        _ => return None,
+    };
+    let shape = match shape_hint {
+        ShapeClass::Auto => ShapeClass::detect(m, k),
+        _ => shape_hint,
+    };
+    match shape {
+        ShapeClass::TallSkinny => None,
+        _ => Some(trans),
+    }
+}
+
+/// Whether square `a` equals its transpose (conjugate transpose if `hermitian`), compared exactly.
+fn is_symmetric_exact<F>(a: &ArrayView2<F>, hermitian: bool) -> bool
+where
+    F: BLASFloat,
+{
+    let op = |x: F| if hermitian { F::conj(x) } else { x };
+    (0..a.len_of(Axis(0))).all(|i| (0..=i).all(|j| a[[i, j]] == op(a[[j, i]])))
+}
+
+/// Fill upper triangle of square matrix from its lower triangle.
+fn symmetrize_lower<F>(mut c: ArrayViewMut2<F>)
+where
+    F: Copy,
+{
+    let n = c.len_of(Axis(0));
+    for i in 0..n {
+        for j in 0..i {
+            c[[j, i]] = c[[i, j]];
+        }
+    }
+}
+
+fn get_gemm_layout<F>(
+    a: &ArrayView2<F>,
+    b: &ArrayView2<F>,
+    c: Option<ArrayView2<F>>,
+    layout: Option<BLASLayout>,
+    assume_layout: Option<BLASLayout>,
+) -> Result<BLASLayout, BLASError> {
+    match assume_layout {
+        Some(assume_layout) => {
+            if let Some(layout) = layout {
+                blas_assert_eq!(layout, assume_layout, InvalidFlag)?;
+            }
+            check_layout_array2(a, assume_layout)?;
+            check_layout_array2(b, assume_layout)?;
+            if let Some(c) = c {
+                check_layout_array2(&c, assume_layout)?;
+            }
+            Ok(assume_layout)
+        },
+        None => {
+            let layout_a = get_layout_array2(a);
+            let layout_b = get_layout_array2(b);
+            let layout_c = c.map(|c| get_layout_array2(&c));
+            Ok(get_layout_default_preferred(&[layout, layout_c], &[layout_a, layout_b]))
+        },
+    }
+}
+
 pub type GEMM<'a, 'b, 'c, F> = GEMM_Builder<'a, 'b, 'c, F>;
 pub type SGEMM<'a, 'b, 'c> = GEMM<'a, 'b, 'c, f32>;
 pub type DGEMM<'a, 'b, 'c> = GEMM<'a, 'b, 'c, f64>;
@@ -214,20 +1011,136 @@ where
     F: GEMMNum,
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
+        if self.transpose_output == Some(true) {
+            return Ok(Self { transpose_output: Some(false), ..self }.run()?.reversed_axes());
+        }
+        if let Some(Some((rtol, atol))) = self.verify {
+            return self.run_verified(rtol, atol);
+        }
+        if self.chunk_large_dims == Some(true) {
+            return self.run_chunked(usize::try_from(blas_int::MAX).unwrap_or(usize::MAX));
+        }
+        if let Some(hermitian) = self.detect_symmetry() {
+            let obj = Self { auto_detect_symmetry: Some(false), ..self };
+            return match hermitian {
+                false => Self { a_symmetric: Some(Some(BLASLower)), ..obj }.run(),
+                true => Self { a_hermitian: Some(Some(BLASLower)), ..obj }.run(),
+            };
+        }
+
         // initialize
-        let GEMM_ { a, b, c, alpha, beta, transa, transb, layout } = self.build()?;
+        let GEMM_ {
+            a,
+            b,
+            c,
+            alpha,
+            beta,
+            transa,
+            transb,
+            layout,
+            check_finite,
+            assume_layout,
+            allow_aliasing_copy,
+            a_symmetric,
+            a_hermitian,
+            output_pool,
+            shape_hint,
+            verify: _,
+            c_uninit: _,
+            transpose_output: _,
+            chunk_large_dims: _,
+            auto_detect_symmetry: _,
+            dispatch,
+        } = self.build()?;
+        let c = match output_pool {
+            Some(pool) => {
+                blas_assert!(c.is_none(), InvalidFlag, "output_pool can not be used together with c")?;
+                let m = if transa == BLASNoTrans { a.len_of(Axis(0)) } else { a.len_of(Axis(1)) };
+                let n = if transb == BLASNoTrans { b.len_of(Axis(1)) } else { b.len_of(Axis(0)) };
+                let layout = get_gemm_layout(&a, &b, None, layout, assume_layout)?;
+                let mut c = pool.view_mut((m, n), layout)?;
+                if beta != F::zero() {
+                    c.fill(F::zero());
+                }
+                Some(c)
+            },
+            None => c,
+        };
+        check_zero_stride(&a)?;
+        check_zero_stride(&b)?;
+        if let Some(c) = c.as_ref() {
+            check_zero_stride(&c.view())?;
+        }
+        let (a_alias, b_alias) = match c.as_ref() {
+            Some(c) => (arrays_overlap(&a, &c.view()), arrays_overlap(&b, &c.view())),
+            None => (false, false),
+        };
+        if (a_alias || b_alias) && !allow_aliasing_copy {
+            return blas_raise!(Aliasing, "output c overlaps with input a or b");
+        }
+        let a_copy = if a_alias { Some(a.to_owned()) } else { None };
+        let b_copy = if b_alias { Some(b.to_owned()) } else { None };
+        let a = a_copy.as_ref().map_or(a.view(), |a| a.view());
+        let b = b_copy.as_ref().map_or(b.view(), |b| b.view());
+
+        // symmetric or hermitian `a` is dispatched to SYMM or HEMM
+        if a_symmetric.is_some() || a_hermitian.is_some() {
+            blas_assert!(a_symmetric.is_none() || a_hermitian.is_none(), InvalidFlag)?;
+            blas_assert_eq!(a.len_of(Axis(0)), a.len_of(Axis(1)), InvalidDim)?;
+            blas_assert_eq!(transb, BLASNoTrans, InvalidFlag)?;
+            if assume_layout.is_some() {
+                return blas_raise!(
+                    InvalidFlag,
+                    "assume_layout is not supported with symmetric or hermitian a"
+                );
+            }
+            let structured_mm = match (a_symmetric, a_hermitian) {
+                (Some(uplo), _) => {
+                    blas_assert!(transa == BLASNoTrans || transa == BLASTrans, InvalidFlag)?;
+                    dispatch.symmetric_mm.map(|f| (f, uplo))
+                },
+                (_, Some(uplo)) => {
+                    blas_assert!(transa == BLASNoTrans || transa == BLASConjTrans, InvalidFlag)?;
+                    dispatch.hermitian_mm.map(|f| (f, uplo))
+                },
+                _ => None,
+            };
+            return match structured_mm {
+                Some((f, uplo)) => f(a, b, c, alpha, beta, uplo, layout, check_finite),
+                None => blas_raise!(InvalidFlag, "routine for symmetric or hermitian a is not set"),
+            };
+        }
+
+        // gram product is dispatched to SYRK
+        if let Some((gram, _)) = dispatch.gram {
+            let trans = gram_trans::<F>(&a, &b, transa, transb, shape_hint);
+            if let Some(trans) =
+                trans.filter(|_| assume_layout.is_none() && (c.is_none() || beta == F::zero()))
+            {
+                return gram(a, c, alpha, trans, layout, check_finite);
+            }
+        }
+
+        if check_finite {
+            check_finite_scalar(&[alpha, beta])?;
+            check_finite_array(&a)?;
+            check_finite_array(&b)?;
+        }
         let at = a.t();
         let bt = b.t();
 
-        let layout_a = get_layout_array2(&a);
-        let layout_b = get_layout_array2(&b);
-        let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
+        let layout = get_gemm_layout(&a, &b, c.as_ref().map(|c| c.view()), layout, assume_layout)?;
 
-        let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a, layout_b]);
         if layout == BLASColMajor {
             // F-contiguous: C = op(A) op(B)
-            let (transa, a_cow) = flip_trans_fpref(transa, &a, &at, false)?;
-            let (transb, b_cow) = flip_trans_fpref(transb, &b, &bt, false)?;
+            let (transa, a_cow) = match assume_layout {
+                Some(_) => (transa, CowArray::from(a.view())),
+                None => flip_trans_fpref(transa, &a, &at, false)?,
+            };
+            let (transb, b_cow) = match assume_layout {
+                Some(_) => (transb, CowArray::from(b.view())),
+                None => flip_trans_fpref(transb, &b, &bt, false)?,
+            };
             let obj = GEMM_ {
                 a: a_cow.view(),
                 b: b_cow.view(),
@@ -237,12 +1150,31 @@ where
                 transa,
                 transb,
                 layout: Some(BLASColMajor),
+                check_finite,
+                assume_layout: assume_layout.map(|_| BLASColMajor),
+                allow_aliasing_copy,
+                a_symmetric: None,
+                a_hermitian: None,
+                output_pool: None,
+                shape_hint: ShapeClass::Auto,
+                verify: None,
+                c_uninit: false,
+                transpose_output: false,
+                chunk_large_dims: false,
+                auto_detect_symmetry: false,
+                dispatch: GEMMDispatch::default(),
             };
             return obj.driver()?.run_blas();
         } else if layout == BLASRowMajor {
             // C-contiguous: C' = op(B') op(A')
-            let (transa, a_cow) = flip_trans_cpref(transa, &a, &at, false)?;
-            let (transb, b_cow) = flip_trans_cpref(transb, &b, &bt, false)?;
+            let (transa, a_cow) = match assume_layout {
+                Some(_) => (transa, CowArray::from(a.view())),
+                None => flip_trans_cpref(transa, &a, &at, false)?,
+            };
+            let (transb, b_cow) = match assume_layout {
+                Some(_) => (transb, CowArray::from(b.view())),
+                None => flip_trans_cpref(transb, &b, &bt, false)?,
+            };
             let obj = GEMM_ {
                 a: b_cow.t(),
                 b: a_cow.t(),
@@ -252,6 +1184,19 @@ where
                 transa: transb,
                 transb: transa,
                 layout: Some(BLASColMajor),
+                check_finite,
+                assume_layout: assume_layout.map(|_| BLASColMajor),
+                allow_aliasing_copy,
+                a_symmetric: None,
+                a_hermitian: None,
+                output_pool: None,
+                shape_hint: ShapeClass::Auto,
+                verify: None,
+                c_uninit: false,
+                transpose_output: false,
+                chunk_large_dims: false,
+                auto_detect_symmetry: false,
+                dispatch: GEMMDispatch::default(),
             };
             return Ok(obj.driver()?.run_blas()?.reversed_axes());
         } else {
@@ -260,4 +1205,301 @@ where
     }
 }
 
+impl<'a, 'b, 'c, F> GEMM_Builder<'a, 'b, 'c, F>
+where
+    F: GEMMNum,
+{
+    fn run_verified(self, rtol: F::RealFloat, atol: F::RealFloat) -> Result<ArrayOut2<'c, F>, BLASError> {
+        if self.a_symmetric.flatten().is_some() || self.a_hermitian.flatten().is_some() {
+            return blas_raise!(InvalidFlag, "verify is not supported with symmetric or hermitian a");
+        }
+        let a = self.a.ok_or(BLASError::UninitializedField("a"))?;
+        let b = self.b.ok_or(BLASError::UninitializedField("b"))?;
+        let alpha = self.alpha.unwrap_or(F::one());
+        let beta = self.beta.unwrap_or(F::zero());
+        let transa = self.transa.unwrap_or(BLASNoTrans);
+        let transb = self.transb.unwrap_or(BLASNoTrans);
+        // input `c` is only read for nonzero beta (it may be uninitialized otherwise)
+        let c = match beta == F::zero() {
+            true => None,
+            false => self.c.as_ref().and_then(|c| c.as_ref()).map(|c| c.to_owned()),
+        };
+
+        let out = Self { verify: Some(None), ..self }.run()?;
+        let expected = reference::gemm(a, b, c.as_ref().map(|c| c.view()), alpha, beta, transa, transb)?;
+        reference::assert_allclose(&out.view(), &expected.view(), rtol, atol);
+        Ok(out)
+    }
+
+    /// Run, and report which matrices were explicitly copied and the layout of computation.
+    ///
+    /// Matrices copied are those predicted by [`plan`](Self::plan), and number of copies is counted during
+    /// the call, so both are available to check against each other.
+    pub fn run_with_report(self) -> Result<(ArrayOut2<'c, F>, CopyReport), BLASError> {
+        let plan = self.plan()?;
+        CopyReport::track(plan, || self.run())
+    }
+
+    /// Run, with each BLAS call limited to dimensions $m$, $n$, $k$ not larger than `max_dim`.
+    ///
+    /// Output is tiled into blocks of at most `max_dim` rows and columns, and each block is computed by GEMM
+    /// on the corresponding slices of `a` and `b`; if $k$ is larger than `max_dim`, the inner dimension is also
+    /// split, with chunks after the first accumulated into the block (`beta = 1`). If no dimension exceeds
+    /// `max_dim`, this is the same as [`run`](BLASBuilder::run). [`GEMM_::chunk_large_dims`] uses the maximum
+    /// of BLAS integer as `max_dim`; smaller values give the same result up to rounding, with more BLAS calls.
+    pub fn run_chunked(self, max_dim: usize) -> Result<ArrayOut2<'c, F>, BLASError> {
+        blas_assert!(max_dim > 0, InvalidDim, "max_dim should be positive")?;
+        let obj = Self { chunk_large_dims: Some(false), ..self };
+        let a = obj.a.as_ref().ok_or(BLASError::UninitializedField("a"))?;
+        let transa = obj.transa.unwrap_or(BLASNoTrans);
+        let transb = obj.transb.unwrap_or(BLASNoTrans);
+        let (m, k) = match transa {
+            BLASNoTrans => a.dim(),
+            _ => a.t().dim(),
+        };
+        let n = match (transb, obj.b.as_ref()) {
+            (BLASNoTrans, Some(b)) => b.len_of(Axis(1)),
+            (_, Some(b)) => b.len_of(Axis(0)),
+            (_, None) => 0,
+        };
+        if m <= max_dim && n <= max_dim && k <= max_dim {
+            return obj.run();
+        }
+
+        let GEMM_ {
+            a,
+            b,
+            c,
+            alpha,
+            beta,
+            transa,
+            transb,
+            layout,
+            check_finite,
+            assume_layout,
+            allow_aliasing_copy,
+            a_symmetric,
+            a_hermitian,
+            output_pool,
+            shape_hint,
+            verify,
+            c_uninit: _,
+            transpose_output,
+            chunk_large_dims: _,
+            auto_detect_symmetry: _,
+            dispatch,
+        } = obj.build()?;
+        blas_assert!(
+            a_symmetric.is_none() && a_hermitian.is_none() && output_pool.is_none() && verify.is_none(),
+            InvalidFlag,
+            "chunked GEMM is not supported with symmetric or hermitian a, output_pool, or verify"
+        )?;
+        blas_assert!(transa != BLASConjNoTrans && transb != BLASConjNoTrans, InvalidFlag)?;
+        let kb = if transb == BLASNoTrans { b.len_of(Axis(0)) } else { b.len_of(Axis(1)) };
+        blas_assert_eq!(kb, k, InvalidDim)?;
+
+        // overlapping input may be overwritten by earlier blocks, so it is checked (and copied) as a whole
+        let (a_alias, b_alias) = match c.as_ref() {
+            Some(c) => (arrays_overlap(&a, &c.view()), arrays_overlap(&b, &c.view())),
+            None => (false, false),
+        };
+        if (a_alias || b_alias) && !allow_aliasing_copy {
+            return blas_raise!(Aliasing, "output c overlaps with input a or b");
+        }
+        let a_copy = if a_alias { Some(a.to_owned()) } else { None };
+        let b_copy = if b_alias { Some(b.to_owned()) } else { None };
+        let a = a_copy.as_ref().map_or(a.view(), |a| a.view());
+        let b = b_copy.as_ref().map_or(b.view(), |b| b.view());
+
+        let mut c = match c {
+            Some(c) => {
+                blas_assert_eq!(c.dim(), (m, n), InvalidDim)?;
+                ArrayOut2::ViewMut(c)
+            },
+            None => match get_gemm_layout(&a, &b, None, layout, assume_layout)? {
+                BLASColMajor => ArrayOut2::Owned(Array2::zeros((m, n).f())),
+                _ => ArrayOut2::Owned(Array2::zeros((m, n))),
+            },
+        };
+
+        if k == 0 {
+            scale_by_beta(c.view_mut(), beta);
+        }
+        let chunks = |len: usize| (0..len).step_by(max_dim).map(move |i| i..len.min(i + max_dim));
+        for i in chunks(m) {
+            for j in chunks(n) {
+                for p in chunks(k) {
+                    // rows `i` and columns `p` of op(A), rows `p` and columns `j` of op(B)
+                    let a_blk = match transa {
+                        BLASNoTrans => a.slice(s![i.clone(), p.clone()]),
+                        _ => a.slice(s![p.clone(), i.clone()]),
+                    };
+                    let b_blk = match transb {
+                        BLASNoTrans => b.slice(s![p.clone(), j.clone()]),
+                        _ => b.slice(s![j.clone(), p.clone()]),
+                    };
+                    let c_blk = c.view_mut().slice_move(s![i.clone(), j.clone()]);
+                    let builder = GEMM::default()
+                        .a(a_blk)
+                        .b(b_blk)
+                        .c(c_blk)
+                        .alpha(alpha)
+                        .beta(if p.start == 0 { beta } else { F::one() })
+                        .transa(transa)
+                        .transb(transb)
+                        .check_finite(check_finite);
+                    GEMM_Builder {
+                        layout: Some(layout),
+                        assume_layout: Some(assume_layout),
+                        shape_hint: Some(shape_hint),
+                        dispatch: Some(dispatch),
+                        ..builder
+                    }
+                    .run()?;
+                }
+            }
+        }
+        Ok(match transpose_output {
+            true => c.reversed_axes(),
+            false => c,
+        })
+    }
+
+    /// Describe what [`run`](BLASBuilder::run) would perform, without actual computation.
+    pub fn plan(&self) -> Result<BlasPlan, BLASError> {
+        let a = self.a.as_ref().ok_or(BLASError::UninitializedField("a"))?;
+        let b = self.b.as_ref().ok_or(BLASError::UninitializedField("b"))?;
+        let c = self.c.as_ref().and_then(|c| c.as_ref()).map(|c| c.view());
+        let transa = self.transa.unwrap_or(BLASNoTrans);
+        let transb = self.transb.unwrap_or(BLASNoTrans);
+        let layout = self.layout.flatten();
+        let assume_layout = self.assume_layout.flatten();
+        if let Some(hermitian) = self.detect_symmetry() {
+            return self.plan_symmetric(hermitian);
+        }
+        if self.a_symmetric.flatten().is_some() || self.a_hermitian.flatten().is_some() {
+            return blas_raise!(InvalidFlag, "plan is not available for symmetric or hermitian a");
+        }
+        let beta = self.beta.unwrap_or(F::zero());
+        let shape_hint = self.shape_hint.unwrap_or_default();
+        if let Some((_, gram_plan)) = self.dispatch.and_then(|d| d.gram) {
+            let trans = gram_trans::<F>(a, b, transa, transb, shape_hint);
+            if let Some(trans) =
+                trans.filter(|_| assume_layout.is_none() && (c.is_none() || beta == F::zero()))
+            {
+                return gram_plan(a, trans, layout);
+            }
+        }
+
+        let (m, k) = match transa {
+            BLASNoTrans => (a.len_of(Axis(0)), a.len_of(Axis(1))),
+            BLASTrans | BLASConjTrans => (a.len_of(Axis(1)), a.len_of(Axis(0))),
+            _ => blas_invalid!(transa)?,
+        };
+        let (kb, n) = match transb {
+            BLASNoTrans => (b.len_of(Axis(0)), b.len_of(Axis(1))),
+            BLASTrans | BLASConjTrans => (b.len_of(Axis(1)), b.len_of(Axis(0))),
+            _ => blas_invalid!(transb)?,
+        };
+        blas_assert_eq!(kb, k, InvalidDim)?;
+        if let Some(c) = c.as_ref() {
+            blas_assert_eq!(c.dim(), (m, n), InvalidDim)?;
+        }
+
+        let layout = get_gemm_layout(a, b, c, layout, assume_layout)?;
+        let (a_alias, b_alias) = match c.as_ref() {
+            Some(c) => (arrays_overlap(a, c), arrays_overlap(b, c)),
+            None => (false, false),
+        };
+        if (a_alias || b_alias) && !self.allow_aliasing_copy.unwrap_or(false) {
+            return blas_raise!(Aliasing, "output c overlaps with input a or b");
+        }
+        let mut copies = Vec::new();
+        if a_alias || (assume_layout.is_none() && flip_trans_copies(transa, a, layout, false)) {
+            copies.push("a");
+        }
+        if b_alias || (assume_layout.is_none() && flip_trans_copies(transb, b, layout, false)) {
+            copies.push("b");
+        }
+        if assume_layout.is_none() {
+            if let Some(c) = c.as_ref() {
+                let c_pref = match layout {
+                    BLASColMajor => c.is_fpref(),
+                    _ => c.is_cpref(),
+                };
+                if !c_pref {
+                    copies.push("c");
+                }
+            }
+        }
+
+        Ok(BlasPlan {
+            routine: blas_routine_name::<F>(["sgemm", "dgemm", "cgemm", "zgemm"]),
+            m,
+            n,
+            k,
+            flops: blas_flops::<F>(2 * m as u64 * n as u64 * k as u64),
+            layout,
+            copies,
+        })
+    }
+
+    /// Plan of symmetric (or hermitian) `a` found by [`GEMM_::auto_detect_symmetry`], dispatched to SYMM (or
+    /// HEMM).
+    fn plan_symmetric(&self, hermitian: bool) -> Result<BlasPlan, BLASError> {
+        let a = self.a.as_ref().ok_or(BLASError::UninitializedField("a"))?;
+        let b = self.b.as_ref().ok_or(BLASError::UninitializedField("b"))?;
+        let c = self.c.as_ref().and_then(|c| c.as_ref()).map(|c| c.view());
+        let (m, n) = b.dim();
+        blas_assert_eq!(a.dim(), (m, m), InvalidDim)?;
+        if let Some(c) = c.as_ref() {
+            blas_assert_eq!(c.dim(), (m, n), InvalidDim)?;
+        }
+
+        let (a_alias, b_alias) = match c.as_ref() {
+            Some(c) => (arrays_overlap(a, c), arrays_overlap(b, c)),
+            None => (false, false),
+        };
+        if (a_alias || b_alias) && !self.allow_aliasing_copy.unwrap_or(false) {
+            return blas_raise!(Aliasing, "output c overlaps with input a or b");
+        }
+        let layout_c = c.as_ref().map(get_layout_array2);
+        let layout = get_layout_default_preferred(
+            &[self.layout.flatten(), layout_c],
+            &[get_layout_array2(a), get_layout_array2(b)],
+        );
+        let pref = |x: &ArrayView2<F>| match layout {
+            BLASColMajor => x.is_fpref(),
+            _ => x.is_cpref(),
+        };
+
+        // SYMM takes transpose of `a` in the other layout for free, while HEMM does not
+        let hemm = hermitian && F::is_complex();
+        let mut copies = Vec::new();
+        if a_alias || !(pref(a) || (!hemm && (a.is_fpref() || a.is_cpref()))) {
+            copies.push("a");
+        }
+        if b_alias || !pref(b) {
+            copies.push("b");
+        }
+        if c.as_ref().is_some_and(|c| !pref(c)) {
+            copies.push("c");
+        }
+
+        let names = match hermitian {
+            false => ["ssymm", "dsymm", "csymm", "zsymm"],
+            true => ["ssymm", "dsymm", "chemm", "zhemm"],
+        };
+        Ok(BlasPlan {
+            routine: blas_routine_name::<F>(names),
+            m,
+            n,
+            k: m,
+            flops: blas_flops::<F>(2 * m as u64 * m as u64 * n as u64),
+            layout,
+            copies,
+        })
+    }
+}
+
 /* #endregion */
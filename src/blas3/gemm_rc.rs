@@ -0,0 +1,97 @@
+use crate::blas3::gemm::{GEMMNum, GEMM};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::{prelude::*, Zip};
+use num_complex::Complex;
+
+/* #region BLAS builder */
+
+/// Mixed real-complex matrix multiply, with real `a` and complex `b`.
+///
+/// $\mathbf{C} = \alpha \mathrm{op} (\mathbf{A}) \mathrm{op} (\mathbf{B}) + \beta \mathbf{C}$, computed by splitting
+/// $\mathbf{B}$ into real and imaginary parts and calling real GEMM twice, instead of widening $\mathbf{A}$ to
+/// complex.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct GEMM_RC_<'a, 'b, 'c, F>
+where
+    F: GEMMNum<RealFloat = F>,
+{
+    pub a: ArrayView2<'a, F>,
+    pub b: ArrayView2<'b, Complex<F>>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub c: Option<ArrayViewMut2<'c, Complex<F>>>,
+    #[builder(setter(into), default = "Complex::new(F::one(), F::zero())")]
+    pub alpha: Complex<F>,
+    #[builder(setter(into), default = "Complex::new(F::zero(), F::zero())")]
+    pub beta: Complex<F>,
+    #[builder(setter(into), default = "BLASNoTrans")]
+    pub transa: BLASTranspose,
+    #[builder(setter(into), default = "BLASNoTrans")]
+    pub transb: BLASTranspose,
+}
+
+impl_builder_clone!(
+    GEMM_RC_Builder<'a, 'b, 'c>,
+    GEMMNum<RealFloat = F>,
+    [a, b, alpha, beta, transa, transb],
+    [c]
+);
+impl_builder_with_lda!(GEMM_RC_Builder<'a, 'b, 'c>, GEMMNum<RealFloat = F>);
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type GEMM_RC<'a, 'b, 'c, F> = GEMM_RC_Builder<'a, 'b, 'c, F>;
+pub type SGEMM_RC<'a, 'b, 'c> = GEMM_RC<'a, 'b, 'c, f32>;
+pub type DGEMM_RC<'a, 'b, 'c> = GEMM_RC<'a, 'b, 'c, f64>;
+
+impl<'a, 'b, 'c, F> BLASBuilder<'c, Complex<F>, Ix2> for GEMM_RC_Builder<'a, 'b, 'c, F>
+where
+    F: GEMMNum<RealFloat = F>,
+{
+    fn run(self) -> Result<ArrayOut2<'c, Complex<F>>, BLASError> {
+        // initialize
+        let GEMM_RC_ { a, b, c, alpha, beta, transa, transb } = self.build()?;
+
+        // conjugate of real matrix is itself; conjugate of complex matrix negates imaginary part
+        let transa = match transa {
+            BLASNoTrans => BLASNoTrans,
+            BLASTrans | BLASConjTrans => BLASTrans,
+            _ => blas_invalid!(transa)?,
+        };
+        let (transb, conjb) = match transb {
+            BLASNoTrans => (BLASNoTrans, false),
+            BLASTrans => (BLASTrans, false),
+            BLASConjTrans => (BLASTrans, true),
+            _ => blas_invalid!(transb)?,
+        };
+
+        // split real and imaginary parts, and perform real GEMM on each
+        let b_re = b.mapv(|x| x.re);
+        let b_im = b.mapv(|x| if conjb { F::zero() - x.im } else { x.im });
+        let c_re = GEMM::default().a(a).b(b_re.view()).transa(transa).transb(transb).run()?.into_owned();
+        let c_im = GEMM::default().a(a).b(b_im.view()).transa(transa).transb(transb).run()?.into_owned();
+
+        // optional intent(out)
+        let mut c = match c {
+            Some(c) => {
+                blas_assert_eq!(c.dim(), c_re.dim(), InvalidDim)?;
+                ArrayOut2::ViewMut(c)
+            },
+            None => ArrayOut2::Owned(Array2::zeros(c_re.dim())),
+        };
+
+        // recombine
+        let beta_zero = beta == Complex::new(F::zero(), F::zero());
+        Zip::from(c.view_mut()).and(&c_re).and(&c_im).for_each(|c, &re, &im| {
+            let ab = alpha * Complex::new(re, im);
+            *c = if beta_zero { ab } else { ab + beta * *c };
+        });
+        Ok(c)
+    }
+}
+
+/* #endregion */
@@ -95,20 +95,11 @@ where
         if n == 0 {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
-            if uplo == BLASLower.try_into()? {
-                for i in 0..n {
-                    c.view_mut().slice_mut(s![i.., i]).mapv_inplace(|v| v * beta);
-                }
-            } else if uplo == BLASUpper.try_into()? {
-                for i in 0..n {
-                    c.view_mut().slice_mut(s![..=i, i]).mapv_inplace(|v| v * beta);
-                }
-            } else {
-                blas_invalid!(uplo)?
-            }
+            scale_by_beta_triangle(c.view_mut(), beta, uplo)?;
             return Ok(c.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("GEMMT", dims: [n, k], flags: [uplo, transa, transb], scalars: [alpha, beta], arrays: [a, b, c]);
         unsafe {
             F::gemmt(&uplo, &transa, &transb, &n, &k, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
         }
@@ -116,12 +107,57 @@ where
     }
 }
 
+impl<'a, 'b, 'c, F> GEMMT_Driver<'a, 'b, 'c, F>
+where
+    F: GEMMTNum,
+{
+    /// Dimensions `(n, k)` passed to BLAS.
+    pub fn dims(&self) -> (blas_int, blas_int) {
+        (self.n, self.k)
+    }
+
+    /// Scalars `(alpha, beta)` passed to BLAS.
+    pub fn scalars(&self) -> (F, F) {
+        (self.alpha, self.beta)
+    }
+
+    /// View of input matrix `a` passed to BLAS.
+    pub fn a_view(&self) -> ArrayView2<'_, F> {
+        self.a.view()
+    }
+
+    /// View of input matrix `b` passed to BLAS.
+    pub fn b_view(&self) -> ArrayView2<'_, F> {
+        self.b.view()
+    }
+
+    /// View of output matrix `c` passed to BLAS.
+    pub fn c_view(&self) -> ArrayView2<'_, F> {
+        self.c.view()
+    }
+
+    /// Leading dimension of `a` passed to BLAS.
+    pub fn lda(&self) -> blas_int {
+        self.lda
+    }
+
+    /// Leading dimension of `b` passed to BLAS.
+    pub fn ldb(&self) -> blas_int {
+        self.ldb
+    }
+
+    /// Leading dimension of `c` passed to BLAS.
+    pub fn ldc(&self) -> blas_int {
+        self.ldc
+    }
+}
+
 /* #endregion */
 
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct GEMMT_<'a, 'b, 'c, F>
 where
     F: GEMMTNum,
@@ -131,9 +167,9 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub c: Option<ArrayViewMut2<'c, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
-    #[builder(setter(into), default = "F::zero()")]
+    #[builder(setter(custom), default = "F::zero()")]
     pub beta: F,
     #[builder(setter(into), default = "BLASLower")]
     pub uplo: BLASUpLo,
@@ -143,18 +179,35 @@ where
     pub transb: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(
+    GEMMT_Builder<'a, 'b, 'c>,
+    GEMMTNum,
+    [a, b, alpha, beta, uplo, transa, transb, layout, check_finite],
+    [c]
+);
+impl_builder_with_lda!(GEMMT_Builder<'a, 'b, 'c>, GEMMTNum);
+impl_builder_validate_uplo!(GEMMT_Builder<'a, 'b, 'c>, GEMMTNum);
+impl_builder_trans_str!(GEMMT_Builder<'a, 'b, 'c>, GEMMTNum, [transa => transa_str, transb => transb_str]);
+impl_builder_scalar!(GEMMT_Builder<'a, 'b, 'c>, GEMMTNum, [alpha, beta]);
+
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for GEMMT_<'a, 'b, 'c, F>
 where
     F: GEMMTNum,
 {
     fn driver(self) -> Result<GEMMT_Driver<'a, 'b, 'c, F>, BLASError> {
-        let Self { a, b, c, alpha, beta, uplo, transa, transb, layout } = self;
+        let Self { a, b, c, alpha, beta, uplo, transa, transb, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref() && b.is_fpref());
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
+        blas_assert!(
+            a.is_fpref() && b.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
 
         // initialize intent(hide)
         let (n, k) = match transa {
@@ -223,7 +276,17 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let GEMMT_ { a, b, c, alpha, beta, uplo, transa, transb, layout } = self.build()?;
+        let GEMMT_ { a, b, c, alpha, beta, uplo, transa, transb, layout, check_finite } = self.build()?;
+        check_zero_stride(&a)?;
+        check_zero_stride(&b)?;
+        if let Some(c) = c.as_ref() {
+            check_zero_stride(&c.view())?;
+        }
+        if check_finite {
+            check_finite_scalar(&[alpha, beta])?;
+            check_finite_array(&a)?;
+            check_finite_array(&b)?;
+        }
         let at = a.t();
         let bt = b.t();
 
@@ -231,7 +294,7 @@ where
         let layout_b = get_layout_array2(&b);
         let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
 
-        let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a, layout_b]);
+        let layout = get_layout_default_preferred(&[layout, layout_c], &[layout_a, layout_b]);
         if layout == BLASColMajor {
             let (transa, a_cow) = flip_trans_fpref(transa, &a, &at, false)?;
             let (transb, b_cow) = flip_trans_fpref(transb, &b, &bt, false)?;
@@ -245,6 +308,7 @@ where
                 transa,
                 transb,
                 layout: Some(BLASColMajor),
+                check_finite,
             };
             return obj.driver()?.run_blas();
         } else if layout == BLASRowMajor {
@@ -260,6 +324,7 @@ where
                 transa: transb,
                 transb: transa,
                 layout: Some(BLASColMajor),
+                check_finite,
             };
             return Ok(obj.driver()?.run_blas()?.reversed_axes());
         } else {
@@ -5,6 +5,20 @@ use ndarray::prelude::*;
 
 /* #region BLAS func */
 
+/// Floats with a `?hemm` BLAS routine; only implemented for `c32` and `c64`.
+///
+/// Real types are rejected at compile time; for them `HEMM` coincides with `SYMM`:
+///
+/// ```compile_fail
+/// use blas_array2::prelude::*;
+/// use ndarray::prelude::*;
+/// let a = Array2::<f64>::zeros((3, 3));
+/// let c = HEMM::<f64>::default().a(a.view()).b(a.view()).run();
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`HEMM` requires a complex float type (`c32` or `c64`), found `{Self}`",
+    note = "for real types, use `SYMM` instead"
+)]
 pub trait HEMMNum: BLASFloat {
     unsafe fn hemm(
         side: *const c_char,
@@ -86,6 +100,7 @@ where
             return Ok(c.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("HEMM", dims: [m, n], flags: [side, uplo], scalars: [alpha, beta], arrays: [a, b, c]);
         unsafe {
             F::hemm(&side, &uplo, &m, &n, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
         }
@@ -93,12 +108,57 @@ where
     }
 }
 
+impl<'a, 'b, 'c, F> HEMM_Driver<'a, 'b, 'c, F>
+where
+    F: HEMMNum,
+{
+    /// Dimensions `(m, n)` passed to BLAS.
+    pub fn dims(&self) -> (blas_int, blas_int) {
+        (self.m, self.n)
+    }
+
+    /// Scalars `(alpha, beta)` passed to BLAS.
+    pub fn scalars(&self) -> (F, F) {
+        (self.alpha, self.beta)
+    }
+
+    /// View of input matrix `a` passed to BLAS.
+    pub fn a_view(&self) -> ArrayView2<'_, F> {
+        self.a.view()
+    }
+
+    /// View of input matrix `b` passed to BLAS.
+    pub fn b_view(&self) -> ArrayView2<'_, F> {
+        self.b.view()
+    }
+
+    /// View of output matrix `c` passed to BLAS.
+    pub fn c_view(&self) -> ArrayView2<'_, F> {
+        self.c.view()
+    }
+
+    /// Leading dimension of `a` passed to BLAS.
+    pub fn lda(&self) -> blas_int {
+        self.lda
+    }
+
+    /// Leading dimension of `b` passed to BLAS.
+    pub fn ldb(&self) -> blas_int {
+        self.ldb
+    }
+
+    /// Leading dimension of `c` passed to BLAS.
+    pub fn ldc(&self) -> blas_int {
+        self.ldc
+    }
+}
+
 /* #endregion */
 
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct HEMM_<'a, 'b, 'c, F>
 where
     F: HEMMNum,
@@ -108,9 +168,9 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub c: Option<ArrayViewMut2<'c, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
-    #[builder(setter(into), default = "F::zero()")]
+    #[builder(setter(custom), default = "F::zero()")]
     pub beta: F,
     #[builder(setter(into), default = "BLASLeft")]
     pub side: BLASSide,
@@ -118,8 +178,27 @@ where
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
+    /// Pick `uplo` by the triangle of `a` that holds data, when the other strict triangle is exactly zero.
+    ///
+    /// This guards against silently wrong results when data is stored in the triangle opposite to `uplo`.
+    /// The override is reported by feature `trace`.
+    #[builder(setter(into), default = "false")]
+    pub auto_uplo_from_data: bool,
 }
 
+impl_builder_clone!(
+    HEMM_Builder<'a, 'b, 'c>,
+    HEMMNum,
+    [a, b, alpha, beta, side, uplo, layout, check_finite, auto_uplo_from_data],
+    [c]
+);
+impl_builder_with_lda!(HEMM_Builder<'a, 'b, 'c>, HEMMNum);
+impl_builder_validate_uplo!(HEMM_Builder<'a, 'b, 'c>, HEMMNum);
+impl_builder_symmetric!(HEMM_Builder<'a, 'b, 'c>, HEMMNum);
+impl_builder_scalar!(HEMM_Builder<'a, 'b, 'c>, HEMMNum, [alpha, beta]);
+
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for HEMM_<'a, 'b, 'c, F>
 where
     F: HEMMNum,
@@ -128,8 +207,12 @@ where
         let Self { a, b, c, alpha, beta, side, uplo, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref() && a.is_fpref());
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
+        blas_assert!(
+            a.is_fpref() && b.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
 
         // initialize intent(hide)
         let m = b.len_of(Axis(0));
@@ -192,13 +275,25 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let HEMM_ { a, b, c, alpha, beta, side, uplo, layout, .. } = self.build()?;
+        let HEMM_ { a, b, c, alpha, beta, side, uplo, layout, check_finite, auto_uplo_from_data } =
+            self.build()?;
+        check_zero_stride(&a)?;
+        check_zero_stride(&b)?;
+        if let Some(c) = c.as_ref() {
+            check_zero_stride(&c.view())?;
+        }
+        let uplo = if auto_uplo_from_data { uplo_from_data(&a, uplo) } else { uplo };
+        if check_finite {
+            check_finite_scalar(&[alpha, beta])?;
+            check_finite_array(&a)?;
+            check_finite_array(&b)?;
+        }
 
         let layout_a = get_layout_array2(&a);
         let layout_b = get_layout_array2(&b);
         let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
 
-        let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a, layout_b]);
+        let layout = get_layout_default_preferred(&[layout, layout_c], &[layout_a, layout_b]);
         if layout == BLASColMajor {
             // F-contiguous: C = op(A) op(B)
             let a_cow = a.to_col_layout()?;
@@ -212,6 +307,8 @@ where
                 side,
                 uplo,
                 layout: Some(BLASColMajor),
+                check_finite,
+                auto_uplo_from_data: false,
             };
             return obj.driver()?.run_blas();
         } else {
@@ -227,6 +324,8 @@ where
                 side: side.flip()?,
                 uplo: uplo.flip()?,
                 layout: Some(BLASColMajor),
+                check_finite,
+                auto_uplo_from_data: false,
             };
             let c = obj.driver()?.run_blas()?.reversed_axes();
             return Ok(c);
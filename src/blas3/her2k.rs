@@ -6,6 +6,20 @@ use num_traits::*;
 
 /* #region BLAS func */
 
+/// Floats with a `?her2k` BLAS routine; only implemented for `c32` and `c64`.
+///
+/// Real types are rejected at compile time; for them `HER2K` coincides with `SYR2K`:
+///
+/// ```compile_fail
+/// use blas_array2::prelude::*;
+/// use ndarray::prelude::*;
+/// let a = Array2::<f64>::zeros((3, 3));
+/// let c = HER2K::<f64>::default().a(a.view()).b(a.view()).run();
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`HER2K` requires a complex float type (`c32` or `c64`), found `{Self}`",
+    note = "for real types, use `SYR2K` instead"
+)]
 pub trait HER2KNum: BLASFloat {
     unsafe fn her2k(
         uplo: *const c_char,
@@ -86,21 +100,13 @@ where
         if n == 0 {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
-            let beta_f = F::RealFloat::from(beta);
-            if uplo == BLASLower.try_into()? {
-                for i in 0..n {
-                    c.view_mut().slice_mut(s![i.., i]).mapv_inplace(|v| v * F::from_real(beta_f));
-                }
-            } else if uplo == BLASUpper.try_into()? {
-                for i in 0..n {
-                    c.view_mut().slice_mut(s![..=i, i]).mapv_inplace(|v| v * F::from_real(beta_f));
-                }
-            } else {
-                blas_invalid!(uplo)?
-            }
+            scale_by_beta_triangle(c.view_mut(), F::from_real(beta), uplo)?;
+            // as BLAS, diagonal of hermitian output is set to real
+            c.view_mut().diag_mut().mapv_inplace(|v| F::from_real(F::real(v)));
             return Ok(c.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("HER2K", dims: [n, k], flags: [uplo, trans], scalars: [alpha, beta], arrays: [a, b, c]);
         unsafe {
             F::her2k(&uplo, &trans, &n, &k, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
         }
@@ -108,12 +114,57 @@ where
     }
 }
 
+impl<'a, 'b, 'c, F> HER2K_Driver<'a, 'b, 'c, F>
+where
+    F: HER2KNum,
+{
+    /// Dimensions `(n, k)` passed to BLAS.
+    pub fn dims(&self) -> (blas_int, blas_int) {
+        (self.n, self.k)
+    }
+
+    /// Scalars `(alpha, beta)` passed to BLAS.
+    pub fn scalars(&self) -> (F, F::RealFloat) {
+        (self.alpha, self.beta)
+    }
+
+    /// View of input matrix `a` passed to BLAS.
+    pub fn a_view(&self) -> ArrayView2<'_, F> {
+        self.a.view()
+    }
+
+    /// View of input matrix `b` passed to BLAS.
+    pub fn b_view(&self) -> ArrayView2<'_, F> {
+        self.b.view()
+    }
+
+    /// View of output matrix `c` passed to BLAS.
+    pub fn c_view(&self) -> ArrayView2<'_, F> {
+        self.c.view()
+    }
+
+    /// Leading dimension of `a` passed to BLAS.
+    pub fn lda(&self) -> blas_int {
+        self.lda
+    }
+
+    /// Leading dimension of `b` passed to BLAS.
+    pub fn ldb(&self) -> blas_int {
+        self.ldb
+    }
+
+    /// Leading dimension of `c` passed to BLAS.
+    pub fn ldc(&self) -> blas_int {
+        self.ldc
+    }
+}
+
 /* #endregion */
 
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct HER2K_<'a, 'b, 'c, F>
 where
     F: HER2KNum,
@@ -123,7 +174,7 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub c: Option<ArrayViewMut2<'c, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
     #[builder(setter(into), default = "F::RealFloat::zero()")]
     pub beta: F::RealFloat,
@@ -133,18 +184,35 @@ where
     pub trans: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(
+    HER2K_Builder<'a, 'b, 'c>,
+    HER2KNum,
+    [a, b, alpha, beta, uplo, trans, layout, check_finite],
+    [c]
+);
+impl_builder_with_lda!(HER2K_Builder<'a, 'b, 'c>, HER2KNum);
+impl_builder_validate_uplo!(HER2K_Builder<'a, 'b, 'c>, HER2KNum);
+impl_builder_trans_str!(HER2K_Builder<'a, 'b, 'c>, HER2KNum, [trans => trans_str]);
+impl_builder_scalar!(HER2K_Builder<'a, 'b, 'c>, HER2KNum, [alpha]);
+
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for HER2K_<'a, 'b, 'c, F>
 where
     F: HER2KNum,
 {
     fn driver(self) -> Result<HER2K_Driver<'a, 'b, 'c, F>, BLASError> {
-        let Self { a, b, c, alpha, beta, uplo, trans, layout } = self;
+        let Self { a, b, c, alpha, beta, uplo, trans, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref() && a.is_fpref());
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
+        blas_assert!(
+            a.is_fpref() && b.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
 
         // initialize intent(hide)
         let (n, k) = match trans {
@@ -211,7 +279,18 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let HER2K_ { a, b, c, alpha, beta, uplo, trans, layout } = self.build()?;
+        let HER2K_ { a, b, c, alpha, beta, uplo, trans, layout, check_finite } = self.build()?;
+        check_zero_stride(&a)?;
+        check_zero_stride(&b)?;
+        if let Some(c) = c.as_ref() {
+            check_zero_stride(&c.view())?;
+        }
+        if check_finite {
+            check_finite_scalar(&[alpha])?;
+            check_finite_scalar(&[beta])?;
+            check_finite_array(&a)?;
+            check_finite_array(&b)?;
+        }
 
         // Note that since we will change `trans` in outer wrapper to utilize mix-contiguous
         // additional check to this parameter is required
@@ -226,7 +305,7 @@ where
         let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
 
         // her2k is difficult to gain any improvement when input matrices layouts are mixed
-        let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a, layout_b]);
+        let layout = get_layout_default_preferred(&[layout, layout_c], &[layout_a, layout_b]);
         if layout == BLASColMajor {
             // F-contiguous: C = A op(B) + B op(A)
             let a_cow = a.to_col_layout()?;
@@ -240,6 +319,7 @@ where
                 uplo,
                 trans,
                 layout: Some(BLASColMajor),
+                check_finite,
             };
             return obj.driver()?.run_blas();
         } else if layout == BLASRowMajor {
@@ -255,6 +335,7 @@ where
                 uplo: uplo.flip()?,
                 trans: trans.flip(true)?,
                 layout: Some(BLASColMajor),
+                check_finite,
             };
             return Ok(obj.driver()?.run_blas()?.reversed_axes());
         } else {
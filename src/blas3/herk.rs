@@ -6,6 +6,20 @@ use num_traits::*;
 
 /* #region BLAS func */
 
+/// Floats with a `?herk` BLAS routine; only implemented for `c32` and `c64`.
+///
+/// Real types are rejected at compile time; for them `HERK` coincides with `SYRK`:
+///
+/// ```compile_fail
+/// use blas_array2::prelude::*;
+/// use ndarray::prelude::*;
+/// let a = Array2::<f32>::zeros((3, 3));
+/// let c = HERK::<f32>::default().a(a.view()).run();
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`HERK` requires a complex float type (`c32` or `c64`), found `{Self}`",
+    note = "for real types, use `SYRK` instead"
+)]
 pub trait HERKNum: BLASFloat {
     unsafe fn herk(
         uplo: *const c_char,
@@ -79,21 +93,13 @@ where
         if n == 0 {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
-            let beta_f = F::from_real(beta);
-            if uplo == BLASLower.try_into()? {
-                for i in 0..n {
-                    c.view_mut().slice_mut(s![i.., i]).mapv_inplace(|v| v * beta_f);
-                }
-            } else if uplo == BLASUpper.try_into()? {
-                for i in 0..n {
-                    c.view_mut().slice_mut(s![..=i, i]).mapv_inplace(|v| v * beta_f);
-                }
-            } else {
-                blas_invalid!(uplo)?
-            }
+            scale_by_beta_triangle(c.view_mut(), F::from_real(beta), uplo)?;
+            // as BLAS, diagonal of hermitian output is set to real
+            c.view_mut().diag_mut().mapv_inplace(|v| F::from_real(F::real(v)));
             return Ok(c.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("HERK", dims: [n, k], flags: [uplo, trans], scalars: [alpha, beta], arrays: [a, c]);
         unsafe {
             F::herk(&uplo, &trans, &n, &k, &alpha, a_ptr, &lda, &beta, c_ptr, &ldc);
         }
@@ -101,12 +107,47 @@ where
     }
 }
 
+impl<'a, 'c, F> HERK_Driver<'a, 'c, F>
+where
+    F: HERKNum,
+{
+    /// Dimensions `(n, k)` passed to BLAS.
+    pub fn dims(&self) -> (blas_int, blas_int) {
+        (self.n, self.k)
+    }
+
+    /// Scalars `(alpha, beta)` passed to BLAS.
+    pub fn scalars(&self) -> (F::RealFloat, F::RealFloat) {
+        (self.alpha, self.beta)
+    }
+
+    /// View of input matrix `a` passed to BLAS.
+    pub fn a_view(&self) -> ArrayView2<'_, F> {
+        self.a.view()
+    }
+
+    /// View of output matrix `c` passed to BLAS.
+    pub fn c_view(&self) -> ArrayView2<'_, F> {
+        self.c.view()
+    }
+
+    /// Leading dimension of `a` passed to BLAS.
+    pub fn lda(&self) -> blas_int {
+        self.lda
+    }
+
+    /// Leading dimension of `c` passed to BLAS.
+    pub fn ldc(&self) -> blas_int {
+        self.ldc
+    }
+}
+
 /* #endregion */
 
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct HERK_<'a, 'c, F>
 where
     F: HERKNum,
@@ -117,26 +158,37 @@ where
     pub c: Option<ArrayViewMut2<'c, F>>,
     #[builder(setter(into), default = "F::RealFloat::one()")]
     pub alpha: F::RealFloat,
+    /// Real scaling of input `c`; imaginary part of diagonal of `c` is discarded, as BLAS does.
+    ///
+    /// If `c` is not given, output is zero-initialized, so any `beta` gives the same (hermitian) result.
     #[builder(setter(into), default = "F::RealFloat::zero()")]
     pub beta: F::RealFloat,
     #[builder(setter(into), default = "BLASLower")]
     pub uplo: BLASUpLo,
+    /// `ConjNoTrans` computes $\mathrm{conj}(\mathbf{A}) \mathbf{A}^\mathrm{T}$ on a conjugated copy of `a`.
     #[builder(setter(into), default = "BLASNoTrans")]
     pub trans: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(HERK_Builder<'a, 'c>, HERKNum, [a, alpha, beta, uplo, trans, layout, check_finite], [c]);
+impl_builder_with_lda!(HERK_Builder<'a, 'c>, HERKNum);
+impl_builder_validate_uplo!(HERK_Builder<'a, 'c>, HERKNum);
+impl_builder_trans_str!(HERK_Builder<'a, 'c>, HERKNum, [trans => trans_str]);
+
 impl<'a, 'c, F> BLASBuilder_<'c, F, Ix2> for HERK_<'a, 'c, F>
 where
     F: HERKNum,
 {
     fn driver(self) -> Result<HERK_Driver<'a, 'c, F>, BLASError> {
-        let Self { a, c, alpha, beta, uplo, trans, layout } = self;
+        let Self { a, c, alpha, beta, uplo, trans, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref());
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
+        blas_assert!(a.is_fpref(), InvalidStride, "only col-major arrays are accepted in inner driver")?;
 
         // initialize intent(hide) (cherk, zherk: NC accepted)
         let (n, k) = match trans {
@@ -192,7 +244,28 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let HERK_ { a, c, alpha, beta, uplo, trans, layout } = self.build()?;
+        let HERK_ { a, c, alpha, beta, uplo, trans, layout, check_finite } = self.build()?;
+        check_zero_stride(&a)?;
+        if let Some(c) = c.as_ref() {
+            check_zero_stride(&c.view())?;
+        }
+        if check_finite {
+            check_finite_scalar(&[alpha, beta])?;
+            check_finite_array(&a)?;
+        }
+
+        // conj(A) A^T (= conj(A) conj(A)^H) is performed by no-trans on conjugated temporary
+        let a_conj = match trans {
+            BLASConjNoTrans => {
+                blas_warn_layout_clone!(a, "Perform element-wise conjugate to matrix")?;
+                Some(conj_array(a.view()))
+            },
+            _ => None,
+        };
+        let (a, trans) = match a_conj.as_ref() {
+            Some(a_conj) => (a_conj.view(), BLASNoTrans),
+            None => (a.view(), trans),
+        };
         let at = a.t();
 
         // Note that since we will change `trans` in outer wrapper to utilize mix-contiguous
@@ -206,11 +279,20 @@ where
         let layout_a = get_layout_array2(&a);
         let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
 
-        let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a]);
+        let layout = get_layout_default_preferred(&[layout, layout_c], &[layout_a]);
         if layout == BLASColMajor {
             // F-contiguous: C = A op(A) or C = op(A) A
             let (trans, a_cow) = flip_trans_fpref(trans, &a, &at, true)?;
-            let obj = HERK_ { a: a_cow.view(), c, alpha, beta, uplo, trans, layout: Some(BLASColMajor) };
+            let obj = HERK_ {
+                a: a_cow.view(),
+                c,
+                alpha,
+                beta,
+                uplo,
+                trans,
+                layout: Some(BLASColMajor),
+                check_finite,
+            };
             return obj.driver()?.run_blas();
         } else if layout == BLASRowMajor {
             let (trans, a_cow) = flip_trans_cpref(trans, &a, &at, true)?;
@@ -222,6 +304,7 @@ where
                 uplo: uplo.flip()?,
                 trans: trans.flip(true)?,
                 layout: Some(BLASColMajor),
+                check_finite,
             };
             return Ok(obj.driver()?.run_blas()?.reversed_axes());
         } else {
@@ -0,0 +1,86 @@
+use crate::blas3::herk::{HERKNum, HERK};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::*;
+
+/* #region BLAS builder */
+
+/// Batched HERK, where the first axis of `a` (and `c`) is the batch dimension.
+///
+/// Each batch performs $\mathbf{C}_i = \alpha \mathbf{A}_i \mathrm{op} (\mathbf{A}_i) + \beta \mathbf{C}_i$ by
+/// [`HERK`], with the same parameters for all batches.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
+pub struct HERKBatch_<'a, 'c, F>
+where
+    F: HERKNum,
+{
+    pub a: ArrayView3<'a, F>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub c: Option<ArrayViewMut3<'c, F>>,
+    #[builder(setter(into), default = "F::RealFloat::one()")]
+    pub alpha: F::RealFloat,
+    #[builder(setter(into), default = "F::RealFloat::zero()")]
+    pub beta: F::RealFloat,
+    #[builder(setter(into), default = "BLASLower")]
+    pub uplo: BLASUpLo,
+    #[builder(setter(into), default = "BLASNoTrans")]
+    pub trans: BLASTranspose,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub layout: Option<BLASLayout>,
+}
+
+impl_builder_clone!(HERKBatch_Builder<'a, 'c>, HERKNum, [a, alpha, beta, uplo, trans, layout], [c]);
+impl_builder_validate_uplo!(HERKBatch_Builder<'a, 'c>, HERKNum);
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type HERKBatch<'a, 'c, F> = HERKBatch_Builder<'a, 'c, F>;
+
+impl<'a, 'c, F> BLASBuilder<'c, F, Ix3> for HERKBatch_Builder<'a, 'c, F>
+where
+    F: HERKNum,
+{
+    fn run(self) -> Result<ArrayOut3<'c, F>, BLASError> {
+        // initialize
+        let HERKBatch_ { a, c, alpha, beta, uplo, trans, layout } = self.build()?;
+        let (nbatch, n) = match trans {
+            BLASNoTrans => (a.len_of(Axis(0)), a.len_of(Axis(1))),
+            BLASConjTrans => (a.len_of(Axis(0)), a.len_of(Axis(2))),
+            _ => blas_invalid!(trans)?,
+        };
+
+        // optional intent(out)
+        let mut c = match c {
+            Some(c) => {
+                blas_assert_eq!(c.len_of(Axis(0)), nbatch, InvalidDim)?;
+                ArrayOut3::ViewMut(c)
+            },
+            None => ArrayOut3::Owned(Array3::zeros((nbatch, n, n))),
+        };
+
+        // perform computation for each batch, in parallel with feature `rayon`
+        let batch = |(a, c)| {
+            let obj = HERK::default().a(a).c(c).alpha(alpha).beta(beta).uplo(uplo).trans(trans);
+            let obj = match layout {
+                Some(layout) => obj.layout(layout),
+                None => obj,
+            };
+            obj.run().map(|_| ())
+        };
+        #[cfg(feature = "rayon")]
+        {
+            use ndarray::parallel::prelude::*;
+            a.outer_iter().into_par_iter().zip(c.view_mut().outer_iter_mut()).try_for_each(batch)?;
+        }
+        #[cfg(not(feature = "rayon"))]
+        a.outer_iter().zip(c.view_mut().outer_iter_mut()).try_for_each(batch)?;
+        Ok(c)
+    }
+}
+
+/* #endregion */
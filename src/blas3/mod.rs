@@ -1,10 +1,13 @@
 pub mod gemm;
+pub mod gemm_rc;
 pub mod hemm;
 pub mod her2k;
 pub mod herk;
+pub mod herk_batch;
 pub mod symm;
 pub mod syr2k;
 pub mod syrk;
+pub mod syrk_batch;
 pub mod trmm;
 pub mod trsm;
 
@@ -88,6 +88,7 @@ where
             return Ok(c.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("SYMM", dims: [m, n], flags: [side, uplo], scalars: [alpha, beta], arrays: [a, b, c]);
         unsafe {
             F::symm(&side, &uplo, &m, &n, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
         }
@@ -95,12 +96,57 @@ where
     }
 }
 
+impl<'a, 'b, 'c, F> SYMM_Driver<'a, 'b, 'c, F>
+where
+    F: SYMMNum,
+{
+    /// Dimensions `(m, n)` passed to BLAS.
+    pub fn dims(&self) -> (blas_int, blas_int) {
+        (self.m, self.n)
+    }
+
+    /// Scalars `(alpha, beta)` passed to BLAS.
+    pub fn scalars(&self) -> (F, F) {
+        (self.alpha, self.beta)
+    }
+
+    /// View of input matrix `a` passed to BLAS.
+    pub fn a_view(&self) -> ArrayView2<'_, F> {
+        self.a.view()
+    }
+
+    /// View of input matrix `b` passed to BLAS.
+    pub fn b_view(&self) -> ArrayView2<'_, F> {
+        self.b.view()
+    }
+
+    /// View of output matrix `c` passed to BLAS.
+    pub fn c_view(&self) -> ArrayView2<'_, F> {
+        self.c.view()
+    }
+
+    /// Leading dimension of `a` passed to BLAS.
+    pub fn lda(&self) -> blas_int {
+        self.lda
+    }
+
+    /// Leading dimension of `b` passed to BLAS.
+    pub fn ldb(&self) -> blas_int {
+        self.ldb
+    }
+
+    /// Leading dimension of `c` passed to BLAS.
+    pub fn ldc(&self) -> blas_int {
+        self.ldc
+    }
+}
+
 /* #endregion */
 
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct SYMM_<'a, 'b, 'c, F>
 where
     F: BLASFloat,
@@ -110,9 +156,9 @@ where
 
     #[builder(setter(into, strip_option), default = "None")]
     pub c: Option<ArrayViewMut2<'c, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
-    #[builder(setter(into), default = "F::zero()")]
+    #[builder(setter(custom), default = "F::zero()")]
     pub beta: F,
     #[builder(setter(into), default = "BLASLeft")]
     pub side: BLASSide,
@@ -120,8 +166,27 @@ where
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
+    /// Pick `uplo` by the triangle of `a` that holds data, when the other strict triangle is exactly zero.
+    ///
+    /// This guards against silently wrong results when data is stored in the triangle opposite to `uplo`.
+    /// The override is reported by feature `trace`.
+    #[builder(setter(into), default = "false")]
+    pub auto_uplo_from_data: bool,
 }
 
+impl_builder_clone!(
+    SYMM_Builder<'a, 'b, 'c>,
+    BLASFloat,
+    [a, b, alpha, beta, side, uplo, layout, check_finite, auto_uplo_from_data],
+    [c]
+);
+impl_builder_with_lda!(SYMM_Builder<'a, 'b, 'c>, BLASFloat);
+impl_builder_validate_uplo!(SYMM_Builder<'a, 'b, 'c>, BLASFloat);
+impl_builder_symmetric!(SYMM_Builder<'a, 'b, 'c>, BLASFloat);
+impl_builder_scalar!(SYMM_Builder<'a, 'b, 'c>, BLASFloat, [alpha, beta]);
+
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for SYMM_<'a, 'b, 'c, F>
 where
     F: SYMMNum,
@@ -130,8 +195,12 @@ where
         let Self { a, b, c, alpha, beta, side, uplo, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref() && a.is_fpref());
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
+        blas_assert!(
+            a.is_fpref() && b.is_fpref(),
+            InvalidStride,
+            "only col-major arrays are accepted in inner driver"
+        )?;
 
         // initialize intent(hide)
         let m = b.len_of(Axis(0));
@@ -196,14 +265,26 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let SYMM_ { a, b, c, alpha, beta, side, uplo, layout, .. } = self.build()?;
+        let SYMM_ { a, b, c, alpha, beta, side, uplo, layout, check_finite, auto_uplo_from_data } =
+            self.build()?;
+        check_zero_stride(&a)?;
+        check_zero_stride(&b)?;
+        if let Some(c) = c.as_ref() {
+            check_zero_stride(&c.view())?;
+        }
+        let uplo = if auto_uplo_from_data { uplo_from_data(&a, uplo) } else { uplo };
+        if check_finite {
+            check_finite_scalar(&[alpha, beta])?;
+            check_finite_array(&a)?;
+            check_finite_array(&b)?;
+        }
         let at = a.t();
 
         let layout_a = get_layout_array2(&a);
         let layout_b = get_layout_array2(&b);
         let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
 
-        let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a, layout_b]);
+        let layout = get_layout_default_preferred(&[layout, layout_c], &[layout_a, layout_b]);
         if layout == BLASColMajor {
             // F-contiguous: C = op(A) op(B)
             let (uplo, a_cow) = match layout_a.is_fpref() {
@@ -220,6 +301,8 @@ where
                 side,
                 uplo,
                 layout: Some(BLASColMajor),
+                check_finite,
+                auto_uplo_from_data: false,
             };
             return obj.driver()?.run_blas();
         } else {
@@ -238,6 +321,8 @@ where
                 side: side.flip()?,
                 uplo: uplo.flip()?,
                 layout: Some(BLASColMajor),
+                check_finite,
+                auto_uplo_from_data: false,
             };
             let c = obj.driver()?.run_blas()?.reversed_axes();
             return Ok(c);
@@ -1,7 +1,11 @@
+extern crate alloc;
+
 use crate::ffi::{self, blas_int, c_char};
 use crate::util::*;
+use alloc::vec::Vec;
 use derive_builder::Builder;
 use ndarray::prelude::*;
+use num_traits::{Float, Zero};
 
 /* #region BLAS func */
 
@@ -80,21 +84,11 @@ where
         if n == 0 {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
-            let beta_f = F::from(beta);
-            if uplo == BLASLower.try_into()? {
-                for i in 0..n {
-                    c.view_mut().slice_mut(s![i.., i]).mapv_inplace(|v| v * beta_f);
-                }
-            } else if uplo == BLASUpper.try_into()? {
-                for i in 0..n {
-                    c.view_mut().slice_mut(s![..=i, i]).mapv_inplace(|v| v * beta_f);
-                }
-            } else {
-                blas_invalid!(uplo)?
-            }
+            scale_by_beta_triangle(c.view_mut(), beta, uplo)?;
             return Ok(c.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("SYRK", dims: [n, k], flags: [uplo, trans], scalars: [alpha, beta], arrays: [a, c]);
         unsafe {
             F::syrk(&uplo, &trans, &n, &k, &alpha, a_ptr, &lda, &beta, c_ptr, &ldc);
         }
@@ -102,42 +96,102 @@ where
     }
 }
 
+impl<'a, 'c, F> SYRK_Driver<'a, 'c, F>
+where
+    F: SYRKNum,
+{
+    /// Dimensions `(n, k)` passed to BLAS.
+    pub fn dims(&self) -> (blas_int, blas_int) {
+        (self.n, self.k)
+    }
+
+    /// Scalars `(alpha, beta)` passed to BLAS.
+    pub fn scalars(&self) -> (F, F) {
+        (self.alpha, self.beta)
+    }
+
+    /// View of input matrix `a` passed to BLAS.
+    pub fn a_view(&self) -> ArrayView2<'_, F> {
+        self.a.view()
+    }
+
+    /// View of output matrix `c` passed to BLAS.
+    pub fn c_view(&self) -> ArrayView2<'_, F> {
+        self.c.view()
+    }
+
+    /// Leading dimension of `a` passed to BLAS.
+    pub fn lda(&self) -> blas_int {
+        self.lda
+    }
+
+    /// Leading dimension of `c` passed to BLAS.
+    pub fn ldc(&self) -> blas_int {
+        self.ldc
+    }
+}
+
 /* #endregion */
 
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct SYRK_<'a, 'c, F>
 where
     F: SYRKNum,
 {
     pub a: ArrayView2<'a, F>,
 
+    /// Output matrix updated in place; newly allocated if not given.
+    ///
+    /// As with [`GEMM_::c`](crate::blas3::gemm::GEMM_::c), views into arrays held in shared ownership
+    /// (`Arc::get_mut`, `ArcArray::view_mut`) are accepted.
     #[builder(setter(into, strip_option), default = "None")]
     pub c: Option<ArrayViewMut2<'c, F>>,
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
-    #[builder(setter(into), default = "F::zero()")]
+    #[builder(setter(custom), default = "F::zero()")]
     pub beta: F,
     #[builder(setter(into), default = "BLASLower")]
     pub uplo: BLASUpLo,
+    /// `ConjNoTrans` (complex types only) computes $\mathrm{conj}(\mathbf{A}) \mathrm{conj}(\mathbf{A})^\mathrm{T}$
+    /// on a conjugated copy of `a`.
     #[builder(setter(into), default = "BLASNoTrans")]
     pub trans: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
+    /// Scale each row $i$ of `a` by $\sqrt{w_i}$ (into a temporary) before computation.
+    ///
+    /// With `trans = T`, this gives the weighted Gram matrix $\mathbf{A}^T \mathbf{W} \mathbf{A}$, where
+    /// $\mathbf{W} = \mathrm{diag}(\mathbf{w})$. Weights should be non-negative, one for each row of `a`.
+    #[builder(setter(into, strip_option, name = "with_row_weights"), default = "None")]
+    pub row_weights: Option<ArrayView1<'a, F::RealFloat>>,
 }
 
+impl_builder_clone!(
+    SYRK_Builder<'a, 'c>,
+    SYRKNum,
+    [a, alpha, beta, uplo, trans, layout, check_finite, row_weights],
+    [c]
+);
+impl_builder_with_lda!(SYRK_Builder<'a, 'c>, SYRKNum);
+impl_builder_validate_uplo!(SYRK_Builder<'a, 'c>, SYRKNum);
+impl_builder_trans_str!(SYRK_Builder<'a, 'c>, SYRKNum, [trans => trans_str]);
+impl_builder_scalar!(SYRK_Builder<'a, 'c>, SYRKNum, [alpha, beta]);
+
 impl<'a, 'c, F> BLASBuilder_<'c, F, Ix2> for SYRK_<'a, 'c, F>
 where
     F: SYRKNum,
 {
     fn driver(self) -> Result<SYRK_Driver<'a, 'c, F>, BLASError> {
-        let Self { a, c, alpha, beta, uplo, trans, layout } = self;
+        let Self { a, c, alpha, beta, uplo, trans, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref());
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
+        blas_assert!(a.is_fpref(), InvalidStride, "only col-major arrays are accepted in inner driver")?;
 
         // initialize intent(hide)
         let (n, k) = match trans {
@@ -165,7 +219,8 @@ where
         let c = match c {
             Some(c) => {
                 blas_assert_eq!(c.dim(), (n, n), InvalidDim)?;
-                if c.view().is_fpref() {
+                // non-contiguous output is computed into a contiguous buffer, then assigned back to `c`
+                if get_layout_array2(&c.view()) != BLASLayout::NonContiguous && c.view().is_fpref() {
                     ArrayOut2::ViewMut(c)
                 } else {
                     let c_buffer = c.view().to_col_layout()?.into_owned();
@@ -197,6 +252,29 @@ where
 
 /* #region BLAS wrapper */
 
+/// Copy of `a` with each row $i$ scaled by $\sqrt{w_i}$, in the same memory order as `a`.
+fn scale_rows_sqrt<F>(a: &ArrayView2<F>, w: &ArrayView1<F::RealFloat>) -> Result<Array2<F>, BLASError>
+where
+    F: BLASFloat,
+{
+    blas_assert_eq!(w.len(), a.len_of(Axis(0)), InvalidDim)?;
+    blas_assert!(
+        w.iter().all(|&wi| wi >= F::RealFloat::zero()),
+        InvalidFlag,
+        "row weights should be non-negative"
+    )?;
+    let mut a_weighted = match a.is_fpref() {
+        true => Array2::zeros(a.raw_dim().f()),
+        false => Array2::zeros(a.raw_dim()),
+    };
+    a_weighted.assign(a);
+    for (mut row, &wi) in a_weighted.rows_mut().into_iter().zip(w.iter()) {
+        let scale = F::from_real(wi.sqrt());
+        row.mapv_inplace(|v| v * scale);
+    }
+    Ok(a_weighted)
+}
+
 pub type SYRK<'a, 'c, F> = SYRK_Builder<'a, 'c, F>;
 pub type SSYRK<'a, 'c> = SYRK<'a, 'c, f32>;
 pub type DSYRK<'a, 'c> = SYRK<'a, 'c, f64>;
@@ -209,7 +287,38 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let SYRK_ { a, c, alpha, beta, uplo, trans, layout } = self.build()?;
+        let SYRK_ { a, c, alpha, beta, uplo, trans, layout, check_finite, row_weights } = self.build()?;
+        check_zero_stride(&a)?;
+        if let Some(c) = c.as_ref() {
+            check_zero_stride(&c.view())?;
+        }
+        if check_finite {
+            check_finite_scalar(&[alpha, beta])?;
+            check_finite_array(&a)?;
+        }
+        let a_weighted = match row_weights {
+            Some(w) => Some(scale_rows_sqrt(&a, &w)?),
+            None => None,
+        };
+        let a = a_weighted.as_ref().map_or(a.view(), |a| a.view());
+
+        // conj(A) conj(A)^T is performed by no-trans on conjugated temporary
+        let a_conj = match trans {
+            BLASConjNoTrans => {
+                blas_assert!(
+                    F::is_complex(),
+                    InvalidFlag,
+                    "ConjNoTrans is only meaningful for complex types"
+                )?;
+                blas_warn_layout_clone!(a, "Perform element-wise conjugate to matrix")?;
+                Some(conj_array(a))
+            },
+            _ => None,
+        };
+        let (a, trans) = match a_conj.as_ref() {
+            Some(a_conj) => (a_conj.view(), BLASNoTrans),
+            None => (a, trans),
+        };
         let at = a.t();
 
         // Note that since we will change `trans` in outer wrapper to utilize mix-contiguous
@@ -230,11 +339,21 @@ where
         let layout_a = get_layout_array2(&a);
         let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
 
-        let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a]);
+        let layout = get_layout_default_preferred(&[layout, layout_c], &[layout_a]);
         if layout == BLASColMajor {
             // F-contiguous: C = A op(A) or C = op(A) A
             let (trans, a_cow) = flip_trans_fpref(trans, &a, &at, false)?;
-            let obj = SYRK_ { a: a_cow.view(), c, alpha, beta, uplo, trans, layout: Some(BLASColMajor) };
+            let obj = SYRK_ {
+                a: a_cow.view(),
+                c,
+                alpha,
+                beta,
+                uplo,
+                trans,
+                layout: Some(BLASColMajor),
+                check_finite,
+                row_weights: None,
+            };
             return obj.driver()?.run_blas();
         } else if layout == BLASRowMajor {
             let (trans, a_cow) = flip_trans_cpref(trans, &a, &at, false)?;
@@ -246,6 +365,8 @@ where
                 uplo: uplo.flip()?,
                 trans: trans.flip(false)?,
                 layout: Some(BLASColMajor),
+                check_finite,
+                row_weights: None,
             };
             return Ok(obj.driver()?.run_blas()?.reversed_axes());
         } else {
@@ -254,4 +375,81 @@ where
     }
 }
 
+impl<'a, 'c, F> SYRK_Builder<'a, 'c, F>
+where
+    F: SYRKNum,
+{
+    /// Run, and report which matrices were explicitly copied and the layout of computation.
+    ///
+    /// Matrices copied are those predicted by [`plan`](Self::plan), and number of copies is counted during
+    /// the call, so both are available to check against each other.
+    pub fn run_with_report(self) -> Result<(ArrayOut2<'c, F>, CopyReport), BLASError> {
+        let plan = self.plan()?;
+        CopyReport::track(plan, || self.run())
+    }
+
+    /// Describe what [`run`](BLASBuilder::run) would perform, without actual computation.
+    pub fn plan(&self) -> Result<BlasPlan, BLASError> {
+        let a = self.a.as_ref().ok_or(BLASError::UninitializedField("a"))?;
+        let c = self.c.as_ref().and_then(|c| c.as_ref()).map(|c| c.view());
+        let trans = self.trans.unwrap_or(BLASNoTrans);
+        let layout = self.layout.flatten();
+
+        let conj_a = trans == BLASConjNoTrans;
+        if conj_a {
+            blas_assert!(F::is_complex(), InvalidFlag, "ConjNoTrans is only meaningful for complex types")?;
+        }
+        let trans = if conj_a { BLASNoTrans } else { trans };
+        match F::is_complex() {
+            false => match trans {
+                BLASNoTrans | BLASTrans | BLASConjTrans => (),
+                _ => blas_invalid!(trans)?,
+            },
+            true => match trans {
+                BLASNoTrans | BLASTrans => (),
+                _ => blas_invalid!(trans)?,
+            },
+        };
+        let (n, k) = match trans {
+            BLASNoTrans => (a.len_of(Axis(0)), a.len_of(Axis(1))),
+            BLASTrans | BLASConjTrans => (a.len_of(Axis(1)), a.len_of(Axis(0))),
+            _ => blas_invalid!(trans)?,
+        };
+        if let Some(c) = c.as_ref() {
+            blas_assert_eq!(c.dim(), (n, n), InvalidDim)?;
+        }
+
+        let layout_a = get_layout_array2(a);
+        let layout_c = c.as_ref().map(get_layout_array2);
+        let layout = get_layout_default_preferred(&[layout, layout_c], &[layout_a]);
+        let row_weights = self.row_weights.flatten();
+        if let Some(w) = row_weights.as_ref() {
+            blas_assert_eq!(w.len(), a.len_of(Axis(0)), InvalidDim)?;
+        }
+        let mut copies = Vec::new();
+        if row_weights.is_some() || conj_a || flip_trans_copies(trans, a, layout, false) {
+            copies.push("a");
+        }
+        if let Some(c) = c.as_ref() {
+            let c_pref = match layout {
+                BLASColMajor => c.is_fpref(),
+                _ => c.is_cpref(),
+            };
+            if !c_pref {
+                copies.push("c");
+            }
+        }
+
+        Ok(BlasPlan {
+            routine: blas_routine_name::<F>(["ssyrk", "dsyrk", "csyrk", "zsyrk"]),
+            m: n,
+            n,
+            k,
+            flops: blas_flops::<F>(n as u64 * (n as u64 + 1) * k as u64),
+            layout,
+            copies,
+        })
+    }
+}
+
 /* #endregion */
@@ -1,3 +1,4 @@
+use crate::blas3::gemm::{GEMMNum, GEMM};
 use crate::ffi::{self, blas_int, c_char};
 use crate::util::*;
 use derive_builder::Builder;
@@ -84,6 +85,7 @@ where
             return Ok(b.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("TRMM", dims: [m, n], flags: [side, uplo, transa, diag], scalars: [alpha], arrays: [a, b]);
         unsafe {
             F::trmm(&side, &uplo, &transa, &diag, &m, &n, &alpha, a_ptr, &lda, b_ptr, &ldb);
         }
@@ -91,12 +93,47 @@ where
     }
 }
 
+impl<'a, 'b, F> TRMM_Driver<'a, 'b, F>
+where
+    F: TRMMNum,
+{
+    /// Dimensions `(m, n)` passed to BLAS.
+    pub fn dims(&self) -> (blas_int, blas_int) {
+        (self.m, self.n)
+    }
+
+    /// Scalar `alpha` passed to BLAS.
+    pub fn scalars(&self) -> F {
+        self.alpha
+    }
+
+    /// View of input matrix `a` passed to BLAS.
+    pub fn a_view(&self) -> ArrayView2<'_, F> {
+        self.a.view()
+    }
+
+    /// View of output matrix `b` passed to BLAS.
+    pub fn b_view(&self) -> ArrayView2<'_, F> {
+        self.b.view()
+    }
+
+    /// Leading dimension of `a` passed to BLAS.
+    pub fn lda(&self) -> blas_int {
+        self.lda
+    }
+
+    /// Leading dimension of `b` passed to BLAS.
+    pub fn ldb(&self) -> blas_int {
+        self.ldb
+    }
+}
+
 /* #endregion */
 
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct TRMM_<'a, 'b, F>
 where
     F: TRMMNum,
@@ -104,7 +141,7 @@ where
     pub a: ArrayView2<'a, F>,
     pub b: ArrayViewMut2<'b, F>,
 
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
     #[builder(setter(into), default = "BLASLeft")]
     pub side: BLASSide,
@@ -116,18 +153,32 @@ where
     pub diag: BLASDiag,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
 }
 
+impl_builder_clone!(
+    TRMM_Builder<'a, 'b>,
+    TRMMNum,
+    [a, alpha, side, uplo, transa, diag, layout, check_finite],
+    [b]
+);
+impl_builder_with_lda!(TRMM_Builder<'a, 'b>, TRMMNum);
+impl_builder_validate_uplo!(TRMM_Builder<'a, 'b>, TRMMNum);
+impl_builder_triangular!(TRMM_Builder<'a, 'b>, TRMMNum);
+impl_builder_trans_str!(TRMM_Builder<'a, 'b>, TRMMNum, [transa => transa_str]);
+impl_builder_scalar!(TRMM_Builder<'a, 'b>, TRMMNum, [alpha]);
+
 impl<'a, 'b, F> BLASBuilder_<'b, F, Ix2> for TRMM_<'a, 'b, F>
 where
     F: TRMMNum,
 {
     fn driver(self) -> Result<TRMM_Driver<'a, 'b, F>, BLASError> {
-        let Self { a, b, alpha, side, uplo, transa, diag, layout } = self;
+        let Self { a, b, alpha, side, uplo, transa, diag, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref());
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
+        blas_assert!(a.is_fpref(), InvalidStride, "only col-major arrays are accepted in inner driver")?;
 
         // initialize intent(hide)
         let (m, n) = b.dim();
@@ -183,13 +234,21 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'b, F>, BLASError> {
         // initialize
-        let TRMM_ { a, b, alpha, side, uplo, transa, diag, layout } = self.build()?;
+        let TRMM_ { a, b, alpha, side, uplo, transa, diag, layout, check_finite } = self.build()?;
+        check_zero_stride(&a)?;
+        check_zero_stride(&b.view())?;
+        check_side_dim(a.dim(), b.dim(), side)?;
+        if check_finite {
+            check_finite_scalar(&[alpha])?;
+            check_finite_array(&a)?;
+            check_finite_array(&b.view())?;
+        }
         let at = a.t();
 
         let layout_a = get_layout_array2(&a);
         let layout_b = get_layout_array2(&b.view());
 
-        let layout = get_layout_row_preferred(&[layout, Some(layout_b)], &[layout_a]);
+        let layout = get_layout_default_preferred(&[layout, Some(layout_b)], &[layout_a]);
         if layout == BLASColMajor {
             // F-contiguous: B = op(A) B (if side = L)
             let (transa_new, a_cow) = flip_trans_fpref(transa, &a, &at, false)?;
@@ -203,6 +262,7 @@ where
                 transa: transa_new,
                 diag,
                 layout: Some(BLASColMajor),
+                check_finite,
             };
             return obj.driver()?.run_blas();
         } else {
@@ -218,10 +278,41 @@ where
                 transa: transa_new,
                 diag,
                 layout: Some(BLASColMajor),
+                check_finite,
             };
             return Ok(obj.driver()?.run_blas()?.reversed_axes());
         }
     }
 }
 
+impl<'a, 'b, F> TRMM_Builder<'a, 'b, F>
+where
+    F: TRMMNum + GEMMNum,
+{
+    /// Run by densifying triangular `a` (see [`densify_triangular`]) and calling GEMM instead of TRMM.
+    ///
+    /// This guarantees correct results on backends with buggy or missing handling of some `transa`/`diag`
+    /// combinations of TRMM, at cost of a dense copy of `a` and a copy of `b`.
+    pub fn run_gemm_fallback(self) -> Result<ArrayOut2<'b, F>, BLASError> {
+        // initialize
+        let TRMM_ { a, b, alpha, side, uplo, transa, diag, layout: _, check_finite } = self.build()?;
+        check_zero_stride(&a)?;
+        check_zero_stride(&b.view())?;
+        check_side_dim(a.dim(), b.dim(), side)?;
+        if check_finite {
+            check_finite_scalar(&[alpha])?;
+            check_finite_array(&a)?;
+            check_finite_array(&b.view())?;
+        }
+        let a_dense = densify_triangular(&a, uplo, diag)?;
+        let b_copy = b.to_owned();
+        let obj = match side {
+            BLASLeft => GEMM::default().a(a_dense.view()).b(b_copy.view()).transa(transa),
+            BLASRight => GEMM::default().a(b_copy.view()).b(a_dense.view()).transb(transa),
+            _ => return blas_invalid!(side),
+        };
+        obj.c(b).alpha(alpha).beta(F::zero()).run()
+    }
+}
+
 /* #endregion */
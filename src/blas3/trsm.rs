@@ -84,6 +84,7 @@ where
             return Ok(b.clone_to_view_mut());
         }
 
+        crate::record::blas_record!("TRSM", dims: [m, n], flags: [side, uplo, transa, diag], scalars: [alpha], arrays: [a, b]);
         unsafe {
             F::trsm(&side, &uplo, &transa, &diag, &m, &n, &alpha, a_ptr, &lda, b_ptr, &ldb);
         }
@@ -91,12 +92,47 @@ where
     }
 }
 
+impl<'a, 'b, F> TRSM_Driver<'a, 'b, F>
+where
+    F: TRSMNum,
+{
+    /// Dimensions `(m, n)` passed to BLAS.
+    pub fn dims(&self) -> (blas_int, blas_int) {
+        (self.m, self.n)
+    }
+
+    /// Scalar `alpha` passed to BLAS.
+    pub fn scalars(&self) -> F {
+        self.alpha
+    }
+
+    /// View of input matrix `a` passed to BLAS.
+    pub fn a_view(&self) -> ArrayView2<'_, F> {
+        self.a.view()
+    }
+
+    /// View of output matrix `b` passed to BLAS.
+    pub fn b_view(&self) -> ArrayView2<'_, F> {
+        self.b.view()
+    }
+
+    /// Leading dimension of `a` passed to BLAS.
+    pub fn lda(&self) -> blas_int {
+        self.lda
+    }
+
+    /// Leading dimension of `b` passed to BLAS.
+    pub fn ldb(&self) -> blas_int {
+        self.ldb
+    }
+}
+
 /* #endregion */
 
 /* #region BLAS builder */
 
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+#[builder(pattern = "owned", build_fn(error = "BLASError", validate = "Self::validate"), no_std)]
 pub struct TRSM_<'a, 'b, F>
 where
     F: TRSMNum,
@@ -104,7 +140,7 @@ where
     pub a: ArrayView2<'a, F>,
     pub b: ArrayViewMut2<'b, F>,
 
-    #[builder(setter(into), default = "F::one()")]
+    #[builder(setter(custom), default = "F::one()")]
     pub alpha: F,
     #[builder(setter(into), default = "BLASLeft")]
     pub side: BLASSide,
@@ -116,18 +152,42 @@ where
     pub diag: BLASDiag,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into), default = "false")]
+    pub check_finite: bool,
+    /// Report (by feature `trace`) diagonal elements of `a` with magnitude below `eps * max |diagonal|`
+    /// before solving, which indicate an ill-conditioned (or singular) triangular matrix. This does not
+    /// change the result.
+    #[builder(setter(into), default = "false")]
+    pub warn_ill_conditioned: bool,
+    /// Right-hand side `b` is triangular (or trapezoidal) by this `uplo`: elements of the other triangle are
+    /// ignored, and zeroed before solving (`b` is overwritten by the solution anyway), so stale data there is
+    /// never read. Setting this does not change the cost of solving.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub b_triangular: Option<BLASUpLo>,
 }
 
+impl_builder_clone!(
+    TRSM_Builder<'a, 'b>,
+    TRSMNum,
+    [a, alpha, side, uplo, transa, diag, layout, check_finite, warn_ill_conditioned, b_triangular],
+    [b]
+);
+impl_builder_with_lda!(TRSM_Builder<'a, 'b>, TRSMNum);
+impl_builder_validate_uplo!(TRSM_Builder<'a, 'b>, TRSMNum);
+impl_builder_triangular!(TRSM_Builder<'a, 'b>, TRSMNum);
+impl_builder_trans_str!(TRSM_Builder<'a, 'b>, TRSMNum, [transa => transa_str]);
+impl_builder_scalar!(TRSM_Builder<'a, 'b>, TRSMNum, [alpha]);
+
 impl<'a, 'b, F> BLASBuilder_<'b, F, Ix2> for TRSM_<'a, 'b, F>
 where
     F: TRSMNum,
 {
     fn driver(self) -> Result<TRSM_Driver<'a, 'b, F>, BLASError> {
-        let Self { a, b, alpha, side, uplo, transa, diag, layout } = self;
+        let Self { a, b, alpha, side, uplo, transa, diag, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref());
+        blas_assert_eq!(layout, Some(BLASColMajor), InvalidFlag)?;
+        blas_assert!(a.is_fpref(), InvalidStride, "only col-major arrays are accepted in inner driver")?;
 
         // initialize intent(hide)
         let (m, n) = b.dim();
@@ -183,13 +243,39 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'b, F>, BLASError> {
         // initialize
-        let TRSM_ { a, b, alpha, side, uplo, transa, diag, layout } = self.build()?;
+        let TRSM_ {
+            a,
+            mut b,
+            alpha,
+            side,
+            uplo,
+            transa,
+            diag,
+            layout,
+            check_finite,
+            warn_ill_conditioned,
+            b_triangular,
+        } = self.build()?;
+        check_zero_stride(&a)?;
+        check_zero_stride(&b.view())?;
+        check_side_dim(a.dim(), b.dim(), side)?;
+        if let Some(uplo_b) = b_triangular {
+            zero_opposite_triangle(b.view_mut(), uplo_b)?;
+        }
+        if check_finite {
+            check_finite_scalar(&[alpha])?;
+            check_finite_array(&a)?;
+            check_finite_array(&b.view())?;
+        }
+        if warn_ill_conditioned && diag == BLASNonUnit {
+            warn_small_diagonal(a.diag());
+        }
         let at = a.t();
 
         let layout_a = get_layout_array2(&a);
         let layout_b = get_layout_array2(&b.view());
 
-        let layout = get_layout_row_preferred(&[layout, Some(layout_b)], &[layout_a]);
+        let layout = get_layout_default_preferred(&[layout, Some(layout_b)], &[layout_a]);
         if layout == BLASColMajor {
             // F-contiguous: B = op(A) B (if side = L)
             let (transa_new, a_cow) = flip_trans_fpref(transa, &a, &at, false)?;
@@ -203,6 +289,9 @@ where
                 transa: transa_new,
                 diag,
                 layout: Some(BLASColMajor),
+                check_finite,
+                warn_ill_conditioned: false,
+                b_triangular: None,
             };
             return obj.driver()?.run_blas();
         } else {
@@ -218,6 +307,9 @@ where
                 transa: transa_new,
                 diag,
                 layout: Some(BLASColMajor),
+                check_finite,
+                warn_ill_conditioned: false,
+                b_triangular: None,
             };
             return Ok(obj.driver()?.run_blas()?.reversed_axes());
         }
@@ -0,0 +1,131 @@
+extern crate alloc;
+
+use crate::blas2::trsv::{TRSVNum, TRSV};
+use crate::util::*;
+use alloc::vec;
+use ndarray::prelude::*;
+use num_traits::{Float, NumCast, Zero};
+
+/* #region cond_1_estimate */
+
+/// Factorization used by [`cond_1_estimate`] to apply $\mathbf{A}^{-1}$ by triangular solves.
+#[derive(Debug, Clone, Copy)]
+pub enum CondFactor<'a, F> {
+    /// Matrix `a` itself is triangular, with data stored in triangle `uplo`.
+    Triangular { uplo: BLASUpLo, diag: BLASDiag },
+    /// LU factors packed in one matrix (unit lower $\mathbf{L}$ below diagonal, $\mathbf{U}$ on and above),
+    /// such that row `perm[i]` of `a` is row `i` of $\mathbf{L} \mathbf{U}$; no pivoting if `perm` is `None`.
+    ///
+    /// Note that `perm` is a permutation of rows, not the sequence of interchanges of LAPACK `ipiv`.
+    LU { lu: ArrayView2<'a, F>, perm: Option<&'a [usize]> },
+}
+
+/// Solve $\mathbf{A} \mathbf{x} = \mathbf{b}$ (or with $\mathbf{A}^\dagger$) in-place by TRSV.
+fn factor_solve<F>(
+    a: &ArrayView2<F>,
+    factor: &CondFactor<F>,
+    x: &mut Array1<F>,
+    trans: BLASTranspose,
+) -> Result<(), BLASError>
+where
+    F: TRSVNum,
+{
+    let trsv = |t: &ArrayView2<F>, x: &mut Array1<F>, uplo: BLASUpLo, diag: BLASDiag| {
+        TRSV::default().a(t.view()).x(x.view_mut()).uplo(uplo).diag(diag).trans(trans).run().map(|_| ())
+    };
+    match *factor {
+        CondFactor::Triangular { uplo, diag } => trsv(a, x, uplo, diag),
+        CondFactor::LU { lu, perm } => {
+            if trans == BLASNoTrans {
+                if let Some(perm) = perm {
+                    *x = perm.iter().map(|&p| x[p]).collect();
+                }
+                trsv(&lu, x, BLASLower, BLASUnit)?;
+                trsv(&lu, x, BLASUpper, BLASNonUnit)
+            } else {
+                trsv(&lu, x, BLASUpper, BLASNonUnit)?;
+                trsv(&lu, x, BLASLower, BLASUnit)?;
+                if let Some(perm) = perm {
+                    let z = x.clone();
+                    perm.iter().zip(z.iter()).for_each(|(&p, &v)| x[p] = v);
+                }
+                Ok(())
+            }
+        },
+    }
+}
+
+/// Estimate 1-norm condition number $\Vert \mathbf{A} \Vert_1 \Vert \mathbf{A}^{-1} \Vert_1$ of a square
+/// matrix.
+///
+/// $\Vert \mathbf{A} \Vert_1$ is computed exactly from `a`, and $\Vert \mathbf{A}^{-1} \Vert_1$ is estimated
+/// by Hager's algorithm (simplified LAPACK `?gecon`), where each iteration applies $\mathbf{A}^{-1}$ and
+/// $\mathbf{A}^{-\dagger}$ to a vector by TRSV on the given `factor`, for at most 5 iterations. The estimate
+/// is a lower bound of the true value, and is usually exact or within a small factor of it.
+///
+/// Infinity is returned for exactly singular matrices, and empty matrices give zero.
+pub fn cond_1_estimate<F>(a: ArrayView2<F>, factor: CondFactor<F>) -> Result<F::RealFloat, BLASError>
+where
+    F: TRSVNum,
+{
+    let n = a.len_of(Axis(0));
+    blas_assert_eq!(a.len_of(Axis(1)), n, InvalidDim)?;
+    if let CondFactor::LU { lu, perm } = factor {
+        blas_assert_eq!(lu.dim(), (n, n), InvalidDim)?;
+        if let Some(perm) = perm {
+            blas_assert_eq!(perm.len(), n, InvalidDim)?;
+            let mut seen = vec![false; n];
+            for &p in perm {
+                blas_assert!(p < n && !seen[p], InvalidFlag, "perm is not a permutation")?;
+                seen[p] = true;
+            }
+        }
+    }
+    if n == 0 {
+        return Ok(F::RealFloat::zero());
+    }
+
+    let sum_modulus = |x: ArrayView1<F>| x.iter().fold(F::RealFloat::zero(), |s, &v| s + F::modulus(v));
+    let norm_1 =
+        |x: ArrayView2<F>| x.columns().into_iter().map(sum_modulus).fold(F::RealFloat::zero(), Float::max);
+    let a_norm = match factor {
+        CondFactor::Triangular { uplo, diag } => norm_1(densify_triangular(&a, uplo, diag)?.view()),
+        CondFactor::LU { .. } => norm_1(a.view()),
+    };
+
+    // Hager's algorithm for estimating norm of inverse
+    let trans_h = if F::is_complex() { BLASConjTrans } else { BLASTrans };
+    let mut x = Array1::from_elem(n, F::from_real(<F::RealFloat as NumCast>::from(n).unwrap().recip()));
+    let mut inv_norm = F::RealFloat::zero();
+    let mut j_last = None;
+    for _ in 0..5 {
+        let mut y = x.clone();
+        factor_solve(&a, &factor, &mut y, BLASNoTrans)?;
+        inv_norm = sum_modulus(y.view());
+        if !inv_norm.is_finite() {
+            return Ok(F::RealFloat::infinity());
+        }
+
+        let mut z = y.mapv(|v| match F::modulus(v) == F::RealFloat::zero() {
+            true => F::one(),
+            false => v / F::from_real(F::modulus(v)),
+        });
+        factor_solve(&a, &factor, &mut z, trans_h)?;
+
+        let (j, z_max) =
+            z.iter().enumerate().fold((0, F::RealFloat::zero()), |(j, m), (i, &v)| match F::modulus(v) > m {
+                true => (i, F::modulus(v)),
+                false => (j, m),
+            });
+        let ztx = z.iter().zip(x.iter()).fold(F::zero(), |s, (&zi, &xi)| s + F::conj(zi) * xi);
+        if z_max <= F::real(ztx) || j_last == Some(j) {
+            break;
+        }
+        j_last = Some(j);
+        x.fill(F::zero());
+        x[j] = F::one();
+    }
+    Ok(a_norm * inv_norm)
+}
+
+/* #endregion */
@@ -0,0 +1,321 @@
+extern crate alloc;
+
+use crate::blas1::dot::{DOTNum, DOT};
+use crate::blas1::nrm2::{NRM2Num, NRM2};
+use crate::blas1::scal::{SCALNum, SCAL};
+use crate::blas2::gemv::{GEMVNum, GEMV};
+use crate::blas2::ger::{GERNum, GER};
+use crate::blas2::gerc::{GERCNum, GERC};
+use crate::blas2::hemv::{HEMVNum, HEMV};
+use crate::blas3::gemm::{GEMMNum, GEMM};
+use crate::blasext::elementwise::scale;
+use crate::util::*;
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::Float;
+
+use alloc::vec::Vec;
+
+/* #region BlasDot */
+
+/// Matrix product by BLAS, in method-call syntax similar to ndarray's `.dot()`.
+///
+/// Products of 2-D × 2-D (GEMM), 2-D × 1-D and 1-D × 2-D (GEMV) are supported. Like ndarray's `.dot()`,
+/// no complex conjugate is applied to either operand. Layout of operands is handled by the underlying
+/// wrappers, and result is always an owned array.
+pub trait BlasDot<Rhs> {
+    type Output;
+
+    fn blas_dot(&self, rhs: &Rhs) -> Result<Self::Output, BLASError>;
+}
+
+/// $\mathbf{C} = \mathbf{A} \mathbf{B}$ by GEMM.
+impl<F, S, S2> BlasDot<ArrayBase<S2, Ix2>> for ArrayBase<S, Ix2>
+where
+    F: GEMMNum,
+    S: Data<Elem = F>,
+    S2: Data<Elem = F>,
+{
+    type Output = Array2<F>;
+
+    fn blas_dot(&self, rhs: &ArrayBase<S2, Ix2>) -> Result<Array2<F>, BLASError> {
+        Ok(GEMM::default().a(self.view()).b(rhs.view()).run()?.into_owned())
+    }
+}
+
+/// $\mathbf{y} = \mathbf{A} \mathbf{x}$ by GEMV.
+impl<F, S, S2> BlasDot<ArrayBase<S2, Ix1>> for ArrayBase<S, Ix2>
+where
+    F: GEMVNum,
+    S: Data<Elem = F>,
+    S2: Data<Elem = F>,
+{
+    type Output = Array1<F>;
+
+    fn blas_dot(&self, rhs: &ArrayBase<S2, Ix1>) -> Result<Array1<F>, BLASError> {
+        Ok(GEMV::default().a(self.view()).x(rhs.view()).run()?.into_owned())
+    }
+}
+
+/// $\mathbf{y} = \mathbf{x}^T \mathbf{A}$ (as 1-D array) by GEMV with transposed `A`.
+impl<F, S, S2> BlasDot<ArrayBase<S2, Ix2>> for ArrayBase<S, Ix1>
+where
+    F: GEMVNum,
+    S: Data<Elem = F>,
+    S2: Data<Elem = F>,
+{
+    type Output = Array1<F>;
+
+    fn blas_dot(&self, rhs: &ArrayBase<S2, Ix2>) -> Result<Array1<F>, BLASError> {
+        Ok(GEMV::default().a(rhs.view()).x(self.view()).trans(BLASTrans).run()?.into_owned())
+    }
+}
+
+/* #endregion */
+
+/* #region gemm_diag */
+
+/// Matrix product with diagonal matrix in between $\mathbf{C} = \mathbf{A} \mathrm{diag}(\mathbf{d}) \mathbf{B}$.
+///
+/// Instead of forming $\mathrm{diag}(\mathbf{d})$ as full matrix, columns of `a` (or rows of `b`, whichever
+/// operand is smaller) are scaled by `d` into a temporary by SCAL, and then a single GEMM is performed.
+pub fn gemm_diag<F>(a: ArrayView2<F>, d: ArrayView1<F>, b: ArrayView2<F>) -> Result<Array2<F>, BLASError>
+where
+    F: GEMMNum + SCALNum,
+{
+    blas_assert_eq!(a.len_of(Axis(1)), b.len_of(Axis(0)), InvalidDim)?;
+    blas_assert_eq!(d.len(), a.len_of(Axis(1)), InvalidDim)?;
+
+    // scaled copy of `x` in the same memory order, with lanes along `axis` scaled by `d`
+    let scaled = |x: ArrayView2<F>, axis: Axis| -> Result<Array2<F>, BLASError> {
+        let mut x_scaled = match x.is_fpref() {
+            true => Array2::zeros(x.raw_dim().f()),
+            false => Array2::zeros(x.raw_dim()),
+        };
+        x_scaled.assign(&x);
+        for (lane, &di) in x_scaled.axis_iter_mut(axis).zip(d.iter()) {
+            SCAL::default().x(lane).alpha(di).run()?;
+        }
+        Ok(x_scaled)
+    };
+
+    let c = if a.len() <= b.len() {
+        let a_scaled = scaled(a, Axis(1))?;
+        GEMM::default().a(a_scaled.view()).b(b).run()?
+    } else {
+        let b_scaled = scaled(b, Axis(0))?;
+        GEMM::default().a(a).b(b_scaled.view()).run()?
+    };
+    Ok(c.into_owned())
+}
+
+/* #endregion */
+
+/* #region outer */
+
+/// Outer product $\mathbf{x} \mathbf{y}^T$ by GER (GERU for complex types), without complex conjugate.
+///
+/// Result is a newly allocated `x.len() x y.len()` matrix. Empty vectors give [`BLASError::InvalidDim`].
+pub fn outer<F>(x: ArrayView1<F>, y: ArrayView1<F>) -> Result<Array2<F>, BLASError>
+where
+    F: GERNum,
+{
+    blas_assert!(!x.is_empty() && !y.is_empty(), InvalidDim, "outer product of empty vector")?;
+    Ok(GER::default().x(x).y(y).run()?.into_owned())
+}
+
+/// Outer product $\mathbf{x} \mathbf{y}^\dagger$ by GERC, with complex conjugate on `y`.
+///
+/// Result is a newly allocated `x.len() x y.len()` matrix. Empty vectors give [`BLASError::InvalidDim`].
+pub fn outer_conj<F>(x: ArrayView1<F>, y: ArrayView1<F>) -> Result<Array2<F>, BLASError>
+where
+    F: GERCNum,
+{
+    blas_assert!(!x.is_empty() && !y.is_empty(), InvalidDim, "outer product of empty vector")?;
+    Ok(GERC::default().x(x).y(y).run()?.into_owned())
+}
+
+/* #endregion */
+
+/* #region kron */
+
+/// Kronecker product $\mathbf{A} \otimes \mathbf{B}$, by copying `b` into each block and scaling it by SCAL.
+///
+/// Block `(i, j)` of the `(ma * mb) x (na * nb)` result is `a[[i, j]] * b`; this performs `ma * na` block
+/// copies and scalings, so it is intended for small factor `a`. No complex conjugate is applied. Empty inputs
+/// give [`BLASError::InvalidDim`].
+pub fn kron<F>(a: ArrayView2<F>, b: ArrayView2<F>) -> Result<Array2<F>, BLASError>
+where
+    F: SCALNum,
+{
+    blas_assert!(!a.is_empty() && !b.is_empty(), InvalidDim, "kronecker product of empty matrix")?;
+    let (ma, na) = a.dim();
+    let (mb, nb) = b.dim();
+    let mut c = Array2::zeros((ma * mb, na * nb));
+    for ((i, j), &aij) in a.indexed_iter() {
+        let mut block = c.slice_mut(s![i * mb..(i + 1) * mb, j * nb..(j + 1) * nb]);
+        block.assign(&b);
+        scale(aij, block)?;
+    }
+    Ok(c)
+}
+
+/* #endregion */
+
+/* #region dot_streaming */
+
+/// Unconjugated dot product of vectors given chunk by chunk, such as disk-backed or generated data.
+///
+/// Each pair of chunks is reduced by DOT, and partial results are summed with Kahan compensated summation, so
+/// that rounding error does not grow with the number of chunks. Chunks of `xs` and `ys` are paired in order;
+/// paired chunks of different lengths, or different numbers of chunks, give [`BLASError::InvalidDim`].
+pub fn dot_streaming<'x, 'y, F>(
+    mut xs: impl Iterator<Item = ArrayView1<'x, F>>,
+    mut ys: impl Iterator<Item = ArrayView1<'y, F>>,
+) -> Result<F, BLASError>
+where
+    F: DOTNum + 'x + 'y,
+{
+    let mut sum = F::zero();
+    let mut comp = F::zero();
+    loop {
+        let (x, y) = match (xs.next(), ys.next()) {
+            (Some(x), Some(y)) => (x, y),
+            (None, None) => return Ok(sum),
+            _ => blas_raise!(InvalidDim, "different number of chunks in x and y")?,
+        };
+        blas_assert_eq!(x.len(), y.len(), InvalidDim)?;
+        let part = DOT::default().x(x).y(y).run()? - comp;
+        let total = sum + part;
+        comp = (total - sum) - part;
+        sum = total;
+    }
+}
+
+/* #endregion */
+
+/* #region tensordot */
+
+/// Tensor contraction of `a` and `b` over pairs of axes, like numpy's `tensordot`.
+///
+/// Axis `axes.0[i]` of `a` is contracted with axis `axes.1[i]` of `b`. Result has the remaining axes of `a`
+/// followed by the remaining axes of `b`, each in original order. Both operands are permuted (free axes of `a`
+/// first, contracted axes of `b` first) and reshaped into matrices, which copies them unless they are already in
+/// that standard layout; the contraction is then a single GEMM, whose output is reshaped back without copy. No
+/// complex conjugate is applied.
+///
+/// Different numbers of axes, out-of-range or repeated axes, and mismatched lengths of contracted axes give
+/// [`BLASError::InvalidDim`].
+pub fn tensordot<F>(
+    a: ArrayViewD<F>,
+    b: ArrayViewD<F>,
+    axes: (&[usize], &[usize]),
+) -> Result<ArrayD<F>, BLASError>
+where
+    F: GEMMNum,
+{
+    let (axes_a, axes_b) = axes;
+    blas_assert_eq!(axes_a.len(), axes_b.len(), InvalidDim)?;
+    for (axes, ndim) in [(axes_a, a.ndim()), (axes_b, b.ndim())] {
+        for (i, &ax) in axes.iter().enumerate() {
+            blas_assert!(ax < ndim, InvalidDim, "contracted axis out of range")?;
+            blas_assert!(!axes[..i].contains(&ax), InvalidDim, "contracted axis repeated")?;
+        }
+    }
+    for (&ax_a, &ax_b) in axes_a.iter().zip(axes_b.iter()) {
+        blas_assert_eq!(a.len_of(Axis(ax_a)), b.len_of(Axis(ax_b)), InvalidDim)?;
+    }
+
+    let free_a: Vec<usize> = (0..a.ndim()).filter(|ax| !axes_a.contains(ax)).collect();
+    let free_b: Vec<usize> = (0..b.ndim()).filter(|ax| !axes_b.contains(ax)).collect();
+    let m: usize = free_a.iter().map(|&ax| a.len_of(Axis(ax))).product();
+    let n: usize = free_b.iter().map(|&ax| b.len_of(Axis(ax))).product();
+    let k: usize = axes_a.iter().map(|&ax| a.len_of(Axis(ax))).product();
+    let shape: Vec<usize> = free_a
+        .iter()
+        .map(|&ax| a.len_of(Axis(ax)))
+        .chain(free_b.iter().map(|&ax| b.len_of(Axis(ax))))
+        .collect();
+
+    // empty contraction or empty result
+    if m == 0 || n == 0 || k == 0 {
+        return Ok(ArrayD::zeros(shape));
+    }
+
+    // a as (free, contracted) matrix, b as (contracted, free) matrix, both row-major
+    let perm_a: Vec<usize> = free_a.iter().chain(axes_a.iter()).copied().collect();
+    let perm_b: Vec<usize> = axes_b.iter().chain(free_b.iter()).copied().collect();
+    let a_perm = a.permuted_axes(perm_a);
+    let b_perm = b.permuted_axes(perm_b);
+    let a_std = a_perm.as_standard_layout();
+    let b_std = b_perm.as_standard_layout();
+    let a_mat = a_std.view().into_shape((m, k)).unwrap();
+    let b_mat = b_std.view().into_shape((k, n)).unwrap();
+
+    let mut c = Array2::zeros((m, n));
+    GEMM::default().a(a_mat).b(b_mat).c(c.view_mut()).run()?;
+    Ok(c.into_shape(shape).unwrap())
+}
+
+/* #endregion */
+
+/* #region quadratic_form */
+
+/// $\mathbf{x}^\dagger \mathbf{A} \mathbf{x}$ and $\mathbf{A} \mathbf{x}$ for hermitian (real symmetric) `a`.
+fn quadratic_form_parts<F>(
+    a: ArrayView2<F>,
+    x: ArrayView1<F>,
+    uplo: BLASUpLo,
+) -> Result<(F, Array1<F>), BLASError>
+where
+    F: HEMVNum + DOTNum,
+{
+    let ax = HEMV::default().a(a).x(x).uplo(uplo).run()?.into_owned();
+    let xax = DOT::default().x(x).y(ax.view()).conjx(true).run()?;
+    Ok((xax, ax))
+}
+
+/// Quadratic form $\mathbf{x}^\dagger \mathbf{A} \mathbf{x}$ of hermitian (real symmetric) `a`, such as energy
+/// or expectation value.
+///
+/// $\mathbf{A} \mathbf{x}$ is computed into a temporary by HEMV (SYMV for real types), referencing only the
+/// `uplo` triangle of `a`, then reduced by DOTC (DOT for real types). The result is real in exact arithmetic;
+/// the imaginary part, which is only rounding error, is discarded. See [`quadratic_form_checked`] to have it
+/// checked.
+pub fn quadratic_form<F>(
+    a: ArrayView2<F>,
+    x: ArrayView1<F>,
+    uplo: BLASUpLo,
+) -> Result<F::RealFloat, BLASError>
+where
+    F: HEMVNum + DOTNum,
+{
+    let (xax, _) = quadratic_form_parts(a, x, uplo)?;
+    Ok(F::real(xax))
+}
+
+/// Quadratic form as [`quadratic_form`], checking that the discarded imaginary part is negligible.
+///
+/// Gives [`BLASError::FailedCheck`] if $|\mathrm{Im}(\mathbf{x}^\dagger \mathbf{A} \mathbf{x})| > \mathrm{rtol}
+/// \cdot \| \mathbf{x} \|_2 \| \mathbf{A} \mathbf{x} \|_2$ (by NRM2), or if the result is not finite.
+pub fn quadratic_form_checked<F>(
+    a: ArrayView2<F>,
+    x: ArrayView1<F>,
+    uplo: BLASUpLo,
+    rtol: F::RealFloat,
+) -> Result<F::RealFloat, BLASError>
+where
+    F: HEMVNum + DOTNum + NRM2Num,
+{
+    let (xax, ax) = quadratic_form_parts(a, x, uplo)?;
+    let bound = rtol * NRM2::default().x(x).run()? * NRM2::default().x(ax.view()).run()?;
+    blas_assert!(F::is_finite(xax), FailedCheck, "quadratic form is not finite")?;
+    blas_assert!(
+        F::imag(xax).abs() <= bound,
+        FailedCheck,
+        "imaginary part of quadratic form is not negligible"
+    )?;
+    Ok(F::real(xax))
+}
+
+/* #endregion */
@@ -0,0 +1,163 @@
+use crate::blas1::axpy::{AXPYNum, AXPY};
+use crate::blas1::copy::{COPYNum, COPY};
+use crate::blas1::scal::{SCALNum, SCAL};
+use crate::util::*;
+use core::ops::Range;
+use ndarray::prelude::*;
+
+/* #region mat_axpy */
+
+/// In-place matrix accumulation $\mathbf{A} = \mathbf{A} + \alpha \mathbf{B}$ by AXPY.
+///
+/// If `a` and `b` are both contiguous with the same strides, this is performed by a single AXPY call on
+/// flattened data; otherwise AXPY is called on each row (or column, if `a` is col-major), in parallel with
+/// feature `rayon`.
+pub fn mat_axpy<F>(alpha: F, b: ArrayView2<F>, mut a: ArrayViewMut2<F>) -> Result<(), BLASError>
+where
+    F: AXPYNum,
+{
+    blas_assert_eq!(a.dim(), b.dim(), InvalidDim)?;
+    if a.is_empty() {
+        return Ok(());
+    }
+
+    // fast path: flatten to single AXPY call
+    if a.strides() == b.strides() {
+        if let (Some(b_slc), Some(a_slc)) = (b.as_slice_memory_order(), a.as_slice_memory_order_mut()) {
+            AXPY::default().x(ArrayView1::from(b_slc)).y(ArrayViewMut1::from(a_slc)).alpha(alpha).run()?;
+            return Ok(());
+        }
+    }
+
+    // fallback: AXPY on each lane of `a` that is possibly contiguous
+    let axis = match get_layout_array2(&a.view()) {
+        BLASColMajor => Axis(1),
+        _ => Axis(0),
+    };
+    #[cfg(feature = "rayon")]
+    {
+        use ndarray::parallel::prelude::*;
+        b.axis_iter(axis).into_par_iter().zip(a.axis_iter_mut(axis)).try_for_each(|(b_lane, a_lane)| {
+            AXPY::default().x(b_lane).y(a_lane).alpha(alpha).run().map(|_| ())
+        })
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        for (b_lane, a_lane) in b.axis_iter(axis).zip(a.axis_iter_mut(axis)) {
+            AXPY::default().x(b_lane).y(a_lane).alpha(alpha).run()?;
+        }
+        Ok(())
+    }
+}
+
+/* #endregion */
+
+/* #region axpy_real_alpha */
+
+/// In-place vector accumulation $\mathbf{y} = \mathbf{y} + \alpha \mathbf{x}$ by AXPY, with real $\alpha$.
+///
+/// For complex types, `alpha` is widened to complex with zero imaginary part; for real types, this is the same as
+/// AXPY. This is convenient in generic code, where `F::RealFloat` is not known to convert into `F`.
+pub fn axpy_real_alpha<F>(alpha: F::RealFloat, x: ArrayView1<F>, y: ArrayViewMut1<F>) -> Result<(), BLASError>
+where
+    F: AXPYNum,
+{
+    AXPY::default().x(x).y(y).alpha(F::from_real(alpha)).run()?;
+    Ok(())
+}
+
+/* #endregion */
+
+/* #region scale */
+
+/// In-place matrix scaling $\mathbf{A} = \alpha \mathbf{A}$ by SCAL.
+///
+/// If `a` is contiguous, this is performed by a single SCAL call on flattened data; otherwise SCAL is called on
+/// each row (or column, if `a` is col-major), in parallel with feature `rayon`.
+pub fn scale<F>(alpha: F, mut a: ArrayViewMut2<F>) -> Result<(), BLASError>
+where
+    F: SCALNum,
+{
+    if a.is_empty() {
+        return Ok(());
+    }
+
+    // fast path: flatten to single SCAL call
+    if let Some(a_slc) = a.as_slice_memory_order_mut() {
+        SCAL::default().x(ArrayViewMut1::from(a_slc)).alpha(alpha).run()?;
+        return Ok(());
+    }
+
+    // fallback: SCAL on each lane of `a` that is possibly contiguous
+    let axis = match get_layout_array2(&a.view()) {
+        BLASColMajor => Axis(1),
+        _ => Axis(0),
+    };
+    #[cfg(feature = "rayon")]
+    {
+        use ndarray::parallel::prelude::*;
+        a.axis_iter_mut(axis)
+            .into_par_iter()
+            .try_for_each(|a_lane| SCAL::default().x(a_lane).alpha(alpha).run().map(|_| ()))
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        for a_lane in a.axis_iter_mut(axis) {
+            SCAL::default().x(a_lane).alpha(alpha).run()?;
+        }
+        Ok(())
+    }
+}
+
+/// In-place matrix negation $\mathbf{A} = - \mathbf{A}$ by SCAL.
+pub fn negate<F>(a: ArrayViewMut2<F>) -> Result<(), BLASError>
+where
+    F: SCALNum,
+{
+    scale(F::zero() - F::one(), a)
+}
+
+/* #endregion */
+
+/* #region extract_block */
+
+/// Contiguous col-major copy of sub-block `a[rows, cols]` by COPY.
+///
+/// If the sub-block is already contiguous in col-major order, it is copied by a single COPY call on flattened
+/// data; otherwise COPY is called on each column. Ranges out of bound give [`BLASError::InvalidDim`].
+pub fn extract_block<F>(
+    a: ArrayView2<F>,
+    rows: Range<usize>,
+    cols: Range<usize>,
+) -> Result<Array2<F>, BLASError>
+where
+    F: COPYNum,
+{
+    let (nrow, ncol) = a.dim();
+    blas_assert!(
+        rows.start <= rows.end && rows.end <= nrow && cols.start <= cols.end && cols.end <= ncol,
+        InvalidDim,
+        "block range out of bound"
+    )?;
+    let block = a.slice_move(s![rows, cols]);
+    let mut out = Array2::zeros(block.raw_dim().f());
+    if out.is_empty() {
+        return Ok(out);
+    }
+
+    // fast path: flatten to single COPY call
+    if block.strides() == out.strides() {
+        if let (Some(x), Some(y)) = (block.as_slice_memory_order(), out.as_slice_memory_order_mut()) {
+            COPY::default().x(ArrayView1::from(x)).y(ArrayViewMut1::from(y)).run()?;
+            return Ok(out);
+        }
+    }
+
+    // fallback: COPY on each column
+    for (x, y) in block.columns().into_iter().zip(out.columns_mut()) {
+        COPY::default().x(x).y(y).run()?;
+    }
+    Ok(out)
+}
+
+/* #endregion */
@@ -0,0 +1,64 @@
+use crate::blas2::gemv::{GEMVNum, GEMV};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/* #region gemv_over_rows */
+
+/// Lazy iterator of GEMV products over rows, created by [`gemv_over_rows`].
+pub struct GEMVOverRows<'a, 'x, F>
+where
+    F: GEMVNum,
+{
+    a: ArrayView2<'a, F>,
+    xs: ArrayView2<'x, F>,
+    index: usize,
+}
+
+impl<'a, 'x, F> Iterator for GEMVOverRows<'a, 'x, F>
+where
+    F: GEMVNum,
+{
+    type Item = Result<Array1<F>, BLASError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.xs.len_of(Axis(0)) {
+            return None;
+        }
+        let x = self.xs.row(self.index);
+        self.index += 1;
+        Some(GEMV::default().a(self.a).x(x).run().map(|y| y.into_owned()))
+    }
+
+    /// Skipped rows are not computed.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n).min(self.xs.len_of(Axis(0)));
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.xs.len_of(Axis(0)) - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, 'x, F> ExactSizeIterator for GEMVOverRows<'a, 'x, F> where F: GEMVNum {}
+
+/// Lazily compute $\mathbf{A} \mathbf{x}_i$ for each row $\mathbf{x}_i$ of `xs`.
+///
+/// Each call of `next()` performs one GEMV call and allocates only the resulting vector, so the iterator
+/// composes with iterator combinators without materializing the full `xs.nrows() x a.nrows()` result. For
+/// computing all rows at once, a single GEMM (`xs * A^T`) is more efficient.
+///
+/// Dimensions are checked when creating the iterator; `xs` must have as many columns as `a`.
+pub fn gemv_over_rows<'a, 'x, F>(
+    a: ArrayView2<'a, F>,
+    xs: ArrayView2<'x, F>,
+) -> Result<GEMVOverRows<'a, 'x, F>, BLASError>
+where
+    F: GEMVNum,
+{
+    blas_assert_eq!(xs.len_of(Axis(1)), a.len_of(Axis(1)), InvalidDim)?;
+    Ok(GEMVOverRows { a, xs, index: 0 })
+}
+
+/* #endregion */
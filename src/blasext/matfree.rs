@@ -0,0 +1,31 @@
+use crate::blas2::gemv::{GEMVNum, GEMV};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/* #region gemm_matfree */
+
+/// Compute $\mathbf{A} \mathbf{B}$ where columns of $\mathbf{B}$ are generated on the fly.
+///
+/// For each `j` in `0..n_cols`, `col(j, b_j)` is called to fill column $\mathbf{b}_j$ into a temporary
+/// buffer of length `a.ncols()`, and one GEMV call writes $\mathbf{A} \mathbf{b}_j$ into column `j` of the
+/// result. Only a single column of $\mathbf{B}$ is stored at any time, which is suitable for matrix-free
+/// operators. The result is a col-major `a.nrows() x n_cols` matrix.
+///
+/// The buffer is reused across columns and not reset between calls, so the callback should write every
+/// element of it.
+pub fn gemm_matfree<F, C>(a: ArrayView2<F>, n_cols: usize, mut col: C) -> Result<Array2<F>, BLASError>
+where
+    F: GEMVNum,
+    C: FnMut(usize, ArrayViewMut1<F>),
+{
+    let (m, k) = a.dim();
+    let mut b_col = Array1::<F>::zeros(k);
+    let mut c = Array2::<F>::zeros((m, n_cols).f());
+    for j in 0..n_cols {
+        col(j, b_col.view_mut());
+        GEMV::default().a(a).x(b_col.view()).y(c.column_mut(j)).run()?;
+    }
+    Ok(c)
+}
+
+/* #endregion */
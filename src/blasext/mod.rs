@@ -0,0 +1,17 @@
+//! Extensions built on top of BLAS wrappers.
+//!
+//! Functions in this module are not BLAS routines themselves, but common operations that could be efficiently
+//! performed by (possibly several calls of) BLAS routines.
+
+pub mod condition;
+pub mod dot;
+pub mod elementwise;
+pub mod iter;
+pub mod matfree;
+pub mod norms;
+pub mod orthogonal;
+pub mod reduction;
+pub mod rotation;
+pub mod spectral;
+pub mod statistics;
+pub mod triangular;
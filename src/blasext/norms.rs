@@ -0,0 +1,206 @@
+extern crate alloc;
+
+use crate::blas1::asum::{ASUMNum, ASUM};
+use crate::blas1::iamax::{IAMAXNum, IAMAX};
+use crate::blas1::nrm2::{NRM2Num, NRM2};
+use crate::blas1::scal::{SCALNum, SCAL};
+use crate::util::*;
+use alloc::vec::Vec;
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::{Float, One, Zero};
+
+/* #region BlasNorms */
+
+/// Level-1 norms by BLAS, in method-call syntax.
+///
+/// For 1-D arrays these are NRM2, ASUM and IAMAX on the vector. For 2-D arrays these operate on all
+/// elements of the matrix: NRM2 gives the Frobenius norm, and IAMAX gives the `(row, col)` index of the
+/// first maximum in row-major order. See [`BlasNormsAxis`] for norms of rows or columns.
+///
+/// As with the underlying wrappers, ASUM and IAMAX measure complex elements by $|\mathrm{Re}| +
+/// |\mathrm{Im}|$, and IAMAX of an empty array gives index zero.
+pub trait BlasNorms<F>
+where
+    F: BLASFloat,
+{
+    type Index;
+
+    fn blas_nrm2(&self) -> Result<F::RealFloat, BLASError>;
+    fn blas_asum(&self) -> Result<F::RealFloat, BLASError>;
+    fn blas_iamax(&self) -> Result<Self::Index, BLASError>;
+}
+
+impl<F, S> BlasNorms<F> for ArrayBase<S, Ix1>
+where
+    F: NRM2Num + ASUMNum + IAMAXNum,
+    S: Data<Elem = F>,
+{
+    type Index = usize;
+
+    fn blas_nrm2(&self) -> Result<F::RealFloat, BLASError> {
+        NRM2::default().x(self.view()).run()
+    }
+
+    fn blas_asum(&self) -> Result<F::RealFloat, BLASError> {
+        ASUM::default().x(self.view()).run()
+    }
+
+    /// Vectors of negative stride, which IAMAX does not accept, are copied first.
+    fn blas_iamax(&self) -> Result<usize, BLASError> {
+        match self.stride_of(Axis(0)) < 0 {
+            true => IAMAX::default().x(self.to_owned().view()).run(),
+            false => IAMAX::default().x(self.view()).run(),
+        }
+    }
+}
+
+/// All elements of matrix as 1-D array, in memory order if possible, otherwise copied.
+fn flatten<F, S>(a: &ArrayBase<S, Ix2>) -> CowArray<'_, F, Ix1>
+where
+    F: Clone,
+    S: Data<Elem = F>,
+{
+    match a.as_slice_memory_order() {
+        Some(slc) => CowArray::from(ArrayView1::from(slc)),
+        None => CowArray::from(a.iter().cloned().collect::<Array1<F>>()),
+    }
+}
+
+impl<F, S> BlasNorms<F> for ArrayBase<S, Ix2>
+where
+    F: NRM2Num + ASUMNum + IAMAXNum,
+    S: Data<Elem = F>,
+{
+    type Index = (usize, usize);
+
+    fn blas_nrm2(&self) -> Result<F::RealFloat, BLASError> {
+        flatten(self).blas_nrm2()
+    }
+
+    fn blas_asum(&self) -> Result<F::RealFloat, BLASError> {
+        flatten(self).blas_asum()
+    }
+
+    fn blas_iamax(&self) -> Result<(usize, usize), BLASError> {
+        let ncols = self.len_of(Axis(1));
+        if self.is_empty() {
+            return Ok((0, 0));
+        }
+        let a = self.as_standard_layout();
+        let idx = ArrayView1::from(a.as_slice().unwrap()).blas_iamax()?;
+        Ok((idx / ncols, idx % ncols))
+    }
+}
+
+/// Apply `f` on each lane of `a` along `axis`, in parallel with feature `rayon`.
+fn map_lanes<F, S, T, M>(a: &ArrayBase<S, Ix2>, axis: Axis, f: M) -> Result<Array1<T>, BLASError>
+where
+    F: BLASFloat,
+    S: Data<Elem = F>,
+    T: Send,
+    M: Fn(ArrayView1<F>) -> Result<T, BLASError> + Send + Sync,
+{
+    blas_assert!(axis.index() < 2, InvalidDim)?;
+    // lanes along `axis` are subviews along the other axis
+    let lanes = a.axis_iter(Axis(1 - axis.index()));
+    #[cfg(feature = "rayon")]
+    let result = {
+        use ndarray::parallel::prelude::*;
+        lanes.into_par_iter().map(f).collect::<Result<Vec<T>, BLASError>>()?
+    };
+    #[cfg(not(feature = "rayon"))]
+    let result = lanes.map(f).collect::<Result<Vec<T>, BLASError>>()?;
+    Ok(Array1::from(result))
+}
+
+/// Level-1 norms of each lane of matrix along an axis, in method-call syntax.
+///
+/// Following ndarray's `sum_axis`, the given axis is collapsed: `Axis(0)` gives norms of columns, and
+/// `Axis(1)` gives norms of rows. Each lane is handled by one BLAS call; lanes are processed in parallel
+/// with feature `rayon`.
+pub trait BlasNormsAxis<F>
+where
+    F: BLASFloat,
+{
+    fn blas_nrm2_axis(&self, axis: Axis) -> Result<Array1<F::RealFloat>, BLASError>;
+    fn blas_asum_axis(&self, axis: Axis) -> Result<Array1<F::RealFloat>, BLASError>;
+    fn blas_iamax_axis(&self, axis: Axis) -> Result<Array1<usize>, BLASError>;
+}
+
+impl<F, S> BlasNormsAxis<F> for ArrayBase<S, Ix2>
+where
+    F: NRM2Num + ASUMNum + IAMAXNum,
+    S: Data<Elem = F>,
+{
+    fn blas_nrm2_axis(&self, axis: Axis) -> Result<Array1<F::RealFloat>, BLASError> {
+        map_lanes(self, axis, |x| x.blas_nrm2())
+    }
+
+    fn blas_asum_axis(&self, axis: Axis) -> Result<Array1<F::RealFloat>, BLASError> {
+        map_lanes(self, axis, |x| x.blas_asum())
+    }
+
+    fn blas_iamax_axis(&self, axis: Axis) -> Result<Array1<usize>, BLASError> {
+        map_lanes(self, axis, |x| x.blas_iamax())
+    }
+}
+
+/* #endregion */
+
+/* #region matrix norms */
+
+/// Largest element of norms of lanes; zero if empty, and NaN if any norm is NaN.
+fn max_norm<T>(norms: Array1<T>) -> T
+where
+    T: Float,
+{
+    norms.into_iter().fold(T::zero(), |acc, v| if v > acc || v.is_nan() { v } else { acc })
+}
+
+/// Matrix 1-norm $\max_j \sum_i |A_{ij}|$ (maximum absolute column sum), by ASUM on each column.
+///
+/// For complex matrices, ASUM measures elements by $|\mathrm{Re}| + |\mathrm{Im}|$, so this is an upper bound
+/// of the true 1-norm within a factor of $\sqrt{2}$. Any strides are accepted; empty matrix gives zero.
+pub fn matrix_norm_1<F>(a: ArrayView2<F>) -> Result<F::RealFloat, BLASError>
+where
+    F: ASUMNum,
+{
+    Ok(max_norm(map_lanes(&a, Axis(0), |x| ASUM::default().x(x).run())?))
+}
+
+/// Matrix infinity-norm $\max_i \sum_j |A_{ij}|$ (maximum absolute row sum), by ASUM on each row.
+///
+/// See [`matrix_norm_1`] for complex matrices and empty matrices.
+pub fn matrix_norm_inf<F>(a: ArrayView2<F>) -> Result<F::RealFloat, BLASError>
+where
+    F: ASUMNum,
+{
+    Ok(max_norm(map_lanes(&a, Axis(1), |x| ASUM::default().x(x).run())?))
+}
+
+/* #endregion */
+
+/* #region normalization */
+
+/// Divide each column of `a` by its 2-norm in place, by NRM2 and SCAL on each column.
+///
+/// Columns of zero norm are left untouched, and their indices are returned (in increasing order). Columns of
+/// infinite or NaN norm are scaled as well, which gives zeros or NaNs.
+pub fn normalize_columns<F>(mut a: ArrayViewMut2<F>) -> Result<Vec<usize>, BLASError>
+where
+    F: NRM2Num + SCALNum,
+{
+    let mut skipped = Vec::new();
+    for (j, col) in a.axis_iter_mut(Axis(1)).enumerate() {
+        let norm = NRM2::default().x(col.view()).run()?;
+        if norm == F::RealFloat::zero() {
+            skipped.push(j);
+            continue;
+        }
+        SCAL::default().x(col).alpha(F::from_real(F::RealFloat::one() / norm)).run()?;
+    }
+    Ok(skipped)
+}
+
+/* #endregion */
@@ -0,0 +1,40 @@
+use crate::blas1::axpy::{AXPYNum, AXPY};
+use crate::blas1::dot::{DOTNum, DOT};
+use crate::blas1::nrm2::{NRM2Num, NRM2};
+use crate::blas1::scal::{SCALNum, SCAL};
+use crate::util::*;
+use ndarray::prelude::*;
+use num_traits::{One, Zero};
+
+/* #region mgs_step */
+
+/// One step of modified Gram-Schmidt: orthogonalize `v` in place against orthonormal columns of `q`, then
+/// normalize it.
+///
+/// For each column $\mathbf{q}_j$ in order, $h_j = \mathbf{q}_j^\dagger \mathbf{v}$ is computed by DOT from the
+/// current (already partially orthogonalized) $\mathbf{v}$, and $h_j \mathbf{q}_j$ is subtracted by AXPY; finally
+/// $\mathbf{v}$ is divided by its 2-norm by NRM2 and SCAL. Returns projections $h_j$ and the norm before
+/// normalization, which are the new column and subdiagonal element of the Hessenberg matrix in Arnoldi iteration.
+///
+/// If the norm is zero (`v` lies in the span of `q`), `v` is left unnormalized. Columns of `q` are assumed to be
+/// orthonormal, which is not checked.
+pub fn mgs_step<F>(q: ArrayView2<F>, mut v: ArrayViewMut1<F>) -> Result<(Array1<F>, F::RealFloat), BLASError>
+where
+    F: DOTNum + AXPYNum + NRM2Num + SCALNum,
+{
+    blas_assert_eq!(q.len_of(Axis(0)), v.len(), InvalidDim)?;
+
+    let mut h = Array1::zeros(q.len_of(Axis(1)));
+    for (q_j, h_j) in q.axis_iter(Axis(1)).zip(h.iter_mut()) {
+        *h_j = DOT::default().x(q_j).y(v.view()).conjx(true).run()?;
+        AXPY::default().x(q_j).y(v.view_mut()).alpha(F::zero() - *h_j).run()?;
+    }
+
+    let norm = NRM2::default().x(v.view()).run()?;
+    if norm != F::RealFloat::zero() {
+        SCAL::default().x(v).alpha(F::from_real(F::RealFloat::one() / norm)).run()?;
+    }
+    Ok((h, norm))
+}
+
+/* #endregion */
@@ -0,0 +1,96 @@
+use crate::blas1::iamax::{IAMAXNum, IAMAX};
+#[cfg(feature = "iamin")]
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use ndarray::prelude::*;
+use num_traits::Float;
+
+/* #region IAMIN */
+
+/// Index of element with minimum absolute value, by BLAS extension `i?amin` (with feature `iamin`).
+///
+/// Absolute value of complex numbers is measured by $|\mathrm{Re}| + |\mathrm{Im}|$, same to IAMAX. Without
+/// feature `iamin` (or for non-positive strides), index is obtained by a scan in Rust instead.
+pub trait IAMINNum: IAMAXNum {
+    /// 0-indexed position of the first minimum; `x` should not be empty.
+    fn iamin(x: ArrayView1<Self>) -> Result<usize, BLASError>;
+}
+
+/// Measure of absolute value used by `i?amax` and `i?amin`.
+fn abs_measure<F>(x: F) -> F::RealFloat
+where
+    F: BLASFloat,
+{
+    F::real(x).abs() + F::imag(x).abs()
+}
+
+fn iamin_fallback<F>(x: &ArrayView1<F>) -> usize
+where
+    F: BLASFloat,
+{
+    let mut idx_min = 0;
+    let mut val_min = F::RealFloat::infinity();
+    for (idx, &v) in x.iter().enumerate() {
+        let val = abs_measure(v);
+        if val < val_min {
+            (idx_min, val_min) = (idx, val);
+        }
+    }
+    idx_min
+}
+
+macro_rules! impl_iamin {
+    ($type: ty, $func: ident) => {
+        impl IAMINNum for $type {
+            fn iamin(x: ArrayView1<Self>) -> Result<usize, BLASError> {
+                #[cfg(feature = "iamin")]
+                if x.stride_of(Axis(0)) > 0 {
+                    let n: blas_int = x.len().try_into()?;
+                    let incx: blas_int = x.stride_of(Axis(0)).try_into()?;
+                    // 0-index for C/Rust v.s. 1-index for Fortran
+                    return unsafe { Ok((ffi::$func(&n, x.as_ptr(), &incx) - 1).try_into()?) };
+                }
+                Ok(iamin_fallback(&x))
+            }
+        }
+    };
+}
+
+impl_iamin!(f32, isamin_);
+impl_iamin!(f64, idamin_);
+impl_iamin!(c32, icamin_);
+impl_iamin!(c64, izamin_);
+
+/* #endregion */
+
+/* #region max_abs / min_abs */
+
+/// Maximum absolute value of vector, at the position found by IAMAX.
+///
+/// Absolute value of complex numbers is measured by $|\mathrm{Re}| + |\mathrm{Im}|$ as BLAS does, so for real
+/// vectors this is the infinity norm. NaN is returned if any element is NaN. Empty input gives an error.
+pub fn max_abs<F>(x: ArrayView1<F>) -> Result<F::RealFloat, BLASError>
+where
+    F: IAMAXNum,
+{
+    blas_assert!(!x.is_empty(), InvalidDim, "max_abs of empty vector")?;
+    // BLAS does not accept negative increments; only value is needed, so reversed order is fine
+    let x = if x.stride_of(Axis(0)) < 0 { x.slice_move(s![..;-1]) } else { x };
+    let idx = IAMAX::default().x(x.view()).run()?;
+    Ok(abs_measure(x[idx]))
+}
+
+/// Minimum absolute value of vector, at the position found by `i?amin` (see [`IAMINNum`]).
+///
+/// Absolute value of complex numbers is measured by $|\mathrm{Re}| + |\mathrm{Im}|$ as BLAS does. Empty input
+/// gives an error.
+pub fn min_abs<F>(x: ArrayView1<F>) -> Result<F::RealFloat, BLASError>
+where
+    F: IAMINNum,
+{
+    blas_assert!(!x.is_empty(), InvalidDim, "min_abs of empty vector")?;
+    let idx = F::iamin(x.view())?;
+    Ok(abs_measure(x[idx]))
+}
+
+/* #endregion */
@@ -0,0 +1,73 @@
+use crate::blas1::rot::{rotg, ROTGNum, ROTNum, ROT};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/* #region apply_givens_qr_step */
+
+/// Zero element `r[j, i]` by a Givens rotation of rows `i` and `j`, a building block of QR factorization.
+///
+/// The rotation is computed by ROTG from `(r[i, i], r[j, i])`, and applied to the whole rows `i` and `j` by
+/// ROT; `r[j, i]` is then set to exactly zero. Returns the rotation `(c, s)`, which can be applied to other
+/// matrices (such as accumulating $\mathbf{Q}^T$) by ROT.
+///
+/// Only real types are supported, since ROT takes a real sine.
+pub fn apply_givens_qr_step<F>(mut r: ArrayViewMut2<F>, i: usize, j: usize) -> Result<(F, F), BLASError>
+where
+    F: ROTNum + ROTGNum + BLASFloat<RealFloat = F>,
+{
+    let (nrow, ncol) = r.dim();
+    blas_assert!(i < nrow && j < nrow && i < ncol, InvalidDim, "row or column index out of bound")?;
+    blas_assert!(i != j, InvalidFlag, "rows to be rotated should be different")?;
+
+    let (_, c, s) = rotg(r[[i, i]], r[[j, i]]);
+    let (row_i, row_j) = r.multi_slice_mut((s![i, ..], s![j, ..]));
+    ROT::default().x(row_i).y(row_j).c(c).s(s).run()?;
+    r[[j, i]] = F::zero();
+    Ok((c, s))
+}
+
+/* #endregion */
+
+/* #region apply_givens_sequence */
+
+/// Apply a sequence of Givens rotations `(i, j, c, s)` in order to rows (`side = Left`) or columns
+/// (`side = Right`) of `m`, each by ROT, as in the inner loop of bidiagonal QR (SVD) sweeps.
+///
+/// Rotation `(i, j, c, s)` is the identity matrix except $G_{ii} = G_{jj} = c$, $G_{ij} = s$, $G_{ji} = -s$.
+/// For `Left`, the result is $\mathbf{G}_k \cdots \mathbf{G}_1 \mathbf{M}$ (rows `i` and `j` are rotated); for
+/// `Right`, it is $\mathbf{M} \mathbf{G}_1^T \cdots \mathbf{G}_k^T$ (columns `i` and `j` are rotated), which is
+/// the transpose of applying the same sequence to rows of $\mathbf{M}^T$. `c` and `s` are real, so complex
+/// matrices are rotated by CSROT (ZDROT).
+///
+/// All indices are checked before any rotation is applied: out-of-range index gives
+/// [`BLASError::InvalidDim`], and `i == j` gives [`BLASError::InvalidFlag`].
+pub fn apply_givens_sequence<F>(
+    rotations: &[(usize, usize, F::RealFloat, F::RealFloat)],
+    mut m: ArrayViewMut2<F>,
+    side: BLASSide,
+) -> Result<(), BLASError>
+where
+    F: ROTNum,
+{
+    let axis = match side {
+        BLASLeft => Axis(0),
+        BLASRight => Axis(1),
+        _ => blas_invalid!(side)?,
+    };
+    let len = m.len_of(axis);
+    for &(i, j, _, _) in rotations {
+        blas_assert!(i < len && j < len, InvalidDim, "rotated row or column index out of bound")?;
+        blas_assert!(i != j, InvalidFlag, "rows or columns to be rotated should be different")?;
+    }
+
+    for &(i, j, c, s) in rotations {
+        let (x, y) = match axis {
+            Axis(0) => m.multi_slice_mut((s![i, ..], s![j, ..])),
+            _ => m.multi_slice_mut((s![.., i], s![.., j])),
+        };
+        ROT::default().x(x).y(y).c(c).s(s).run()?;
+    }
+    Ok(())
+}
+
+/* #endregion */
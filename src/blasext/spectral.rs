@@ -0,0 +1,116 @@
+use crate::blas1::nrm2::{NRM2Num, NRM2};
+use crate::blas1::scal::{SCALNum, SCAL};
+use crate::blas2::gemv::{GEMVNum, GEMV};
+use crate::blas3::gemm::{GEMMNum, GEMM};
+use crate::util::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+/* #region power_iteration */
+
+/// Scale vector to unit 2-norm in-place by NRM2 and SCAL, returning the original norm.
+fn normalize<F>(x: &mut Array1<F>) -> Result<F::RealFloat, BLASError>
+where
+    F: NRM2Num + SCALNum,
+    F::RealFloat: Float,
+{
+    let norm = NRM2::default().x(x.view()).run()?;
+    if norm != F::RealFloat::zero() {
+        SCAL::default().x(x.view_mut()).alpha(F::from_real(norm.recip())).run()?;
+    }
+    Ok(norm)
+}
+
+/// Estimate dominant eigenvalue and eigenvector of a square matrix by power iteration.
+///
+/// Each iteration applies GEMV and normalizes the vector by NRM2/SCAL. The eigenvalue is estimated by the
+/// (real part of) Rayleigh quotient $\mathbf{x}^\dagger \mathbf{A} \mathbf{x}$; iteration stops after `iters`
+/// steps, or when two successive estimates differ by no more than `tol`.
+///
+/// Convergence rate depends on the ratio of the two largest eigenvalues in magnitude; the result is only
+/// meaningful for matrices with a single real dominant eigenvalue (such as symmetric or hermitian matrices).
+pub fn power_iteration<F>(
+    a: ArrayView2<F>,
+    iters: usize,
+    tol: F::RealFloat,
+) -> Result<(F::RealFloat, Array1<F>), BLASError>
+where
+    F: GEMVNum + NRM2Num + SCALNum,
+    F::RealFloat: Float,
+{
+    blas_assert_eq!(a.len_of(Axis(0)), a.len_of(Axis(1)), InvalidDim)?;
+    let n = a.len_of(Axis(0));
+    blas_assert!(n > 0, InvalidDim, "matrix should not be empty")?;
+
+    // non-uniform initial guess, to avoid being orthogonal to simple eigenvectors
+    let n_real = <F::RealFloat as NumCast>::from(n).unwrap();
+    let mut x = Array1::from_shape_fn(n, |i| {
+        F::from_real(F::RealFloat::one() + <F::RealFloat as NumCast>::from(i).unwrap() / n_real)
+    });
+    normalize(&mut x)?;
+
+    let mut eig = F::RealFloat::zero();
+    for iter in 0..iters {
+        let mut y = GEMV::default().a(a).x(x.view()).run()?.into_owned();
+        let eig_new =
+            F::real(x.iter().zip(y.iter()).fold(F::zero(), |acc, (&xi, &yi)| acc + F::conj(xi) * yi));
+        if normalize(&mut y)? == F::RealFloat::zero() {
+            // x is in null space of A
+            return Ok((F::RealFloat::zero(), x));
+        }
+        x = y;
+        let converged = iter > 0 && (eig_new - eig).abs() <= tol;
+        eig = eig_new;
+        if converged {
+            break;
+        }
+    }
+    Ok((eig, x))
+}
+
+/* #endregion */
+
+/* #region matrix_power */
+
+/// Integer power $\mathbf{A}^n$ of a square matrix by repeated squaring.
+///
+/// Binary exponentiation takes at most $2 \log_2 n$ GEMM calls. Products are written alternately into two
+/// preallocated buffers (besides the result), so no allocation is made per step. `n = 0` gives the identity
+/// matrix; non-square `a` gives [`BLASError::InvalidDim`].
+pub fn matrix_power<F>(a: ArrayView2<F>, n: u32) -> Result<Array2<F>, BLASError>
+where
+    F: GEMMNum,
+{
+    blas_assert_eq!(a.len_of(Axis(0)), a.len_of(Axis(1)), InvalidDim)?;
+    let dim = a.len_of(Axis(0));
+    if n == 0 {
+        return Ok(Array2::eye(dim));
+    }
+
+    // `result` is not touched until the lowest set bit of `n`, where it is simply a copy of `base`
+    let mut base = a.to_owned();
+    let mut result = Array2::zeros((dim, dim));
+    let mut temp = Array2::zeros((dim, dim));
+    let mut initialized = false;
+    let mut n = n;
+    loop {
+        if n & 1 == 1 {
+            if initialized {
+                GEMM::default().a(result.view()).b(base.view()).c(temp.view_mut()).run()?;
+                core::mem::swap(&mut result, &mut temp);
+            } else {
+                result.assign(&base);
+                initialized = true;
+            }
+        }
+        n >>= 1;
+        if n == 0 {
+            break;
+        }
+        GEMM::default().a(base.view()).b(base.view()).c(temp.view_mut()).run()?;
+        core::mem::swap(&mut base, &mut temp);
+    }
+    Ok(result)
+}
+
+/* #endregion */
@@ -0,0 +1,81 @@
+use crate::blas3::herk::HERK;
+use crate::blas3::syrk::{SYRKNum, SYRK};
+use crate::util::*;
+use ndarray::prelude::*;
+use num_traits::{Float, NumCast};
+
+/* #region CovarianceNum */
+
+/// Float types of which covariance matrix can be computed by rank-k update.
+///
+/// This is SYRK for real types, and HERK for complex types (where covariance is hermitian).
+pub trait CovarianceNum: SYRKNum {
+    /// Lower triangle of $\alpha \mathbf{X}^\dagger \mathbf{X}$ (upper triangle is not referenced).
+    fn gram_lower(x: ArrayView2<Self>, alpha: Self::RealFloat) -> Result<Array2<Self>, BLASError>;
+}
+
+macro_rules! impl_covariance_real {
+    ($type: ty) => {
+        impl CovarianceNum for $type {
+            fn gram_lower(x: ArrayView2<Self>, alpha: Self) -> Result<Array2<Self>, BLASError> {
+                let c = SYRK::default().a(x).alpha(alpha).uplo(BLASLower).trans(BLASTrans).run()?;
+                Ok(c.into_owned())
+            }
+        }
+    };
+}
+
+macro_rules! impl_covariance_complex {
+    ($type: ty) => {
+        impl CovarianceNum for $type {
+            fn gram_lower(x: ArrayView2<Self>, alpha: Self::RealFloat) -> Result<Array2<Self>, BLASError> {
+                let c = HERK::default().a(x).alpha(alpha).uplo(BLASLower).trans(BLASConjTrans).run()?;
+                Ok(c.into_owned())
+            }
+        }
+    };
+}
+
+impl_covariance_real!(f32);
+impl_covariance_real!(f64);
+impl_covariance_complex!(c32);
+impl_covariance_complex!(c64);
+
+/* #endregion */
+
+/* #region covariance */
+
+/// Covariance matrix of columns of data matrix `x` (rows are observations, columns are variables).
+///
+/// $\mathbf{C} = \mathbf{X}_\mathrm{c}^T \overline{\mathbf{X}_\mathrm{c}} / (n - \mathrm{ddof})$, where
+/// $\mathbf{X}_\mathrm{c}$ is `x` with column means subtracted, and $n$ is the number of observations; `ddof = 1`
+/// gives the unbiased estimate, `ddof = 0` the maximum likelihood one. This is the same as
+/// `numpy.cov(x, rowvar=False, ddof=ddof)`, also for complex types. Centered data is a temporary copy; the
+/// product is computed by SYRK (HERK for complex types, giving hermitian covariance), and the other triangle is
+/// filled afterwards. Requires `n > ddof`, otherwise [`BLASError::InvalidDim`].
+pub fn covariance<F>(x: ArrayView2<F>, ddof: usize) -> Result<Array2<F>, BLASError>
+where
+    F: CovarianceNum,
+{
+    let (n, p) = x.dim();
+    blas_assert!(n > ddof, InvalidDim, "number of observations should be larger than ddof")?;
+
+    // center columns into col-major temporary
+    let to_real = |v: usize| -> F::RealFloat { <F::RealFloat as NumCast>::from(v).unwrap() };
+    let mean = x.sum_axis(Axis(0)).mapv(|s| s * F::from_real(to_real(n).recip()));
+    let mut x_centered = Array2::zeros((n, p).f());
+    x_centered.assign(&x);
+    x_centered.zip_mut_with(&mean, |v, &m| *v = *v - m);
+
+    // lower triangle of X^H X is the upper triangle of X^T conj(X)
+    let mut c = F::gram_lower(x_centered.view(), to_real(n - ddof).recip())?;
+    for i in 0..p {
+        for j in 0..i {
+            c[[j, i]] = c[[i, j]];
+            c[[i, j]] = F::conj(c[[i, j]]);
+        }
+    }
+    Ok(c)
+}
+
+/* #endregion */
@@ -0,0 +1,56 @@
+use crate::blas2::tbsv::{TBSVNum, TBSV};
+use crate::blas2::tpsv::{TPSVNum, TPSV};
+use crate::blas2::trsv::{TRSVNum, TRSV};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/* #region solve_triangular */
+
+/// Storage format of a triangular matrix, for [`solve_triangular`].
+#[derive(Debug, Clone)]
+pub enum TriangularStorage<'a, F> {
+    /// Full `n x n` matrix; only the triangle indicated by `uplo` is referenced (as TRSV).
+    Dense(ArrayView2<'a, F>),
+    /// Packed triangle of length `n * (n + 1) / 2` and dimension `n`, in row-major packing order by default
+    /// (as TPSV).
+    Packed(ArrayView1<'a, F>, usize),
+    /// Band storage with `k` off-diagonals: shape `(k + 1, n)` for col-major, or `(n, k + 1)` for row-major
+    /// (as TBSV).
+    Banded(ArrayView2<'a, F>, usize),
+}
+
+/// Solve $\mathrm{op}(\mathbf{A}) \mathbf{x} = \mathbf{b}$ in place for a triangular matrix in any storage format.
+///
+/// Dense, packed and banded storage are dispatched to TRSV, TPSV and TBSV respectively, with the same `uplo`,
+/// `trans` and `diag`; `b` is overwritten by the solution. Dimension given by packed or banded storage should
+/// agree with length of `b` and shape of storage, otherwise [`BLASError::InvalidDim`] is raised.
+pub fn solve_triangular<'x, F>(
+    a: TriangularStorage<'_, F>,
+    b: ArrayViewMut1<'x, F>,
+    uplo: BLASUpLo,
+    trans: BLASTranspose,
+    diag: BLASDiag,
+) -> Result<ArrayOut1<'x, F>, BLASError>
+where
+    F: TRSVNum + TPSVNum + TBSVNum,
+{
+    let n = b.len();
+    match a {
+        TriangularStorage::Dense(a) => TRSV::default().a(a).x(b).uplo(uplo).trans(trans).diag(diag).run(),
+        TriangularStorage::Packed(ap, n_ap) => {
+            blas_assert_eq!(n_ap, n, InvalidDim)?;
+            blas_assert_eq!(ap.len(), n * (n + 1) / 2, InvalidDim)?;
+            TPSV::default().ap(ap).x(b).uplo(uplo).trans(trans).diag(diag).run()
+        },
+        TriangularStorage::Banded(ab, k) => {
+            blas_assert!(
+                ab.dim() == (k + 1, n) || ab.dim() == (n, k + 1),
+                InvalidDim,
+                "band storage should have shape (k + 1, n) or (n, k + 1)"
+            )?;
+            TBSV::default().a(ab).x(b).uplo(uplo).trans(trans).diag(diag).run()
+        },
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,6 @@
+//! Adapters for users migrating from other linear algebra crates.
+//!
+//! Functions in this module follow signatures and output conventions of the corresponding crate, but dispatch
+//! to BLAS wrappers of this crate.
+
+pub mod ndarray_linalg;
@@ -0,0 +1,165 @@
+//! Drop-in replacements for matrix products of `ndarray` (as used with `ndarray-linalg`).
+//!
+//! | `ndarray` / `ndarray-linalg` | this module | BLAS wrapper |
+//! |--|--|--|
+//! | `ndarray::linalg::general_mat_mul` | [`general_mat_mul`] | [`GEMM`] |
+//! | `ndarray::linalg::general_mat_vec_mul` | [`general_mat_vec_mul`] | [`GEMV`] |
+//! | `a.dot(&b)` (matrix-matrix) | [`mat_mul`] | [`GEMM`] |
+//! | `a.dot(&x)` (matrix-vector) | [`mat_vec_mul`] | [`GEMV`] |
+//! | `x.dot(&a)` (vector-matrix) | [`vec_mat_mul`] | [`GEMV`] (transposed) |
+//!
+//! As in `ndarray`, these functions panic if array shapes are not compatible, instead of returning
+//! [`BLASError`]. Output of [`mat_mul`] is col-major if both inputs have unit stride on the first axis, and
+//! row-major (standard layout) otherwise, which is the same convention of `ndarray`'s `dot`.
+//!
+//! Broadcasted (zero-stride) inputs are copied before calling BLAS. Unlike `ndarray`, complex types (`c32`, `c64`) are also dispatched to BLAS.
+
+use crate::blas2::gemv::{GEMVNum, GEMV};
+use crate::blas3::gemm::{GEMMNum, GEMM};
+use crate::util::*;
+use ndarray::prelude::*;
+use ndarray::{Data, DataMut};
+
+#[cold]
+#[inline(never)]
+fn dot_shape_error(m: usize, k: usize, k2: usize, n: usize) -> ! {
+    match m.checked_mul(n) {
+        Some(len) if len <= isize::MAX as usize => {},
+        _ => panic!("ndarray: shape {} × {} overflows isize", m, n),
+    }
+    panic!("ndarray: inputs {} × {} and {} × {} are not compatible for matrix multiplication", m, k, k2, n);
+}
+
+#[cold]
+#[inline(never)]
+fn general_dot_shape_error(m: usize, k: usize, k2: usize, n: usize, c1: usize, c2: usize) -> ! {
+    panic!(
+        "ndarray: inputs {} × {}, {} × {}, and output {} × {} are not compatible for matrix multiplication",
+        m, k, k2, n, c1, c2
+    );
+}
+
+/// Materialize broadcasted (zero-stride) inputs, which BLAS wrappers reject but `ndarray` accepts.
+fn materialize_broadcast<F, D>(a: ArrayView<'_, F, D>) -> CowArray<'_, F, D>
+where
+    F: Clone,
+    D: Dimension,
+{
+    if a.shape().iter().zip(a.strides()).any(|(&len, &stride)| len > 1 && stride == 0) {
+        CowArray::from(a.as_standard_layout().into_owned())
+    } else {
+        CowArray::from(a)
+    }
+}
+
+/* #region matrix-matrix */
+
+/// General matrix-matrix multiplication $\mathbf{C} = \alpha \mathbf{A} \mathbf{B} + \beta \mathbf{C}$.
+///
+/// Same as `ndarray::linalg::general_mat_mul`.
+///
+/// ***Panics*** if array shapes are not compatible.
+pub fn general_mat_mul<F, S1, S2, S3>(
+    alpha: F,
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix2>,
+    beta: F,
+    c: &mut ArrayBase<S3, Ix2>,
+) where
+    F: GEMMNum,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+    S3: DataMut<Elem = F>,
+{
+    let ((m, k), (k2, n)) = (a.dim(), b.dim());
+    let (c1, c2) = c.dim();
+    if k != k2 || m != c1 || n != c2 {
+        general_dot_shape_error(m, k, k2, n, c1, c2);
+    }
+    let (a, b) = (materialize_broadcast(a.view()), materialize_broadcast(b.view()));
+    GEMM::default().a(a.view()).b(b.view()).c(c.view_mut()).alpha(alpha).beta(beta).run().unwrap();
+}
+
+/// Matrix-matrix product, same as `a.dot(&b)` of `ndarray`.
+///
+/// ***Panics*** if array shapes are not compatible.
+pub fn mat_mul<F, S1, S2>(a: &ArrayBase<S1, Ix2>, b: &ArrayBase<S2, Ix2>) -> Array2<F>
+where
+    F: GEMMNum,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+{
+    let ((m, k), (k2, n)) = (a.dim(), b.dim());
+    if k != k2 || m.checked_mul(n).is_none() {
+        dot_shape_error(m, k, k2, n);
+    }
+    let column_major = a.strides()[0] == 1 && b.strides()[0] == 1;
+    let mut c = Array2::zeros((m, n).set_f(column_major));
+    general_mat_mul(F::one(), a, b, F::zero(), &mut c);
+    c
+}
+
+/* #endregion */
+
+/* #region matrix-vector */
+
+/// General matrix-vector multiplication $\mathbf{y} = \alpha \mathbf{A} \mathbf{x} + \beta \mathbf{y}$.
+///
+/// Same as `ndarray::linalg::general_mat_vec_mul`.
+///
+/// ***Panics*** if array shapes are not compatible.
+pub fn general_mat_vec_mul<F, S1, S2, S3>(
+    alpha: F,
+    a: &ArrayBase<S1, Ix2>,
+    x: &ArrayBase<S2, Ix1>,
+    beta: F,
+    y: &mut ArrayBase<S3, Ix1>,
+) where
+    F: GEMVNum,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+    S3: DataMut<Elem = F>,
+{
+    let ((m, k), k2) = (a.dim(), x.dim());
+    if k != k2 || m != y.dim() {
+        general_dot_shape_error(m, k, k2, 1, y.dim(), 1);
+    }
+    let (a, x) = (materialize_broadcast(a.view()), materialize_broadcast(x.view()));
+    GEMV::default().a(a.view()).x(x.view()).y(y.view_mut()).alpha(alpha).beta(beta).run().unwrap();
+}
+
+/// Matrix-vector product, same as `a.dot(&x)` of `ndarray`.
+///
+/// ***Panics*** if array shapes are not compatible.
+pub fn mat_vec_mul<F, S1, S2>(a: &ArrayBase<S1, Ix2>, x: &ArrayBase<S2, Ix1>) -> Array1<F>
+where
+    F: GEMVNum,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+{
+    let ((m, k), k2) = (a.dim(), x.dim());
+    if k != k2 {
+        dot_shape_error(m, k, k2, 1);
+    }
+    let mut y = Array1::zeros(m);
+    general_mat_vec_mul(F::one(), a, x, F::zero(), &mut y);
+    y
+}
+
+/// Vector-matrix product, same as `x.dot(&a)` of `ndarray`.
+///
+/// ***Panics*** if array shapes are not compatible.
+pub fn vec_mat_mul<F, S1, S2>(x: &ArrayBase<S1, Ix1>, a: &ArrayBase<S2, Ix2>) -> Array1<F>
+where
+    F: GEMVNum,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+{
+    let (k, (k2, n)) = (x.dim(), a.dim());
+    if k != k2 {
+        dot_shape_error(1, k, k2, n);
+    }
+    mat_vec_mul(&a.t(), x)
+}
+
+/* #endregion */
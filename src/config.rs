@@ -0,0 +1,65 @@
+//! Crate-level configuration.
+//!
+//! # Default layout
+//!
+//! When a level-3 wrapper (GEMM, SYRK, TRSM, ...) is not given `.layout()`, and the layouts of its operands do not
+//! decide the layout of computation (the output matrix is not given, and inputs are sequential or mixed row- and
+//! col-major), the wrapper falls back to a preferred layout. This preference is row-major by default, and can be
+//! changed by [`set_default_layout`]; code storing everything col-major may prefer col-major, so that owned
+//! outputs are allocated col-major and mixed-layout inputs are handled without copying the col-major ones.
+//!
+//! Interaction with other options:
+//! - `.layout()` on a builder always takes priority over the default layout;
+//! - the layout of a given output matrix (such as `c` in GEMM) also takes priority, since it can't be changed;
+//! - the default layout is not consulted by packed (TPMV, HPR, ...) and banded (GBMV, TBSV, ...) wrappers, where
+//!   layout decides how storage is interpreted, not only how computation is performed.
+//!
+//! With feature `std`, the default layout is thread-local (each thread starts with row-major); otherwise it is
+//! process-wide.
+
+use crate::util::*;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+use core::cell::Cell;
+#[cfg(not(feature = "std"))]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static COL_MAJOR_DEFAULT: Cell<bool> = const { Cell::new(false) };
+}
+
+#[cfg(not(feature = "std"))]
+static COL_MAJOR_DEFAULT: AtomicBool = AtomicBool::new(false);
+
+/// Set preferred layout of computation, when it is not decided by `.layout()` or operands.
+///
+/// Only [`BLASRowMajor`] and [`BLASColMajor`] are accepted; other layouts give [`BLASError::InvalidFlag`].
+/// See [module documentation](self) for the interaction with per-call `.layout()`.
+pub fn set_default_layout(layout: BLASLayout) -> Result<(), BLASError> {
+    let col_major = match layout {
+        BLASRowMajor => false,
+        BLASColMajor => true,
+        _ => blas_invalid!(layout)?,
+    };
+    #[cfg(feature = "std")]
+    COL_MAJOR_DEFAULT.with(|f| f.set(col_major));
+    #[cfg(not(feature = "std"))]
+    COL_MAJOR_DEFAULT.store(col_major, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Preferred layout of computation, [`BLASRowMajor`] unless changed by [`set_default_layout`].
+pub fn default_layout() -> BLASLayout {
+    #[cfg(feature = "std")]
+    let col_major = COL_MAJOR_DEFAULT.with(|f| f.get());
+    #[cfg(not(feature = "std"))]
+    let col_major = COL_MAJOR_DEFAULT.load(Ordering::Relaxed);
+    match col_major {
+        true => BLASColMajor,
+        false => BLASRowMajor,
+    }
+}
@@ -196,6 +196,18 @@ extern "C" {
 extern "C" {
     pub fn izamax_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> blas_int;
 }
+extern "C" {
+    pub fn icamin_(n: *const blas_int, x: *const c32, incx: *const blas_int) -> blas_int;
+}
+extern "C" {
+    pub fn idamin_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> blas_int;
+}
+extern "C" {
+    pub fn isamin_(n: *const blas_int, x: *const f32, incx: *const blas_int) -> blas_int;
+}
+extern "C" {
+    pub fn izamin_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> blas_int;
+}
 extern "C" {
     pub fn dnrm2_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> f64;
 }
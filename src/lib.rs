@@ -7,8 +7,13 @@
 pub mod blas1;
 pub mod blas2;
 pub mod blas3;
+pub mod blasext;
+pub mod compat;
+pub mod config;
 pub mod ffi;
 pub mod prelude;
+pub mod record;
+pub mod reference;
 pub mod util;
 
 pub mod document_dev {
@@ -2,8 +2,13 @@ pub use crate::ffi::blas_int;
 pub use crate::util::*;
 
 pub use crate::blas1::asum::{ASUMNum, ASUM, DASUM, DZASUM, SASUM, SCASUM};
-pub use crate::blas1::iamax::{IAMAXNum, IAMAX, ICAMAX, IDAMAX, ISAMAX, IZAMAX};
+pub use crate::blas1::axpy::{AXPYNum, AXPY, CAXPY, DAXPY, SAXPY, ZAXPY};
+pub use crate::blas1::copy::{COPYNum, CCOPY, COPY, DCOPY, SCOPY, ZCOPY};
+pub use crate::blas1::dot::{DOTNum, CDOTU, DDOT, DOT, SDOT, ZDOTU};
+pub use crate::blas1::iamax::{IAMAXNum, NanPolicy, IAMAX, ICAMAX, IDAMAX, ISAMAX, IZAMAX};
 pub use crate::blas1::nrm2::{NRM2Num, DNRM2, DZNRM2, NRM2, SCNRM2, SNRM2};
+pub use crate::blas1::rot::{rotg, ROTGNum, ROTNum, CSROT, DROT, ROT, SROT, ZDROT};
+pub use crate::blas1::scal::{SCALNum, CSCAL, DSCAL, SCAL, SSCAL, ZSCAL};
 
 pub use crate::blas2::gbmv::{GBMVNum, CGBMV, DGBMV, GBMV, SGBMV, ZGBMV};
 pub use crate::blas2::gemv::{GEMVNum, CGEMV, DGEMV, GEMV, SGEMV, ZGEMV};
@@ -23,24 +28,57 @@ pub use crate::blas2::tpsv::{TPSVNum, CTPSV, DTPSV, STPSV, TPSV, ZTPSV};
 pub use crate::blas2::trmv::{TRMVNum, CTRMV, DTRMV, STRMV, TRMV, ZTRMV};
 pub use crate::blas2::trsv::{TRSVNum, CTRSV, DTRSV, STRSV, TRSV, ZTRSV};
 
-pub use crate::blas3::gemm::{GEMMNum, CGEMM, DGEMM, GEMM, SGEMM, ZGEMM};
+pub use crate::blas3::gemm::{
+    Accumulator, GEMMDispatch, GEMMDispatchNum, GEMMNum, GemmOutputPool, ShapeClass, CGEMM, DGEMM, GEMM,
+    SGEMM, ZGEMM,
+};
+pub use crate::blas3::gemm_rc::{DGEMM_RC, GEMM_RC, SGEMM_RC};
 pub use crate::blas3::hemm::{HEMMNum, CHEMM, HEMM, ZHEMM};
 pub use crate::blas3::her2k::{HER2KNum, CHER2K, HER2K, ZHER2K};
 pub use crate::blas3::herk::{HERKNum, CHERK, HERK, ZHERK};
+pub use crate::blas3::herk_batch::HERKBatch;
 pub use crate::blas3::symm::{SYMMNum, CSYMM, DSYMM, SSYMM, SYMM, ZSYMM};
 pub use crate::blas3::syr2k::{SYR2KNum, CSYR2K, DSYR2K, SSYR2K, SYR2K, ZSYR2K};
 pub use crate::blas3::syrk::{SYRKNum, CSYRK, DSYRK, SSYRK, SYRK, ZSYRK};
+pub use crate::blas3::syrk_batch::SYRKBatch;
 pub use crate::blas3::trmm::{TRMMNum, CTRMM, DTRMM, STRMM, TRMM, ZTRMM};
 pub use crate::blas3::trsm::{TRSMNum, CTRSM, DTRSM, STRSM, TRSM, ZTRSM};
 
+pub use crate::blasext::condition::{cond_1_estimate, CondFactor};
+pub use crate::blasext::dot::{
+    dot_streaming, gemm_diag, kron, outer, outer_conj, quadratic_form, quadratic_form_checked, tensordot,
+    BlasDot,
+};
+pub use crate::blasext::elementwise::{axpy_real_alpha, extract_block, mat_axpy, negate, scale};
+pub use crate::blasext::iter::gemv_over_rows;
+pub use crate::blasext::matfree::gemm_matfree;
+pub use crate::blasext::norms::{
+    matrix_norm_1, matrix_norm_inf, normalize_columns, BlasNorms, BlasNormsAxis,
+};
+pub use crate::blasext::orthogonal::mgs_step;
+pub use crate::blasext::reduction::{max_abs, min_abs, IAMINNum};
+pub use crate::blasext::rotation::{apply_givens_qr_step, apply_givens_sequence};
+pub use crate::blasext::spectral::{matrix_power, power_iteration};
+pub use crate::blasext::statistics::{covariance, CovarianceNum};
+pub use crate::blasext::triangular::{solve_triangular, TriangularStorage};
+
 #[cfg(feature = "gemmt")]
 #[cfg_attr(docsrs, doc(cfg(feature = "gemmt")))]
 pub use crate::blas3::gemmt::{GEMMTNum, CGEMMT, DGEMMT, GEMMT, SGEMMT, ZGEMMT};
 
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+pub use crate::blas2::hgemv::HGEMV;
+
 pub mod generic {
     pub use crate::blas1::asum::ASUM_;
+    pub use crate::blas1::axpy::AXPY_;
+    pub use crate::blas1::copy::COPY_;
+    pub use crate::blas1::dot::DOT_;
     pub use crate::blas1::iamax::IAMAX_;
     pub use crate::blas1::nrm2::NRM2_;
+    pub use crate::blas1::rot::ROT_;
+    pub use crate::blas1::scal::SCAL_;
 
     pub use crate::blas2::gbmv::GBMV_;
     pub use crate::blas2::gemv::GEMV_;
@@ -61,16 +99,23 @@ pub mod generic {
     pub use crate::blas2::trsv::TRSV_;
 
     pub use crate::blas3::gemm::GEMM_;
+    pub use crate::blas3::gemm_rc::GEMM_RC_;
     pub use crate::blas3::hemm::HEMM_;
     pub use crate::blas3::her2k::HER2K_;
     pub use crate::blas3::herk::HERK_;
+    pub use crate::blas3::herk_batch::HERKBatch_;
     pub use crate::blas3::symm::SYMM_;
     pub use crate::blas3::syr2k::SYR2K_;
     pub use crate::blas3::syrk::SYRK_;
+    pub use crate::blas3::syrk_batch::SYRKBatch_;
     pub use crate::blas3::trmm::TRMM_;
     pub use crate::blas3::trsm::TRSM_;
 
     #[cfg(feature = "gemmt")]
     #[cfg_attr(docsrs, doc(cfg(feature = "gemmt")))]
     pub use crate::blas3::gemmt::GEMMT_;
+
+    #[cfg(feature = "half")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+    pub use crate::blas2::hgemv::HGEMV_;
 }
@@ -0,0 +1,113 @@
+//! Log of BLAS calls, for capturing and replaying call sequences.
+//!
+//! With crate feature `record`, every call into BLAS made by wrappers appends a [`CallRecord`] to a
+//! thread-local log, which can be taken out by [`drain`]. Records only hold metadata (dimensions, flags and
+//! scalars as passed to BLAS) and checksums of array operands; array data is never stored. Without feature
+//! `record`, nothing is recorded and [`drain`] always returns an empty log.
+//!
+//! Dimensions and flags are recorded as passed to the (column-major) BLAS routine, after the wrapper has
+//! resolved layout; calls that are short-circuited by wrappers (such as empty outputs) are not recorded.
+
+extern crate alloc;
+#[cfg(feature = "record")]
+extern crate std;
+
+use crate::util::*;
+use alloc::vec::Vec;
+use ndarray::prelude::*;
+use num_traits::ToPrimitive;
+
+#[cfg(feature = "record")]
+use core::cell::RefCell;
+
+/// Metadata of one call into BLAS.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallRecord {
+    /// Name of BLAS wrapper (such as `"GEMM"`).
+    pub routine: &'static str,
+    /// Dimensions (such as `m`, `n`, `k`) passed to BLAS.
+    pub dims: Vec<(&'static str, usize)>,
+    /// Character flags (such as `trans`, `uplo`) passed to BLAS.
+    pub flags: Vec<(&'static str, char)>,
+    /// Scalars (such as `alpha`, `beta`) passed to BLAS, widened to `c64`.
+    pub scalars: Vec<(&'static str, c64)>,
+    /// Checksums of array operands before the call, see [`checksum`].
+    pub checksums: Vec<(&'static str, u64)>,
+}
+
+#[cfg(feature = "record")]
+std::thread_local! {
+    static CALL_LOG: RefCell<Vec<CallRecord>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Take out all records of the current thread, leaving the log empty.
+pub fn drain() -> Vec<CallRecord> {
+    #[cfg(feature = "record")]
+    return CALL_LOG.with(|log| core::mem::take(&mut *log.borrow_mut()));
+    #[cfg(not(feature = "record"))]
+    return Vec::new();
+}
+
+/// Cheap checksum (FNV-1a) of array values in logical order.
+///
+/// The checksum only depends on shape and values, not on memory layout, so the same matrix stored in row-major
+/// or column-major gives the same checksum.
+pub fn checksum<F, D>(a: &ArrayView<F, D>) -> u64
+where
+    F: BLASFloat,
+    D: Dimension,
+{
+    const OFFSET: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    let feed = |h: u64, v: u64| v.to_le_bytes().iter().fold(h, |h, &b| (h ^ b as u64).wrapping_mul(PRIME));
+    let h = a.shape().iter().fold(OFFSET, |h, &d| feed(h, d as u64));
+    a.iter().fold(h, |h, &v| {
+        let (re, im) = widen(v);
+        feed(feed(h, re.to_bits()), im.to_bits())
+    })
+}
+
+fn widen<F>(x: F) -> (f64, f64)
+where
+    F: BLASFloat,
+{
+    (F::real(x).to_f64().unwrap_or(f64::NAN), F::imag(x).to_f64().unwrap_or(f64::NAN))
+}
+
+/// Whether calls are recorded; resolved in this crate so that recording is optimized out when disabled.
+#[doc(hidden)]
+pub const ENABLED: bool = cfg!(feature = "record");
+
+#[doc(hidden)]
+pub fn scalar<F>(x: F) -> c64
+where
+    F: BLASFloat,
+{
+    let (re, im) = widen(x);
+    c64::new(re, im)
+}
+
+#[doc(hidden)]
+pub fn push(record: CallRecord) {
+    #[cfg(feature = "record")]
+    CALL_LOG.with(|log| log.borrow_mut().push(record));
+    #[cfg(not(feature = "record"))]
+    let _ = record;
+}
+
+/// Append record of BLAS call to thread-local log, when feature `record` is enabled; otherwise do nothing.
+macro_rules! blas_record {
+    ($routine:literal, dims: [$($dim:ident),*], flags: [$($flag:ident),*], scalars: [$($scalar:ident),*], arrays: [$($array:ident),*]) => {
+        if $crate::record::ENABLED {
+            $crate::record::push($crate::record::CallRecord {
+                routine: $routine,
+                dims: [$((stringify!($dim), $dim as usize)),*].into(),
+                flags: [$((stringify!($flag), $flag as u8 as char)),*].into(),
+                scalars: [$((stringify!($scalar), $crate::record::scalar($scalar))),*].into(),
+                checksums: [$((stringify!($array), $crate::record::checksum(&$array.view()))),*].into(),
+            });
+        }
+    };
+}
+
+pub(crate) use blas_record;
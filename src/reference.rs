@@ -0,0 +1,94 @@
+//! Naive reference implementations of BLAS operations, for verifying results of BLAS wrappers.
+//!
+//! Functions here are straightforward loops without any BLAS call, and are only meant for debugging and
+//! testing; see [`GEMM_::verify`](crate::blas3::gemm::GEMM_::verify).
+
+use crate::util::*;
+use ndarray::prelude::*;
+use num_traits::{Float, ToPrimitive, Zero};
+
+/// Naive $\mathbf{C} = \alpha \mathrm{op}(\mathbf{A}) \mathrm{op}(\mathbf{B}) + \beta \mathbf{C}$.
+///
+/// `c` is only referenced if `beta` is nonzero, and is taken as zero if not given (same as owned output of
+/// wrappers); result is a newly allocated matrix.
+pub fn gemm<F>(
+    a: ArrayView2<F>,
+    b: ArrayView2<F>,
+    c: Option<ArrayView2<F>>,
+    alpha: F,
+    beta: F,
+    transa: BLASTranspose,
+    transb: BLASTranspose,
+) -> Result<Array2<F>, BLASError>
+where
+    F: BLASFloat,
+{
+    let op = |x: ArrayView2<F>, trans: BLASTranspose| -> Result<Array2<F>, BLASError> {
+        match trans {
+            BLASNoTrans => Ok(x.to_owned()),
+            BLASTrans => Ok(x.t().to_owned()),
+            BLASConjTrans => Ok(x.t().mapv(F::conj)),
+            _ => blas_invalid!(trans),
+        }
+    };
+    let (a, b) = (op(a, transa)?, op(b, transb)?);
+    let (m, k) = a.dim();
+    let n = b.len_of(Axis(1));
+    blas_assert_eq!(b.len_of(Axis(0)), k, InvalidDim)?;
+    let mut result = Array2::from_shape_fn((m, n), |(i, j)| {
+        alpha * (0..k).fold(F::zero(), |s, l| s + a[[i, l]] * b[[l, j]])
+    });
+    if let Some(c) = c.filter(|_| beta != F::zero()) {
+        blas_assert_eq!(c.dim(), (m, n), InvalidDim)?;
+        result.zip_mut_with(&c, |r, &c| *r = *r + beta * c);
+    }
+    Ok(result)
+}
+
+/// Value of element as `c64`, for diagnostics.
+fn to_c64<F>(x: F) -> c64
+where
+    F: BLASFloat,
+{
+    let f = |v: F::RealFloat| v.to_f64().unwrap_or(f64::NAN);
+    c64::new(f(F::real(x)), f(F::imag(x)))
+}
+
+/// Assert that `actual` and `expected` agree elementwise within `atol + rtol * |expected|`.
+///
+/// # Panics
+///
+/// On shape mismatch, or on the first element out of tolerance, with its index and values, and the
+/// maximum absolute difference over all elements.
+pub fn assert_allclose<F, D>(
+    actual: &ArrayView<F, D>,
+    expected: &ArrayView<F, D>,
+    rtol: F::RealFloat,
+    atol: F::RealFloat,
+) where
+    F: BLASFloat,
+    D: Dimension,
+{
+    let to_f64 = |v: F::RealFloat| v.to_f64().unwrap_or(f64::NAN);
+    assert_eq!(actual.shape(), expected.shape(), "verification failed: shape mismatch");
+    let diff = |(&x, &y): (&F, &F)| F::modulus(x - y);
+    let max_diff = actual.iter().zip(expected.iter()).map(diff).fold(F::RealFloat::zero(), Float::max);
+    let indexed = actual.indexed_iter().zip(expected.iter());
+    for ((idx, &x), &y) in indexed {
+        let d = F::modulus(x - y);
+        // NaN difference is also out of tolerance
+        if d.is_nan() || d > atol + rtol * F::modulus(y) {
+            panic!(
+                "verification failed at index {:?}: actual {}, expected {}, difference {:e} \
+                 (rtol {:e}, atol {:e}, maximum difference {:e})",
+                idx,
+                to_c64(x),
+                to_c64(y),
+                to_f64(d),
+                to_f64(rtol),
+                to_f64(atol),
+                to_f64(max_diff)
+            );
+        }
+    }
+}
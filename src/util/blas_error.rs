@@ -12,9 +12,12 @@ pub enum BLASError {
     OverflowDimension(String),
     InvalidDim(String),
     InvalidFlag(String),
+    InvalidStride(String),
     FailedCheck(String),
     UninitializedField(&'static str),
     ExplicitCopy(String),
+    NonFinite(String),
+    Aliasing(String),
     Miscellaneous(String),
     RuntimeError(String),
 }
@@ -24,6 +27,16 @@ pub enum BLASError {
 #[cfg(feature = "std")]
 impl std::error::Error for BLASError {}
 
+/// Conversion for `?` in functions returning [`std::io::Result`], such as `main` of command-line tools.
+///
+/// All errors map to [`std::io::ErrorKind::InvalidInput`], with `BLASError` kept as the inner error.
+#[cfg(feature = "std")]
+impl From<BLASError> for std::io::Error {
+    fn from(e: BLASError) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, e)
+    }
+}
+
 impl From<UninitializedFieldError> for BLASError {
     fn from(e: UninitializedFieldError) -> BLASError {
         BLASError::UninitializedField(e.field_name())
@@ -134,37 +147,62 @@ macro_rules! blas_invalid {
 
 /* #region macros (warning) */
 
-#[macro_export]
-macro_rules! blas_warn_layout_clone {
-    ($array:expr) => {{
-        #[cfg(feature = "std")]
-        extern crate std;
-
-        if cfg!(all(feature = "std", feature = "warn_on_copy")) {
-            std::eprintln!(
-                "Warning: Copying array due to non-standard layout, shape={:?}, strides={:?}",
-                $array.shape(),
-                $array.strides()
-            );
-            Result::<(), BLASError>::Ok(())
-        } else if cfg!(feature = "error_on_copy") {
+/// Functions called by exported macros.
+///
+/// Exported macros may expand outside this crate, so crate-private items are reached through these public
+/// functions. Features are also resolved in these functions (in this crate) instead of in macro expansion, so
+/// behavior does not depend on features of the crate that expands the macro.
+#[doc(hidden)]
+pub mod macro_support {
+    #[cfg(feature = "std")]
+    extern crate std;
+
+    use super::BLASError;
+
+    /// Handle an explicit copy of array by wrappers (see [`crate::blas_warn_layout_clone`]).
+    pub fn warn_layout_clone(msg: &str, shape: &[usize], strides: &[isize]) -> Result<(), BLASError> {
+        crate::util::instrument::record_copy(shape.iter().product());
+        #[cfg(all(feature = "std", feature = "warn_on_copy"))]
+        std::eprintln!("Warning: {msg}, shape={shape:?}, strides={strides:?}");
+        #[cfg(not(all(feature = "std", feature = "warn_on_copy")))]
+        let _ = (msg, strides);
+        if cfg!(feature = "error_on_copy") {
             blas_raise!(ExplicitCopy)
         } else {
-            Result::<(), BLASError>::Ok(())
+            Ok(())
         }
+    }
+
+    /// Print diagnostic message to stderr (see [`crate::blas_trace`]).
+    pub fn trace(args: core::fmt::Arguments) {
+        crate::util::instrument::record_trace();
+        #[cfg(feature = "trace")]
+        std::eprintln!("{args}");
+        #[cfg(not(feature = "trace"))]
+        let _ = args;
+    }
+}
+
+#[macro_export]
+macro_rules! blas_warn_layout_clone {
+    ($array:expr) => {{
+        $crate::util::blas_error::macro_support::warn_layout_clone(
+            "Copying array due to non-standard layout",
+            $array.shape(),
+            $array.strides(),
+        )
     }};
     ($array:expr, $msg:tt) => {{
-        #[cfg(feature = "std")]
-        extern crate std;
+        $crate::util::blas_error::macro_support::warn_layout_clone($msg, $array.shape(), $array.strides())
+    }};
+}
 
-        if cfg!(all(feature = "std", feature = "warn_on_copy")) {
-            std::eprintln!("Warning: {:?}, shape={:?}, strides={:?}", $msg, $array.shape(), $array.strides());
-            Result::<(), BLASError>::Ok(())
-        } else if cfg!(feature = "error_on_copy") {
-            blas_raise!(ExplicitCopy)
-        } else {
-            Result::<(), BLASError>::Ok(())
-        }
+/// Print diagnostic message to stderr, when feature `trace` is enabled. The diagnostic is counted in either
+/// case (see [`trace_count`](crate::util::trace_count)).
+#[macro_export]
+macro_rules! blas_trace {
+    ($($arg:tt)*) => {{
+        $crate::util::blas_error::macro_support::trace(format_args!($($arg)*))
     }};
 }
 
@@ -189,6 +227,23 @@ mod tests {
         assert_eq!(blas_error, BLASError::OverflowDimension("TryFromIntError".to_string()));
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_into_io_error() {
+        fn check_dim(m: usize, n: usize) -> std::io::Result<()> {
+            blas_assert_eq!(m, n, InvalidDim)?;
+            Ok(())
+        }
+        assert!(check_dim(3, 3).is_ok());
+        let err = check_dim(3, 4).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        let msg = err.to_string();
+        assert!(msg.contains("InvalidDim"));
+        assert!(msg.contains("not equal to"));
+        let inner = err.into_inner().unwrap().downcast::<BLASError>().unwrap();
+        assert!(matches!(*inner, BLASError::InvalidDim(_)));
+    }
+
     #[test]
     fn test_blas_assert_macro_with_args() {
         let result = blas_assert!(false, InvalidFlag, "test_condition");
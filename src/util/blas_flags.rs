@@ -24,7 +24,9 @@ pub enum BLASTranspose {
     ConjNoTrans = 114,
 }
 
-pub use BLASTranspose::{ConjTrans as BLASConjTrans, NoTrans as BLASNoTrans, Trans as BLASTrans};
+pub use BLASTranspose::{
+    ConjNoTrans as BLASConjNoTrans, ConjTrans as BLASConjTrans, NoTrans as BLASNoTrans, Trans as BLASTrans,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BLASUpLo {
@@ -56,7 +58,19 @@ pub enum BLASSide {
 
 pub use BLASSide::{Left as BLASLeft, Right as BLASRight};
 
+/// Element order of packed triangular storage, named by the triangle whose BLAS packing order is followed.
+///
+/// For col-major layout, BLAS packs the stored triangle column by column; so data of the lower triangle in
+/// [`PackedOrder::Upper`] order is packed row by row (the order of upper triangle of the transposed matrix).
+/// For row-major layout, rows and columns are swapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackedOrder {
+    Upper,
+    Lower,
+}
+
 use super::{blas_invalid, BLASError};
+use ndarray::Order;
 
 impl From<char> for BLASLayout {
     #[inline]
@@ -93,6 +107,29 @@ impl TryFrom<BLASLayout> for c_char {
     }
 }
 
+impl From<Order> for BLASLayout {
+    #[inline]
+    fn from(order: Order) -> Self {
+        match order {
+            Order::RowMajor => BLASRowMajor,
+            Order::ColumnMajor => BLASColMajor,
+            _ => Self::Undefined,
+        }
+    }
+}
+
+impl TryFrom<BLASLayout> for Order {
+    type Error = BLASError;
+    #[inline]
+    fn try_from(layout: BLASLayout) -> Result<Self, BLASError> {
+        match layout {
+            BLASRowMajor => Ok(Order::RowMajor),
+            BLASColMajor => Ok(Order::ColumnMajor),
+            _ => blas_invalid!(layout),
+        }
+    }
+}
+
 impl From<char> for BLASTranspose {
     #[inline]
     fn from(c: char) -> Self {
@@ -105,6 +142,20 @@ impl From<char> for BLASTranspose {
     }
 }
 
+impl TryFrom<&str> for BLASTranspose {
+    type Error = BLASError;
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if matches!(c.to_ascii_uppercase(), 'N' | 'T' | 'C') => {
+                Ok(BLASTranspose::from(c))
+            },
+            _ => blas_invalid!(s),
+        }
+    }
+}
+
 impl TryFrom<BLASTranspose> for char {
     type Error = BLASError;
     #[inline]
@@ -142,6 +193,18 @@ impl From<char> for BLASUpLo {
     }
 }
 
+impl TryFrom<&str> for BLASUpLo {
+    type Error = BLASError;
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if matches!(c.to_ascii_uppercase(), 'U' | 'L') => Ok(BLASUpLo::from(c)),
+            _ => blas_invalid!(s),
+        }
+    }
+}
+
 impl TryFrom<BLASUpLo> for char {
     type Error = BLASError;
     #[inline]
@@ -177,6 +240,18 @@ impl From<char> for BLASDiag {
     }
 }
 
+impl TryFrom<&str> for BLASDiag {
+    type Error = BLASError;
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if matches!(c.to_ascii_uppercase(), 'N' | 'U') => Ok(BLASDiag::from(c)),
+            _ => blas_invalid!(s),
+        }
+    }
+}
+
 impl TryFrom<BLASDiag> for char {
     type Error = BLASError;
     #[inline]
@@ -212,6 +287,18 @@ impl From<char> for BLASSide {
     }
 }
 
+impl TryFrom<&str> for BLASSide {
+    type Error = BLASError;
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if matches!(c.to_ascii_uppercase(), 'L' | 'R') => Ok(BLASSide::from(c)),
+            _ => blas_invalid!(s),
+        }
+    }
+}
+
 impl TryFrom<BLASSide> for char {
     type Error = BLASError;
     #[inline]
@@ -237,6 +324,11 @@ impl TryFrom<BLASSide> for c_char {
 }
 
 impl BLASLayout {
+    /// Swap row-major and col-major.
+    ///
+    /// Only [`BLASRowMajor`] and [`BLASColMajor`] can be flipped; other values (including
+    /// [`BLASLayout::Sequential`], which is unchanged under transpose anyway) give
+    /// [`BLASError::InvalidFlag`].
     #[inline]
     pub fn flip(&self) -> Result<Self, BLASError> {
         match self {
@@ -248,6 +340,9 @@ impl BLASLayout {
 }
 
 impl BLASUpLo {
+    /// Swap upper and lower triangle, which is the triangle of the same data when seen transposed.
+    ///
+    /// [`BLASUpLo::Undefined`] gives [`BLASError::InvalidFlag`].
     #[inline]
     pub fn flip(&self) -> Result<Self, BLASError> {
         match self {
@@ -258,6 +353,29 @@ impl BLASUpLo {
     }
 }
 
+impl BLASDiag {
+    /// Diagonal kind of the same data when seen transposed, which is unchanged.
+    ///
+    /// Provided so that all parameter flags can be flipped alike when transposing a problem;
+    /// [`BLASDiag::Undefined`] gives [`BLASError::InvalidFlag`].
+    #[inline]
+    pub fn flip(&self) -> Result<Self, BLASError> {
+        match self {
+            BLASNonUnit => Ok(BLASNonUnit),
+            BLASUnit => Ok(BLASUnit),
+            _ => blas_invalid!(self),
+        }
+    }
+}
+
+impl PackedOrder {
+    /// Whether this order is the BLAS packing order of triangle `uplo`.
+    #[inline]
+    pub fn matches(&self, uplo: BLASUpLo) -> bool {
+        matches!((self, uplo), (PackedOrder::Upper, BLASUpper) | (PackedOrder::Lower, BLASLower))
+    }
+}
+
 impl BLASSide {
     #[inline]
     pub fn flip(&self) -> Result<Self, BLASError> {
@@ -284,6 +402,23 @@ impl BLASTranspose {
     }
 }
 
+/// Display parameter flags as the BLAS character (such as `N` for [`BLASNoTrans`]), or as variant name for
+/// values that have no BLAS character (such as `Undefined`).
+macro_rules! impl_display_flag {
+    ($($ty: ty),*) => {$(
+        impl core::fmt::Display for $ty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match char::try_from(*self) {
+                    Ok(c) => write!(f, "{c}"),
+                    Err(_) => write!(f, "{self:?}"),
+                }
+            }
+        }
+    )*};
+}
+
+impl_display_flag!(BLASTranspose, BLASUpLo, BLASDiag, BLASSide);
+
 unsafe impl Send for BLASLayout {}
 unsafe impl Send for BLASTranspose {}
 unsafe impl Send for BLASUpLo {}
@@ -297,6 +432,10 @@ unsafe impl Sync for BLASDiag {}
 unsafe impl Sync for BLASSide {}
 
 impl BLASLayout {
+    /// Whether data in this layout can be passed to BLAS as row-major (C-contiguous).
+    ///
+    /// True for [`BLASRowMajor`] and [`BLASLayout::Sequential`] (valid as both row-major and col-major);
+    /// false for [`BLASColMajor`], [`BLASLayout::NonContiguous`] and [`BLASLayout::Undefined`].
     #[inline]
     pub fn is_cpref(&self) -> bool {
         match self {
@@ -306,6 +445,10 @@ impl BLASLayout {
         }
     }
 
+    /// Whether data in this layout can be passed to BLAS as col-major (F-contiguous).
+    ///
+    /// True for [`BLASColMajor`] and [`BLASLayout::Sequential`] (valid as both row-major and col-major);
+    /// false for [`BLASRowMajor`], [`BLASLayout::NonContiguous`] and [`BLASLayout::Undefined`].
     #[inline]
     pub fn is_fpref(&self) -> bool {
         match self {
@@ -316,26 +459,53 @@ impl BLASLayout {
     }
 }
 
-pub(crate) fn get_layout_row_preferred(by_first: &[Option<BLASLayout>], by_all: &[BLASLayout]) -> BLASLayout {
-    for x in by_first {
-        if let Some(x) = x {
-            if x.is_cpref() {
-                return BLASRowMajor;
-            } else if x.is_fpref() {
-                return BLASColMajor;
-            }
+/// Decide layout of computation, preferring `preferred` when operands do not decide it.
+///
+/// Layouts in `by_first` are checked in order, and the first one that is row-major or col-major decides the
+/// layout. Otherwise, layout is the other one of row-major and col-major only if all of `by_all` prefer it, and
+/// `preferred` in any other case. Sequential arrays can be interpreted either way, so they do not decide the
+/// layout by themselves.
+pub(crate) fn get_layout_by_preference(
+    by_first: &[Option<BLASLayout>],
+    by_all: &[BLASLayout],
+    preferred: BLASLayout,
+) -> BLASLayout {
+    for x in by_first.iter().flatten() {
+        match x {
+            BLASRowMajor => return BLASRowMajor,
+            BLASColMajor => return BLASColMajor,
+            _ => (),
         }
     }
 
-    if by_all.iter().all(|f| f.is_cpref()) {
-        return BLASRowMajor;
-    } else if by_all.iter().all(|f| f.is_fpref()) {
-        return BLASColMajor;
-    } else {
-        return BLASRowMajor;
+    match preferred {
+        BLASColMajor if by_all.iter().all(|f| f.is_fpref()) => BLASColMajor,
+        BLASColMajor if by_all.iter().all(|f| f.is_cpref()) => BLASRowMajor,
+        BLASColMajor => BLASColMajor,
+        _ if by_all.iter().all(|f| f.is_cpref()) => BLASRowMajor,
+        _ if by_all.iter().all(|f| f.is_fpref()) => BLASColMajor,
+        _ => BLASRowMajor,
     }
 }
 
+/// Decide layout of computation, preferring row-major.
+///
+/// Used where layout decides how storage is interpreted (such as banded matrices), so the crate default layout
+/// must not apply. See [`get_layout_by_preference`].
+pub(crate) fn get_layout_row_preferred(by_first: &[Option<BLASLayout>], by_all: &[BLASLayout]) -> BLASLayout {
+    get_layout_by_preference(by_first, by_all, BLASRowMajor)
+}
+
+/// Decide layout of computation, preferring [`crate::config::default_layout`].
+///
+/// See [`get_layout_by_preference`].
+pub(crate) fn get_layout_default_preferred(
+    by_first: &[Option<BLASLayout>],
+    by_all: &[BLASLayout],
+) -> BLASLayout {
+    get_layout_by_preference(by_first, by_all, crate::config::default_layout())
+}
+
 // Following test is generated by DeepSeek
 #[cfg(test)]
 mod tests {
@@ -364,6 +534,16 @@ mod tests {
         assert!(c_char::try_from(BLASLayout::Undefined).is_err());
     }
 
+    #[test]
+    fn test_blaslayout_ndarray_order() {
+        assert_eq!(BLASLayout::from(ndarray::Order::C), BLASRowMajor);
+        assert_eq!(BLASLayout::from(ndarray::Order::F), BLASColMajor);
+        assert_eq!(ndarray::Order::try_from(BLASRowMajor), Ok(ndarray::Order::RowMajor));
+        assert_eq!(ndarray::Order::try_from(BLASColMajor), Ok(ndarray::Order::ColumnMajor));
+        assert!(ndarray::Order::try_from(BLASLayout::Sequential).is_err());
+        assert!(ndarray::Order::try_from(BLASLayout::NonContiguous).is_err());
+    }
+
     #[test]
     fn test_blastranspose_from_char() {
         assert_eq!(BLASTranspose::from('N'), BLASNoTrans);
@@ -375,6 +555,17 @@ mod tests {
         assert_eq!(BLASTranspose::from('X'), BLASTranspose::Undefined);
     }
 
+    #[test]
+    fn test_blastranspose_try_from_str() {
+        assert_eq!(BLASTranspose::try_from("N"), Ok(BLASNoTrans));
+        assert_eq!(BLASTranspose::try_from("t"), Ok(BLASTrans));
+        assert_eq!(BLASTranspose::try_from("C"), Ok(BLASConjTrans));
+        assert!(BLASTranspose::try_from("").is_err());
+        assert!(BLASTranspose::try_from("X").is_err());
+        assert!(BLASTranspose::try_from("NT").is_err());
+        assert!(BLASTranspose::try_from("Trans").is_err());
+    }
+
     #[test]
     fn test_blastranspose_try_from_blastranspose_for_char() {
         assert_eq!(char::try_from(BLASNoTrans), Ok('N'));
@@ -492,6 +683,65 @@ mod tests {
         assert!(BLASTranspose::Undefined.flip(false).is_err());
     }
 
+    #[test]
+    fn test_blasdiag_flip() {
+        assert_eq!(BLASNonUnit.flip(), Ok(BLASNonUnit));
+        assert_eq!(BLASUnit.flip(), Ok(BLASUnit));
+        assert!(BLASDiag::Undefined.flip().is_err());
+    }
+
+    #[test]
+    fn test_blasdiag_try_from_str() {
+        assert_eq!(BLASDiag::try_from("N"), Ok(BLASNonUnit));
+        assert_eq!(BLASDiag::try_from("u"), Ok(BLASUnit));
+        assert!(BLASDiag::try_from("").is_err());
+        assert!(BLASDiag::try_from("X").is_err());
+        assert!(BLASDiag::try_from("Unit").is_err());
+        assert_eq!(BLASUpLo::try_from("l"), Ok(BLASLower));
+        assert!(BLASUpLo::try_from("N").is_err());
+        assert_eq!(BLASSide::try_from("R"), Ok(BLASRight));
+        assert!(BLASSide::try_from("U").is_err());
+    }
+
+    #[test]
+    fn test_flags_char_round_trip() {
+        extern crate std;
+        use std::string::ToString;
+
+        // every ASCII character: valid ones round-trip (upper-cased) through char, c_char, &str and Display;
+        // invalid ones are Undefined, rejected by conversions, and displayed as variant name
+        fn check<T>(valid: &str)
+        where
+            T: From<char> + Copy + PartialEq + core::fmt::Debug + core::fmt::Display + Default,
+            T: for<'s> TryFrom<&'s str, Error = BLASError>,
+            char: TryFrom<T, Error = BLASError>,
+            c_char: TryFrom<T, Error = BLASError>,
+        {
+            for c in (0..128u8).map(char::from) {
+                let flag = T::from(c);
+                let s = c.to_string();
+                if valid.contains(c.to_ascii_uppercase()) {
+                    assert_eq!(char::try_from(flag), Ok(c.to_ascii_uppercase()));
+                    assert_eq!(c_char::try_from(flag), Ok(c.to_ascii_uppercase() as c_char));
+                    assert_eq!(T::try_from(s.as_str()), Ok(flag));
+                    assert_eq!(T::from(char::try_from(flag).unwrap()), flag);
+                    assert_eq!(flag.to_string(), c.to_ascii_uppercase().to_string());
+                } else {
+                    assert_eq!(flag, T::default());
+                    assert!(matches!(char::try_from(flag), Err(BLASError::InvalidFlag(_))));
+                    assert!(matches!(c_char::try_from(flag), Err(BLASError::InvalidFlag(_))));
+                    assert!(matches!(T::try_from(s.as_str()), Err(BLASError::InvalidFlag(_))));
+                    assert_eq!(flag.to_string(), "Undefined");
+                }
+            }
+        }
+        check::<BLASTranspose>("NTC");
+        check::<BLASUpLo>("UL");
+        check::<BLASDiag>("NU");
+        check::<BLASSide>("LR");
+        assert_eq!(BLASTranspose::ConjNoTrans.to_string(), "ConjNoTrans");
+    }
+
     #[test]
     fn test_blaslayout_is_cpref() {
         assert!(BLASRowMajor.is_cpref());
@@ -508,6 +758,44 @@ mod tests {
         assert!(!BLASLayout::Undefined.is_fpref());
     }
 
+    #[test]
+    fn test_blaslayout_truth_table() {
+        // (layout, is_cpref, is_fpref, flip)
+        let table = [
+            (BLASRowMajor, true, false, Some(BLASColMajor)),
+            (BLASColMajor, false, true, Some(BLASRowMajor)),
+            (BLASLayout::Sequential, true, true, None),
+            (BLASLayout::NonContiguous, false, false, None),
+            (BLASLayout::Undefined, false, false, None),
+        ];
+        for (layout, cpref, fpref, flip) in table {
+            assert_eq!(layout.is_cpref(), cpref, "is_cpref of {layout:?}");
+            assert_eq!(layout.is_fpref(), fpref, "is_fpref of {layout:?}");
+            match flip {
+                Some(flip) => {
+                    assert_eq!(layout.flip(), Ok(flip));
+                    assert_eq!(flip.flip(), Ok(layout));
+                },
+                None => assert!(matches!(layout.flip(), Err(BLASError::InvalidFlag(_)))),
+            }
+        }
+    }
+
+    #[test]
+    fn test_blasuplo_truth_table() {
+        for (uplo, flip) in
+            [(BLASUpper, Some(BLASLower)), (BLASLower, Some(BLASUpper)), (BLASUpLo::Undefined, None)]
+        {
+            match flip {
+                Some(flip) => {
+                    assert_eq!(uplo.flip(), Ok(flip));
+                    assert_eq!(flip.flip(), Ok(uplo));
+                },
+                None => assert!(matches!(uplo.flip(), Err(BLASError::InvalidFlag(_)))),
+            }
+        }
+    }
+
     #[test]
     fn test_get_layout_row_preferred() {
         let by_first = [Some(BLASRowMajor), Some(BLASColMajor), None];
@@ -529,5 +817,38 @@ mod tests {
         let by_all = [BLASRowMajor, BLASColMajor, BLASLayout::Sequential];
 
         assert_eq!(get_layout_row_preferred(&by_first, &by_all), BLASRowMajor);
+
+        // sequential does not decide layout
+        let by_first = [None, Some(BLASLayout::Sequential)];
+        let by_all = [BLASColMajor, BLASLayout::Sequential];
+
+        assert_eq!(get_layout_row_preferred(&by_first, &by_all), BLASColMajor);
+
+        let by_first = [Some(BLASLayout::Sequential), Some(BLASRowMajor)];
+        let by_all = [BLASColMajor, BLASColMajor];
+
+        assert_eq!(get_layout_row_preferred(&by_first, &by_all), BLASRowMajor);
+    }
+
+    #[test]
+    fn test_get_layout_col_preferred() {
+        // decided by first
+        let by_first = [None, Some(BLASRowMajor)];
+        let by_all = [BLASColMajor, BLASColMajor];
+        assert_eq!(get_layout_by_preference(&by_first, &by_all, BLASColMajor), BLASRowMajor);
+
+        // sequential and mixed fall back to preference
+        let by_first = [None, Some(BLASLayout::Sequential)];
+        let by_all = [BLASLayout::Sequential, BLASLayout::Sequential];
+        assert_eq!(get_layout_by_preference(&by_first, &by_all, BLASColMajor), BLASColMajor);
+        assert_eq!(get_layout_by_preference(&by_first, &by_all, BLASRowMajor), BLASRowMajor);
+
+        let by_all = [BLASRowMajor, BLASColMajor];
+        assert_eq!(get_layout_by_preference(&[], &by_all, BLASColMajor), BLASColMajor);
+        assert_eq!(get_layout_by_preference(&[], &by_all, BLASRowMajor), BLASRowMajor);
+
+        // all row-major inputs still give row-major
+        let by_all = [BLASRowMajor, BLASLayout::Sequential];
+        assert_eq!(get_layout_by_preference(&[], &by_all, BLASColMajor), BLASRowMajor);
     }
 }
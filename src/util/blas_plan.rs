@@ -0,0 +1,84 @@
+extern crate alloc;
+
+use crate::util::*;
+use alloc::vec::Vec;
+
+/// Description of what a BLAS wrapper would perform, without actual computation.
+///
+/// Obtained by `plan()` method of builders (such as [`GEMM`](crate::blas3::gemm::GEMM)). This could be
+/// useful for profiling or cost model estimation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlasPlan {
+    /// Name of the (legacy BLAS) routine to be called, such as `"dgemm"`.
+    pub routine: &'static str,
+    pub m: usize,
+    pub n: usize,
+    pub k: usize,
+    /// Floating point operations by the standard formula (real multiply and add are each counted as one).
+    pub flops: u64,
+    /// Layout the computation is performed in (row-major or col-major).
+    pub layout: BLASLayout,
+    /// Names of matrices that will be explicitly copied before (or after, for output) computation.
+    pub copies: Vec<&'static str>,
+}
+
+/// Select routine name by float type, from names in order of `f32`, `f64`, `c32`, `c64`.
+pub(crate) fn blas_routine_name<F>(names: [&'static str; 4]) -> &'static str
+where
+    F: BLASFloat,
+{
+    let double = core::mem::size_of::<F::RealFloat>() == 8;
+    match (F::is_complex(), double) {
+        (false, false) => names[0],
+        (false, true) => names[1],
+        (true, false) => names[2],
+        (true, true) => names[3],
+    }
+}
+
+/// Scale real flop count for complex float type (complex multiply-add is 4 times of real one).
+pub(crate) fn blas_flops<F>(flops_real: u64) -> u64
+where
+    F: BLASFloat,
+{
+    match F::is_complex() {
+        false => flops_real,
+        true => 4 * flops_real,
+    }
+}
+
+/// Explicit copies made by a finished BLAS wrapper call, together with the layout of computation.
+///
+/// Obtained by `run_with_report()` method of builders (such as [`GEMM`](crate::blas3::gemm::GEMM)). This is the
+/// programmatic counterpart to warnings of feature `warn_on_copy`, for callers deciding whether to restructure
+/// their data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyReport {
+    /// Layout the computation was performed in (row-major or col-major).
+    pub layout: BLASLayout,
+    /// Names of matrices that were explicitly copied (same as [`BlasPlan::copies`]).
+    pub copied: Vec<&'static str>,
+    /// Number of explicit copies counted during the call (by [`copy_stats`]; always zero without feature `std`).
+    pub copies: usize,
+    /// Number of elements copied during the call (by [`copy_stats`]; always zero without feature `std`).
+    pub elements: usize,
+}
+
+impl CopyReport {
+    /// Run `f`, and report copies counted during the call, on matrices named by `plan`.
+    pub(crate) fn track<T>(
+        plan: BlasPlan,
+        f: impl FnOnce() -> Result<T, BLASError>,
+    ) -> Result<(T, Self), BLASError> {
+        let before = copy_stats();
+        let out = f()?;
+        let after = copy_stats();
+        let report = CopyReport {
+            layout: plan.layout,
+            copied: plan.copies,
+            copies: after.copies - before.copies,
+            elements: after.elements - before.elements,
+        };
+        Ok((out, report))
+    }
+}
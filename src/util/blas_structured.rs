@@ -0,0 +1,157 @@
+use crate::util::*;
+use ndarray::prelude::*;
+
+/* #region SymmetricMatrix */
+
+/// Square matrix view together with the triangle (`uplo`) that holds its data.
+///
+/// Constructed by [`SymmetricMatrix::new`] (or `TryFrom<&ArrayView2>`, which takes [`BLASLower`] as SYMM and HEMM
+/// do by default), which checks squareness and `uplo` once; then it could be given to `.symmetric()` of SYMM, HEMM
+/// and HEMV builders, setting both `a` and `uplo`. Note that HEMV defaults to [`BLASUpper`] instead, so for HEMV
+/// the triangle taken by `TryFrom` differs from that of setting `a` alone; use [`SymmetricMatrix::new`] to choose
+/// it explicitly. Symmetry itself (or hermiticity) of the data is not checked, since the other triangle is not
+/// referenced.
+#[derive(Debug, Clone)]
+pub struct SymmetricMatrix<'a, F> {
+    a: ArrayView2<'a, F>,
+    uplo: BLASUpLo,
+}
+
+impl<'a, F> SymmetricMatrix<'a, F> {
+    /// Wrap square `a` with data in triangle `uplo`.
+    ///
+    /// Non-square `a` gives [`BLASError::InvalidDim`], and `uplo` other than [`BLASUpper`] or [`BLASLower`] gives
+    /// [`BLASError::InvalidFlag`].
+    pub fn new(a: ArrayView2<'a, F>, uplo: BLASUpLo) -> Result<Self, BLASError> {
+        blas_assert_eq!(a.len_of(Axis(0)), a.len_of(Axis(1)), InvalidDim)?;
+        match uplo {
+            BLASUpper | BLASLower => Ok(Self { a, uplo }),
+            _ => blas_invalid!(uplo),
+        }
+    }
+
+    pub fn view(&self) -> ArrayView2<'a, F> {
+        self.a
+    }
+
+    pub fn uplo(&self) -> BLASUpLo {
+        self.uplo
+    }
+
+    /// Dimension `n` of the `n x n` matrix.
+    pub fn dim(&self) -> usize {
+        self.a.len_of(Axis(0))
+    }
+}
+
+impl<'a, F> TryFrom<&ArrayView2<'a, F>> for SymmetricMatrix<'a, F> {
+    type Error = BLASError;
+
+    fn try_from(a: &ArrayView2<'a, F>) -> Result<Self, BLASError> {
+        Self::new(*a, BLASLower)
+    }
+}
+
+/* #endregion */
+
+/* #region TriangularMatrix */
+
+/// Square matrix view together with its triangle (`uplo`) and whether its diagonal is unit (`diag`).
+///
+/// Constructed by [`TriangularMatrix::new`] (or `TryFrom<&ArrayView2>`, which takes [`BLASUpper`] and
+/// [`BLASNonUnit`] as TRSV and TRSM do by default), which checks squareness and flags once; then it could be given
+/// to `.triangular()` of TRMV, TRSV, TRMM and TRSM builders, setting `a`, `uplo` and `diag`.
+#[derive(Debug, Clone)]
+pub struct TriangularMatrix<'a, F> {
+    a: ArrayView2<'a, F>,
+    uplo: BLASUpLo,
+    diag: BLASDiag,
+}
+
+impl<'a, F> TriangularMatrix<'a, F> {
+    /// Wrap square `a` with data in triangle `uplo`, and unit or non-unit diagonal by `diag`.
+    ///
+    /// Non-square `a` gives [`BLASError::InvalidDim`], and `uplo` other than [`BLASUpper`] or [`BLASLower`] (or
+    /// `diag` other than [`BLASUnit`] or [`BLASNonUnit`]) gives [`BLASError::InvalidFlag`].
+    pub fn new(a: ArrayView2<'a, F>, uplo: BLASUpLo, diag: BLASDiag) -> Result<Self, BLASError> {
+        blas_assert_eq!(a.len_of(Axis(0)), a.len_of(Axis(1)), InvalidDim)?;
+        match uplo {
+            BLASUpper | BLASLower => (),
+            _ => blas_invalid!(uplo)?,
+        }
+        match diag {
+            BLASUnit | BLASNonUnit => Ok(Self { a, uplo, diag }),
+            _ => blas_invalid!(diag),
+        }
+    }
+
+    pub fn view(&self) -> ArrayView2<'a, F> {
+        self.a
+    }
+
+    pub fn uplo(&self) -> BLASUpLo {
+        self.uplo
+    }
+
+    pub fn diag(&self) -> BLASDiag {
+        self.diag
+    }
+
+    /// Dimension `n` of the `n x n` matrix.
+    pub fn dim(&self) -> usize {
+        self.a.len_of(Axis(0))
+    }
+}
+
+impl<'a, F> TryFrom<&ArrayView2<'a, F>> for TriangularMatrix<'a, F> {
+    type Error = BLASError;
+
+    fn try_from(a: &ArrayView2<'a, F>) -> Result<Self, BLASError> {
+        Self::new(*a, BLASUpper, BLASNonUnit)
+    }
+}
+
+/* #endregion */
+
+/* #region builder macros */
+
+/// Implement `symmetric` for BLAS builders with symmetric (or hermitian) input matrix `a`.
+macro_rules! impl_builder_symmetric {
+    ($builder: ident<$lt_a: lifetime $(, $lt: lifetime)*>, $bound: path) => {
+        impl<$lt_a $(, $lt)*, F> $builder<$lt_a $(, $lt)*, F>
+        where
+            F: $bound,
+        {
+            /// Set `a` and `uplo` from a validated [`SymmetricMatrix`].
+            pub fn symmetric(mut self, a: SymmetricMatrix<$lt_a, F>) -> Self {
+                self.uplo = Some(a.uplo());
+                self.a = Some(a.view());
+                self
+            }
+        }
+    };
+}
+
+pub(crate) use impl_builder_symmetric;
+
+/// Implement `triangular` for BLAS builders with triangular input matrix `a`.
+macro_rules! impl_builder_triangular {
+    ($builder: ident<$lt_a: lifetime $(, $lt: lifetime)*>, $bound: path) => {
+        impl<$lt_a $(, $lt)*, F> $builder<$lt_a $(, $lt)*, F>
+        where
+            F: $bound,
+        {
+            /// Set `a`, `uplo` and `diag` from a validated [`TriangularMatrix`].
+            pub fn triangular(mut self, a: TriangularMatrix<$lt_a, F>) -> Self {
+                self.uplo = Some(a.uplo());
+                self.diag = Some(a.diag());
+                self.a = Some(a.view());
+                self
+            }
+        }
+    };
+}
+
+pub(crate) use impl_builder_triangular;
+
+/* #endregion */
@@ -1,3 +1,6 @@
+#[cfg(feature = "std")]
+extern crate std;
+
 use crate::util::*;
 use ndarray::Dimension;
 use num_complex::*;
@@ -9,15 +12,29 @@ pub type c32 = Complex<f32>;
 pub type c64 = Complex<f64>;
 
 /// Trait for defining real part float types
-pub trait BLASFloat: Num + Copy {
-    type RealFloat: BLASFloat;
+///
+/// All BLAS float types are `Send + Sync`, so wrappers can be called from multiple threads (with feature
+/// `rayon`) on disjoint data.
+pub trait BLASFloat: Num + Copy + Send + Sync {
+    type RealFloat: BLASFloat + Float;
+    /// Complex float type of the same precision (`c32` for `f32` and `c32`, `c64` for `f64` and `c64`).
+    type ComplexFloat: BLASFloat<RealFloat = Self::RealFloat>;
     fn is_complex() -> bool;
     fn conj(x: Self) -> Self;
     fn from_real(x: Self::RealFloat) -> Self;
+    fn real(x: Self) -> Self::RealFloat;
+    fn imag(x: Self) -> Self::RealFloat;
+    fn to_complex(x: Self) -> Self::ComplexFloat;
+    fn is_finite(x: Self) -> bool;
+    /// Absolute value (modulus for complex numbers).
+    fn modulus(x: Self) -> Self::RealFloat;
+    /// Machine epsilon of the real part float type.
+    fn eps() -> Self::RealFloat;
 }
 
 impl BLASFloat for f32 {
     type RealFloat = f32;
+    type ComplexFloat = c32;
     #[inline]
     fn is_complex() -> bool {
         false
@@ -30,10 +47,35 @@ impl BLASFloat for f32 {
     fn from_real(x: Self::RealFloat) -> Self {
         x
     }
+    #[inline]
+    fn real(x: Self) -> Self::RealFloat {
+        x
+    }
+    #[inline]
+    fn imag(_x: Self) -> Self::RealFloat {
+        0.0
+    }
+    #[inline]
+    fn to_complex(x: Self) -> Self::ComplexFloat {
+        c32::new(x, 0.0)
+    }
+    #[inline]
+    fn is_finite(x: Self) -> bool {
+        x.is_finite()
+    }
+    #[inline]
+    fn modulus(x: Self) -> Self::RealFloat {
+        x.abs()
+    }
+    #[inline]
+    fn eps() -> Self::RealFloat {
+        f32::EPSILON
+    }
 }
 
 impl BLASFloat for f64 {
     type RealFloat = f64;
+    type ComplexFloat = c64;
     #[inline]
     fn is_complex() -> bool {
         false
@@ -46,10 +88,35 @@ impl BLASFloat for f64 {
     fn from_real(x: Self::RealFloat) -> Self {
         x
     }
+    #[inline]
+    fn real(x: Self) -> Self::RealFloat {
+        x
+    }
+    #[inline]
+    fn imag(_x: Self) -> Self::RealFloat {
+        0.0
+    }
+    #[inline]
+    fn to_complex(x: Self) -> Self::ComplexFloat {
+        c64::new(x, 0.0)
+    }
+    #[inline]
+    fn is_finite(x: Self) -> bool {
+        x.is_finite()
+    }
+    #[inline]
+    fn modulus(x: Self) -> Self::RealFloat {
+        x.abs()
+    }
+    #[inline]
+    fn eps() -> Self::RealFloat {
+        f64::EPSILON
+    }
 }
 
 impl BLASFloat for c32 {
     type RealFloat = f32;
+    type ComplexFloat = c32;
     #[inline]
     fn is_complex() -> bool {
         true
@@ -62,10 +129,35 @@ impl BLASFloat for c32 {
     fn from_real(x: Self::RealFloat) -> Self {
         c32::new(x, 0.0)
     }
+    #[inline]
+    fn real(x: Self) -> Self::RealFloat {
+        x.re
+    }
+    #[inline]
+    fn imag(x: Self) -> Self::RealFloat {
+        x.im
+    }
+    #[inline]
+    fn to_complex(x: Self) -> Self::ComplexFloat {
+        x
+    }
+    #[inline]
+    fn is_finite(x: Self) -> bool {
+        x.is_finite()
+    }
+    #[inline]
+    fn modulus(x: Self) -> Self::RealFloat {
+        x.norm()
+    }
+    #[inline]
+    fn eps() -> Self::RealFloat {
+        f32::EPSILON
+    }
 }
 
 impl BLASFloat for c64 {
     type RealFloat = f64;
+    type ComplexFloat = c64;
     #[inline]
     fn is_complex() -> bool {
         true
@@ -78,6 +170,56 @@ impl BLASFloat for c64 {
     fn from_real(x: Self::RealFloat) -> Self {
         c64::new(x, 0.0)
     }
+    #[inline]
+    fn real(x: Self) -> Self::RealFloat {
+        x.re
+    }
+    #[inline]
+    fn imag(x: Self) -> Self::RealFloat {
+        x.im
+    }
+    #[inline]
+    fn to_complex(x: Self) -> Self::ComplexFloat {
+        x
+    }
+    #[inline]
+    fn is_finite(x: Self) -> bool {
+        x.is_finite()
+    }
+    #[inline]
+    fn modulus(x: Self) -> Self::RealFloat {
+        x.norm()
+    }
+    #[inline]
+    fn eps() -> Self::RealFloat {
+        f64::EPSILON
+    }
+}
+
+/// Scalar that can be passed as `alpha` or `beta` to BLAS builders.
+///
+/// Any type convertible into `F` is accepted (such as `f64` for `c64`); complex scalars additionally accept
+/// `(re, im)` tuples, such as `.alpha((1.0, 2.0))`. `M` only keeps these implementations apart, and is always
+/// inferred.
+pub trait IntoBLASScalar<F, M> {
+    fn into_blas_scalar(self) -> F;
+}
+
+impl<F, T> IntoBLASScalar<F, ()> for T
+where
+    T: Into<F>,
+{
+    #[inline]
+    fn into_blas_scalar(self) -> F {
+        self.into()
+    }
+}
+
+impl<R> IntoBLASScalar<Complex<R>, (R, R)> for (R, R) {
+    #[inline]
+    fn into_blas_scalar(self) -> Complex<R> {
+        Complex::new(self.0, self.1)
+    }
 }
 
 /// Trait for BLAS drivers
@@ -100,9 +242,134 @@ pub trait BLASBuilder<'c, F, D>
 where
     D: Dimension,
 {
+    #[must_use = "the computed result is dropped if unused"]
     fn run(self) -> Result<ArrayOut<'c, F, D>, BLASError>;
+
+    /// Same as [`run`](BLASBuilder::run), additionally returning the wall-clock time elapsed.
+    ///
+    /// Elapsed time covers the whole wrapper call, including checks and explicit copies.
+    #[cfg(feature = "std")]
+    #[must_use = "the computed result is dropped if unused"]
+    fn run_and_time(self) -> Result<(ArrayOut<'c, F, D>, std::time::Duration), BLASError>
+    where
+        Self: Sized,
+    {
+        let start = std::time::Instant::now();
+        let result = self.run()?;
+        Ok((result, start.elapsed()))
+    }
 }
 
+/// Implement `Clone` for BLAS builders that contain mutable views.
+///
+/// Mutable views are exclusive borrows, so they are left unset in the cloned builder; all other fields
+/// (input views, scalars and flags) are cloned.
+macro_rules! impl_builder_clone {
+    ($builder: ident<$($lt: lifetime),+>, $bound: path, [$($field: ident),*], [$($out: ident),*]) => {
+        impl<$($lt),+, F> Clone for $builder<$($lt),+, F>
+        where
+            F: $bound,
+        {
+            fn clone(&self) -> Self {
+                Self { $($field: self.$field.clone(),)* $($out: None,)* }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_builder_clone;
+
+/// Implement setters of scalar fields (`alpha`, `beta`) for BLAS builders, accepting [`IntoBLASScalar`].
+///
+/// Scalar fields should be declared with `#[builder(setter(custom))]`.
+macro_rules! impl_builder_scalar {
+    ($builder: ident<$($lt: lifetime),+>, $bound: path, [$($field: ident),*]) => {
+        impl<$($lt),+, F> $builder<$($lt),+, F>
+        where
+            F: $bound,
+        {
+            $(
+                pub fn $field<M>(mut self, value: impl IntoBLASScalar<F, M>) -> Self {
+                    self.$field = Some(value.into_blas_scalar());
+                    self
+                }
+            )*
+        }
+    };
+}
+
+pub(crate) use impl_builder_scalar;
+
+/// Implement setters of transpose flags from single-character strings (such as `trans_str`) for BLAS
+/// builders.
+///
+/// Invalid string sets the flag as undefined, so error [`BLASError::InvalidFlag`] is raised when running.
+macro_rules! impl_builder_trans_str {
+    ($builder: ident<$($lt: lifetime),+>, $bound: path, [$($field: ident => $setter: ident),*]) => {
+        impl<$($lt),+, F> $builder<$($lt),+, F>
+        where
+            F: $bound,
+        {
+            $(
+                #[doc = concat!("Set `", stringify!($field), "` from string `\"N\"`, `\"T\"` or `\"C\"` (case-insensitive).")]
+                pub fn $setter(mut self, $field: &str) -> Self {
+                    self.$field = Some(BLASTranspose::try_from($field).unwrap_or(BLASTranspose::Undefined));
+                    self
+                }
+            )*
+        }
+    };
+}
+
+pub(crate) use impl_builder_trans_str;
+
+/// Implement builder-level validation for BLAS builders with `uplo`, rejecting values other than `Upper` and
+/// `Lower` when building.
+macro_rules! impl_builder_validate_uplo {
+    ($builder: ident<$($lt: lifetime),+>, $bound: path) => {
+        impl<$($lt),+, F> $builder<$($lt),+, F>
+        where
+            F: $bound,
+        {
+            fn validate(&self) -> Result<(), BLASError> {
+                match self.uplo {
+                    None | Some(BLASUpper) | Some(BLASLower) => Ok(()),
+                    Some(uplo) => blas_invalid!(uplo),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_builder_validate_uplo;
+
+/// Implement `with_lda` for BLAS builders with input matrix `a`, overriding its leading dimension.
+macro_rules! impl_builder_with_lda {
+    ($builder: ident<$($lt: lifetime),+>, $bound: path) => {
+        impl<$($lt),+, F> $builder<$($lt),+, F>
+        where
+            F: $bound,
+        {
+            /// Override leading dimension of `a` (which should be given before) by `lda`, as in raw BLAS.
+            ///
+            /// This is a low-level escape hatch for operating on a sub-block of a larger buffer in place; see
+            /// [`with_leading_dim`]. Missing `a` gives [`BLASError::UninitializedField`].
+            ///
+            /// # Safety
+            ///
+            /// The caller guarantees that the backing storage of `a` extends to `lda` elements per row
+            /// (row-major) or column (col-major).
+            pub unsafe fn with_lda(mut self, lda: usize) -> Result<Self, BLASError> {
+                let a = self.a.ok_or(BLASError::UninitializedField("a"))?;
+                self.a = Some(with_leading_dim(a, lda)?);
+                Ok(self)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_builder_with_lda;
+
 // Following test is assisted by DeepSeek
 #[cfg(test)]
 mod tests {
@@ -138,5 +405,55 @@ mod tests {
         assert_eq!(<c64 as BLASFloat>::is_complex(), true);
         assert_eq!(<c64 as BLASFloat>::conj(x), x.conj());
         assert_eq!(<c64 as BLASFloat>::from_real(3.0_f64), Complex::new(3.0_f64, 0.0_f64));
+        assert_eq!(<c64 as BLASFloat>::real(x), 3.0_f64);
+    }
+
+    #[test]
+    fn test_is_finite() {
+        assert!(<f32 as BLASFloat>::is_finite(1.0));
+        assert!(!<f32 as BLASFloat>::is_finite(f32::NAN));
+        assert!(!<f64 as BLASFloat>::is_finite(f64::INFINITY));
+        assert!(<c32 as BLASFloat>::is_finite(c32::new(1.0, 2.0)));
+        assert!(!<c32 as BLASFloat>::is_finite(c32::new(1.0, f32::NAN)));
+        assert!(!<c64 as BLASFloat>::is_finite(c64::new(f64::NEG_INFINITY, 0.0)));
+    }
+
+    fn check_roundtrip<F>(x: F)
+    where
+        F: BLASFloat + core::fmt::Debug,
+        F::RealFloat: core::fmt::Debug,
+        F::ComplexFloat: core::fmt::Debug,
+    {
+        let z = F::to_complex(x);
+        assert_eq!(<F::ComplexFloat as BLASFloat>::real(z), F::real(x));
+        assert_eq!(<F::ComplexFloat as BLASFloat>::imag(z), F::imag(x));
+        assert!(<F::ComplexFloat as BLASFloat>::is_complex());
+        assert!(!<F::RealFloat as BLASFloat>::is_complex());
+        if !F::is_complex() {
+            assert_eq!(F::from_real(F::real(x)), x);
+            assert_eq!(F::imag(x), F::RealFloat::zero());
+        }
+        // conjugate only flips imaginary part
+        assert_eq!(F::real(F::conj(x)), F::real(x));
+        assert_eq!(F::imag(F::conj(x)), F::RealFloat::zero() - F::imag(x));
+    }
+
+    #[test]
+    fn test_associated_types() {
+        check_roundtrip(1.5_f32);
+        check_roundtrip(-2.5_f64);
+        check_roundtrip(c32::new(1.5, -0.5));
+        check_roundtrip(c64::new(-2.5, 3.0));
+        assert_eq!(<f64 as BLASFloat>::to_complex(2.0), c64::new(2.0, 0.0));
+        assert_eq!(<c32 as BLASFloat>::imag(c32::new(1.0, 2.0)), 2.0);
+    }
+
+    #[test]
+    fn test_real_conj_identity() {
+        // flip helpers rely on conj being exactly identity for real types
+        for x in [0.0, -0.0, 1.5, -2.25, f32::MIN_POSITIVE, f32::MAX, f32::INFINITY, f32::NAN] {
+            assert_eq!(<f32 as BLASFloat>::conj(x).to_bits(), x.to_bits());
+            assert_eq!(<f64 as BLASFloat>::conj(x as f64).to_bits(), (x as f64).to_bits());
+        }
     }
 }
@@ -0,0 +1,72 @@
+//! Counters of explicit copies and diagnostics of BLAS wrappers.
+//!
+//! Every explicit copy (layout change or element-wise conjugate of an operand) made by wrappers is recorded
+//! on a thread-local counter, so copy behavior can be observed programmatically, such as in benchmarks.
+//! Diagnostics reported by [`crate::blas_trace`] are counted likewise, whether or not feature `trace` prints
+//! them. Counters are only available with feature `std`; otherwise they always stay zero.
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+use core::cell::Cell;
+
+/// Statistics of explicit copies made by BLAS wrappers in the current thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CopyStats {
+    /// Number of operands that have been copied.
+    pub copies: usize,
+    /// Total number of elements that have been copied.
+    pub elements: usize,
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static COPY_STATS: Cell<CopyStats> = const { Cell::new(CopyStats { copies: 0, elements: 0 }) };
+    static TRACE_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Copy statistics of the current thread since last [`reset_copy_stats`].
+pub fn copy_stats() -> CopyStats {
+    #[cfg(feature = "std")]
+    return COPY_STATS.with(|s| s.get());
+    #[cfg(not(feature = "std"))]
+    return CopyStats::default();
+}
+
+/// Reset copy statistics of the current thread.
+pub fn reset_copy_stats() {
+    #[cfg(feature = "std")]
+    COPY_STATS.with(|s| s.set(CopyStats::default()));
+}
+
+/// Record an explicit copy of `elements` elements.
+pub(crate) fn record_copy(elements: usize) {
+    #[cfg(feature = "std")]
+    COPY_STATS.with(|s| {
+        let CopyStats { copies, elements: count } = s.get();
+        s.set(CopyStats { copies: copies + 1, elements: count + elements });
+    });
+    #[cfg(not(feature = "std"))]
+    let _ = elements;
+}
+
+/// Number of diagnostics reported in the current thread since last [`reset_trace_count`].
+pub fn trace_count() -> usize {
+    #[cfg(feature = "std")]
+    return TRACE_COUNT.with(|s| s.get());
+    #[cfg(not(feature = "std"))]
+    return 0;
+}
+
+/// Reset diagnostic count of the current thread.
+pub fn reset_trace_count() {
+    #[cfg(feature = "std")]
+    TRACE_COUNT.with(|s| s.set(0));
+}
+
+/// Record a reported diagnostic.
+pub(crate) fn record_trace() {
+    #[cfg(feature = "std")]
+    TRACE_COUNT.with(|s| s.set(s.get() + 1));
+}
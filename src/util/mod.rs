@@ -1,11 +1,17 @@
 pub mod blas_error;
 pub mod blas_flags;
+pub mod blas_plan;
+pub mod blas_structured;
 pub mod blas_traits;
+pub mod instrument;
 pub mod util_ndarray;
 
 pub use blas_error::*;
 pub use blas_flags::*;
+pub use blas_plan::*;
+pub use blas_structured::*;
 pub use blas_traits::*;
+pub use instrument::{copy_stats, reset_copy_stats, reset_trace_count, trace_count, CopyStats};
 pub use util_ndarray::*;
 
-pub use crate::{blas_assert, blas_assert_eq, blas_invalid, blas_raise, blas_warn_layout_clone};
+pub use crate::{blas_assert, blas_assert_eq, blas_invalid, blas_raise, blas_trace, blas_warn_layout_clone};
@@ -1,6 +1,23 @@
+use crate::ffi::{blas_int, c_char};
 use crate::util::*;
 use ndarray::prelude::*;
+use ndarray::{Data, DataMut};
+use num_traits::{One, Zero};
 
+/// Output of BLAS wrappers: either the user-given mutable view, or a newly allocated array.
+///
+/// This type is intentionally not `#[must_use]`: for in-place computation (output view given by user), the
+/// result has already been written, and dropping the returned view is the usual pattern. Methods that only
+/// produce values (such as [`into_owned`](ArrayOut::into_owned)) are `#[must_use]` instead, so dropping an
+/// owned result is warned:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use blas_array2::prelude::*;
+/// use ndarray::prelude::*;
+/// let a = Array2::<f64>::ones((3, 3));
+/// DGEMM::default().a(a.view()).b(a.view()).run().unwrap().into_owned();
+/// ```
 #[derive(Debug)]
 pub enum ArrayOut<'a, F, D>
 where
@@ -16,6 +33,7 @@ where
     F: Clone,
     D: Dimension,
 {
+    #[must_use]
     pub fn view(&self) -> ArrayView<'_, F, D> {
         match self {
             Self::ViewMut(arr) => arr.view(),
@@ -32,6 +50,7 @@ where
         }
     }
 
+    #[must_use]
     pub fn into_owned(self) -> Array<F, D> {
         match self {
             Self::ViewMut(arr) => arr.to_owned(),
@@ -43,6 +62,7 @@ where
         }
     }
 
+    #[must_use]
     pub fn is_view_mut(&mut self) -> bool {
         match self {
             Self::ViewMut(_) => true,
@@ -51,6 +71,7 @@ where
         }
     }
 
+    #[must_use]
     pub fn is_owned(&mut self) -> bool {
         match self {
             Self::ViewMut(_) => false,
@@ -69,6 +90,7 @@ where
         }
     }
 
+    #[must_use]
     pub fn reversed_axes(self) -> Self {
         match self {
             ArrayOut::ViewMut(arr) => ArrayOut::ViewMut(arr.reversed_axes()),
@@ -92,6 +114,7 @@ where
 pub type ArrayOut1<'a, F> = ArrayOut<'a, F, Ix1>;
 pub type ArrayOut2<'a, F> = ArrayOut<'a, F, Ix2>;
 pub type ArrayOut3<'a, F> = ArrayOut<'a, F, Ix3>;
+pub type ArrayOut4<'a, F> = ArrayOut<'a, F, Ix4>;
 
 /* #endregion */
 
@@ -103,10 +126,60 @@ pub fn get_layout_array2<F>(arr: &ArrayView2<F>) -> BLASLayout {
     // not c/f-contiguous (memory layout)
     // So some sequential (both c/f-contiguous) cases may be considered as only row or col major
     // Examples:
-    // RowMajor     ==>   shape=[1, 4], strides=[0, 1], layout=CFcf (0xf)
-    // ColMajor     ==>   shape=[4, 1], strides=[1, 0], layout=CFcf (0xf)
-    // Sequential   ==>   shape=[1, 1], strides=[0, 0], layout=CFcf (0xf)
-    // NonContig    ==>   shape=[4, 1], strides=[10, 0], layout=Custom (0x0)
+    // RowMajor     ==>   shape=[4, 4], strides=[4, 1]
+    // ColMajor     ==>   shape=[4, 4], strides=[1, 4]
+    // Sequential   ==>   shape=[1, 1], strides=[0, 0]
+    // Sequential   ==>   shape=[1, 4], strides=[4, 1] (valid as both row-major and col-major)
+    // ColMajor     ==>   shape=[1, 4], strides=[1, 1] (leading dimension too small for row-major)
+    // NonContig    ==>   shape=[4, 4], strides=[10, 2]
+    let (d0, d1) = arr.dim();
+    let [s0, s1] = arr.strides().try_into().unwrap();
+    if d0 == 1 && d1 > 1 && s1 == 1 {
+        // row vector: always valid as col-major, with leading dimension 1
+        if s0 >= d1 as isize {
+            return BLASLayout::Sequential;
+        } else {
+            return BLASColMajor;
+        }
+    } else if d1 == 1 && d0 > 1 && s0 == 1 {
+        // column vector: always valid as row-major, with leading dimension 1
+        if s1 >= d0 as isize {
+            return BLASLayout::Sequential;
+        } else {
+            return BLASRowMajor;
+        }
+    }
+    return get_layout_array2_strict(arr);
+}
+
+/// Layout of 2-D array, as wrappers see it when choosing between row-major and col-major computation.
+///
+/// This is the public name of [`get_layout_array2`]. Relation to predicates of `ndarray`:
+///
+/// | `ndarray` predicate | `blas_layout_of` |
+/// |---|---|
+/// | `a.is_standard_layout()` (C-contiguous), not vector-shaped | [`BLASRowMajor`] or [`BLASLayout::Sequential`] (`is_cpref`) |
+/// | `a.t().is_standard_layout()` (F-contiguous), not vector-shaped | [`BLASColMajor`] or [`BLASLayout::Sequential`] (`is_fpref`) |
+/// | C- or F-contiguous, vector-shaped (one row or column) | [`BLASLayout::Sequential`], or [`BLASColMajor`] ([`BLASRowMajor`]) for a row (column) whose other stride is too small to be leading dimension |
+/// | empty or one element | [`BLASLayout::Sequential`] |
+/// | neither, but rows (columns) are unit-strided | [`BLASRowMajor`] ([`BLASColMajor`]), with leading dimension larger than number of columns (rows) |
+/// | neither, and no unit stride | [`BLASLayout::NonContiguous`] |
+///
+/// Implications are one-way: a row-major sub-block of a larger matrix is [`BLASRowMajor`] without being standard
+/// layout. Wrappers pass `is_cpref` (`is_fpref`) arrays to BLAS without copy in row-major (col-major) computation;
+/// [`BLASLayout::NonContiguous`] arrays are always copied. Conversion from/to [`ndarray::Order`] is given by
+/// `From<Order>` and `TryFrom<BLASLayout>`.
+#[inline]
+pub fn blas_layout_of<F>(arr: &ArrayView2<F>) -> BLASLayout {
+    get_layout_array2(arr)
+}
+
+/// Layout of 2-D array, where vector-shaped arrays are not considered as sequential.
+///
+/// This is used for banded matrices, where layout of array decides how the matrix is interpreted; so
+/// inference of layout should be stable for vector-shaped arrays.
+#[inline]
+pub fn get_layout_array2_strict<F>(arr: &ArrayView2<F>) -> BLASLayout {
     let (d0, d1) = arr.dim();
     let [s0, s1] = arr.strides().try_into().unwrap();
     if d0 == 0 || d1 == 0 {
@@ -127,6 +200,86 @@ pub fn get_layout_array2<F>(arr: &ArrayView2<F>) -> BLASLayout {
     }
 }
 
+/// View of `a` with its leading dimension replaced by `ld`, as in raw BLAS.
+///
+/// Layout of `a` is decided by [`get_layout_array2_strict`]: stride of rows is replaced for row-major, and stride
+/// of columns for col-major. Views without a leading dimension (empty or one element) are returned unchanged;
+/// non-contiguous views give [`BLASError::InvalidStride`], and `ld` smaller than length of the contiguous axis
+/// gives [`BLASError::InvalidDim`].
+///
+/// # Safety
+///
+/// The caller guarantees that memory of the returned view (`ld` elements per row or column, from the
+/// first element of `a`) is valid for reads during lifetime `'a`; this is usually a sub-block of a larger
+/// buffer, which `a` does not cover.
+pub unsafe fn with_leading_dim<'a, F>(
+    a: ArrayView2<'a, F>,
+    ld: usize,
+) -> Result<ArrayView2<'a, F>, BLASError> {
+    let (d0, d1) = a.dim();
+    let strides = match get_layout_array2_strict(&a) {
+        BLASRowMajor => (ld, 1),
+        BLASColMajor => (1, ld),
+        BLASLayout::Sequential => return Ok(a),
+        _ => blas_raise!(InvalidStride, "leading dimension can only be given for contiguous `a`")?,
+    };
+    let inner = if strides.1 == 1 { d1 } else { d0 };
+    blas_assert!(ld >= inner.max(1), InvalidDim, "leading dimension too small")?;
+    Ok(ArrayView2::from_shape_ptr((d0, d1).strides(strides), a.as_ptr()))
+}
+
+/// Check that a 2-D view can be passed to BLAS as the given layout, without inspecting its layout heuristically.
+///
+/// The contiguous axis should have unit stride (or length not larger than 1), and the leading dimension
+/// should not be smaller than length of the contiguous axis.
+pub(crate) fn check_layout_array2<F>(arr: &ArrayView2<F>, layout: BLASLayout) -> Result<(), BLASError> {
+    let (d0, d1) = arr.dim();
+    let [s0, s1] = arr.strides().try_into().unwrap();
+    let (d_inner, s_inner, s_outer) = match layout {
+        BLASRowMajor => (d1, s1, s0),
+        BLASColMajor => (d0, s0, s1),
+        _ => blas_invalid!(layout)?,
+    };
+    blas_assert!(d_inner <= 1 || s_inner == 1, InvalidDim, "array is not contiguous in the assumed layout")?;
+    blas_assert!(
+        s_outer >= d_inner.max(1) as isize,
+        InvalidDim,
+        "leading dimension is not valid in the assumed layout"
+    )?;
+    Ok(())
+}
+
+/// Obtain length and increment of a 1-D view, possibly with user-overrided increment.
+///
+/// If `inc` is given, the view must be contiguous, and `inc` is applied to the base pointer of the view as
+/// raw BLAS increment. Returned length is the number of elements that BLAS visits, which always stays in
+/// bounds of the view.
+///
+/// Zero increment (such as broadcasted views) with more than one element gives
+/// [`BLASError::InvalidStride`], since BLAS would visit the same element repeatedly.
+pub(crate) fn get_len_inc_array1<F>(
+    x: &ArrayView1<F>,
+    inc: Option<blas_int>,
+) -> Result<(usize, isize), BLASError> {
+    let len = x.len_of(Axis(0));
+    match inc {
+        None => {
+            let stride = x.stride_of(Axis(0));
+            blas_assert!(len <= 1 || stride != 0, InvalidStride, "zero increment of vector")?;
+            Ok((len, stride))
+        },
+        Some(inc) => {
+            blas_assert!(inc > 0, InvalidFlag, "increment override should be positive")?;
+            if len > 1 {
+                blas_assert_eq!(x.stride_of(Axis(0)), 1, InvalidDim)?;
+            }
+            let inc: usize = inc.try_into()?;
+            let n = if len == 0 { 0 } else { (len - 1) / inc + 1 };
+            Ok((n, inc.try_into()?))
+        },
+    }
+}
+
 /* #endregion */
 
 /* #region flip */
@@ -197,12 +350,36 @@ where
     }
 }
 
+/// Whether [`flip_trans_fpref`] or [`flip_trans_cpref`] (by `layout`) would perform explicit copy.
+pub(crate) fn flip_trans_copies<F>(
+    trans: BLASTranspose,
+    view: &ArrayView2<F>,
+    layout: BLASLayout,
+    hermi: bool,
+) -> bool {
+    let (is_pref, is_pref_t) = match layout {
+        BLASColMajor => (view.is_fpref(), view.is_cpref()),
+        _ => (view.is_cpref(), view.is_fpref()),
+    };
+    if is_pref {
+        return false;
+    }
+    match trans {
+        BLASNoTrans => hermi || !is_pref_t,
+        BLASTrans => !is_pref_t,
+        _ => true,
+    }
+}
+
 /* #endregion */
 
 /* #region contiguous preference */
 
-pub(crate) trait LayoutPref {
+/// Contiguous preference of a matrix, by layout from [`get_layout_array2`].
+pub trait LayoutPref {
+    /// Whether the matrix can be passed to BLAS as col-major; see [`BLASLayout::is_fpref`].
     fn is_fpref(&self) -> bool;
+    /// Whether the matrix can be passed to BLAS as row-major; see [`BLASLayout::is_cpref`].
     fn is_cpref(&self) -> bool;
 }
 
@@ -218,6 +395,39 @@ impl<A> LayoutPref for ArrayView2<'_, A> {
 
 /* #endregion */
 
+/* #region layout materialization */
+
+/// Row-major (C) layout of a matrix that BLAS accepts: borrowed if already so, otherwise an owned copy.
+///
+/// Rows should be contiguous, but leading dimension may be larger than number of columns.
+pub fn to_c_layout<A>(a: ArrayView2<'_, A>) -> CowArray<'_, A, Ix2>
+where
+    A: Clone,
+{
+    if get_layout_array2(&a).is_cpref() {
+        CowArray::from(a)
+    } else {
+        CowArray::from(a.as_standard_layout().into_owned())
+    }
+}
+
+/// Col-major (Fortran) layout of a matrix that BLAS accepts: borrowed if already so, otherwise an owned
+/// copy.
+///
+/// Columns should be contiguous, but leading dimension may be larger than number of rows.
+pub fn to_fortran_layout<A>(a: ArrayView2<'_, A>) -> CowArray<'_, A, Ix2>
+where
+    A: Clone,
+{
+    if get_layout_array2(&a).is_fpref() {
+        CowArray::from(a)
+    } else {
+        CowArray::from(a.t().as_standard_layout().into_owned().reversed_axes())
+    }
+}
+
+/* #endregion */
+
 /* #region warn on clone */
 
 pub trait ToLayoutCowArray2<A> {
@@ -230,23 +440,17 @@ where
     A: Clone,
 {
     fn to_row_layout(&self) -> Result<CowArray<'_, A, Ix2>, BLASError> {
-        if self.is_cpref() {
-            Ok(CowArray::from(self))
-        } else {
+        if !self.is_cpref() {
             blas_warn_layout_clone!(self)?;
-            let owned = self.into_owned();
-            Ok(CowArray::from(owned))
         }
+        Ok(to_c_layout(self.view()))
     }
 
     fn to_col_layout(&self) -> Result<CowArray<'_, A, Ix2>, BLASError> {
-        if self.is_fpref() {
-            Ok(CowArray::from(self))
-        } else {
+        if !self.is_fpref() {
             blas_warn_layout_clone!(self)?;
-            let owned = self.t().into_owned().reversed_axes();
-            Ok(CowArray::from(owned))
         }
+        Ok(to_fortran_layout(self.view()))
     }
 }
 
@@ -268,3 +472,1004 @@ where
 }
 
 /* #endregion */
+
+/* #region zero stride check */
+
+/// Check that no axis with extent larger than one has zero stride (such as broadcasted views).
+///
+/// BLAS would read (or write) the same memory repeatedly for such arrays, which is almost never intended.
+/// Empty arrays are always valid (ndarray may give zero strides to all axes of them).
+pub(crate) fn check_zero_stride<F, D>(arr: &ArrayView<F, D>) -> Result<(), BLASError>
+where
+    D: Dimension,
+{
+    let valid = arr.is_empty()
+        || arr.shape().iter().zip(arr.strides()).all(|(&len, &stride)| len <= 1 || stride != 0);
+    blas_assert!(valid, InvalidStride, "zero stride on axis with extent larger than one")
+}
+
+/* #endregion */
+
+/* #region side dimension check */
+
+/// Check that triangular `a` is square, and matches `b` (`m x n`) for the given side of TRMM/TRSM.
+///
+/// For `side = Left`, `a` must be `m x m` (rows of `b`); for `side = Right`, `a` must be `n x n` (columns of
+/// `b`). The error message names the dimensions that do not match.
+pub(crate) fn check_side_dim(
+    a_dim: (usize, usize),
+    b_dim: (usize, usize),
+    side: BLASSide,
+) -> Result<(), BLASError> {
+    extern crate alloc;
+    use alloc::string::String;
+    use core::fmt::Write;
+
+    let (m, n) = b_dim;
+    let (expected, rule) = match side {
+        BLASLeft => (m, "side = Left requires A to be m x m, where m = number of rows of B"),
+        BLASRight => (n, "side = Right requires A to be n x n, where n = number of columns of B"),
+        _ => return blas_invalid!(side),
+    };
+    if a_dim == (expected, expected) {
+        return Ok(());
+    }
+    let mut s = String::from(concat!(file!(), ":", line!(), ": ", "BLASError::InvalidDim : "));
+    write!(s, "{rule}; found A of shape {} x {} and B of shape {m} x {n}", a_dim.0, a_dim.1).unwrap();
+    Err(BLASError::InvalidDim(s))
+}
+
+/* #endregion */
+
+/* #region empty dimension */
+
+/// Scale output by `beta` in-place, for empty inner dimension where BLAS is not called.
+///
+/// As in BLAS, `beta = 0` overwrites output by zero instead of multiplying, so NaN in output is not kept.
+pub(crate) fn scale_by_beta<F, D>(mut c: ArrayViewMut<F, D>, beta: F)
+where
+    F: BLASFloat,
+    D: Dimension,
+{
+    if beta == F::zero() {
+        c.fill(F::zero());
+    } else if beta != F::one() {
+        c.mapv_inplace(|v| v * beta);
+    }
+}
+
+/// Scale triangle `uplo` (as passed to BLAS) of square output by `beta` in-place; see [`scale_by_beta`].
+pub(crate) fn scale_by_beta_triangle<F>(
+    mut c: ArrayViewMut2<F>,
+    beta: F,
+    uplo: c_char,
+) -> Result<(), BLASError>
+where
+    F: BLASFloat,
+{
+    let n = c.len_of(Axis(0));
+    for i in 0..n {
+        if uplo == BLASLower.try_into()? {
+            scale_by_beta(c.slice_mut(s![i.., i]), beta);
+        } else if uplo == BLASUpper.try_into()? {
+            scale_by_beta(c.slice_mut(s![..=i, i]), beta);
+        } else {
+            return blas_invalid!(uplo);
+        }
+    }
+    Ok(())
+}
+
+/* #endregion */
+
+/* #region ill-conditioned check */
+
+/// Index of the first diagonal element with magnitude not larger than `F::eps() * max_diag`, if any.
+///
+/// Such element indicates a (nearly) singular triangular matrix, where triangular solve gives huge or
+/// meaningless result.
+pub fn small_diagonal_index<F>(diag: ArrayView1<F>) -> Option<usize>
+where
+    F: BLASFloat,
+{
+    if diag.is_empty() {
+        return None;
+    }
+    let max_diag = diag.iter().map(|&v| F::modulus(v)).fold(F::RealFloat::zero(), |acc, v| match v > acc {
+        true => v,
+        false => acc,
+    });
+    let threshold = F::eps() * max_diag;
+    diag.iter().position(|&v| F::modulus(v) <= threshold)
+}
+
+/// Diagonal of packed triangular matrix of dimension `n`.
+pub(crate) fn packed_diagonal<F>(
+    ap: &ArrayView1<F>,
+    n: usize,
+    uplo: BLASUpLo,
+    layout: BLASLayout,
+) -> Array1<F>
+where
+    F: BLASFloat,
+{
+    // diagonal is the first element of each packed column (row-major: row) for lower (row-major: upper)
+    let first = matches!((layout, uplo), (BLASColMajor, BLASLower) | (BLASRowMajor, BLASUpper));
+    Array1::from_shape_fn(n, |j| match first {
+        true => ap[j * n - (j * j - j) / 2],
+        false => ap[j * (j + 3) / 2],
+    })
+}
+
+/// Repack triangle `uplo` of packed matrix of dimension `n` from the transposed order (row by row for
+/// col-major, column by column for row-major) to the BLAS packing order of `layout`.
+pub(crate) fn packed_reorder<F>(
+    ap: &ArrayView1<F>,
+    n: usize,
+    uplo: BLASUpLo,
+    layout: BLASLayout,
+) -> Result<Array1<F>, BLASError>
+where
+    F: BLASFloat,
+{
+    blas_assert_eq!(ap.len(), n * (n + 1) / 2, InvalidDim)?;
+    // packed index of (i, j) when packed column by column, and row by row
+    let index_col = |i: usize, j: usize| match uplo {
+        BLASLower => j * (2 * n - j + 1) / 2 + (i - j),
+        _ => j * (j + 1) / 2 + i,
+    };
+    let index_row = |i: usize, j: usize| match uplo {
+        BLASLower => i * (i + 1) / 2 + j,
+        _ => i * (2 * n - i + 1) / 2 + (j - i),
+    };
+    let mut ap_new = Array1::zeros(ap.len());
+    for j in 0..n {
+        let rows = match uplo {
+            BLASLower => j..n,
+            BLASUpper => 0..j + 1,
+            _ => return blas_invalid!(uplo),
+        };
+        for i in rows {
+            match layout {
+                BLASColMajor => ap_new[index_col(i, j)] = ap[index_row(i, j)],
+                BLASRowMajor => ap_new[index_row(i, j)] = ap[index_col(i, j)],
+                _ => return blas_invalid!(layout),
+            }
+        }
+    }
+    Ok(ap_new)
+}
+
+/// Report (by feature `trace`) diagonal elements of triangular matrix that make solve ill-conditioned.
+pub(crate) fn warn_small_diagonal<F>(diag: ArrayView1<F>)
+where
+    F: BLASFloat,
+{
+    if let Some(index) = small_diagonal_index(diag) {
+        blas_trace!(
+            "Warning: triangular matrix is ill-conditioned, diagonal element {index} has magnitude below \
+             epsilon * max |diagonal|"
+        );
+    }
+}
+
+/* #endregion */
+
+/* #region finite check */
+
+pub(crate) fn check_finite_scalar<F>(scalars: &[F]) -> Result<(), BLASError>
+where
+    F: BLASFloat,
+{
+    blas_assert!(scalars.iter().all(|&x| F::is_finite(x)), NonFinite, "scalar is not finite")
+}
+
+pub(crate) fn check_finite_array<F, D>(arr: &ArrayView<F, D>) -> Result<(), BLASError>
+where
+    F: BLASFloat,
+    D: Dimension,
+{
+    blas_assert!(arr.iter().all(|&x| F::is_finite(x)), NonFinite, "array contains non-finite value")
+}
+
+/* #endregion */
+
+/* #region hermitian */
+
+/// Make a square matrix hermitian in-place, by the triangle indicated by `uplo`.
+///
+/// The other triangle is overwritten by conjugate of the given triangle, and imaginary part of diagonal is
+/// zeroed. For real matrices, this symmetrizes the matrix.
+pub fn hermitianize<F>(mut a: ArrayViewMut2<F>, uplo: BLASUpLo) -> Result<(), BLASError>
+where
+    F: BLASFloat,
+{
+    blas_assert_eq!(a.len_of(Axis(0)), a.len_of(Axis(1)), InvalidDim)?;
+    // check flag before writing anything, so that input is unchanged on error
+    let lower = match uplo {
+        BLASLower => true,
+        BLASUpper => false,
+        _ => blas_invalid!(uplo)?,
+    };
+    let n = a.len_of(Axis(0));
+    for i in 0..n {
+        a[[i, i]] = F::from_real(F::real(a[[i, i]]));
+    }
+    for i in 0..n {
+        for j in 0..i {
+            match lower {
+                true => a[[j, i]] = F::conj(a[[i, j]]),
+                false => a[[i, j]] = F::conj(a[[j, i]]),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Triangle of a square matrix that holds data, for symmetric (hermitian) routines.
+///
+/// If the triangle indicated by `uplo` is exactly zero (diagonal excluded) while the other one is not, the
+/// other triangle is returned; otherwise `uplo` is kept.
+pub(crate) fn uplo_from_data<F>(a: &ArrayView2<F>, uplo: BLASUpLo) -> BLASUpLo
+where
+    F: BLASFloat,
+{
+    if a.len_of(Axis(0)) != a.len_of(Axis(1)) {
+        return uplo;
+    }
+    let (mut lower_zero, mut upper_zero) = (true, true);
+    for ((i, j), &x) in a.indexed_iter() {
+        if x != F::zero() {
+            if i > j {
+                lower_zero = false;
+            } else if i < j {
+                upper_zero = false;
+            }
+        }
+    }
+    let uplo_data = match uplo {
+        BLASLower if lower_zero && !upper_zero => BLASUpper,
+        BLASUpper if upper_zero && !lower_zero => BLASLower,
+        _ => uplo,
+    };
+    if uplo_data != uplo {
+        blas_trace!("blas-array2: uplo {:?} refers to zero triangle, using {:?} from data", uplo, uplo_data);
+    }
+    uplo_data
+}
+
+/// Element-wise conjugate of a matrix.
+///
+/// For real types this is a plain clone; for complex types every element is conjugated.
+pub fn conj_array<F>(a: ArrayView2<F>) -> Array2<F>
+where
+    F: BLASFloat,
+{
+    if F::is_complex() {
+        a.mapv(F::conj)
+    } else {
+        a.to_owned()
+    }
+}
+
+/// Symmetric part $(\mathbf{A} + \mathbf{A}^T) / 2$ of a square matrix.
+///
+/// Unlike [`hermitianize`], both triangles contribute, so this is suitable for matrices that are only
+/// symmetric up to floating-point error. The result is exactly symmetric. Memory order of `a` is kept.
+pub fn symmetric_part<F>(a: ArrayView2<F>) -> Result<Array2<F>, BLASError>
+where
+    F: BLASFloat,
+{
+    average_transpose(a, false)
+}
+
+/// Hermitian part $(\mathbf{A} + \mathbf{A}^H) / 2$ of a square matrix.
+///
+/// The result is exactly hermitian (diagonal is real). For real matrices, this is the same as
+/// [`symmetric_part`]. Memory order of `a` is kept.
+pub fn hermitian_part<F>(a: ArrayView2<F>) -> Result<Array2<F>, BLASError>
+where
+    F: BLASFloat,
+{
+    average_transpose(a, F::is_complex())
+}
+
+fn average_transpose<F>(a: ArrayView2<F>, conj: bool) -> Result<Array2<F>, BLASError>
+where
+    F: BLASFloat,
+{
+    blas_assert_eq!(a.len_of(Axis(0)), a.len_of(Axis(1)), InvalidDim)?;
+    let one = F::RealFloat::one();
+    let half = F::from_real(one / (one + one));
+    let mut b = match a.is_fpref() {
+        true => Array2::zeros(a.raw_dim().f()),
+        false => Array2::zeros(a.raw_dim()),
+    };
+    // addition is commutative and scaling by one half is exact, so mirrored elements are bitwise equal
+    match conj {
+        true => {
+            ndarray::Zip::from(&mut b).and(&a).and(a.t()).for_each(|b, &x, &y| *b = (x + F::conj(y)) * half)
+        },
+        false => ndarray::Zip::from(&mut b).and(&a).and(a.t()).for_each(|b, &x, &y| *b = (x + y) * half),
+    }
+    Ok(b)
+}
+
+/* #endregion */
+
+/* #region densify */
+
+/// Dense copy of a triangular matrix, as BLAS interprets it by `uplo` and `diag`.
+///
+/// The non-referenced triangle is zeroed, and the diagonal is set to one if `diag = Unit`. Memory order of `a`
+/// is kept. This is useful for falling back to GEMM (or other dense routines) when triangular (or symmetric)
+/// routines are not available or not reliable.
+pub fn densify_triangular<F>(
+    a: &ArrayView2<F>,
+    uplo: BLASUpLo,
+    diag: BLASDiag,
+) -> Result<Array2<F>, BLASError>
+where
+    F: BLASFloat,
+{
+    blas_assert_eq!(a.len_of(Axis(0)), a.len_of(Axis(1)), InvalidDim)?;
+    let mut a_dense = match a.is_fpref() {
+        true => Array2::zeros(a.raw_dim().f()),
+        false => Array2::zeros(a.raw_dim()),
+    };
+    for ((i, j), x) in a_dense.indexed_iter_mut() {
+        let referenced = match uplo {
+            BLASLower => i >= j,
+            BLASUpper => i <= j,
+            _ => return blas_invalid!(uplo),
+        };
+        if referenced {
+            *x = a[[i, j]];
+        }
+    }
+    match diag {
+        BLASUnit => a_dense.diag_mut().fill(F::one()),
+        BLASNonUnit => (),
+        _ => return blas_invalid!(diag),
+    }
+    Ok(a_dense)
+}
+
+/// Zero elements of `a` outside of the triangle indicated by `uplo`, in place.
+///
+/// The diagonal is kept. `a` may be rectangular, in which case the upper (lower) trapezoid is kept. This
+/// makes a full matrix, whose other triangle holds stale data, exactly triangular.
+pub fn zero_opposite_triangle<F>(mut a: ArrayViewMut2<F>, uplo: BLASUpLo) -> Result<(), BLASError>
+where
+    F: BLASFloat,
+{
+    let keep = match uplo {
+        BLASLower => |i: usize, j: usize| i >= j,
+        BLASUpper => |i: usize, j: usize| i <= j,
+        _ => return blas_invalid!(uplo),
+    };
+    for ((i, j), x) in a.indexed_iter_mut() {
+        if !keep(i, j) {
+            *x = F::zero();
+        }
+    }
+    Ok(())
+}
+
+/// Dense `m x n` matrix from BLAS band storage `ab` (col-major convention) of `kl` sub-diagonals and `ku`
+/// super-diagonals.
+///
+/// Element $A_{ij}$ is `ab[[ku + i - j, j]]` for `j - ku <= i <= j + kl`, and other elements are zero; `ab`
+/// must have shape `(kl + ku + 1, n)`. Row-major band storage of $\mathbf{A}$ (`ab[[i, kl + j - i]]`) is
+/// col-major band storage of $\mathbf{A}^T$, and can be densified by
+/// `densify_band(&ab.t(), ku, kl, n, m)?.reversed_axes()`.
+///
+/// Symmetric, hermitian or triangular band storage (as of SBMV, HBMV, TBMV) of bandwidth `k` is upper band
+/// `(kl, ku) = (0, k)` or lower band `(k, 0)`, where only the stored triangle is filled.
+pub fn densify_band<F>(
+    ab: &ArrayView2<F>,
+    kl: usize,
+    ku: usize,
+    m: usize,
+    n: usize,
+) -> Result<Array2<F>, BLASError>
+where
+    F: BLASFloat,
+{
+    blas_assert_eq!(ab.dim(), (kl + ku + 1, n), InvalidDim)?;
+    let mut a = Array2::zeros((m, n).f());
+    for j in 0..n {
+        for i in j.saturating_sub(ku)..(j + kl + 1).min(m) {
+            a[[i, j]] = ab[[ku + i - j, j]];
+        }
+    }
+    Ok(a)
+}
+
+/// Dense square matrix from symmetric, hermitian or triangular band storage `ab` in `layout`, with only the
+/// stored triangle `uplo` filled.
+pub(crate) fn densify_band_triangle<F>(
+    ab: &ArrayView2<F>,
+    uplo: BLASUpLo,
+    layout: BLASLayout,
+) -> Result<Array2<F>, BLASError>
+where
+    F: BLASFloat,
+{
+    let (k_, n) = match layout {
+        BLASColMajor => ab.dim(),
+        BLASRowMajor => ab.t().dim(),
+        _ => blas_invalid!(layout)?,
+    };
+    blas_assert!(k_ > 0, InvalidDim, "Rows of input `a` must larger than zero.")?;
+    let (kl, ku) = match uplo {
+        BLASUpper => (0, k_ - 1),
+        BLASLower => (k_ - 1, 0),
+        _ => blas_invalid!(uplo)?,
+    };
+    match layout {
+        BLASColMajor => densify_band(ab, kl, ku, n, n),
+        _ => Ok(densify_band(&ab.t(), ku, kl, n, n)?.reversed_axes()),
+    }
+}
+
+/* #endregion */
+
+/* #region aliasing */
+
+/// Address range `[lo, hi)` spanned by an array in memory, or `None` if the array is empty.
+fn memory_span<F, D>(arr: &ArrayView<F, D>) -> Option<(usize, usize)>
+where
+    D: Dimension,
+{
+    if arr.is_empty() {
+        return None;
+    }
+    let (mut lo, mut hi) = (0_isize, 0_isize);
+    for (&len, &stride) in arr.shape().iter().zip(arr.strides()) {
+        let offset = (len as isize - 1) * stride;
+        if offset < 0 {
+            lo += offset;
+        } else {
+            hi += offset;
+        }
+    }
+    let size = core::mem::size_of::<F>() as isize;
+    let ptr = arr.as_ptr() as isize;
+    Some(((ptr + lo * size) as usize, (ptr + (hi + 1) * size) as usize))
+}
+
+/// Residues of element addresses (in bytes) modulo `modulus` spanned by an array, as cyclic interval
+/// `(lowest, extent)`, or `None` if the interval may cover all residues.
+///
+/// Axes with stride multiple of `modulus` do not change the residue, so only the other axes extend the
+/// interval from the single element.
+fn residue_interval<F, D>(arr: &ArrayView<F, D>, modulus: isize) -> Option<(isize, isize)>
+where
+    D: Dimension,
+{
+    let size = core::mem::size_of::<F>() as isize;
+    let (mut lo, mut extent) = (arr.as_ptr() as isize, size);
+    for (&len, &stride) in arr.shape().iter().zip(arr.strides()) {
+        let stride = stride * size;
+        if len > 1 && stride % modulus != 0 {
+            let offset = (len as isize - 1) * stride;
+            lo += offset.min(0);
+            extent += offset.abs();
+        }
+    }
+    (extent < modulus).then_some((lo.rem_euclid(modulus), extent))
+}
+
+fn gcd(a: isize, b: isize) -> isize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+/// Whether two arrays share any element in memory.
+///
+/// Arrays with disjoint address ranges never share elements. Otherwise, strided views are checked by residues
+/// of element addresses modulo each stride of both arrays (and their gcd): if the residues of two arrays are
+/// disjoint for any modulus, so are their elements. This decides common interleaved views exactly, such as
+/// even and odd columns, or left and right column blocks of a matrix; if no modulus decides, the arrays are
+/// conservatively reported as overlapping.
+pub fn arrays_overlap<F, D1, D2>(a: &ArrayView<F, D1>, b: &ArrayView<F, D2>) -> bool
+where
+    D1: Dimension,
+    D2: Dimension,
+{
+    match (memory_span(a), memory_span(b)) {
+        (Some((a_lo, a_hi)), Some((b_lo, b_hi))) if a_lo < b_hi && b_lo < a_hi => (),
+        _ => return false,
+    }
+
+    // candidate moduli: strides (in bytes) of non-trivial axes of both arrays
+    let size = core::mem::size_of::<F>() as isize;
+    let strides = || {
+        let axes = a.shape().iter().zip(a.strides()).chain(b.shape().iter().zip(b.strides()));
+        axes.filter(|(&len, &stride)| len > 1 && stride != 0).map(move |(_, &stride)| (stride * size).abs())
+    };
+    let modulus_gcd = strides().fold(0, gcd);
+    let disjoint = strides().chain([modulus_gcd]).filter(|&m| m > 0).any(|m| {
+        match (residue_interval(a, m), residue_interval(b, m)) {
+            (Some((a_lo, a_ext)), Some((b_lo, b_ext))) => {
+                (b_lo - a_lo).rem_euclid(m) >= a_ext && (a_lo - b_lo).rem_euclid(m) >= b_ext
+            },
+            _ => false,
+        }
+    });
+    !disjoint
+}
+
+/* #endregion */
+
+/* #region vector shape */
+
+/// Index of the vector axis of a matrix-shaped vector (`n x 1` or `1 x n`).
+fn vector_axis(dim: (usize, usize)) -> Result<Axis, BLASError> {
+    match dim {
+        (_, 1) => Ok(Axis(1)),
+        (1, _) => Ok(Axis(0)),
+        _ => blas_raise!(InvalidDim, "matrix-shaped vector should be of shape n x 1 or 1 x n"),
+    }
+}
+
+/// Vector argument of BLAS builders, either 1-D array view, or matrix-shaped (`n x 1` or `1 x n`) 2-D array
+/// view; references to such arrays are also accepted.
+pub trait AsVector<'a, F> {
+    fn into_vector(self) -> Result<ArrayView1<'a, F>, BLASError>;
+}
+
+impl<'a, F> AsVector<'a, F> for ArrayView1<'a, F> {
+    fn into_vector(self) -> Result<ArrayView1<'a, F>, BLASError> {
+        Ok(self)
+    }
+}
+
+impl<'a, F> AsVector<'a, F> for ArrayView2<'a, F> {
+    fn into_vector(self) -> Result<ArrayView1<'a, F>, BLASError> {
+        let axis = vector_axis(self.dim())?;
+        Ok(self.index_axis_move(axis, 0))
+    }
+}
+
+impl<'a, F, S> AsVector<'a, F> for &'a ArrayBase<S, Ix1>
+where
+    S: Data<Elem = F>,
+{
+    fn into_vector(self) -> Result<ArrayView1<'a, F>, BLASError> {
+        Ok(self.view())
+    }
+}
+
+impl<'a, F, S> AsVector<'a, F> for &'a ArrayBase<S, Ix2>
+where
+    S: Data<Elem = F>,
+{
+    fn into_vector(self) -> Result<ArrayView1<'a, F>, BLASError> {
+        self.view().into_vector()
+    }
+}
+
+/// Mutable vector argument of BLAS builders, see [`AsVector`].
+pub trait AsVectorMut<'a, F> {
+    fn into_vector_mut(self) -> Result<ArrayViewMut1<'a, F>, BLASError>;
+}
+
+impl<'a, F> AsVectorMut<'a, F> for ArrayViewMut1<'a, F> {
+    fn into_vector_mut(self) -> Result<ArrayViewMut1<'a, F>, BLASError> {
+        Ok(self)
+    }
+}
+
+impl<'a, F> AsVectorMut<'a, F> for ArrayViewMut2<'a, F> {
+    fn into_vector_mut(self) -> Result<ArrayViewMut1<'a, F>, BLASError> {
+        let axis = vector_axis(self.dim())?;
+        Ok(self.index_axis_move(axis, 0))
+    }
+}
+
+impl<'a, F, S> AsVectorMut<'a, F> for &'a mut ArrayBase<S, Ix1>
+where
+    S: DataMut<Elem = F>,
+{
+    fn into_vector_mut(self) -> Result<ArrayViewMut1<'a, F>, BLASError> {
+        Ok(self.view_mut())
+    }
+}
+
+impl<'a, F, S> AsVectorMut<'a, F> for &'a mut ArrayBase<S, Ix2>
+where
+    S: DataMut<Elem = F>,
+{
+    fn into_vector_mut(self) -> Result<ArrayViewMut1<'a, F>, BLASError> {
+        self.view_mut().into_vector_mut()
+    }
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reversed_axes_strided_to_be_cloned() {
+        // owned buffer holds the transposed (col-major) result of a strided row-major view
+        let mut c = Array2::<f64>::zeros((5, 7));
+        let view = c.slice_mut(s![1..5;2, ..;3]);
+        let owned = Array2::from_shape_fn((3, 2).f(), |(i, j)| (10 * i + j + 1) as f64);
+        let out = ArrayOut::ToBeCloned(view.reversed_axes(), owned).reversed_axes();
+        assert_eq!(out.view(), array![[1.0, 11.0, 21.0], [2.0, 12.0, 22.0]]);
+        drop(out);
+        let mut expect = Array2::<f64>::zeros((5, 7));
+        expect.slice_mut(s![1..5;2, ..;3]).assign(&array![[1.0, 11.0, 21.0], [2.0, 12.0, 22.0]]);
+        assert_eq!(c, expect);
+    }
+
+    #[test]
+    fn test_array_out4_variants() {
+        let src =
+            Array4::from_shape_fn((2, 3, 4, 5), |(i, j, k, l)| (1000 * i + 100 * j + 10 * k + l) as f64);
+
+        // ViewMut: written in place, finalized by copy
+        let mut c = Array4::<f64>::zeros((2, 3, 4, 5));
+        let mut out = ArrayOut4::ViewMut(c.view_mut());
+        out.view_mut().assign(&src);
+        assert!(out.is_view_mut());
+        assert_eq!(out.into_owned(), src);
+        assert_eq!(c, src);
+
+        // Owned: returned as is; reversed axes reverse all four
+        let out = ArrayOut4::Owned(src.clone());
+        assert!(out.view().is_standard_layout());
+        assert_eq!(out.reversed_axes().into_owned(), src.t());
+
+        // ToBeCloned: buffer copied to view on finalize, for strided view and F-contiguous buffer
+        let mut c = Array4::<f64>::zeros((4, 3, 8, 5));
+        let owned = src.t().as_standard_layout().reversed_axes().into_owned();
+        let out = ArrayOut4::ToBeCloned(c.slice_mut(s![..;2, .., ..;2, ..]), owned);
+        assert_eq!(out.view(), src);
+        let out = out.clone_to_view_mut();
+        assert!(matches!(out, ArrayOut::ViewMut(_)));
+        drop(out);
+        assert_eq!(c.slice(s![..;2, .., ..;2, ..]), src);
+        assert_eq!(c.slice(s![1..;2, .., .., ..]).sum(), 0.0);
+
+        let mut c = Array4::<f64>::zeros((5, 4, 3, 2));
+        let out = ArrayOut4::ToBeCloned(c.view_mut().reversed_axes(), src.clone()).reversed_axes();
+        assert!(matches!(out, ArrayOut::ViewMut(_)));
+        assert_eq!(out.view(), src.t());
+        drop(out);
+        assert_eq!(c, src.t());
+    }
+
+    #[test]
+    fn test_clone_to_view_mut_mixed_layout() {
+        // view is C-contiguous, owned buffer is F-contiguous (as from `to_col_layout`); assignment must follow
+        // logical index, not raw memory order
+        let mut c = Array2::<f64>::zeros((3, 4));
+        let src = Array2::from_shape_fn((3, 4), |(i, j)| (10 * i + j) as f64);
+        let owned = src.view().to_col_layout().unwrap().into_owned();
+        assert!(owned.t().is_standard_layout());
+        assert_ne!(owned.as_slice_memory_order(), src.as_slice_memory_order());
+        let out = ArrayOut::ToBeCloned(c.view_mut(), owned).clone_to_view_mut();
+        assert!(matches!(out, ArrayOut::ViewMut(_)));
+        drop(out);
+        assert_eq!(c, src);
+        assert!(c.is_standard_layout());
+    }
+
+    #[test]
+    fn test_symmetric_hermitian_part() {
+        use num_complex::Complex;
+        let a = Array2::from_shape_fn((4, 4), |(i, j)| {
+            Complex::new(0.1 * (3 * i + j) as f64, 0.3 / (1 + i * j) as f64)
+        });
+        let a = a.t().as_standard_layout().into_owned().reversed_axes();
+
+        let sym = symmetric_part(a.view()).unwrap();
+        assert_eq!(sym, sym.t());
+        assert!(sym.t().is_standard_layout());
+        let herm = hermitian_part(a.view()).unwrap();
+        assert_eq!(herm, herm.t().mapv(|x| x.conj()));
+        assert!(herm.diag().iter().all(|x| x.im == 0.0));
+        for ((i, j), &x) in a.indexed_iter() {
+            assert_eq!(sym[[i, j]], (x + a[[j, i]]) * 0.5);
+            assert_eq!(herm[[i, j]], (x + a[[j, i]].conj()) * 0.5);
+        }
+
+        let a = Array2::from_shape_fn((3, 3), |(i, j)| (i * i + 2 * j) as f64 / 7.0);
+        assert_eq!(hermitian_part(a.view()).unwrap(), symmetric_part(a.view()).unwrap());
+        assert_eq!(symmetric_part(a.view()).unwrap(), (&a + &a.t()) / 2.0);
+        assert!(symmetric_part(a.slice(s![..2, ..])).is_err());
+    }
+
+    #[test]
+    fn test_densify_triangular() {
+        let a = Array2::from_shape_fn((3, 3), |(i, j)| (3 * i + j + 1) as f64);
+        let a_dense = densify_triangular(&a.view(), BLASLower, BLASNonUnit).unwrap();
+        assert_eq!(a_dense, array![[1.0, 0.0, 0.0], [4.0, 5.0, 0.0], [7.0, 8.0, 9.0]]);
+        let a_dense = densify_triangular(&a.t(), BLASUpper, BLASUnit).unwrap();
+        assert_eq!(a_dense, array![[1.0, 4.0, 7.0], [0.0, 1.0, 8.0], [0.0, 0.0, 1.0]]);
+        assert!(a_dense.t().is_standard_layout());
+        assert!(densify_triangular(&a.slice(s![..2, ..]), BLASLower, BLASNonUnit).is_err());
+    }
+
+    #[test]
+    fn test_get_layout_array2() {
+        let a = Array2::<f64>::zeros((4, 4));
+        assert_eq!(get_layout_array2(&a.view()), BLASRowMajor);
+        assert_eq!(get_layout_array2(&a.t()), BLASColMajor);
+        assert_eq!(get_layout_array2(&a.slice(s![.., ..;2])), BLASLayout::NonContiguous);
+        assert_eq!(get_layout_array2(&a.slice(s![..1, ..1])), BLASLayout::Sequential);
+        let a = Array2::<f64>::zeros((1, 4));
+        assert_eq!(get_layout_array2(&a.view()), BLASLayout::Sequential);
+        assert_eq!(get_layout_array2(&a.t()), BLASLayout::Sequential);
+        // vector-shaped, but leading dimension is too small for one of layouts
+        let a = Array2::<f64>::zeros((1, 4).f());
+        assert_eq!(get_layout_array2(&a.view()), BLASColMajor);
+        assert_eq!(get_layout_array2(&a.t()), BLASRowMajor);
+        let a = Array2::<f64>::zeros((4, 4));
+        assert_eq!(get_layout_array2(&a.slice(s![..1, ..])), BLASColMajor);
+        // strict layout does not consider vector-shaped arrays
+        let a = Array2::<f64>::zeros((1, 4));
+        assert_eq!(get_layout_array2_strict(&a.view()), BLASRowMajor);
+        assert_eq!(get_layout_array2_strict(&a.t()), BLASColMajor);
+    }
+
+    #[test]
+    fn test_layout_pref() {
+        let a = Array2::<f64>::zeros((4, 5));
+        let b = Array2::<f64>::zeros((1, 5));
+        // (array, is_cpref, is_fpref)
+        let table = [
+            (a.view(), true, false),
+            (a.t(), false, true),
+            (a.slice(s![.., ..;2]), false, false),
+            (b.view(), true, true),
+            (b.t(), true, true),
+            (a.slice(s![..1, ..]), false, true),
+        ];
+        for (arr, cpref, fpref) in table {
+            assert_eq!(arr.is_cpref(), cpref, "is_cpref of strides {:?}", arr.strides());
+            assert_eq!(arr.is_fpref(), fpref, "is_fpref of strides {:?}", arr.strides());
+        }
+    }
+
+    #[test]
+    fn test_blas_layout_of_ndarray_predicates() {
+        let a = Array2::<f64>::zeros((4, 5));
+        let v = Array2::<f64>::zeros((1, 5));
+        let e = Array2::<f64>::zeros((0, 3));
+        let arrays = [
+            a.view(),
+            a.t(),
+            a.slice(s![.., ..;2]),
+            a.slice(s![..;2, ..]),
+            a.slice(s![1..3, 1..4]),
+            a.slice(s![..;2, ..;2]),
+            a.slice(s![..;-1, ..]),
+            a.slice(s![..1, ..]),
+            a.slice(s![.., ..1]),
+            a.slice(s![..1, ..1]),
+            v.view(),
+            v.t(),
+            e.view(),
+            e.t(),
+        ];
+        for arr in arrays {
+            let layout = blas_layout_of(&arr);
+            assert_eq!(layout, get_layout_array2(&arr));
+            let c_contig = arr.is_standard_layout();
+            let f_contig = arr.t().is_standard_layout();
+            let msg = format!("shape {:?}, strides {:?}, layout {:?}", arr.shape(), arr.strides(), layout);
+            // standard (C- or F-contiguous) layout is always passed to BLAS without copy; ndarray ignores
+            // stride of axis of length 1, so vectors may only be valid in one of layouts
+            if arr.nrows() == 1 || arr.ncols() == 1 {
+                assert!(!(c_contig || f_contig) || layout.is_cpref() || layout.is_fpref(), "{msg}");
+            } else {
+                assert!(!c_contig || layout.is_cpref(), "{msg}");
+                assert!(!f_contig || layout.is_fpref(), "{msg}");
+            }
+            // non-contiguous layout has no unit stride, so neither C- nor F-contiguous
+            if layout == BLASLayout::NonContiguous {
+                assert!(!c_contig && !f_contig, "{msg}");
+            }
+            // round trip through ndarray order
+            if let Ok(order) = ndarray::Order::try_from(layout) {
+                assert_eq!(BLASLayout::from(order), layout);
+            }
+        }
+    }
+
+    #[test]
+    fn test_flip_trans_conj_trans_conjugates_once() {
+        // op(A) represented by (trans, matrix) should be A^H exactly, for any layout of A
+        let a = Array2::from_shape_fn((3, 4), |(i, j)| c64::new(i as f64 + 1.0, j as f64 - 2.0));
+        let a_h = a.t().mapv(|x| x.conj());
+        let apply = |trans: BLASTranspose, m: &ArrayView2<c64>| match trans {
+            BLASNoTrans => m.to_owned(),
+            BLASTrans => m.t().to_owned(),
+            BLASConjTrans => m.t().mapv(|x| x.conj()),
+            _ => panic!(),
+        };
+        let a_f = a.t().as_standard_layout().reversed_axes().into_owned();
+        let mut a_nc = Array2::<c64>::zeros((6, 8));
+        a_nc.slice_mut(s![..;2, ..;2]).assign(&a);
+        for view in [a.view(), a_f.view(), a_nc.slice(s![..;2, ..;2])] {
+            let view_t = view.t();
+            for hermi in [false, true] {
+                let (trans, m) = flip_trans_fpref(BLASConjTrans, &view, &view_t, hermi).unwrap();
+                assert!(m.view().is_fpref());
+                assert_eq!(apply(trans, &m.view()), a_h);
+                let (trans, m) = flip_trans_cpref(BLASConjTrans, &view, &view_t, hermi).unwrap();
+                assert!(m.view().is_cpref());
+                assert_eq!(apply(trans, &m.view()), a_h);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hermitianize() {
+        let data: Vec<c64> = (0..16).map(|i| c64::new(i as f64 + 0.5, 1.0 - i as f64 * 0.25)).collect();
+        for uplo in [BLASLower, BLASUpper] {
+            let mut a = Array2::from_shape_vec((4, 4), data.clone()).unwrap();
+            hermitianize(a.view_mut(), uplo).unwrap();
+            assert_eq!(a, a.t().mapv(|x| x.conj()));
+            a.diag().iter().for_each(|x| assert_eq!(x.im, 0.0));
+            // the referenced triangle is kept
+            let a_orig = Array2::from_shape_vec((4, 4), data.clone()).unwrap();
+            for i in 0..4 {
+                for j in 0..i {
+                    match uplo {
+                        BLASLower => assert_eq!(a[[i, j]], a_orig[[i, j]]),
+                        _ => assert_eq!(a[[j, i]], a_orig[[j, i]]),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hermitianize_real_strided() {
+        let mut a = Array2::from_shape_fn((6, 6), |(i, j)| (i * 6 + j) as f64);
+        hermitianize(a.slice_mut(s![..;2, ..;2]), BLASUpper).unwrap();
+        let a = a.slice(s![..;2, ..;2]);
+        assert_eq!(a, a.t());
+    }
+
+    #[test]
+    fn test_hermitianize_invalid() {
+        let mut a = Array2::<c32>::zeros((3, 4));
+        assert!(hermitianize(a.view_mut(), BLASLower).is_err());
+        let mut a = Array2::<c32>::zeros((3, 3));
+        assert!(hermitianize(a.view_mut(), BLASUpLo::Undefined).is_err());
+
+        // input is left unchanged on error
+        let a_orig = Array2::from_shape_fn((3, 3), |(i, j)| c32::new(i as f32, j as f32 + 1.0));
+        let mut a = a_orig.clone();
+        assert!(matches!(hermitianize(a.view_mut(), BLASUpLo::Undefined), Err(BLASError::InvalidFlag(_))));
+        assert_eq!(a, a_orig);
+    }
+
+    #[test]
+    fn test_arrays_overlap() {
+        let a = Array2::<f64>::zeros((4, 6));
+        assert!(arrays_overlap(&a.view(), &a.view()));
+        assert!(arrays_overlap(&a.slice(s![..2, ..]), &a.slice(s![1.., ..])));
+        assert!(!arrays_overlap(&a.slice(s![..2, ..]), &a.slice(s![2.., ..])));
+        assert!(arrays_overlap(&a.slice(s![..;-1, ..]), &a.slice(s![3, ..])));
+        assert!(!arrays_overlap(&a.slice(s![..0, ..]), &a.view()));
+        let b = Array2::<f64>::zeros((4, 6));
+        assert!(!arrays_overlap(&a.view(), &b.view()));
+
+        // interleaved or side-by-side views with intersecting address ranges
+        assert!(!arrays_overlap(&a.slice(s![.., ..;2]), &a.slice(s![.., 1..;2])));
+        assert!(!arrays_overlap(&a.slice(s![.., ..3]), &a.slice(s![.., 3..])));
+        assert!(!arrays_overlap(&a.slice(s![..;2, ..]), &a.slice(s![1..;2, ..])));
+        assert!(!arrays_overlap(&a.slice(s![.., 1..3]), &a.slice(s![1.., 3..;-1])));
+        assert!(!arrays_overlap(&a.slice(s![.., ..;3]), &a.slice(s![.., 1..;3])));
+        assert!(arrays_overlap(&a.slice(s![.., ..4]), &a.slice(s![.., 3..])));
+        assert!(arrays_overlap(&a.slice(s![.., ..;2]), &a.slice(s![.., 2..;2])));
+        assert!(arrays_overlap(&a.slice(s![.., ..;2]), &a.t()));
+        assert!(arrays_overlap(&a.slice(s![..1, ..]), &a.slice(s![.., 5])));
+    }
+
+    #[test]
+    fn test_conj_array() {
+        let a = Array2::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64 - 5.5);
+        assert_eq!(conj_array(a.view()), a);
+        assert_eq!(conj_array(a.t()), a.t());
+
+        let z = Array2::from_shape_fn((3, 4), |(i, j)| c32::new(i as f32, j as f32 - 1.5));
+        let z_conj = conj_array(z.slice(s![..;-1, 1..]));
+        assert_eq!(z_conj, z.slice(s![..;-1, 1..]).mapv(|x| x.conj()));
+        assert_eq!(conj_array(z_conj.view()), z.slice(s![..;-1, 1..]));
+    }
+
+    #[test]
+    fn test_uplo_from_data() {
+        let a = Array2::from_shape_fn((4, 4), |(i, j)| if i >= j { 1.0 + (i + j) as f64 } else { 0.0 });
+        assert_eq!(uplo_from_data(&a.view(), BLASLower), BLASLower);
+        assert_eq!(uplo_from_data(&a.view(), BLASUpper), BLASLower);
+        assert_eq!(uplo_from_data(&a.t(), BLASLower), BLASUpper);
+        // diagonal, full or non-square matrices keep the given uplo
+        let d = Array2::<f64>::eye(3);
+        assert_eq!(uplo_from_data(&d.view(), BLASUpper), BLASUpper);
+        let f = Array2::<f64>::ones((3, 3));
+        assert_eq!(uplo_from_data(&f.view(), BLASLower), BLASLower);
+        assert_eq!(uplo_from_data(&a.slice(s![.., 1..]), BLASUpper), BLASUpper);
+    }
+
+    #[test]
+    fn test_to_layout_memory_order() {
+        // copies of contiguous arrays in the opposite layout should be in the requested memory order
+        let a = Array2::from_shape_fn((3, 4).f(), |(i, j)| (4 * i + j) as f64);
+        let a_view = a.view();
+        let a_row = a_view.to_row_layout().unwrap();
+        assert!(a_row.is_standard_layout());
+        assert_eq!(a_row, a);
+        let b = Array2::from_shape_fn((3, 4), |(i, j)| (4 * i + j) as f64);
+        let b_view = b.view();
+        let b_col = b_view.to_col_layout().unwrap();
+        assert!(b_col.t().is_standard_layout());
+        assert_eq!(b_col, b);
+    }
+
+    #[test]
+    fn test_to_layout_cow() {
+        let a_c = Array2::from_shape_fn((4, 6), |(i, j)| (i * 6 + j) as f64);
+        let a_f = a_c.t().as_standard_layout().into_owned().reversed_axes();
+        assert_eq!(a_c, a_f);
+        let a_seq = Array2::from_shape_fn((1, 6), |(_, j)| j as f64);
+        // (input, borrowed by to_c_layout, borrowed by to_fortran_layout)
+        let cases = [
+            (a_c.view(), true, false),
+            (a_f.view(), false, true),
+            (a_c.slice(s![.., 1..5]), true, false),
+            (a_f.slice(s![1..3, ..]), false, true),
+            (a_c.slice(s![.., ..;2]), false, false),
+            (a_seq.view(), true, true),
+        ];
+        for (a, c_borrowed, f_borrowed) in cases {
+            let c = to_c_layout(a);
+            assert_eq!(c.is_view(), c_borrowed);
+            assert!(c.is_standard_layout() || c_borrowed);
+            assert_eq!(c, a);
+            let f = to_fortran_layout(a);
+            assert_eq!(f.is_view(), f_borrowed);
+            assert!(f.t().is_standard_layout() || f_borrowed);
+            assert_eq!(f, a);
+        }
+    }
+
+    #[test]
+    fn test_small_diagonal_index() {
+        let a = array![[2.0, 1.0, 0.0], [0.0, 1e-20, 3.0], [0.0, 0.0, -4.0]];
+        assert_eq!(small_diagonal_index(a.diag()), Some(1));
+        assert_eq!(small_diagonal_index(array![1.0, -4.0, 0.5].view()), None);
+        assert_eq!(small_diagonal_index(array![0.0_f32, 0.0].view()), Some(0));
+        assert_eq!(small_diagonal_index(Array1::<f64>::zeros(0).view()), None);
+
+        // magnitude of complex numbers, and relative to precision
+        let d = array![c32::new(3.0, 4.0), c32::new(0.0, 1e-7), c32::new(0.0, 1e-5)];
+        assert_eq!(small_diagonal_index(d.view()), Some(1));
+        assert_eq!(small_diagonal_index(d.mapv(|v| c64::new(v.re as f64, v.im as f64)).view()), None);
+    }
+
+    #[test]
+    fn test_packed_diagonal() {
+        // [[1, 2, 3], [., 4, 5], [., ., 6]] in upper col-major, which is also lower row-major
+        let ap = array![1.0, 2.0, 4.0, 3.0, 5.0, 6.0];
+        assert_eq!(packed_diagonal(&ap.view(), 3, BLASUpper, BLASColMajor), array![1.0, 4.0, 6.0]);
+        let ap = array![1.0, 2.0, 4.0, 3.0, 5.0, 6.0];
+        assert_eq!(packed_diagonal(&ap.view(), 3, BLASLower, BLASRowMajor), array![1.0, 4.0, 6.0]);
+        // [[1, ., .], [2, 4, .], [3, 5, 6]] in lower col-major, which is also upper row-major
+        let ap = array![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert_eq!(packed_diagonal(&ap.view(), 3, BLASLower, BLASColMajor), array![1.0, 4.0, 6.0]);
+        assert_eq!(packed_diagonal(&ap.view(), 3, BLASUpper, BLASRowMajor), array![1.0, 4.0, 6.0]);
+    }
+}
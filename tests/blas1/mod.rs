@@ -1,3 +1,8 @@
 pub mod test_asum;
+pub mod test_axpy;
+pub mod test_copy;
+pub mod test_dot;
 pub mod test_iamax;
 pub mod test_nrm2;
+pub mod test_rot;
+pub mod test_scal;
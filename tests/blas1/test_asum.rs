@@ -2,6 +2,7 @@ use crate::util::*;
 use approx::*;
 use blas_array2::blas1::asum::ASUM;
 use blas_array2::util::*;
+use ndarray::prelude::*;
 
 #[cfg(test)]
 mod valid {
@@ -27,4 +28,52 @@ mod valid {
             assert_relative_eq!(out, expected, epsilon = 1.0e-6);
         }
     }
+
+    #[test]
+    fn test_negative_stride() {
+        let x = random_array::<c64>(30);
+        let out = ASUM::default().x(x.slice(s![..;-3])).run().unwrap();
+        let expected = x.slice(s![..;-3]).mapv(|v| v.re.abs() + v.im.abs()).sum();
+        assert_relative_eq!(out, expected, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_builder_clone() {
+        let x = random_array::<c64>(20);
+        let base = ASUM::default().x(x.view());
+        let res = base.clone().run().unwrap();
+        let res_strided = base.incx(3).run().unwrap();
+        assert_relative_eq!(res, ASUM::default().x(x.view()).run().unwrap(), epsilon = 1.0e-12);
+        assert_relative_eq!(
+            res_strided,
+            ASUM::default().x(x.slice(s![..;3])).run().unwrap(),
+            epsilon = 1.0e-12
+        );
+    }
+
+    #[test]
+    fn test_incx_override() {
+        // interleaved (re, im) data stored as contiguous real array
+        let x = random_array::<f64>(201);
+        let out = ASUM::default().x(x.view()).incx(2).run().unwrap();
+        let expected = x.slice(s![..;2]).mapv(f64::abs).sum();
+        assert_relative_eq!(out, expected, epsilon = 1.0e-6);
+        let out = ASUM::default().x(x.slice(s![1..])).incx(2).run().unwrap();
+        let expected = x.slice(s![1..;2]).mapv(f64::abs).sum();
+        assert_relative_eq!(out, expected, epsilon = 1.0e-6);
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_incx_override() {
+        let x = random_array::<f64>(100);
+        assert!(ASUM::default().x(x.view()).incx(0).run().is_err());
+        assert!(ASUM::default().x(x.view()).incx(-1).run().is_err());
+        // view should be contiguous if increment is overrided
+        assert!(ASUM::default().x(x.slice(s![..;2])).incx(2).run().is_err());
+    }
 }
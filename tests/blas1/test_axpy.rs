@@ -0,0 +1,84 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (incx, incy) in [(1, 1), (2, 3)] {
+            let n = 100;
+            let alpha = c64::rand();
+            let x = random_array::<c64>(1000);
+            let mut y = random_array::<c64>(1000);
+            let y_naive = &y.slice(slice_1d(n, incy)) + &x.slice(slice_1d(n, incx)).mapv(|v| alpha * v);
+            AXPY::default()
+                .x(x.slice(slice_1d(n, incx)))
+                .y(y.slice_mut(slice_1d(n, incy)))
+                .alpha(alpha)
+                .run()
+                .unwrap();
+            check_same(&y.slice(slice_1d(n, incy)), &y_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_negative_stride() {
+        let alpha = 1.5;
+        let x = random_array::<f32>(20);
+        let mut y = random_array::<f32>(30);
+        let x_slc = s![..;-2];
+        let y_slc = s![5..25;-2];
+        let y_naive = &y.slice(y_slc) + &x.slice(x_slc).mapv(|v| alpha * v);
+        SAXPY::default().x(x.slice(x_slc)).y(y.slice_mut(y_slc)).alpha(alpha).run().unwrap();
+        check_same(&y.slice(y_slc), &y_naive.view(), 4.0 * f32::EPSILON);
+    }
+
+    #[test]
+    fn test_incx_override() {
+        let x = random_array::<f64>(21);
+        let mut y = random_array::<f64>(11);
+        let y_naive = &y + &x.slice(s![..;2]);
+        DAXPY::default().x(x.view()).y(y.view_mut()).incx(2).run().unwrap();
+        check_same(&y.view(), &y_naive.view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_dim_mismatch() {
+        let x = random_array::<f64>(10);
+        let mut y = random_array::<f64>(11);
+        DAXPY::default().x(x.view()).y(y.view_mut()).run().unwrap();
+    }
+
+    #[test]
+    fn test_zero_stride() {
+        // broadcasted vector would make BLAS visit the same element repeatedly
+        let x = Array1::from_elem(10, 1.5);
+        let x_scalar = array![1.5];
+        let x_bcast = x_scalar.broadcast(10).unwrap();
+        assert_eq!(x_bcast.strides(), &[0]);
+        let mut y = random_array::<f64>(10);
+        let err = DAXPY::default().x(x_bcast).y(y.view_mut()).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidStride(_)));
+
+        // same values with real increment work
+        let y_naive = &y + &x;
+        DAXPY::default().x(x.view()).y(y.view_mut()).run().unwrap();
+        check_same(&y.view(), &y_naive.view(), 4.0 * f64::EPSILON);
+
+        // single element of zero stride is valid
+        let mut y = random_array::<f64>(1);
+        DAXPY::default().x(x_scalar.broadcast(1).unwrap()).y(y.view_mut()).run().unwrap();
+
+        let err = ASUM::default().x(x_bcast).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidStride(_)));
+    }
+}
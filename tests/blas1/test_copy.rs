@@ -0,0 +1,50 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (incx, incy) in [(1, 1), (2, 3)] {
+            let n = 100;
+            let x = random_array::<c64>(1000);
+            let mut y = random_array::<c64>(1000);
+            COPY::default().x(x.slice(slice_1d(n, incx))).y(y.slice_mut(slice_1d(n, incy))).run().unwrap();
+            assert_eq!(y.slice(slice_1d(n, incy)), x.slice(slice_1d(n, incx)));
+        }
+    }
+
+    #[test]
+    fn test_negative_stride() {
+        let x = random_array::<f32>(20);
+        let mut y = random_array::<f32>(30);
+        let y_raw = y.clone();
+        let y_slc = s![5..25;-2];
+        SCOPY::default().x(x.slice(s![..;-2])).y(y.slice_mut(y_slc)).run().unwrap();
+        assert_eq!(y.slice(y_slc), x.slice(s![..;-2]));
+        assert_eq!(y.slice(s![..5]), y_raw.slice(s![..5]));
+    }
+
+    #[test]
+    fn test_incx_override() {
+        let x = random_array::<f64>(21);
+        let mut y = random_array::<f64>(11);
+        DCOPY::default().x(x.view()).y(y.view_mut()).incx(2).run().unwrap();
+        assert_eq!(y, x.slice(s![..;2]));
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_dim_mismatch() {
+        let x = random_array::<f64>(10);
+        let mut y = random_array::<f64>(11);
+        assert!(matches!(DCOPY::default().x(x.view()).y(y.view_mut()).run(), Err(BLASError::InvalidDim(_))));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::util::*;
+use approx::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (incx, incy) in [(1, 1), (2, 3)] {
+            let n = 100;
+            let x = random_array::<c64>(1000);
+            let y = random_array::<c64>(1000);
+            let x_slc = x.slice(slice_1d(n, incx));
+            let y_slc = y.slice(slice_1d(n, incy));
+            let res = ZDOTU::default().x(x_slc).y(y_slc).run().unwrap();
+            assert!((res - x_slc.dot(&y_slc)).norm() < 1e-10);
+
+            let x = random_array::<f64>(1000);
+            let y = random_array::<f64>(1000);
+            let x_slc = x.slice(slice_1d(n, incx));
+            let y_slc = y.slice(slice_1d(n, incy));
+            let res = DDOT::default().x(x_slc).y(y_slc).run().unwrap();
+            assert_abs_diff_eq!(res, x_slc.dot(&y_slc), epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_negative_stride() {
+        let x = random_array::<c32>(40);
+        let y = random_array::<c32>(60);
+        for (x_slc, y_slc) in [(s![..;-2], s![..20]), (s![..20], s![..;-3]), (s![10..30;-1], s![5..25;-1])] {
+            let (x, y) = (x.slice(x_slc), y.slice(y_slc));
+            let res = CDOTU::default().x(x).y(y).run().unwrap();
+            assert!((res - x.dot(&y)).norm() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_incx_override() {
+        let x = random_array::<f32>(21);
+        let y = random_array::<f32>(11);
+        let res = SDOT::default().x(x.view()).y(y.view()).incx(2).run().unwrap();
+        assert_abs_diff_eq!(res, x.slice(s![..;2]).dot(&y), epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_small() {
+        let x = array![c64::new(1.0, 2.0)];
+        let y = array![c64::new(3.0, -1.0)];
+        assert_eq!(DOT::default().x(x.view()).y(y.view()).run().unwrap(), c64::new(5.0, 5.0));
+        let x = Array1::<c64>::zeros(0);
+        assert_eq!(DOT::default().x(x.view()).y(x.view()).run().unwrap(), c64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_broadcast() {
+        // zero-stride vectors are copied instead of being rejected
+        let x = random_array::<f64>(20);
+        let v = array![2.5];
+        let v = v.broadcast(20).unwrap();
+        reset_copy_stats();
+        let res = DDOT::default().x(v).y(x.view()).run().unwrap();
+        assert_abs_diff_eq!(res, v.dot(&x), epsilon = 1e-10);
+        let res = DDOT::default().x(x.slice(s![..;-1])).y(v).run().unwrap();
+        assert_abs_diff_eq!(res, v.dot(&x), epsilon = 1e-10);
+        assert_eq!(copy_stats(), CopyStats { copies: 2, elements: 40 });
+
+        let x = random_array::<c64>(20);
+        let v = array![c64::new(1.0, -2.0)];
+        let v = v.broadcast(20).unwrap();
+        let res = ZDOTU::default().x(v).y(v).conjx(true).run().unwrap();
+        assert!((res - v.mapv(|v| v.conj()).dot(&v)).norm() < 1e-10);
+        let res = ZDOTU::default().x(x.view()).y(v).conjx(true).run().unwrap();
+        assert!((res - x.mapv(|v| v.conj()).dot(&v)).norm() < 1e-10);
+    }
+}
+
+#[cfg(test)]
+mod conjx {
+    use super::*;
+
+    #[test]
+    fn test_conjx() {
+        let x = random_array::<c64>(60);
+        let y = random_array::<c64>(60);
+        for (x_slc, y_slc) in
+            [(s![..20], s![..20]), (s![..;3], s![..40;2]), (s![..20;-1], s![..;-3]), (s![..;-2], s![..30])]
+        {
+            let (x, y) = (x.slice(x_slc), y.slice(y_slc));
+            let res = ZDOTU::default().x(x).y(y).conjx(true).run().unwrap();
+            assert!((res - x.mapv(|v| v.conj()).dot(&y)).norm() < 1e-10);
+        }
+
+        // no conjugate for real types
+        let x = random_array::<f64>(30);
+        let y = random_array::<f64>(10);
+        let res = DDOT::default().x(x.slice(s![..;3])).y(y.view()).conjx(true).run().unwrap();
+        assert!((res - x.slice(s![..;3]).dot(&y)).abs() < 1e-10);
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_dim_mismatch() {
+        let x = random_array::<f64>(10);
+        let y = random_array::<f64>(11);
+        assert!(matches!(DDOT::default().x(x.view()).y(y.view()).run(), Err(BLASError::InvalidDim(_))));
+    }
+}
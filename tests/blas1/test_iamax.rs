@@ -1,6 +1,7 @@
 use crate::util::*;
 use blas_array2::blas1::iamax::IAMAX;
 use blas_array2::util::*;
+use ndarray::prelude::*;
 
 #[cfg(test)]
 mod valid {
@@ -40,4 +41,102 @@ mod valid {
             assert_eq!(out, expected);
         }
     }
+
+    #[test]
+    fn test_incx_override() {
+        // interleaved (re, im) data stored as contiguous real array
+        let mut x = random_array::<f64>(201);
+        x[1] = 10.0;
+        x[40] = 5.0;
+        // index refers to position in strided walk, not in `x`
+        let out = IAMAX::default().x(x.view()).incx(2).run().unwrap();
+        assert_eq!(out, 20);
+        let out = IAMAX::default().x(x.slice(s![1..])).incx(2).run().unwrap();
+        assert_eq!(out, 0);
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_incx_override() {
+        let x = random_array::<f64>(100);
+        assert!(IAMAX::default().x(x.view()).incx(0).run().is_err());
+        assert!(IAMAX::default().x(x.view()).incx(-1).run().is_err());
+        // view should be contiguous if increment is overrided
+        assert!(IAMAX::default().x(x.slice(s![..;2])).incx(2).run().is_err());
+    }
+}
+
+#[cfg(test)]
+mod nan_policy {
+    use super::*;
+    use blas_array2::blas1::iamax::NanPolicy;
+    use num_complex::*;
+
+    #[test]
+    fn test_propagate() {
+        for pos in [0, 3, 9] {
+            let mut x = Array1::from_iter((0..10).map(|i| (i as f64 - 4.0) * 0.5));
+            x[pos] = f64::NAN;
+            x[7] = 100.0;
+            // default policy is `Propagate`
+            assert_eq!(IAMAX::default().x(x.view()).run().unwrap(), pos);
+            assert_eq!(IAMAX::default().x(x.view()).nan_policy(NanPolicy::Propagate).run().unwrap(), pos);
+        }
+
+        // first NaN
+        let x = Array1::from_vec(vec![1.0, f64::NAN, 5.0, f64::NAN]);
+        assert_eq!(IAMAX::default().x(x.view()).run().unwrap(), 1);
+
+        // NaN in imaginary part only
+        let mut x = Array1::from_elem(5, Complex64::new(1.0, 1.0));
+        x[2] = Complex64::new(0.0, f64::NAN);
+        assert_eq!(IAMAX::default().x(x.view()).run().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_ignore() {
+        for pos in [0, 3, 7, 9] {
+            let mut x = Array1::from_iter((0..10).map(|i| (i as f64 - 4.0) * 0.5));
+            x[pos] = f64::NAN;
+            x[6] = -100.0;
+            let expected = if pos == 6 { 9 } else { 6 };
+            assert_eq!(IAMAX::default().x(x.view()).nan_policy(NanPolicy::Ignore).run().unwrap(), expected);
+        }
+
+        // ties resolved to first index of maximum, as BLAS
+        let x = Array1::from_vec(vec![f64::NAN, -3.0, 1.0, 3.0, f64::NAN]);
+        assert_eq!(IAMAX::default().x(x.view()).nan_policy(NanPolicy::Ignore).run().unwrap(), 1);
+
+        // complex: |re| + |im| measure
+        let x = Array1::from_vec(vec![
+            Complex64::new(f64::NAN, 10.0),
+            Complex64::new(2.0, -2.0),
+            Complex64::new(3.5, 0.0),
+        ]);
+        assert_eq!(IAMAX::default().x(x.view()).nan_policy(NanPolicy::Ignore).run().unwrap(), 1);
+
+        // all NaN
+        let x = Array1::from_elem(4, f64::NAN);
+        assert_eq!(IAMAX::default().x(x.view()).nan_policy(NanPolicy::Ignore).run().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_strided_and_incx() {
+        let mut x = Array1::from_iter((0..12).map(|i| i as f64));
+        x[5] = f64::NAN;
+        x[4] = f64::NAN;
+        // only even elements are seen
+        let out = IAMAX::default().x(x.slice(s![..;2])).nan_policy(NanPolicy::Propagate).run().unwrap();
+        assert_eq!(out, 2);
+        let out = IAMAX::default().x(x.view()).incx(2).nan_policy(NanPolicy::Ignore).run().unwrap();
+        assert_eq!(out, 5);
+        // NaN at odd position is not seen by `incx = 2`
+        x[4] = 0.0;
+        let out = IAMAX::default().x(x.view()).incx(2).run().unwrap();
+        assert_eq!(out, 5);
+    }
 }
@@ -2,6 +2,7 @@ use crate::util::*;
 use approx::*;
 use blas_array2::blas1::nrm2::NRM2;
 use blas_array2::util::*;
+use ndarray::prelude::*;
 
 #[cfg(test)]
 mod valid {
@@ -27,4 +28,38 @@ mod valid {
             assert_relative_eq!(out, expected, epsilon = 1.0e-6);
         }
     }
+
+    #[test]
+    fn test_negative_stride() {
+        let x = random_array::<c64>(30);
+        let out = NRM2::default().x(x.slice(s![..;-3])).run().unwrap();
+        let expected = x.slice(s![..;-3]).mapv(|v| v.norm_sqr()).sum().sqrt();
+        assert_relative_eq!(out, expected, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_incx_override() {
+        // interleaved (re, im) data stored as contiguous real array
+        let x = random_array::<f64>(201);
+        let out = NRM2::default().x(x.view()).incx(2).run().unwrap();
+        let expected = f64::sqrt(x.slice(s![..;2]).mapv(|x| x * x).sum());
+        assert_relative_eq!(out, expected, epsilon = 1.0e-6);
+        let out = NRM2::default().x(x.slice(s![1..])).incx(2).run().unwrap();
+        let expected = f64::sqrt(x.slice(s![1..;2]).mapv(|x| x * x).sum());
+        assert_relative_eq!(out, expected, epsilon = 1.0e-6);
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_incx_override() {
+        let x = random_array::<f64>(100);
+        assert!(NRM2::default().x(x.view()).incx(0).run().is_err());
+        assert!(NRM2::default().x(x.view()).incx(-1).run().is_err());
+        // view should be contiguous if increment is overrided
+        assert!(NRM2::default().x(x.slice(s![..;2])).incx(2).run().is_err());
+    }
 }
@@ -0,0 +1,56 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_rot() {
+        for (incx, incy) in [(1, 1), (2, 3), (-1, 2)] {
+            let (c, s) = (0.6, 0.8);
+            let mut x_raw = random_array::<c64>(30);
+            let mut y_raw = random_array::<c64>(30);
+            let x_slc = s![..;incx];
+            let y_slc = s![..;incy];
+            let n = 10;
+            let x_orig = x_raw.slice(x_slc).slice(s![..n]).to_owned();
+            let y_orig = y_raw.slice(y_slc).slice(s![..n]).to_owned();
+
+            ROT::default()
+                .x(x_raw.slice_mut(x_slc).slice_mut(s![..n]))
+                .y(y_raw.slice_mut(y_slc).slice_mut(s![..n]))
+                .c(c)
+                .s(s)
+                .run()
+                .unwrap();
+            let x_naive = &x_orig * c64::from(c) + &y_orig * c64::from(s);
+            let y_naive = &y_orig * c64::from(c) - &x_orig * c64::from(s);
+            check_same(&x_raw.slice(x_slc).slice(s![..n]), &x_naive.view(), 4.0 * f64::EPSILON);
+            check_same(&y_raw.slice(y_slc).slice(s![..n]), &y_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_rot_invalid_dim() {
+        let mut x = random_array::<f64>(4);
+        let mut y = random_array::<f64>(5);
+        let err = ROT::default().x(x.view_mut()).y(y.view_mut()).c(1.0).s(0.0).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+    }
+
+    #[test]
+    fn test_rotg() {
+        let (a, b) = (3.0_f64, 4.0_f64);
+        let (r, c, s) = rotg(a, b);
+        assert!((r.abs() - 5.0).abs() < 1e-14);
+        assert!((c * a + s * b - r).abs() < 1e-14);
+        assert!((c * b - s * a).abs() < 1e-14);
+
+        let (a, b) = (c64::new(1.0, 2.0), c64::new(-0.5, 1.5));
+        let (r, c, s) = rotg(a, b);
+        assert!((c64::from(c) * a + s * b - r).norm() < 1e-14);
+        assert!((c64::from(c) * b - s.conj() * a).norm() < 1e-14);
+    }
+}
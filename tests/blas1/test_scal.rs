@@ -0,0 +1,31 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for incx in [1, 3] {
+            let n = 100;
+            let alpha = c32::rand();
+            let mut x = random_array::<c32>(1000);
+            let x_naive = x.slice(slice_1d(n, incx)).mapv(|v| alpha * v);
+            SCAL::default().x(x.slice_mut(slice_1d(n, incx))).alpha(alpha).run().unwrap();
+            check_same(&x.slice(slice_1d(n, incx)), &x_naive.view(), 4.0 * f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_negative_stride() {
+        let mut x = random_array::<f64>(20);
+        let x_naive = x.clone();
+        DSCAL::default().x(x.slice_mut(s![..;-2])).alpha(3.0).run().unwrap();
+        for (i, (v, v_naive)) in x.iter().zip(x_naive.iter()).enumerate() {
+            let expected = if i % 2 == 1 { 3.0 * v_naive } else { *v_naive };
+            assert!((v - expected).abs() < 4.0 * f64::EPSILON * expected.abs().max(1.0));
+        }
+    }
+}
@@ -1,7 +1,10 @@
+pub mod test_empty;
 pub mod test_gbmv;
 pub mod test_gemv;
 pub mod test_ger;
 pub mod test_gerc;
+#[cfg(feature = "half")]
+pub mod test_hgemv;
 pub mod test_sbmv;
 pub mod test_spmv;
 pub mod test_spr;
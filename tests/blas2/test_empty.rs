@@ -0,0 +1,136 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+/// Shapes with empty dimensions: `0 x k`, `m x 0` and `0 x 0`.
+const SHAPES: [(usize, usize); 3] = [(0, 3), (3, 0), (0, 0)];
+
+#[cfg(test)]
+mod general {
+    use super::*;
+
+    #[test]
+    fn test_gemv() {
+        for (m, n) in SHAPES {
+            for layout in ['R', 'C'] {
+                for trans in ['N', 'T', 'C'] {
+                    let a = random_matrix::<c64>(m, n, layout.into());
+                    let (nx, ny) = if trans == 'N' { (n, m) } else { (m, n) };
+                    let x = random_array::<c64>(nx);
+
+                    let y_out = GEMV::default().a(a.view()).x(x.view()).trans(trans).run().unwrap();
+                    assert_eq!(y_out.view(), Array1::<c64>::zeros(ny));
+
+                    // beta = 0 overwrites output (even for NaN), otherwise output is scaled by beta
+                    let mut y = Array1::from_elem(ny, c64::new(f64::NAN, 0.0));
+                    GEMV::default().a(a.view()).x(x.view()).y(y.view_mut()).trans(trans).run().unwrap();
+                    assert_eq!(y, Array1::<c64>::zeros(ny));
+
+                    let y_orig = random_array::<c64>(ny);
+                    let mut y = y_orig.clone();
+                    GEMV::default()
+                        .a(a.view())
+                        .x(x.view())
+                        .y(y.view_mut())
+                        .trans(trans)
+                        .beta((0.0, 2.0))
+                        .run()
+                        .unwrap();
+                    assert_eq!(y, &y_orig * c64::new(0.0, 2.0));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gbmv() {
+        let (kl, ku) = (1, 1);
+        for (m, n) in SHAPES {
+            for trans in ['N', 'T'] {
+                let a = random_matrix::<f64>(kl + ku + 1, n, 'C'.into());
+                let (nx, ny) = if trans == 'N' { (n, m) } else { (m, n) };
+                let x = random_array::<f64>(nx);
+                let y_orig = random_array::<f64>(ny);
+                let mut y = y_orig.clone();
+                GBMV::default()
+                    .a(a.view())
+                    .x(x.view())
+                    .y(y.view_mut())
+                    .m(m)
+                    .kl(kl)
+                    .trans(trans)
+                    .beta(-1.0)
+                    .layout('C')
+                    .run()
+                    .unwrap();
+                assert_eq!(y, -&y_orig);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ger() {
+        for (m, n) in SHAPES {
+            let x = random_array::<c64>(m);
+            let y = random_array::<c64>(n);
+            let a_out = GER::default().x(x.view()).y(y.view()).run().unwrap();
+            assert_eq!(a_out.view().dim(), (m, n));
+            let a_out = GERC::default().x(x.view()).y(y.view()).run().unwrap();
+            assert_eq!(a_out.view().dim(), (m, n));
+        }
+    }
+}
+
+#[cfg(test)]
+mod square {
+    use super::*;
+
+    #[test]
+    fn test_square() {
+        for (m, n) in SHAPES {
+            let a = random_matrix::<c64>(m, n, 'R'.into());
+            let x = random_array::<c64>(n);
+            let mut x_inout = random_array::<c64>(n);
+
+            let results = [
+                HEMV::default().a(a.view()).x(x.view()).run().map(|y| y.view().len()),
+                TRMV::default().a(a.view()).x(x_inout.view_mut()).run().map(|x| x.view().len()),
+                TRSV::default().a(a.view()).x(x_inout.view_mut()).run().map(|x| x.view().len()),
+                HER::default().x(x.view()).a(a.clone().view_mut()).run().map(|a| a.view().len()),
+                HER2::default().x(x.view()).y(x.view()).a(a.clone().view_mut()).run().map(|a| a.view().len()),
+            ];
+            for result in results {
+                match m == n {
+                    true => assert_eq!(result.unwrap(), 0),
+                    false => assert!(result.is_err()),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_band_packed() {
+        // band storage of `0 x 0` matrix with one off-diagonal, in col-major
+        let a = Array2::<c64>::zeros((2, 0).f());
+        let ap = Array1::<c64>::zeros(0);
+        let x = Array1::<c64>::zeros(0);
+        let mut x_inout = Array1::<c64>::zeros(0);
+
+        assert_eq!(HBMV::default().a(a.view()).x(x.view()).layout('C').run().unwrap().view().len(), 0);
+        assert_eq!(
+            TBMV::default().a(a.view()).x(x_inout.view_mut()).layout('C').run().unwrap().view().len(),
+            0
+        );
+        assert_eq!(
+            TBSV::default().a(a.view()).x(x_inout.view_mut()).layout('C').run().unwrap().view().len(),
+            0
+        );
+        assert_eq!(HPMV::default().ap(ap.view()).x(x.view()).run().unwrap().view().len(), 0);
+        assert_eq!(TPMV::default().ap(ap.view()).x(x_inout.view_mut()).run().unwrap().view().len(), 0);
+        assert_eq!(TPSV::default().ap(ap.view()).x(x_inout.view_mut()).run().unwrap().view().len(), 0);
+        assert_eq!(HPR::default().x(x.view()).run().unwrap().view().len(), 0);
+        assert_eq!(HPR2::default().x(x.view()).y(x.view()).run().unwrap().view().len(), 0);
+        assert_eq!(HER::default().x(x.view()).run().unwrap().view().dim(), (0, 0));
+        assert_eq!(HER2::default().x(x.view()).y(x.view()).run().unwrap().view().dim(), (0, 0));
+    }
+}
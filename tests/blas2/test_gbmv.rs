@@ -284,3 +284,40 @@ mod valid_row_major {
         }
     }
 }
+
+#[cfg(test)]
+mod dense_fallback {
+    use super::*;
+
+    #[test]
+    fn test_band_and_dense_agree() {
+        let (m, kl, ku) = (9, 3, 2);
+        let alpha = c64::new(1.5, -0.5);
+        let beta = c64::new(0.5, 1.0);
+        for (layout, trans) in iproduct!(['C', 'R'], ['N', 'T', 'C']) {
+            let a = match layout {
+                'C' => random_matrix::<c64>(kl + ku + 1, m, 'C'.into()),
+                _ => random_matrix::<c64>(m, kl + ku + 1, 'R'.into()),
+            };
+            let x = random_array::<c64>(m);
+            let y = random_array::<c64>(m);
+            let run = |dense_fallback: bool| {
+                let mut y_out = y.clone();
+                let obj = GBMV::default()
+                    .a(a.view())
+                    .x(x.view())
+                    .y(y_out.view_mut())
+                    .m(m)
+                    .kl(kl)
+                    .alpha(alpha)
+                    .beta(beta)
+                    .trans(trans)
+                    .layout(layout);
+                let out = if dense_fallback { obj.run_dense_fallback() } else { obj.run() };
+                out.unwrap();
+                y_out
+            };
+            check_same(&run(true).view(), &run(false).view(), 4.0 * f64::EPSILON);
+        }
+    }
+}
@@ -102,3 +102,285 @@ mod valid {
     test_macro!(test_022: inline, c64, (7, 8, 3, 1), (7, 3), (8, 1), 'C', 'T');
     test_macro!(test_023: inline, c64, (7, 8, 3, 3), (8, 1), (7, 1), 'C', 'N');
 }
+
+#[cfg(test)]
+mod check_finite {
+    use super::*;
+
+    #[test]
+    fn test_nan_alpha() {
+        let a = random_matrix::<f64>(5, 4, 'R'.into());
+        let x = random_array::<f64>(4);
+        let mut y = random_array::<f64>(5);
+        let y_orig = y.clone();
+
+        let result =
+            GEMV::default().a(a.view()).x(x.view()).y(y.view_mut()).alpha(f64::NAN).check_finite(true).run();
+        assert!(matches!(result, Err(BLASError::NonFinite(_))));
+        // output is untouched when check fails
+        assert_eq!(y, y_orig);
+
+        let result = GEMV::default().a(a.view()).x(x.view()).alpha(f64::NAN).run().unwrap();
+        assert!(result.view().iter().all(|x| x.is_nan()));
+    }
+}
+
+#[cfg(test)]
+mod negative_stride {
+    use super::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_reversed_vectors() {
+        for (layout, trans) in [('R', 'N'), ('C', 'N'), ('R', 'T'), ('C', 'C')] {
+            let a = random_matrix::<c64>(7, 8, layout.into());
+            let a_naive = transpose(&a.view(), trans.into());
+            let (m, n) = a_naive.dim();
+            let x_raw = random_array::<c64>(2 * n);
+            let mut y_raw = random_array::<c64>(m);
+            let x = x_raw.slice(s![..;-2]);
+            let mut y_naive = y_raw.clone();
+            y_naive.slice_mut(s![..;-1]).scaled_add(c64::new(1.0, 0.0), &gemv(&a_naive.view(), &x));
+
+            GEMV::default()
+                .a(a.view())
+                .x(x)
+                .y(y_raw.slice_mut(s![..;-1]))
+                .trans(trans)
+                .beta(1.0)
+                .run()
+                .unwrap();
+            check_same(&y_raw.view(), &y_naive.view(), 8.0 * f64::EPSILON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod zero_stride {
+    use super::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_broadcast() {
+        let a = random_matrix::<c32>(4, 5, 'R'.into());
+        let x = Array1::from_elem(1, c32::new(1.0, 2.0));
+        let x = x.broadcast(5).unwrap();
+        let result = GEMV::default().a(a.view()).x(x).run();
+        assert!(matches!(result, Err(BLASError::InvalidStride(_))));
+        let col = random_array::<c32>(4);
+        let a = col.broadcast((5, 4)).unwrap();
+        let result = GEMV::default().a(a.t()).x(random_array::<c32>(5).view()).run();
+        assert!(matches!(result, Err(BLASError::InvalidStride(_))));
+    }
+}
+
+#[cfg(test)]
+mod scalar_into {
+    use super::*;
+
+    #[test]
+    fn test_complex_tuple() {
+        let a = random_matrix::<c32>(4, 5, 'C'.into());
+        let x = random_array::<c32>(5);
+        let y = random_array::<c32>(4);
+        let mut y_out = y.clone();
+        GEMV::default()
+            .a(a.view())
+            .x(x.view())
+            .y(y_out.view_mut())
+            .alpha((0.5, 1.0))
+            .beta(2.0)
+            .run()
+            .unwrap();
+        let y_naive = gemv(&a.view(), &x.view()) * c32::new(0.5, 1.0) + &y * c32::new(2.0, 0.0);
+        check_same(&y_out.view(), &y_naive.view(), 4.0 * f32::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod trans_str {
+    use super::*;
+
+    #[test]
+    fn test_trans_str() {
+        let a = random_matrix::<c64>(4, 3, 'R'.into());
+        let x = random_array::<c64>(4);
+        for (s, c) in [("T", 'T'), ("c", 'C')] {
+            let y_str = GEMV::default().a(a.view()).x(x.view()).trans_str(s).run().unwrap().into_owned();
+            let y_char = GEMV::default().a(a.view()).x(x.view()).trans(c).run().unwrap().into_owned();
+            assert_eq!(y_str, y_char);
+        }
+
+        for s in ["", "X", "NT"] {
+            let err = GEMV::default().a(a.view()).x(x.view()).trans_str(s).run().unwrap_err();
+            assert!(matches!(err, BLASError::InvalidFlag(_)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod as_vector {
+    use super::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_matrix_shaped_x() {
+        let a = random_matrix::<c64>(6, 4, 'R'.into());
+        let x = random_array::<c64>(4);
+        let y_ref = GEMV::default().a(a.view()).x(x.view()).run().unwrap().into_owned();
+
+        let x_col = x.clone().into_shape((4, 1)).unwrap();
+        let y = GEMV::default().a(a.view()).x(x_col.view()).run().unwrap().into_owned();
+        check_same(&y.view(), &y_ref.view(), 4.0 * f64::EPSILON);
+
+        let x_row = x.clone().into_shape((1, 4)).unwrap();
+        let y = GEMV::default().a(a.view()).x(x_row.view()).run().unwrap().into_owned();
+        check_same(&y.view(), &y_ref.view(), 4.0 * f64::EPSILON);
+
+        // strided matrix-shaped vector
+        let x_raw = random_matrix::<c64>(8, 3, 'C'.into());
+        let x_col = x_raw.slice(s![..;2, 1..2]);
+        let y = GEMV::default().a(a.view()).x(x_col).run().unwrap().into_owned();
+        let y_ref = GEMV::default().a(a.view()).x(x_raw.slice(s![..;2, 1])).run().unwrap().into_owned();
+        check_same(&y.view(), &y_ref.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_matrix_shaped_y() {
+        let a = random_matrix::<f64>(6, 4, 'C'.into());
+        let x = random_array::<f64>(6);
+        let y = random_array::<f64>(4);
+        let mut y_ref = y.clone();
+        GEMV::default().a(a.view()).x(x.view()).y(y_ref.view_mut()).trans('T').beta(0.5).run().unwrap();
+
+        let mut y_col = y.clone().into_shape((4, 1)).unwrap();
+        GEMV::default().a(a.view()).x(x.view()).y(y_col.view_mut()).trans('T').beta(0.5).run().unwrap();
+        check_same(&y_col.column(0), &y_ref.view(), 4.0 * f64::EPSILON);
+
+        let mut y_row = y.clone().into_shape((1, 4)).unwrap();
+        let x_row = x.clone().into_shape((1, 6)).unwrap();
+        GEMV::default().a(a.view()).x(x_row.view()).y(y_row.view_mut()).trans('T').beta(0.5).run().unwrap();
+        check_same(&y_row.row(0), &y_ref.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_invalid_shape() {
+        let a = random_matrix::<f64>(6, 4, 'R'.into());
+        let x = random_matrix::<f64>(2, 2, 'R'.into());
+        let err = GEMV::default().a(a.view()).x(x.view()).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+
+        let x = random_array::<f64>(4);
+        let mut y = random_matrix::<f64>(3, 2, 'R'.into());
+        let err = GEMV::default().a(a.view()).x(x.view()).y(y.view_mut()).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+
+        let err = GEMV::<f64>::default().a(a.view()).run().unwrap_err();
+        assert!(matches!(err, BLASError::UninitializedField("x")));
+    }
+
+    #[test]
+    fn test_array_reference() {
+        let a = random_matrix::<f64>(6, 4, 'C'.into());
+        let x = random_array::<f64>(4);
+        let y = random_array::<f64>(6);
+        let mut y_ref = y.clone();
+        GEMV::default().a(a.view()).x(x.view()).y(y_ref.view_mut()).beta(0.5).run().unwrap();
+
+        let mut y_out = y.clone();
+        GEMV::default().a(a.view()).x(&x).y(&mut y_out).beta(0.5).run().unwrap();
+        check_same(&y_out.view(), &y_ref.view(), 4.0 * f64::EPSILON);
+
+        let x_col = x.clone().into_shape((4, 1)).unwrap();
+        let mut y_col = y.clone().into_shape((6, 1)).unwrap();
+        GEMV::default().a(a.view()).x(&x_col).y(&mut y_col).beta(0.5).run().unwrap();
+        check_same(&y_col.column(0), &y_ref.view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod inner_driver {
+    use super::*;
+    use blas_array2::blas2::gemv::GEMV_Builder;
+
+    #[test]
+    fn test_inner_driver_errors() {
+        // inner driver only accepts col-major `a`, and returns error instead of panic
+        let a = random_matrix::<f64>(5, 4, 'R'.into());
+        let x = random_array::<f64>(4);
+        let obj = GEMV_Builder::default().a(a.view()).x(x.view()).build().unwrap();
+        assert!(matches!(obj.driver(), Err(BLASError::InvalidStride(_))));
+
+        let a = random_matrix::<f64>(5, 4, 'C'.into());
+        let obj = GEMV_Builder::default().a(a.view()).x(x.view()).build().unwrap();
+        assert!(obj.driver().is_ok());
+    }
+}
+
+#[cfg(test)]
+mod with_lda {
+    use super::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_sub_block() {
+        // 5 x 4 sub-block at (1, 2) of a row-major 9 x 7 buffer
+        let full = random_matrix::<f32>(9, 7, 'R'.into());
+        let x = random_array::<f32>(4);
+        let data = full.as_slice().unwrap();
+        let a = ArrayView2::from_shape((5, 4), &data[7 + 2..7 + 2 + 20]).unwrap();
+        let y = unsafe { GEMV::default().a(a).with_lda(7).unwrap() }.x(x.view()).run().unwrap().into_owned();
+        let y_naive = gemv(&full.slice(s![1..6, 2..6]), &x.view());
+        check_same(&y.view(), &y_naive.view(), 4.0 * f32::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod strided_y {
+    use super::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_row_major_accumulate_into_strided_y() {
+        // row-major `a` flips trans; stride of `y` should be kept, and other elements of buffer untouched
+        let alpha = c64::new(1.5, -0.5);
+        let beta = c64::new(0.5, 0.25);
+        for trans in ['N', 'T', 'C'] {
+            for y_slc in [s![1..;2], s![..;-3], s![2..13;4]] {
+                let a_raw = random_matrix::<c64>(8, 8, 'R'.into());
+                let y_raw = random_array::<c64>(16);
+                let mut y = y_raw.clone();
+                let n = y.slice(y_slc).len();
+                let a = a_raw.slice(s![..n, ..5]);
+                let a = if trans == 'N' { a } else { a_raw.slice(s![..5, ..n]) };
+                let x = random_array::<c64>(5);
+                assert!(!a.is_fpref());
+
+                GEMV::default()
+                    .a(a)
+                    .x(x.view())
+                    .y(y.slice_mut(y_slc))
+                    .trans(trans)
+                    .alpha(alpha)
+                    .beta(beta)
+                    .run()
+                    .unwrap();
+
+                let a_op = transpose(&a, trans.into());
+                let mut y_naive = y_raw.clone();
+                let y_assign = gemv(&a_op.view(), &x.view()) * alpha + &y_raw.slice(y_slc) * beta;
+                y_naive.slice_mut(y_slc).assign(&y_assign);
+                check_same(&y.view(), &y_naive.view(), 4.0 * f64::EPSILON);
+
+                // elements outside of the slice are bitwise untouched
+                let mut mask = Array1::from_elem(16, true);
+                mask.slice_mut(y_slc).fill(false);
+                for (i, &m) in mask.iter().enumerate() {
+                    if m {
+                        assert_eq!(y[i], y_raw[i]);
+                    }
+                }
+            }
+        }
+    }
+}
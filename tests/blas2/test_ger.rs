@@ -113,3 +113,18 @@ mod valid_cblas {
         }
     }
 }
+
+#[cfg(test)]
+mod negative_increment {
+    use super::*;
+
+    #[test]
+    fn test_reversed_vectors() {
+        let x_raw = random_array::<f64>(16);
+        let y_raw = random_array::<f64>(15);
+        let (x, y) = (x_raw.slice(s![..;-2]), y_raw.slice(s![..;-3]));
+        let a_naive = Array2::from_shape_fn((x.len(), y.len()), |(i, j)| x[i] * y[j]);
+        let a = GER::<f64>::default().x(x).y(y).run().unwrap();
+        check_same(&a.view(), &a_naive.view(), 4.0 * f64::EPSILON);
+    }
+}
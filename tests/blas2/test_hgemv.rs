@@ -0,0 +1,74 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use half::f16;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    fn check_close(out: &ArrayView1<f16>, expected: &ArrayView1<f32>) {
+        for (&o, &e) in out.iter().zip(expected.iter()) {
+            // one rounding to f16 from f32 accumulation
+            assert!((o.to_f32() - e).abs() <= f16::EPSILON.to_f32() * e.abs().max(1.0), "{o} != {e}");
+        }
+    }
+
+    #[test]
+    fn test_against_f32() {
+        for (layout, trans) in [('R', 'N'), ('C', 'N'), ('R', 'T'), ('C', 'T')] {
+            let a = random_matrix::<f32>(7, 9, layout.into()).mapv(f16::from_f32);
+            let x_len = if trans == 'N' { 9 } else { 7 };
+            let x = random_array::<f32>(x_len).mapv(f16::from_f32);
+            let y_raw = random_array::<f32>(9 + 7 - x_len).mapv(f16::from_f32);
+
+            // reference computed in f32 from the same (rounded) values
+            let a32 = a.mapv(f16::to_f32);
+            let a32 = if trans == 'N' { a32 } else { a32.reversed_axes() };
+            let x32 = x.mapv(f16::to_f32);
+            let y32 = y_raw.mapv(f16::to_f32);
+
+            let out = HGEMV::default().a(a.view()).x(x.view()).trans(trans).alpha(1.5).run().unwrap();
+            check_close(&out.view(), &(gemv(&a32.view(), &x32.view()) * 1.5).view());
+
+            let mut y = y_raw.clone();
+            HGEMV::default().a(a.view()).x(x.view()).y(y.view_mut()).trans(trans).beta(0.5).run().unwrap();
+            check_close(&y.view(), &(gemv(&a32.view(), &x32.view()) + &y32 * 0.5).view());
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // identity matrix reproduces f16 values exactly through f32
+        let x = random_array::<f32>(6).mapv(f16::from_f32);
+        let a = Array2::<f32>::eye(6).mapv(f16::from_f32);
+        let out = HGEMV::default().a(a.view()).x(x.view()).run().unwrap().into_owned();
+        assert_eq!(out, x);
+        // strided input
+        let x_raw = random_array::<f32>(12).mapv(f16::from_f32);
+        let out =
+            HGEMV::default().a(a.view()).x(x_raw.slice(s![..;2])).trans('T').run().unwrap().into_owned();
+        assert_eq!(out, x_raw.slice(s![..;2]));
+    }
+
+    #[test]
+    fn test_f32_accumulation() {
+        // partial sum 60000 + 60000 overflows f16 (max 65504), but the final result does not
+        let a = Array2::from_elem((1, 3), f16::ONE);
+        let x = array![60000.0, 60000.0, -60000.0].mapv(f16::from_f32);
+        let naive = x.iter().fold(f16::ZERO, |s, &v| s + v);
+        assert!(naive.is_infinite() || naive.is_nan());
+
+        let out = HGEMV::default().a(a.view()).x(x.view()).run().unwrap();
+        assert_eq!(out.view()[0], f16::from_f32(60000.0));
+    }
+
+    #[test]
+    fn test_invalid_dim() {
+        let a = Array2::from_elem((3, 4), f16::ONE);
+        let x = Array1::from_elem(4, f16::ONE);
+        let mut y = Array1::from_elem(4, f16::ZERO);
+        assert!(HGEMV::default().a(a.view()).x(x.view()).y(y.view_mut()).run().is_err());
+        assert!(HGEMV::default().a(a.view()).x(x.view()).trans('T').run().is_err());
+    }
+}
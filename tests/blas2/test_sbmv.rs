@@ -1,3 +1,4 @@
+use crate::util::hermitianize;
 use crate::util::*;
 use approx::*;
 use blas_array2::prelude::*;
@@ -263,3 +264,31 @@ mod valid_row_major {
         }
     }
 }
+
+#[cfg(test)]
+mod dense_fallback {
+    use super::*;
+
+    #[test]
+    fn test_band_and_dense_agree() {
+        let (n, k) = (9, 3);
+        for (layout, uplo) in iproduct!(['C', 'R'], ['U', 'L']) {
+            let a = match layout {
+                'C' => random_matrix::<c64>(k + 1, n, 'C'.into()),
+                _ => random_matrix::<c64>(n, k + 1, 'R'.into()),
+            };
+            let x = random_array::<c64>(n);
+            let run = |dense_fallback: bool| {
+                let obj = HBMV::default()
+                    .a(a.view())
+                    .x(x.view())
+                    .alpha(c64::new(0.5, 2.0))
+                    .uplo(uplo)
+                    .layout(layout);
+                let out = if dense_fallback { obj.run_dense_fallback() } else { obj.run() };
+                out.unwrap().into_owned()
+            };
+            check_same(&run(true).view(), &run(false).view(), 4.0 * f64::EPSILON);
+        }
+    }
+}
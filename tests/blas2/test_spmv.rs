@@ -131,3 +131,25 @@ mod valid_row_major {
         }
     }
 }
+
+#[cfg(test)]
+mod strided_ap {
+    use super::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_strided_and_negative() {
+        let n = 5;
+        let ap = random_array::<c64>(n * (n + 1) / 2);
+        let x = random_array::<c64>(n);
+        let (buf, rev) = strided_copies(&ap);
+        for (uplo, layout) in [('U', 'C'), ('L', 'R')] {
+            let run = |ap: ArrayView1<c64>| {
+                HPMV::default().ap(ap).x(x.view()).uplo(uplo).layout(layout).run().unwrap().into_owned()
+            };
+            let y_ref = run(ap.view());
+            assert_eq!(run(buf.column(1)), y_ref);
+            assert_eq!(run(rev.slice(s![..;-1])), y_ref);
+        }
+    }
+}
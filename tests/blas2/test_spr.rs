@@ -143,3 +143,48 @@ mod valid_cblas {
         }
     }
 }
+
+#[cfg(test)]
+mod strided_ap {
+    use super::*;
+
+    #[test]
+    fn test_strided_and_negative_output() {
+        let n = 5;
+        let ap = random_array::<c64>(n * (n + 1) / 2);
+        let x = random_array::<c64>(n);
+        let (buf, rev) = strided_copies(&ap);
+        for (uplo, layout) in [('U', 'C'), ('L', 'R')] {
+            let mut ap_ref = ap.clone();
+            HPR::default()
+                .ap(ap_ref.view_mut())
+                .x(x.view())
+                .alpha(0.5)
+                .uplo(uplo)
+                .layout(layout)
+                .run()
+                .unwrap();
+            let mut buf = buf.clone();
+            HPR::default()
+                .ap(buf.column_mut(1))
+                .x(x.view())
+                .alpha(0.5)
+                .uplo(uplo)
+                .layout(layout)
+                .run()
+                .unwrap();
+            assert_eq!(buf.column(1), ap_ref);
+            assert!(buf.column(0).iter().all(|&v| v == c64::from(0.0)));
+            let mut rev = rev.clone();
+            HPR::default()
+                .ap(rev.slice_mut(s![..;-1]))
+                .x(x.view())
+                .alpha(0.5)
+                .uplo(uplo)
+                .layout(layout)
+                .run()
+                .unwrap();
+            assert_eq!(rev.slice(s![..;-1]), ap_ref);
+        }
+    }
+}
@@ -112,3 +112,30 @@ mod valid_cblas {
         }
     }
 }
+
+#[cfg(test)]
+mod strided_ap {
+    use super::*;
+
+    #[test]
+    fn test_strided_and_negative_output() {
+        let n = 5;
+        let ap = random_array::<c64>(n * (n + 1) / 2);
+        let x = random_array::<c64>(n);
+        let y = random_array::<c64>(n);
+        let (buf, rev) = strided_copies(&ap);
+        for (uplo, layout) in [('U', 'C'), ('L', 'R')] {
+            let run = |ap: ArrayViewMut1<c64>| {
+                HPR2::default().ap(ap).x(x.view()).y(y.view()).uplo(uplo).layout(layout).run().unwrap();
+            };
+            let mut ap_ref = ap.clone();
+            run(ap_ref.view_mut());
+            let mut buf = buf.clone();
+            run(buf.column_mut(1));
+            assert_eq!(buf.column(1), ap_ref);
+            let mut rev = rev.clone();
+            run(rev.slice_mut(s![..;-1]));
+            assert_eq!(rev.slice(s![..;-1]), ap_ref);
+        }
+    }
+}
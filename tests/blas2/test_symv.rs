@@ -1,3 +1,4 @@
+use crate::util::hermitianize;
 use crate::util::*;
 use approx::*;
 use blas_array2::prelude::*;
@@ -94,3 +95,47 @@ mod valid {
     test_macro!(test_014: inline, c64, (8, 8, 3, 1), (8, 1), (8, 1), 'C', 'L', HEMV, hermitianize);
     test_macro!(test_015: inline, c64, (8, 8, 3, 1), (8, 1), (8, 3), 'R', 'U', HEMV, hermitianize);
 }
+
+#[cfg(test)]
+mod auto_uplo {
+    use super::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_symv() {
+        // data in lower triangle, default uplo is upper
+        let a_full = symmetrize(&random_matrix::<f32>(6, 6, 'C'.into()).view(), 'L');
+        let a = Array2::from_shape_fn((6, 6), |(i, j)| if i >= j { a_full[[i, j]] } else { 0.0 });
+        let x = random_array::<f32>(6);
+        let y_naive = gemv(&a_full.view(), &x.view());
+        let y = SSYMV::default().a(a.view()).x(x.view()).auto_uplo_from_data(true).run().unwrap();
+        check_same(&y.view(), &y_naive.view(), 4.0 * f32::EPSILON);
+    }
+
+    #[test]
+    fn test_hemv() {
+        let a_full = hermitianize(&random_matrix::<c32>(6, 6, 'R'.into()).view(), 'U');
+        let a =
+            Array2::from_shape_fn((6, 6), |(i, j)| if i <= j { a_full[[i, j]] } else { c32::new(0.0, 0.0) });
+        let x = random_array::<c32>(6);
+        let y_naive = gemv(&a_full.view(), &x.view());
+        let y = CHEMV::default().a(a.view()).x(x.view()).uplo('L').auto_uplo_from_data(true).run().unwrap();
+        check_same(&y.view(), &y_naive.view(), 4.0 * f32::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod uplo_validation {
+    use super::*;
+
+    #[test]
+    fn test_invalid_uplo() {
+        let a = random_matrix::<c64>(4, 4, 'R'.into());
+        let x = random_array::<c64>(4);
+        let err = HEMV::default().a(a.view()).x(x.view()).uplo(BLASUpLo::Undefined).build().err().unwrap();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+        let err = HEMV::default().a(a.view()).x(x.view()).uplo('X').run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+        assert!(HEMV::default().a(a.view()).x(x.view()).uplo('U').run().is_ok());
+    }
+}
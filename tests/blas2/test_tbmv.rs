@@ -173,3 +173,34 @@ mod valid_row_major {
         }
     }
 }
+
+#[cfg(test)]
+mod dense_fallback {
+    use super::*;
+
+    #[test]
+    fn test_band_and_dense_agree() {
+        let (n, k) = (9, 3);
+        for (layout, uplo, trans, diag) in iproduct!(['C', 'R'], ['U', 'L'], ['N', 'T', 'C'], ['N', 'U']) {
+            let a = match layout {
+                'C' => random_matrix::<c32>(k + 1, n, 'C'.into()),
+                _ => random_matrix::<c32>(n, k + 1, 'R'.into()),
+            };
+            let x = random_array::<c32>(n);
+            let run = |dense_fallback: bool| {
+                let mut x_out = x.clone();
+                let obj = TBMV::default()
+                    .a(a.view())
+                    .x(x_out.view_mut())
+                    .uplo(uplo)
+                    .trans(trans)
+                    .diag(diag)
+                    .layout(layout);
+                let out = if dense_fallback { obj.run_dense_fallback() } else { obj.run() };
+                out.unwrap();
+                x_out
+            };
+            check_same(&run(true).view(), &run(false).view(), 4.0 * f32::EPSILON);
+        }
+    }
+}
@@ -152,3 +152,86 @@ mod valid_row_major {
         }
     }
 }
+
+#[cfg(test)]
+mod packed_order {
+    use super::*;
+
+    #[test]
+    fn test_both_orderings() {
+        // the same triangle packed in the order of `uplo`, and in the order of the other triangle
+        let n = 6;
+        let a = random_matrix::<c64>(n, n, 'R'.into()) + Array2::<c64>::eye(n) * c64::from(4.0);
+        let x = random_array::<c64>(n);
+        for (uplo, layout, trans) in [('L', 'C', 'N'), ('U', 'C', 'T'), ('L', 'R', 'C'), ('U', 'R', 'N')] {
+            let other = if uplo == 'L' { PackedOrder::Upper } else { PackedOrder::Lower };
+            let same = if uplo == 'L' { PackedOrder::Lower } else { PackedOrder::Upper };
+            let other_layout = if layout == 'C' { 'R' } else { 'C' };
+            let ap = pack_tril(&a.view(), layout, uplo);
+            let ap_other = pack_tril(&a.view(), other_layout, uplo);
+            assert_ne!(ap, ap_other);
+
+            let run = |ap: ArrayView1<c64>, order: Option<PackedOrder>| {
+                let mut x_out = x.clone();
+                let obj = TPMV::default().ap(ap).x(x_out.view_mut()).uplo(uplo).trans(trans).layout(layout);
+                match order {
+                    Some(order) => obj.packed_order(order).run().unwrap(),
+                    None => obj.run().unwrap(),
+                };
+                x_out
+            };
+            let x_default = run(ap.view(), None);
+            assert_eq!(run(ap.view(), Some(same)), x_default);
+            assert_eq!(run(ap_other.view(), Some(other)), x_default);
+            // strided packed array is also accepted
+            let ap_other_strided =
+                ap_other.iter().flat_map(|&v| [v, c64::from(0.0)]).collect::<Array1<c64>>();
+            assert_eq!(run(ap_other_strided.slice(s![..;2]), Some(other)), x_default);
+
+            let mut a_tri = Array2::<c64>::zeros((n, n));
+            unpack_tril(&ap.view(), &mut a_tri.view_mut(), layout, uplo);
+            let a_tri = transpose(&a_tri.view(), trans.into());
+            check_same(&x_default.view(), &gemv(&a_tri.view(), &x.view()).view(), 4.0 * f64::EPSILON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod strided_ap {
+    use super::*;
+    use blas_array2::blas2::tpmv::TPMV_Builder;
+
+    #[test]
+    fn test_strided_and_negative() {
+        let n = 5;
+        let ap = random_array::<c64>(n * (n + 1) / 2);
+        let x = random_array::<c64>(n);
+        let (buf, rev) = strided_copies(&ap);
+        for (uplo, layout, trans) in [('U', 'C', 'N'), ('L', 'R', 'T'), ('U', 'R', 'C')] {
+            let run = |ap: ArrayView1<c64>| {
+                let mut x_out = x.clone();
+                TPMV::default()
+                    .ap(ap)
+                    .x(x_out.view_mut())
+                    .uplo(uplo)
+                    .layout(layout)
+                    .trans(trans)
+                    .run()
+                    .unwrap();
+                x_out
+            };
+            let x_ref = run(ap.view());
+            assert_eq!(run(buf.column(1)), x_ref);
+            assert_eq!(run(rev.slice(s![..;-1])), x_ref);
+        }
+    }
+
+    #[test]
+    fn test_inner_driver_rejects_strided() {
+        let ap = random_array::<c64>(6);
+        let mut x = random_array::<c64>(3);
+        let (buf, _) = strided_copies(&ap);
+        let obj = TPMV_Builder::default().ap(buf.column(1)).x(x.view_mut()).layout('C').build().unwrap();
+        assert!(matches!(obj.driver(), Err(BLASError::InvalidStride(_))));
+    }
+}
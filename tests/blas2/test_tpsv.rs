@@ -3,6 +3,7 @@ use blas_array2::blas2::tpsv::TPSV;
 use blas_array2::prelude::*;
 use cblas_sys::*;
 use itertools::*;
+use ndarray::prelude::*;
 
 #[cfg(test)]
 mod valid_row_major {
@@ -63,3 +64,116 @@ mod valid_row_major {
         }
     }
 }
+
+#[cfg(test)]
+mod warn_ill_conditioned {
+    use super::*;
+
+    #[test]
+    fn test_result_unchanged() {
+        // upper col-major packed storage, diagonal at 0, 2, 5
+        let mut ap = random_array::<c64>(6);
+        ap[2] = c64::new(0.0, 1e-18);
+        let b = random_array::<c64>(3);
+        let mut x_ref = b.clone();
+        reset_trace_count();
+        TPSV::default().ap(ap.view()).x(x_ref.view_mut()).uplo('U').layout('C').run().unwrap();
+        assert_eq!(trace_count(), 0);
+        let mut x_warn = b.clone();
+        TPSV::default()
+            .ap(ap.view())
+            .x(x_warn.view_mut())
+            .uplo('U')
+            .layout('C')
+            .warn_ill_conditioned(true)
+            .run()
+            .unwrap();
+        assert_eq!(trace_count(), 1);
+        assert_eq!(x_ref, x_warn);
+    }
+
+    #[test]
+    fn test_invalid_packed_length() {
+        let ap = random_array::<c64>(5);
+        let mut x = random_array::<c64>(3);
+        let err = TPSV::default().ap(ap.view()).x(x.view_mut()).warn_ill_conditioned(true).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+    }
+}
+
+#[cfg(test)]
+mod packed_order {
+    use super::*;
+
+    #[test]
+    fn test_both_orderings() {
+        // the same triangle packed in the order of `uplo`, and in the order of the other triangle
+        let n = 6;
+        let a = random_matrix::<c64>(n, n, 'R'.into()) + Array2::<c64>::eye(n) * c64::from(4.0);
+        let x = random_array::<c64>(n);
+        for (uplo, layout, trans) in [('L', 'C', 'N'), ('U', 'C', 'T'), ('L', 'R', 'C'), ('U', 'R', 'N')] {
+            let other = if uplo == 'L' { PackedOrder::Upper } else { PackedOrder::Lower };
+            let same = if uplo == 'L' { PackedOrder::Lower } else { PackedOrder::Upper };
+            let other_layout = if layout == 'C' { 'R' } else { 'C' };
+            let ap = pack_tril(&a.view(), layout, uplo);
+            let ap_other = pack_tril(&a.view(), other_layout, uplo);
+            assert_ne!(ap, ap_other);
+
+            let run = |ap: ArrayView1<c64>, order: Option<PackedOrder>| {
+                let mut x_out = x.clone();
+                let obj = TPSV::default().ap(ap).x(x_out.view_mut()).uplo(uplo).trans(trans).layout(layout);
+                match order {
+                    Some(order) => obj.packed_order(order).run().unwrap(),
+                    None => obj.run().unwrap(),
+                };
+                x_out
+            };
+            let x_default = run(ap.view(), None);
+            assert_eq!(run(ap.view(), Some(same)), x_default);
+            assert_eq!(run(ap_other.view(), Some(other)), x_default);
+            // strided packed array is also accepted
+            let ap_other_strided =
+                ap_other.iter().flat_map(|&v| [v, c64::from(0.0)]).collect::<Array1<c64>>();
+            assert_eq!(run(ap_other_strided.slice(s![..;2]), Some(other)), x_default);
+
+            let mut a_tri = Array2::<c64>::zeros((n, n));
+            unpack_tril(&ap.view(), &mut a_tri.view_mut(), layout, uplo);
+            let a_tri = transpose(&a_tri.view(), trans.into());
+            check_same(&gemv(&a_tri.view(), &x_default.view()).view(), &x.view(), 4.0 * f64::EPSILON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod strided_ap {
+    use super::*;
+
+    #[test]
+    fn test_strided_and_negative() {
+        let n = 5;
+        let mut ap = random_array::<c64>(n * (n + 1) / 2);
+        // well-conditioned diagonal (upper col-major and lower row-major packing share diagonal positions)
+        for j in 0..n {
+            ap[j * (j + 3) / 2] += c64::from(4.0);
+        }
+        let x = random_array::<c64>(n);
+        let (buf, rev) = strided_copies(&ap);
+        for (uplo, layout, trans) in [('U', 'C', 'N'), ('L', 'R', 'T'), ('U', 'C', 'C')] {
+            let run = |ap: ArrayView1<c64>| {
+                let mut x_out = x.clone();
+                TPSV::default()
+                    .ap(ap)
+                    .x(x_out.view_mut())
+                    .uplo(uplo)
+                    .layout(layout)
+                    .trans(trans)
+                    .run()
+                    .unwrap();
+                x_out
+            };
+            let x_ref = run(ap.view());
+            assert_eq!(run(buf.column(1)), x_ref);
+            assert_eq!(run(rev.slice(s![..;-1])), x_ref);
+        }
+    }
+}
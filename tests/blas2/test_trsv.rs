@@ -102,3 +102,61 @@ mod valid {
     test_macro!(test_022: inline, c64, (8, 8, 3, 1), (8, 3), 'R', 'L', 'T', 'N');
     test_macro!(test_023: inline, c64, (8, 8, 3, 3), (8, 1), 'R', 'L', 'N', 'U');
 }
+
+#[cfg(test)]
+mod solve {
+    use super::*;
+    use blas_array2::blas2::trmv::TRMV;
+
+    #[test]
+    fn test_solve() {
+        for (layout, uplo, trans) in [('R', 'L', 'N'), ('C', 'U', 'T'), ('C', 'L', 'C'), ('R', 'U', 'C')] {
+            let mut a = random_matrix::<c64>(6, 6, layout.into());
+            // keep the system well-conditioned
+            a.diag_mut().mapv_inplace(|x| x + c64::new(6.0, 0.0));
+            let b = random_array::<c64>(12);
+            let b = b.slice(s![..;2]);
+            let b_orig = b.to_owned();
+
+            let y = TRSV::default().a(a.view()).uplo(uplo).trans(trans).solve(b).unwrap();
+            assert_eq!(b, b_orig);
+
+            let mut b_recon = y.clone();
+            TRMV::default().a(a.view()).x(b_recon.view_mut()).uplo(uplo).trans(trans).run().unwrap();
+            check_same(&b_recon.view(), &b, 8.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_solve_invalid() {
+        let a = random_matrix::<f64>(4, 4, 'R'.into());
+        let b = random_array::<f64>(3);
+        assert!(TRSV::default().a(a.view()).solve(b.view()).is_err());
+        let b = random_array::<f64>(4);
+        let mut x = random_array::<f64>(4);
+        assert!(TRSV::default().a(a.view()).x(x.view_mut()).solve(b.view()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod warn_ill_conditioned {
+    use super::*;
+
+    #[test]
+    fn test_result_unchanged() {
+        for layout in ['R', 'C'] {
+            let mut a = random_matrix::<f64>(5, 5, layout.into());
+            a[[3, 3]] = 1e-18;
+            assert_eq!(small_diagonal_index(a.diag()), Some(3));
+
+            let b = random_array::<f64>(5);
+            reset_trace_count();
+            let x_ref = TRSV::default().a(a.view()).uplo('L').solve(b.view()).unwrap();
+            assert_eq!(trace_count(), 0);
+            let x_warn =
+                TRSV::default().a(a.view()).uplo('L').warn_ill_conditioned(true).solve(b.view()).unwrap();
+            assert_eq!(trace_count(), 1);
+            assert_eq!(x_ref, x_warn);
+        }
+    }
+}
@@ -1,7 +1,10 @@
+pub mod test_empty;
 pub mod test_gemm;
+pub mod test_gemm_rc;
 pub mod test_symm;
 pub mod test_syr2k;
 pub mod test_syrk;
+pub mod test_syrk_batch;
 pub mod test_trmm;
 pub mod test_trsm;
 
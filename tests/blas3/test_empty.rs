@@ -0,0 +1,133 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+/// Shapes with empty dimensions: `0 x k`, `m x 0` and `0 x 0`.
+const SHAPES: [(usize, usize); 3] = [(0, 3), (3, 0), (0, 0)];
+
+/// Check that the `uplo` triangle of `c` is `c_orig` scaled by `beta` (zero if `beta` is zero).
+fn check_triangle_scaled<F>(c: &ArrayView2<F>, c_orig: &ArrayView2<F>, beta: F, uplo: char)
+where
+    F: TestFloat,
+{
+    for ((i, j), &v) in c.indexed_iter() {
+        if (uplo == 'L' && i >= j) || (uplo == 'U' && i <= j) {
+            let expected = if beta == F::zero() { F::zero() } else { c_orig[[i, j]] * beta };
+            assert_eq!(v, expected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod general {
+    use super::*;
+
+    #[test]
+    fn test_gemm() {
+        for (m, k) in SHAPES {
+            for n in [0, 2] {
+                for layout in ['R', 'C'] {
+                    let a = random_matrix::<c64>(m, k, layout.into());
+                    let b = random_matrix::<c64>(k, n, layout.into());
+
+                    let c_out = GEMM::default().a(a.view()).b(b.view()).run().unwrap();
+                    assert_eq!(c_out.view(), Array2::<c64>::zeros((m, n)));
+
+                    let mut c = Array2::from_elem((m, n), c64::new(f64::NAN, 0.0));
+                    GEMM::default().a(a.view()).b(b.view()).c(c.view_mut()).run().unwrap();
+                    assert_eq!(c, Array2::<c64>::zeros((m, n)));
+
+                    let c_orig = random_matrix::<c64>(m, n, layout.into());
+                    let mut c = c_orig.clone();
+                    GEMM::default().a(a.view()).b(b.view()).c(c.view_mut()).beta(2.0).run().unwrap();
+                    assert_eq!(c, &c_orig * c64::from(2.0));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gemm_rc() {
+        for (m, k) in SHAPES {
+            let a = random_matrix::<f64>(m, k, 'R'.into());
+            let b = random_matrix::<c64>(k, 2, 'R'.into());
+            let c_out = GEMM_RC::default().a(a.view()).b(b.view()).run().unwrap();
+            assert_eq!(c_out.view().dim(), (m, 2));
+        }
+    }
+
+    #[test]
+    fn test_trmm_trsm() {
+        for (m, n) in SHAPES {
+            for side in ['L', 'R'] {
+                let na = if side == 'L' { m } else { n };
+                let a = random_matrix::<f64>(na, na, 'R'.into());
+                let b_orig = random_matrix::<f64>(m, n, 'C'.into());
+                let mut b = b_orig.clone();
+                TRMM::default().a(a.view()).b(b.view_mut()).side(side).run().unwrap();
+                TRSM::default().a(a.view()).b(b.view_mut()).side(side).run().unwrap();
+                assert_eq!(b, b_orig);
+            }
+        }
+    }
+
+    #[test]
+    fn test_symm_hemm() {
+        for (m, n) in SHAPES {
+            for side in ['L', 'R'] {
+                let na = if side == 'L' { m } else { n };
+                let a = random_matrix::<c64>(na, na, 'R'.into());
+                let b = random_matrix::<c64>(m, n, 'C'.into());
+                let c_out = SYMM::default().a(a.view()).b(b.view()).side(side).run().unwrap();
+                assert_eq!(c_out.view().dim(), (m, n));
+                let c_out = HEMM::default().a(a.view()).b(b.view()).side(side).run().unwrap();
+                assert_eq!(c_out.view().dim(), (m, n));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod rank_k {
+    use super::*;
+
+    #[test]
+    fn test_syrk_herk() {
+        for (n, k) in SHAPES {
+            for uplo in ['L', 'U'] {
+                let a = random_matrix::<c64>(n, k, 'R'.into());
+                // diagonal of hermitian output is real
+                let mut c_orig = random_matrix::<c64>(n, n, 'C'.into());
+                c_orig.diag_mut().mapv_inplace(|v| c64::from(v.re));
+
+                let mut c = c_orig.clone();
+                SYRK::default().a(a.view()).c(c.view_mut()).uplo(uplo).run().unwrap();
+                check_triangle_scaled(&c.view(), &c_orig.view(), c64::from(0.0), uplo);
+                let mut c = c_orig.clone();
+                SYRK::default().a(a.view()).c(c.view_mut()).uplo(uplo).beta((0.0, 1.0)).run().unwrap();
+                check_triangle_scaled(&c.view(), &c_orig.view(), c64::new(0.0, 1.0), uplo);
+
+                let mut c = c_orig.clone();
+                HERK::default().a(a.view()).c(c.view_mut()).uplo(uplo).beta(3.0).run().unwrap();
+                check_triangle_scaled(&c.view(), &c_orig.view(), c64::from(3.0), uplo);
+
+                let b = random_matrix::<c64>(n, k, 'R'.into());
+                let mut c = c_orig.clone();
+                SYR2K::default().a(a.view()).b(b.view()).c(c.view_mut()).uplo(uplo).beta(2.0).run().unwrap();
+                check_triangle_scaled(&c.view(), &c_orig.view(), c64::from(2.0), uplo);
+                let mut c = c_orig.clone();
+                HER2K::default().a(a.view()).b(b.view()).c(c.view_mut()).uplo(uplo).run().unwrap();
+                check_triangle_scaled(&c.view(), &c_orig.view(), c64::from(0.0), uplo);
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch() {
+        for (n, k) in SHAPES {
+            let a = Array3::<f64>::zeros((2, n, k));
+            let c_out = SYRKBatch::default().a(a.view()).run().unwrap();
+            assert_eq!(c_out.view().dim(), (2, n, n));
+        }
+    }
+}
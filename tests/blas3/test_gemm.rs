@@ -544,3 +544,1608 @@ mod valid_cblas {
     test_macro!(test_010: inline, c32, cblas_cgemm, (8, 7, 1, 1), (9, 8, 1, 1), (7, 9, 1, 1), 'C', 'C', 'C', 'C', 'C', 'C');
     test_macro!(test_011: inline, c32, cblas_cgemm, (8, 7, 1, 1), (9, 8, 1, 1), (7, 9, 1, 1), 'C', 'C', 'C', 'C', 'C', 'C');
 }
+
+#[cfg(test)]
+mod check_finite {
+    use super::*;
+
+    #[test]
+    fn test_nan_alpha() {
+        let a = random_matrix::<f64>(5, 4, 'R'.into());
+        let b = random_matrix::<f64>(4, 3, 'C'.into());
+
+        let result = GEMM::default().a(a.view()).b(b.view()).alpha(f64::NAN).check_finite(true).run();
+        assert!(matches!(result, Err(BLASError::NonFinite(_))));
+        let result = GEMM::default().a(a.view()).b(b.view()).beta(f64::INFINITY).check_finite(true).run();
+        assert!(matches!(result, Err(BLASError::NonFinite(_))));
+
+        // not checked by default
+        let c = GEMM::default().a(a.view()).b(b.view()).alpha(f64::NAN).run().unwrap().into_owned();
+        assert!(c.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_nan_complex() {
+        let a = random_matrix::<c64>(5, 4, 'R'.into());
+        let mut b = random_matrix::<c64>(4, 3, 'C'.into());
+
+        let alpha = c64::new(1.0, f64::NAN);
+        let result = GEMM::default().a(a.view()).b(b.view()).alpha(alpha).check_finite(true).run();
+        assert!(matches!(result, Err(BLASError::NonFinite(_))));
+
+        b[[1, 2]] = c64::new(f64::INFINITY, 0.0);
+        let result = GEMM::default().a(a.view()).b(b.view()).check_finite(true).run();
+        assert!(matches!(result, Err(BLASError::NonFinite(_))));
+        assert!(GEMM::default().a(a.view()).b(b.view()).run().is_ok());
+    }
+}
+
+#[cfg(test)]
+mod assume_layout {
+    use super::*;
+
+    #[test]
+    fn test_degenerate_row() {
+        // shape [1, 10] with strides [30, 3]: inferred as non-contiguous, but valid as col-major
+        let a_raw = random_matrix::<c64>(4, 30, 'R'.into());
+        let a = a_raw.slice(s![1..2, ..;3]);
+        assert_eq!(get_layout_array2(&a), BLASLayout::NonContiguous);
+        let b = random_matrix::<c64>(10, 5, 'C'.into());
+        let mut c_raw = random_matrix::<c64>(3, 20, 'C'.into());
+        let c_naive = gemm(&a, &b.view());
+
+        let c_out = GEMM::default().a(a).b(b.view()).assume_layout(BLASColMajor).run().unwrap();
+        check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+        let c = c_raw.slice_mut(s![1..2, ..;4]);
+        GEMM::default().a(a).b(b.view()).c(c).transb('N').assume_layout(BLASColMajor).run().unwrap();
+        check_same(&c_raw.slice(s![1..2, ..;4]), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_row_major() {
+        let a_raw = random_matrix::<f64>(40, 4, 'C'.into());
+        let a = a_raw.slice(s![..;4, 1..2]);
+        let b = random_matrix::<f64>(5, 1, 'R'.into());
+        let c_naive = gemm(&a, &b.t());
+
+        let c_out = GEMM::default().a(a).b(b.view()).transb('T').assume_layout(BLASRowMajor).run().unwrap();
+        check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        assert!(c_out.view().is_standard_layout());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let a = random_matrix::<f64>(3, 3, 'R'.into());
+        let b = random_matrix::<f64>(3, 3, 'C'.into());
+        assert!(GEMM::default().a(a.view()).b(b.view()).assume_layout(BLASColMajor).run().is_err());
+        assert!(GEMM::default().a(b.view()).b(b.view()).assume_layout(BLASLayout::Sequential).run().is_err());
+        let result =
+            GEMM::default().a(b.view()).b(b.view()).layout(BLASRowMajor).assume_layout(BLASColMajor).run();
+        assert!(result.is_err());
+        assert!(GEMM::default().a(b.view()).b(b.view()).assume_layout(BLASColMajor).run().is_ok());
+    }
+}
+
+#[cfg(test)]
+mod builder_clone {
+    use super::*;
+
+    #[test]
+    fn test_alpha_sweep() {
+        let a = random_matrix::<c64>(7, 5, 'R'.into());
+        let b = random_matrix::<c64>(6, 5, 'C'.into());
+        let c_naive = gemm(&a.view(), &b.t());
+
+        let base = GEMM::default().a(a.view()).b(b.view()).transb('T');
+        for alpha in [c64::new(1.0, 0.0), c64::new(-0.5, 2.0)] {
+            let c_out = base.clone().alpha(alpha).run().unwrap();
+            check_same(&c_out.view(), &c_naive.mapv(|x| alpha * x).view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_output_not_cloned() {
+        let a = random_matrix::<f64>(7, 5, 'R'.into());
+        let b = random_matrix::<f64>(5, 6, 'R'.into());
+        let mut c = Array2::<f64>::zeros((7, 6));
+
+        let base = GEMM::default().a(a.view()).b(b.view()).c(c.view_mut());
+        let mut c_clone = base.clone().run().unwrap();
+        assert!(c_clone.is_owned());
+        let mut c_out = base.run().unwrap();
+        assert!(c_out.is_view_mut());
+        check_same(&c_out.view(), &c_clone.view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod plan {
+    use super::*;
+
+    #[test]
+    fn test_plan_owned() {
+        let a = random_matrix::<f64>(7, 5, 'R'.into());
+        let b = random_matrix::<f64>(5, 6, 'C'.into());
+
+        let builder = GEMM::default().a(a.view()).b(b.view());
+        let plan = builder.plan().unwrap();
+        assert_eq!(plan.routine, "dgemm");
+        assert_eq!((plan.m, plan.n, plan.k), (7, 6, 5));
+        assert_eq!(plan.flops, 2 * 7 * 6 * 5);
+        assert_eq!(plan.layout, BLASRowMajor);
+        assert!(plan.copies.is_empty());
+
+        let c_out = builder.run().unwrap().into_owned();
+        assert_eq!(c_out.dim(), (plan.m, plan.n));
+        assert!(c_out.is_standard_layout());
+
+        let plan = GEMM::default().a(b.t()).b(a.t()).layout(BLASColMajor).plan().unwrap();
+        assert_eq!(plan.layout, BLASColMajor);
+        assert!(plan.copies.is_empty());
+        let c_out = GEMM::default().a(b.t()).b(a.t()).layout(BLASColMajor).run().unwrap().into_owned();
+        assert!(c_out.t().is_standard_layout());
+    }
+
+    #[test]
+    fn test_plan_copies() {
+        let a = random_matrix::<c32>(7, 5, 'R'.into());
+        let b = random_matrix::<c32>(6, 5, 'C'.into());
+        let mut c = Array2::<c32>::zeros((14, 12));
+
+        // conjugate transpose of mismatched layout, and non-contiguous output
+        let builder = GEMM::default().a(a.view()).b(b.view()).transb('C').c(c.slice_mut(s![..;2, ..;2]));
+        let plan = builder.plan().unwrap();
+        assert_eq!(plan.routine, "cgemm");
+        assert_eq!(plan.flops, 4 * 2 * 7 * 6 * 5);
+        assert_eq!(plan.copies, vec!["b", "c"]);
+        let c_naive = gemm(&a.view(), &transpose(&b.view(), BLASConjTrans).view());
+        builder.run().unwrap();
+        check_same(&c.slice(s![..;2, ..;2]), &c_naive.view(), 4.0 * f32::EPSILON);
+
+        // non-contiguous input
+        let plan = GEMM::default().a(a.slice(s![.., ..;2])).b(b.slice(s![.., ..3]).t()).plan().unwrap();
+        assert_eq!(plan.copies, vec!["a"]);
+
+        // assumed layout never copies
+        let plan = GEMM::default()
+            .a(a.t())
+            .b(b.view())
+            .transa('T')
+            .transb('T')
+            .assume_layout(BLASColMajor)
+            .plan()
+            .unwrap();
+        assert!(plan.copies.is_empty());
+    }
+
+    #[test]
+    fn test_plan_invalid() {
+        let a = random_matrix::<f64>(7, 5, 'R'.into());
+        let b = random_matrix::<f64>(4, 6, 'C'.into());
+        assert!(GEMM::default().a(a.view()).b(b.view()).plan().is_err());
+        assert!(GEMM::<f64>::default().a(a.view()).plan().is_err());
+    }
+}
+
+#[cfg(test)]
+mod sequential {
+    use super::*;
+
+    #[test]
+    fn test_row_vector_operand() {
+        // 1 x N operand is valid as both row-major and col-major
+        let a = random_matrix::<c64>(1, 5, 'R'.into());
+        assert_eq!(get_layout_array2(&a.view()), BLASLayout::Sequential);
+
+        for layout_b in ['R', 'C'] {
+            let b = random_matrix::<c64>(6, 5, layout_b.into());
+            let builder = GEMM::default().a(a.view()).b(b.view()).transb('C');
+            let plan = builder.plan().unwrap();
+            assert_eq!(plan.layout, layout_b.into());
+            assert!(plan.copies.is_empty());
+
+            let c_naive = gemm(&a.view(), &transpose(&b.view(), BLASConjTrans).view());
+            let c_out = builder.run().unwrap();
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_row_vector_output() {
+        let a = random_matrix::<c64>(5, 1, 'C'.into());
+        let b = random_matrix::<c64>(6, 5, 'C'.into());
+        let mut c = random_matrix::<c64>(1, 6, 'R'.into());
+        let c_naive =
+            gemm(&transpose(&a.view(), BLASConjTrans).view(), &transpose(&b.view(), BLASConjTrans).view());
+
+        let builder = GEMM::default().a(a.view()).b(b.view()).c(c.view_mut()).transa('C').transb('C');
+        let plan = builder.plan().unwrap();
+        assert_eq!(plan.layout, BLASColMajor);
+        assert!(plan.copies.is_empty());
+        builder.run().unwrap();
+        check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod aliasing {
+    use super::*;
+
+    #[test]
+    fn test_c_overlaps_a() {
+        // a occupies rows 0..6, c occupies rows 3..9 of the same buffer
+        let mut buf = random_matrix::<f64>(9, 5, 'R'.into());
+        let b = random_matrix::<f64>(5, 5, 'C'.into());
+        let beta = 0.5;
+        let mut c_naive = buf.slice(s![3..9, ..]).to_owned();
+        c_naive = gemm(&buf.slice(s![0..6, ..]), &b.view()) + beta * c_naive;
+
+        let ptr = buf.as_mut_ptr();
+        let a = unsafe { ArrayView2::from_shape_ptr((6, 5), ptr) };
+        let c = unsafe { ArrayViewMut2::from_shape_ptr((6, 5), ptr.add(15)) };
+        let result = GEMM::default().a(a).b(b.view()).c(c).beta(beta).run();
+        assert!(matches!(result, Err(BLASError::Aliasing(_))));
+
+        let a = unsafe { ArrayView2::from_shape_ptr((6, 5), ptr) };
+        let c = unsafe { ArrayViewMut2::from_shape_ptr((6, 5), ptr.add(15)) };
+        let plan = GEMM::default().a(a).b(b.view()).c(c).allow_aliasing_copy(true).plan().unwrap();
+        assert_eq!(plan.copies, vec!["a"]);
+
+        let a = unsafe { ArrayView2::from_shape_ptr((6, 5), ptr) };
+        let c = unsafe { ArrayViewMut2::from_shape_ptr((6, 5), ptr.add(15)) };
+        GEMM::default().a(a).b(b.view()).c(c).beta(beta).allow_aliasing_copy(true).run().unwrap();
+        check_same(&buf.slice(s![3..9, ..]), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_c_is_b() {
+        // c = a b, with c sharing memory of b; transposed a to exercise flip as well
+        let a = random_matrix::<c64>(4, 4, 'C'.into());
+        let mut buf = random_matrix::<c64>(4, 4, 'R'.into());
+        let c_naive = gemm(&a.t(), &buf.view());
+
+        let ptr = buf.as_mut_ptr();
+        let b = unsafe { ArrayView2::from_shape_ptr((4, 4), ptr) };
+        let c = unsafe { ArrayViewMut2::from_shape_ptr((4, 4), ptr) };
+        GEMM::default().a(a.view()).b(b).c(c).transa('T').allow_aliasing_copy(true).run().unwrap();
+        check_same(&buf.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_disjoint_interleaved() {
+        // `a` and `c` share address range in one workspace but no element, which is not aliasing
+        let b = random_matrix::<f64>(4, 4, 'C'.into());
+        for (slc_a, slc_c) in [(s![.., ..;2], s![.., 1..;2]), (s![.., ..4], s![.., 4..])] {
+            let mut w = random_matrix::<f64>(5, 8, 'R'.into());
+            let w_orig = w.clone();
+            let c_naive = gemm(&w_orig.slice(slc_a), &b.view()) + 0.5 * &w_orig.slice(slc_c);
+            let (a, c) = w.multi_slice_mut((slc_a, slc_c));
+            GEMM::default().a(a.view()).b(b.view()).c(c).beta(0.5).run().unwrap();
+            check_same(&w.slice(slc_c), &c_naive.view(), 4.0 * f64::EPSILON);
+            assert_eq!(w.slice(slc_a), w_orig.slice(slc_a));
+        }
+    }
+}
+
+#[cfg(test)]
+mod zero_stride {
+    use super::*;
+
+    #[test]
+    fn test_broadcast() {
+        let row = random_array::<f64>(4);
+        let a = row.broadcast((3, 4)).unwrap();
+        assert_eq!(a.strides(), &[0, 1]);
+        let b = random_matrix::<f64>(4, 5, 'C'.into());
+        let result = GEMM::default().a(a).b(b.view()).run();
+        assert!(matches!(result, Err(BLASError::InvalidStride(_))));
+        let result = GEMM::default().a(b.t()).b(a.t()).run();
+        assert!(matches!(result, Err(BLASError::InvalidStride(_))));
+
+        // single row broadcast is not degenerate
+        let a = row.broadcast((1, 4)).unwrap();
+        let c = GEMM::default().a(a).b(b.view()).run().unwrap();
+        check_same(&c.view(), &gemm(&a, &b.view()).view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod instrument {
+    use super::*;
+
+    #[test]
+    fn test_copy_stats_match_plan() {
+        for layout_a in ['R', 'C'] {
+            for layout_b in ['R', 'C'] {
+                for transa in ['N', 'T', 'C'] {
+                    for transb in ['N', 'T', 'C'] {
+                        let a = random_matrix::<c64>(7, 5, layout_a.into());
+                        let b = random_matrix::<c64>(6, 5, layout_b.into());
+                        let a = if transa == 'N' { a } else { a.reversed_axes() };
+                        let b = if transb == 'N' { b.reversed_axes() } else { b };
+                        let builder = GEMM::default().a(a.view()).b(b.view()).transa(transa).transb(transb);
+                        let plan = builder.plan().unwrap();
+
+                        reset_copy_stats();
+                        let _ = builder.run().unwrap();
+                        let stats = copy_stats();
+                        assert_eq!(stats.copies, plan.copies.len());
+                        let elements: usize =
+                            plan.copies.iter().map(|&name| if name == "a" { a.len() } else { b.len() }).sum();
+                        assert_eq!(stats.elements, elements);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_copy_stats_elements() {
+        let a = random_matrix::<f64>(7, 10, 'R'.into());
+        let b = random_matrix::<f64>(5, 6, 'C'.into());
+        let mut c = Array2::<f64>::zeros((7, 12));
+
+        reset_copy_stats();
+        GEMM::default().a(a.slice(s![.., ..;2])).b(b.view()).c(c.slice_mut(s![.., ..6])).run().unwrap();
+        assert_eq!(copy_stats(), CopyStats { copies: 1, elements: 35 });
+
+        let builder = GEMM::default().a(a.slice(s![.., ..;2])).b(b.view()).c(c.slice_mut(s![.., ..;2]));
+        let plan = builder.plan().unwrap();
+        assert_eq!(plan.copies, vec!["a", "c"]);
+        reset_copy_stats();
+        builder.run().unwrap();
+        assert_eq!(copy_stats(), CopyStats { copies: 2, elements: 35 + 42 });
+
+        reset_copy_stats();
+        GEMM::default().a(a.view()).b(a.view()).transb('T').run().unwrap();
+        assert_eq!(copy_stats(), CopyStats::default());
+    }
+}
+
+#[cfg(test)]
+mod symmetric_dispatch {
+    use super::*;
+
+    #[test]
+    fn test_a_symmetric() {
+        for uplo in ['L', 'U'] {
+            for (layout_a, layout_b) in [('R', 'R'), ('C', 'C'), ('R', 'C'), ('C', 'R')] {
+                for transa in ['N', 'T'] {
+                    let a = random_matrix::<c64>(6, 6, layout_a.into());
+                    let b = random_matrix::<c64>(6, 4, layout_b.into());
+                    let a_full = symmetrize(&a.view(), uplo);
+                    let c_naive = gemm(&a_full.view(), &b.view());
+
+                    let c_out =
+                        GEMM::default().a(a.view()).b(b.view()).transa(transa).a_symmetric(uplo).run();
+                    check_same(&c_out.unwrap().view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+                    let c_full = GEMM::default().a(a_full.view()).b(b.view()).run().unwrap();
+                    check_same(&c_full.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_a_hermitian() {
+        for uplo in ['L', 'U'] {
+            for (layout_a, layout_b) in [('R', 'R'), ('C', 'C'), ('R', 'C'), ('C', 'R')] {
+                for transa in ['N', 'C'] {
+                    let a = random_matrix::<c32>(6, 6, layout_a.into());
+                    let b = random_matrix::<c32>(6, 4, layout_b.into());
+                    let mut c = random_matrix::<c32>(6, 4, 'R'.into());
+                    let a_full = crate::util::hermitianize(&a.view(), uplo);
+                    let c_naive =
+                        gemm(&a_full.view(), &b.view()) * c32::new(1.5, 0.5) + &c * c32::new(0.5, 1.0);
+
+                    GEMM::default()
+                        .a(a.view())
+                        .b(b.view())
+                        .c(c.view_mut())
+                        .transa(transa)
+                        .alpha(c32::new(1.5, 0.5))
+                        .beta(c32::new(0.5, 1.0))
+                        .a_hermitian(uplo)
+                        .run()
+                        .unwrap();
+                    check_same(&c.view(), &c_naive.view(), 16.0 * f32::EPSILON);
+                }
+            }
+        }
+
+        // hermitian is the same as symmetric for real types
+        let a = random_matrix::<f64>(5, 5, 'C'.into());
+        let b = random_matrix::<f64>(5, 3, 'R'.into());
+        let c_naive = gemm(&symmetrize(&a.view(), 'U').view(), &b.view());
+        let c_out = GEMM::default().a(a.view()).b(b.view()).a_hermitian('U').run().unwrap();
+        check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let a = random_matrix::<c64>(6, 5, 'R'.into());
+        let b = random_matrix::<c64>(5, 4, 'R'.into());
+        assert!(GEMM::default().a(a.view()).b(b.view()).a_symmetric('L').run().is_err());
+
+        let a = random_matrix::<c64>(5, 5, 'R'.into());
+        let bt = random_matrix::<c64>(4, 5, 'R'.into());
+        assert!(GEMM::default().a(a.view()).b(bt.view()).transb('T').a_symmetric('L').run().is_err());
+        assert!(GEMM::default().a(a.view()).b(b.view()).transa('C').a_symmetric('L').run().is_err());
+        assert!(GEMM::default().a(a.view()).b(b.view()).transa('T').a_hermitian('L').run().is_err());
+        assert!(GEMM::default().a(a.view()).b(b.view()).a_symmetric('L').a_hermitian('L').run().is_err());
+        assert!(GEMM::default().a(a.view()).b(b.view()).a_symmetric('L').plan().is_err());
+    }
+}
+
+#[cfg(test)]
+mod scalar_into {
+    use super::*;
+
+    #[test]
+    fn test_complex_scalar_forms() {
+        let a = random_matrix::<c64>(4, 5, 'R'.into());
+        let b = random_matrix::<c64>(5, 3, 'C'.into());
+        let c = random_matrix::<c64>(4, 3, 'R'.into());
+        let ab = gemm(&a.view(), &b.view());
+
+        // (re, im) tuples
+        let mut c_out = c.clone();
+        GEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .c(c_out.view_mut())
+            .alpha((1.5, -2.0))
+            .beta((0.0, 1.0))
+            .run()
+            .unwrap();
+        let c_naive = &ab * c64::new(1.5, -2.0) + &c * c64::new(0.0, 1.0);
+        check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+        // real scalars
+        let mut c_out = c.clone();
+        GEMM::default().a(a.view()).b(b.view()).c(c_out.view_mut()).alpha(2.0).beta(-1.0).run().unwrap();
+        let c_naive = &ab * c64::new(2.0, 0.0) - &c;
+        check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+        // complex scalars, and mixed with tuples
+        let mut c_out = c.clone();
+        GEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .c(c_out.view_mut())
+            .alpha(c64::new(0.5, 0.5))
+            .beta((2.0, 0.0))
+            .run()
+            .unwrap();
+        let c_naive = &ab * c64::new(0.5, 0.5) + &c * c64::new(2.0, 0.0);
+        check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_real_scalar_forms() {
+        let a = random_matrix::<f32>(4, 5, 'R'.into());
+        let b = random_matrix::<f32>(5, 3, 'C'.into());
+        let ab = gemm(&a.view(), &b.view());
+        let c_out = GEMM::default().a(a.view()).b(b.view()).alpha(3.0).run().unwrap();
+        check_same(&c_out.view(), &(ab * 3.0).view(), 4.0 * f32::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod driver {
+    use super::*;
+    use blas_array2::blas3::gemm::{GEMMDispatch, ShapeClass, GEMM_};
+
+    fn gemm_obj<'a, 'b, 'c>(
+        a: ArrayView2<'a, f64>,
+        b: ArrayView2<'b, f64>,
+        c: Option<ArrayViewMut2<'c, f64>>,
+    ) -> GEMM_<'a, 'b, 'c, f64> {
+        GEMM_ {
+            a,
+            b,
+            c,
+            alpha: 2.0,
+            beta: 0.5,
+            transa: BLASNoTrans,
+            transb: BLASNoTrans,
+            layout: Some(BLASColMajor),
+            check_finite: false,
+            assume_layout: None,
+            allow_aliasing_copy: false,
+            a_symmetric: None,
+            a_hermitian: None,
+            output_pool: None,
+            shape_hint: ShapeClass::Auto,
+            verify: None,
+            c_uninit: false,
+            transpose_output: false,
+            chunk_large_dims: false,
+            auto_detect_symmetry: false,
+            dispatch: GEMMDispatch::default(),
+        }
+    }
+
+    #[test]
+    fn test_inner_driver_errors() {
+        // inner driver only accepts col-major layout and arrays, and returns error instead of panic
+        let a = random_matrix::<f64>(5, 4, 'C'.into());
+        let b = random_matrix::<f64>(4, 3, 'C'.into());
+        let mut obj = gemm_obj(a.view(), b.view(), None);
+        obj.layout = Some(BLASRowMajor);
+        assert!(matches!(obj.driver(), Err(BLASError::InvalidFlag(_))));
+        let mut obj = gemm_obj(a.view(), b.view(), None);
+        obj.layout = None;
+        assert!(matches!(obj.driver(), Err(BLASError::InvalidFlag(_))));
+
+        let b = random_matrix::<f64>(4, 3, 'R'.into());
+        let obj = gemm_obj(a.view(), b.view(), None);
+        assert!(matches!(obj.driver(), Err(BLASError::InvalidStride(_))));
+    }
+
+    #[test]
+    fn test_leading_dimension() {
+        // sub-matrix of col-major matrix, and contiguous col-major matrix; owned output
+        let a = random_matrix::<f64>(10, 8, 'C'.into());
+        let b = random_matrix::<f64>(4, 3, 'C'.into());
+        let driver = gemm_obj(a.slice(s![..5, ..4]), b.view(), None).driver().unwrap();
+        assert_eq!(driver.dims(), (5, 3, 4));
+        assert_eq!((driver.lda(), driver.ldb(), driver.ldc()), (10, 4, 5));
+        assert_eq!(driver.scalars(), (2.0, 0.5));
+        assert_eq!(driver.a_view(), a.slice(s![..5, ..4]));
+        assert_eq!(driver.c_view().dim(), (5, 3));
+
+        // transposed row-major matrix is col-major
+        let a = random_matrix::<f64>(4, 5, 'R'.into());
+        let b = random_matrix::<f64>(6, 4, 'R'.into());
+        let b = b.slice(s![..3, ..]);
+        let driver = gemm_obj(a.t(), b.t(), None).driver().unwrap();
+        assert_eq!(driver.dims(), (5, 3, 4));
+        assert_eq!((driver.lda(), driver.ldb(), driver.ldc()), (5, 4, 5));
+
+        // output as sub-matrix of col-major matrix
+        let a = random_matrix::<f64>(5, 4, 'C'.into());
+        let b = random_matrix::<f64>(4, 3, 'C'.into());
+        let mut c = random_matrix::<f64>(8, 6, 'C'.into());
+        let driver = gemm_obj(a.view(), b.view(), Some(c.slice_mut(s![..5, ..3]))).driver().unwrap();
+        assert_eq!(driver.ldc(), 8);
+
+        // row-major output is copied to col-major buffer
+        let mut c = random_matrix::<f64>(5, 3, 'R'.into());
+        let driver = gemm_obj(a.view(), b.view(), Some(c.view_mut())).driver().unwrap();
+        assert_eq!(driver.ldc(), 5);
+        assert!(driver.c_view().t().is_standard_layout());
+    }
+}
+
+#[cfg(test)]
+mod complex_real_part {
+    use super::*;
+
+    #[test]
+    fn test_into_complex_real_part() {
+        for (layout_a, layout_c, transa) in
+            [('R', 'R', 'N'), ('C', 'R', 'T'), ('R', 'C', 'T'), ('C', 'C', 'N')]
+        {
+            let a = random_matrix::<f64>(5, 5, layout_a.into());
+            let b = random_matrix::<f64>(5, 3, 'R'.into());
+            let c = random_matrix::<c64>(7, 6, layout_c.into());
+            let c_slc = s![1..6, ..;2];
+
+            let mut c_out = c.clone();
+            GEMM::default()
+                .a(a.view())
+                .b(b.view())
+                .into_complex_real_part(c_out.slice_mut(c_slc))
+                .transa(transa)
+                .alpha(1.5)
+                .beta(-0.5)
+                .run()
+                .unwrap();
+
+            // compare to ZGEMM with widened matrices, accumulating into real part only
+            let a_wide = a.mapv(c64::from);
+            let b_wide = b.mapv(c64::from);
+            let mut c_re = c.slice(c_slc).mapv(|x| c64::from(x.re));
+            GEMM::default()
+                .a(a_wide.view())
+                .b(b_wide.view())
+                .c(c_re.view_mut())
+                .transa(transa)
+                .alpha(1.5)
+                .beta(-0.5)
+                .run()
+                .unwrap();
+            let mut c_naive = c.clone();
+            c_naive.slice_mut(c_slc).zip_mut_with(&c_re, |x, y| x.re = y.re);
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+            // compare to DGEMM on real part
+            let mut c_re = c.slice(c_slc).mapv(|x| x.re);
+            GEMM::default()
+                .a(a.view())
+                .b(b.view())
+                .c(c_re.view_mut())
+                .transa(transa)
+                .alpha(1.5)
+                .beta(-0.5)
+                .run()
+                .unwrap();
+            let c_out_re = c_out.slice(c_slc).mapv(|x| x.re);
+            check_same(&c_out_re.view(), &c_re.view(), 4.0 * f64::EPSILON);
+            let im_out = c_out.mapv(|x| x.im);
+            let im_ref = c.mapv(|x| x.im);
+            check_same(&im_out.view(), &im_ref.view(), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_run_into_complex() {
+        for (layout_a, layout_c, transa) in
+            [('R', 'R', 'N'), ('C', 'R', 'T'), ('R', 'C', 'T'), ('C', 'C', 'N')]
+        {
+            for beta in [c64::new(0.0, 1.0), c64::new(-0.5, 0.75), c64::new(2.0, 0.0)] {
+                let a = random_matrix::<f64>(5, 5, layout_a.into());
+                let b = random_matrix::<f64>(5, 3, 'R'.into());
+                let c = random_matrix::<c64>(7, 6, layout_c.into());
+                let c_slc = s![1..6, ..;2];
+
+                let mut c_out = c.clone();
+                GEMM::default()
+                    .a(a.view())
+                    .b(b.view())
+                    .transa(transa)
+                    .alpha(1.5)
+                    .run_into_complex(c_out.slice_mut(c_slc), beta)
+                    .unwrap();
+
+                // compare to ZGEMM with widened matrices
+                let a_wide = a.mapv(c64::from);
+                let b_wide = b.mapv(c64::from);
+                let mut c_naive = c.clone();
+                GEMM::default()
+                    .a(a_wide.view())
+                    .b(b_wide.view())
+                    .c(c_naive.slice_mut(c_slc))
+                    .transa(transa)
+                    .alpha(1.5)
+                    .beta(beta)
+                    .run()
+                    .unwrap();
+                check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_into_complex_invalid() {
+        let a = random_matrix::<f64>(5, 4, 'R'.into());
+        let b = random_matrix::<f64>(4, 3, 'R'.into());
+        let c = random_matrix::<c64>(5, 3, 'R'.into());
+
+        // zero beta does not read `c`
+        let mut c_out = Array2::from_elem((5, 3), c64::new(f64::NAN, f64::NAN));
+        GEMM::default().a(a.view()).b(b.view()).run_into_complex(c_out.view_mut(), 0.0).unwrap();
+        let ab = gemm(&a.view(), &b.view()).mapv(c64::from);
+        check_same(&c_out.view(), &ab.view(), 4.0 * f64::EPSILON);
+
+        // `c` and `beta` are given by `run_into_complex`, not builder
+        let mut c_re = Array2::<f64>::zeros((5, 3));
+        let mut c_out = c.clone();
+        let builder = GEMM::default().a(a.view()).b(b.view()).c(c_re.view_mut());
+        assert!(builder.run_into_complex(c_out.view_mut(), 1.0).is_err());
+        let builder = GEMM::default().a(a.view()).b(b.view()).beta(1.0);
+        assert!(builder.run_into_complex(c_out.view_mut(), 1.0).is_err());
+
+        // dimension mismatch leaves `c` untouched
+        let mut c_out = random_matrix::<c64>(5, 4, 'R'.into());
+        let c_ref = c_out.clone();
+        let builder = GEMM::default().a(a.view()).b(b.view());
+        assert!(builder.run_into_complex(c_out.view_mut(), c64::new(0.0, 1.0)).is_err());
+        assert_eq!(c_out, c_ref);
+    }
+}
+
+#[cfg(test)]
+mod trans_str {
+    use super::*;
+
+    #[test]
+    fn test_trans_str() {
+        let a = random_matrix::<c64>(4, 5, 'R'.into());
+        let b = random_matrix::<c64>(3, 4, 'C'.into());
+        let c_str = GEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .transa_str("c")
+            .transb_str("T")
+            .run()
+            .unwrap()
+            .into_owned();
+        let c_naive =
+            gemm(&transpose(&a.view(), BLASConjTrans).view(), &transpose(&b.view(), BLASTrans).view());
+        check_same(&c_str.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+        for s in ["", "H", "CT"] {
+            let err =
+                GEMM::default().a(a.view()).b(b.view()).transa_str(s).transb_str("T").run().unwrap_err();
+            assert!(matches!(err, BLASError::InvalidFlag(_)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod output_pool {
+    use super::*;
+    use blas_array2::blas3::gemm::GemmOutputPool;
+
+    #[test]
+    fn test_output_pool() {
+        let mut pool = GemmOutputPool::<c64>::new();
+        // (m, n, k, layout, allocated)
+        let shapes = [
+            (4, 5, 3, 'R', true),
+            (5, 4, 6, 'C', false),
+            (2, 3, 4, 'R', false),
+            (6, 7, 2, 'C', true),
+            (7, 6, 5, 'R', false),
+            (1, 1, 8, 'C', false),
+            (8, 8, 3, 'R', true),
+        ];
+        for (m, n, k, layout, allocated) in shapes {
+            let a = random_matrix::<c64>(m, k, layout.into());
+            let b = random_matrix::<c64>(k, n, layout.into());
+            let allocations = pool.allocations();
+            let c_out =
+                GEMM::default().a(a.view()).b(b.view()).output_pool(&mut pool).run().unwrap().into_owned();
+            let c_naive = gemm(&a.view(), &b.view());
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+            assert_eq!(pool.allocations() - allocations, allocated as usize);
+            assert!(pool.capacity() >= m * n);
+        }
+        assert_eq!(pool.capacity(), 64);
+        assert_eq!(pool.allocations(), 3);
+    }
+
+    #[test]
+    fn test_output_pool_beta() {
+        // stale contents of pool should not leak into result
+        let mut pool = GemmOutputPool::<f64>::with_capacity(30);
+        pool.view_mut((5, 6), BLASRowMajor).unwrap().fill(f64::NAN);
+        let a = random_matrix::<f64>(5, 4, 'C'.into());
+        let b = random_matrix::<f64>(4, 6, 'R'.into());
+        let c_out = GEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .beta(2.0)
+            .output_pool(&mut pool)
+            .run()
+            .unwrap()
+            .into_owned();
+        let c_naive = gemm(&a.view(), &b.view());
+        check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        assert_eq!(pool.allocations(), 1);
+    }
+
+    #[test]
+    fn test_output_pool_overflow() {
+        let mut pool = GemmOutputPool::<f64>::new();
+        let err = pool.view_mut((usize::MAX, 2), BLASColMajor).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+        assert_eq!(pool.allocations(), 0);
+    }
+
+    #[test]
+    fn test_output_pool_with_c() {
+        let mut pool = GemmOutputPool::<f64>::new();
+        let a = random_matrix::<f64>(3, 3, 'R'.into());
+        let mut c = Array2::<f64>::zeros((3, 3));
+        let err =
+            GEMM::default().a(a.view()).b(a.view()).c(c.view_mut()).output_pool(&mut pool).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+    }
+}
+
+#[cfg(test)]
+mod c_uninit {
+    use super::*;
+    use std::alloc::{alloc, dealloc, Layout};
+    use std::mem::MaybeUninit;
+
+    #[test]
+    fn test_c_uninit() {
+        for (layout_c, transa) in
+            [(BLASRowMajor, 'N'), (BLASColMajor, 'N'), (BLASRowMajor, 'C'), (BLASColMajor, 'T')]
+        {
+            let (m, n, k) = (5, 4, 3);
+            let a = random_matrix::<c64>(
+                if transa == 'N' { m } else { k },
+                if transa == 'N' { k } else { m },
+                'R'.into(),
+            );
+            let b = random_matrix::<c64>(k, n, 'C'.into());
+
+            let mem_layout = Layout::array::<c64>(m * n).unwrap();
+            let ptr = unsafe { alloc(mem_layout) } as *mut MaybeUninit<c64>;
+            assert!(!ptr.is_null());
+
+            let c_out = unsafe {
+                GEMM::default().a(a.view()).b(b.view()).transa(transa).c_uninit(ptr, (m, n), layout_c)
+            }
+            .alpha(1.5)
+            .run()
+            .unwrap();
+            assert!(matches!(c_out, ArrayOut::ViewMut(_)));
+            let c_out = c_out.into_owned();
+            let c_naive = gemm(&transpose(&a.view(), transa.into()).view(), &b.view()).mapv(|x| x * 1.5);
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+            // memory now holds the result in requested layout
+            let shape = match layout_c {
+                BLASColMajor => (m, n).f(),
+                _ => (m, n).into_shape(),
+            };
+            let c_mem = unsafe { ArrayView2::from_shape_ptr(shape, ptr as *const c64) }.to_owned();
+            check_same(&c_mem.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+            unsafe { dealloc(ptr as *mut u8, mem_layout) };
+        }
+    }
+
+    #[test]
+    fn test_c_uninit_invalid() {
+        let a = random_matrix::<f64>(3, 3, 'R'.into());
+        let mut buf = [MaybeUninit::<f64>::uninit(); 9];
+
+        // nonzero beta would read uninitialized memory
+        let builder = unsafe {
+            GEMM::default().a(a.view()).b(a.view()).c_uninit(buf.as_mut_ptr(), (3, 3), BLASRowMajor)
+        };
+        let err = builder.beta(1.0).build().err().unwrap();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+
+        // layout mismatch would copy uninitialized memory
+        let builder = unsafe {
+            GEMM::default().a(a.view()).b(a.view()).c_uninit(buf.as_mut_ptr(), (3, 3), BLASRowMajor)
+        };
+        let err = builder.layout(BLASColMajor).build().err().unwrap();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+
+        let builder = unsafe {
+            GEMM::default().a(a.view()).b(a.view()).c_uninit(buf.as_mut_ptr(), (3, 3), BLASRowMajor)
+        };
+        let c_out = builder.layout(BLASRowMajor).run().unwrap().into_owned();
+        check_same(&c_out.view(), &gemm(&a.view(), &a.view()).view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod run_and_time {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_and_time() {
+        let a = random_matrix::<f64>(6, 5, 'R'.into());
+        let b = random_matrix::<f64>(5, 4, 'C'.into());
+        let builder = GEMM::default().a(a.view()).b(b.view()).alpha(2.0);
+        let c_plain = builder.clone().run().unwrap().into_owned();
+        let (c_timed, elapsed) = builder.run_and_time().unwrap();
+        assert!(elapsed >= Duration::ZERO);
+        check_same(&c_timed.into_owned().view(), &c_plain.view(), 0.0);
+
+        let err = GEMM::default().a(a.view()).b(a.view()).run_and_time().err().unwrap();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+    }
+}
+
+#[cfg(test)]
+mod shape_hint {
+    use super::*;
+    use blas_array2::blas3::gemm::ShapeClass;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(ShapeClass::detect(40, 5), ShapeClass::TallSkinny);
+        assert_eq!(ShapeClass::detect(5, 40), ShapeClass::ShortWide);
+        assert_eq!(ShapeClass::detect(10, 12), ShapeClass::Square);
+        assert_eq!(ShapeClass::detect(0, 0), ShapeClass::Square);
+        assert_eq!(ShapeClass::detect(4, 0), ShapeClass::TallSkinny);
+    }
+
+    #[test]
+    fn test_gram_routine() {
+        // (shape of a, transa, transb, hint, routine)
+        let cases = [
+            ((30, 5), 'T', 'N', ShapeClass::Auto, "zsyrk"),
+            ((5, 30), 'N', 'T', ShapeClass::Auto, "zsyrk"),
+            ((5, 30), 'T', 'N', ShapeClass::Auto, "zgemm"),
+            ((8, 7), 'T', 'N', ShapeClass::Auto, "zsyrk"),
+            ((8, 7), 'T', 'N', ShapeClass::TallSkinny, "zgemm"),
+            ((5, 30), 'T', 'N', ShapeClass::Square, "zsyrk"),
+            ((30, 5), 'C', 'N', ShapeClass::Auto, "zgemm"),
+            ((8, 7), 'N', 'N', ShapeClass::ShortWide, "zgemm"),
+        ];
+        for ((m, k), transa, transb, hint, routine) in cases {
+            for layout in ['R', 'C'] {
+                let a = random_matrix::<c64>(m, k, layout.into());
+                // gram products need `b` to be the same array as `a`; plain product uses a separate array
+                let at = a.t().to_owned();
+                let b = if transa == 'N' && transb == 'N' { at.view() } else { a.view() };
+                let builder = GEMM::default()
+                    .a(a.view())
+                    .b(b)
+                    .transa(transa)
+                    .transb(transb)
+                    .alpha(c64::new(1.5, -0.5))
+                    .shape_hint(hint);
+                assert_eq!(builder.plan().unwrap().routine, routine);
+
+                let c_out = builder.run().unwrap().into_owned();
+                let c_naive = gemm(
+                    &transpose(&a.view(), transa.into()).view(),
+                    &transpose(&b.view(), transb.into()).view(),
+                ) * c64::new(1.5, -0.5);
+                check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gram_with_c() {
+        let a = random_matrix::<f64>(20, 4, 'C'.into());
+        let c = random_matrix::<f64>(4, 4, 'R'.into());
+        let a_ta = gemm(&a.t(), &a.view());
+
+        // without hint, gram product is computed by GEMM
+        let builder = GEMM::default().a(a.view()).b(a.view()).transa('T');
+        assert_eq!(builder.plan().unwrap().routine, "dgemm");
+
+        // beta of zero overwrites c by SYRK
+        let mut c_out = c.clone();
+        let builder = GEMM::default()
+            .a(a.view())
+            .b(a.view())
+            .transa('T')
+            .c(c_out.view_mut())
+            .shape_hint(ShapeClass::Auto);
+        assert_eq!(builder.plan().unwrap().routine, "dsyrk");
+        builder.run().unwrap();
+        check_same(&c_out.view(), &a_ta.view(), 4.0 * f64::EPSILON);
+
+        // nonzero beta falls back to GEMM
+        let mut c_out = c.clone();
+        let builder = GEMM::default()
+            .a(a.view())
+            .b(a.view())
+            .transa('C')
+            .c(c_out.view_mut())
+            .beta(0.5)
+            .shape_hint(ShapeClass::Auto);
+        assert_eq!(builder.plan().unwrap().routine, "dgemm");
+        builder.run().unwrap();
+        let c_naive = &a_ta + &(&c * 0.5);
+        check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod arc_output {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_arc_get_mut() {
+        let a = random_matrix::<f64>(5, 4, 'R'.into());
+        let b = random_matrix::<f64>(4, 6, 'C'.into());
+        let mut c = Arc::new(Array2::<f64>::zeros((5, 6)));
+        let ptr = c.as_ptr();
+
+        let c_mut = Arc::get_mut(&mut c).unwrap();
+        let c_out = GEMM::default().a(a.view()).b(b.view()).c(c_mut.view_mut()).run().unwrap();
+        assert!(matches!(c_out, ArrayOut2::ViewMut(_)));
+
+        // share after writing in place
+        let shared = Arc::clone(&c);
+        assert_eq!(shared.as_ptr(), ptr);
+        check_same(&shared.view(), &gemm(&a.view(), &b.view()).view(), 4.0 * f64::EPSILON);
+        assert!(Arc::get_mut(&mut c).is_none());
+    }
+
+    #[test]
+    fn test_arc_array_unique() {
+        let a = random_matrix::<f64>(5, 4, 'R'.into());
+        let b = random_matrix::<f64>(4, 6, 'C'.into());
+        let mut c = ArcArray::<f64, Ix2>::zeros((5, 6));
+        let ptr = c.as_ptr();
+
+        // uniquely owned: written in place without copy
+        GEMM::default().a(a.view()).b(b.view()).c(c.view_mut()).run().unwrap();
+        assert_eq!(c.as_ptr(), ptr);
+        let c_ref = gemm(&a.view(), &b.view());
+        check_same(&c.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+
+        // shared: `view_mut` copies on write, so the other owner keeps the old values
+        let shared = c.clone();
+        GEMM::default().a(a.view()).b(b.view()).c(c.view_mut()).beta(1.0).run().unwrap();
+        assert_ne!(c.as_ptr(), shared.as_ptr());
+        assert_eq!(shared.as_ptr(), ptr);
+        check_same(&shared.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+        check_same(&c.view(), &(&c_ref * 2.0).view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod verify {
+    use super::*;
+    use blas_array2::reference;
+
+    #[test]
+    fn test_verify_pass() {
+        for (transa, transb, layout) in [('N', 'N', 'R'), ('T', 'C', 'C'), ('C', 'T', 'R')] {
+            let a = random_matrix::<c64>(6, 6, layout.into());
+            let b = random_matrix::<c64>(6, 5, 'C'.into());
+            let c = random_matrix::<c64>(6, 5, 'R'.into());
+            let b = if transb == 'N' { b } else { b.t().to_owned() };
+
+            let mut c_out = c.clone();
+            GEMM::default()
+                .a(a.view())
+                .b(b.view())
+                .c(c_out.view_mut())
+                .transa(transa)
+                .transb(transb)
+                .alpha(c64::new(1.5, 0.5))
+                .beta(c64::new(-0.5, 1.0))
+                .verify(1e-12, 1e-12)
+                .run()
+                .unwrap();
+            let c_ref = reference::gemm(
+                a.view(),
+                b.view(),
+                Some(c.view()),
+                c64::new(1.5, 0.5),
+                c64::new(-0.5, 1.0),
+                transa.into(),
+                transb.into(),
+            )
+            .unwrap();
+            check_same(&c_out.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+        }
+
+        // owned output with nonzero beta, and flag off by default
+        let a = random_matrix::<f64>(4, 3, 'R'.into());
+        GEMM::default().a(a.view()).b(a.view()).transb('T').beta(2.0).verify(1e-12, 1e-12).run().unwrap();
+        let builder = GEMM::default().a(a.view()).b(a.view()).transb('T');
+        assert!(builder.clone().build().unwrap().verify.is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "verification failed at index (2, 1)")]
+    fn test_verify_fires_on_corruption() {
+        let a = random_matrix::<f64>(4, 3, 'R'.into());
+        let b = random_matrix::<f64>(3, 5, 'C'.into());
+        let mut c = GEMM::default().a(a.view()).b(b.view()).run().unwrap().into_owned();
+        c[[2, 1]] += 1e-6;
+        let c_ref = reference::gemm(a.view(), b.view(), None, 1.0, 0.0, BLASNoTrans, BLASNoTrans).unwrap();
+        reference::assert_allclose(&c.view(), &c_ref.view(), 1e-12, 1e-12);
+    }
+
+    #[test]
+    fn test_verify_unsupported() {
+        let a = random_matrix::<f64>(4, 4, 'R'.into());
+        let err =
+            GEMM::default().a(a.view()).b(a.view()).a_symmetric('L').verify(1e-12, 0.0).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+    }
+}
+
+#[cfg(test)]
+mod transpose_output {
+    use super::*;
+
+    #[test]
+    fn test_owned() {
+        for layout in ['R', 'C'] {
+            let a = random_matrix::<f64>(7, 4, layout.into());
+            let b = random_matrix::<f64>(4, 5, layout.into());
+            let c_naive = gemm(&a.view(), &b.view());
+            let c_out = GEMM::default().a(a.view()).b(b.view()).transpose_output(true).run().unwrap();
+            assert!(matches!(c_out, ArrayOut::Owned(_)));
+            let c_out = c_out.into_owned();
+            assert_eq!(c_out.dim(), (5, 7));
+            check_same(&c_out.view(), &c_naive.t(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_view() {
+        for layout in ['R', 'C'] {
+            let a = random_matrix::<c64>(7, 4, 'R'.into());
+            let b = random_matrix::<c64>(4, 5, 'C'.into());
+            let c_naive = gemm(&a.view(), &b.view());
+            let mut c = random_matrix::<c64>(7, 5, layout.into());
+            let c_ptr = c.as_ptr();
+            let c_out =
+                GEMM::default().a(a.view()).b(b.view()).c(c.view_mut()).transpose_output(true).run().unwrap();
+            let c_out = c_out.view();
+            // logical transpose of output; physical memory of `c` still holds C
+            assert_eq!(c_out.dim(), (5, 7));
+            assert_eq!(c_out.as_ptr(), c_ptr);
+            check_same(&c_out, &c_naive.t(), 4.0 * f64::EPSILON);
+            check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_view_to_be_cloned() {
+        // strided c is computed in a temporary and copied back before transposing
+        let a = random_matrix::<f64>(7, 4, 'R'.into());
+        let b = random_matrix::<f64>(4, 5, 'R'.into());
+        let c_naive = gemm(&a.view(), &b.view());
+        let mut c_raw = Array2::<f64>::zeros((14, 10));
+        let c_out = GEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .c(c_raw.slice_mut(s![..;2, ..;2]))
+            .transpose_output(true)
+            .run()
+            .unwrap()
+            .into_owned();
+        check_same(&c_out.view(), &c_naive.t(), 4.0 * f64::EPSILON);
+        check_same(&c_raw.slice(s![..;2, ..;2]), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod with_lda {
+    use super::*;
+
+    #[test]
+    fn test_sub_block() {
+        // 4 x 3 sub-block at (2, 3) of a 10 x 8 buffer, addressed by pointer offset and leading dimension
+        let b = random_matrix::<c64>(3, 5, 'R'.into());
+        for layout in ['C', 'R'] {
+            let full = random_matrix::<c64>(10, 8, layout.into());
+            let (offset, ld, shape) = match layout {
+                'C' => (2 + 3 * 10, 10, (4, 3).f()),
+                _ => (2 * 8 + 3, 8, (4, 3).into_shape()),
+            };
+            let data = full.as_slice_memory_order().unwrap();
+            let a = ArrayView2::from_shape(shape, &data[offset..offset + 12]).unwrap();
+            let c =
+                unsafe { GEMM::default().a(a).with_lda(ld).unwrap() }.b(b.view()).run().unwrap().into_owned();
+            let c_naive = gemm(&full.slice(s![2..6, 3..6]), &b.view());
+            check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let a = random_matrix::<f64>(4, 3, 'C'.into());
+        let a_strided = random_matrix::<f64>(8, 6, 'C'.into());
+        unsafe {
+            assert!(matches!(GEMM::<f64>::default().with_lda(4), Err(BLASError::UninitializedField("a"))));
+            assert!(matches!(GEMM::default().a(a.view()).with_lda(3), Err(BLASError::InvalidDim(_))));
+            assert!(matches!(
+                GEMM::default().a(a_strided.slice(s![..;2, ..;2])).with_lda(8),
+                Err(BLASError::InvalidStride(_))
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod accumulator {
+    use super::*;
+    use blas_array2::blas3::gemm::Accumulator;
+
+    #[test]
+    fn test_accumulate_products() {
+        // sum_i A_i B_i equals a single GEMM of [A_1 .. A_p] and [B_1; ..; B_p] stacked along k
+        let (m, n, ks) = (6, 5, [3, 4, 2]);
+        let a_stack = random_matrix::<c64>(m, ks.iter().sum(), 'R'.into());
+        let b_stack = random_matrix::<c64>(ks.iter().sum(), n, 'C'.into());
+        let alpha = c64::new(0.5, 1.5);
+        let c_ref =
+            GEMM::default().a(a_stack.view()).b(b_stack.view()).alpha(alpha).run().unwrap().into_owned();
+
+        for layout in ['R', 'C'] {
+            let mut acc = Accumulator::zeros((m, n), layout.into());
+            let mut start = 0;
+            for k in ks {
+                let a = a_stack.slice(s![.., start..start + k]);
+                let b = b_stack.slice(s![start..start + k, ..]);
+                acc.gemm_add(a, b, alpha).unwrap();
+                start += k;
+            }
+            check_same(&acc.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+            check_same(&acc.into_inner().view(), &c_ref.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_from_array() {
+        let c = random_matrix::<f64>(4, 4, 'C'.into());
+        let a = random_matrix::<f64>(3, 4, 'R'.into());
+        let mut acc = Accumulator::from_array(c.clone());
+        acc.gemm_add(a.t(), a.view(), 2.0).unwrap();
+        let c_naive = &c + &(gemm(&a.t(), &a.view()) * 2.0);
+        check_same(&acc.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        assert!(acc.gemm_add(a.view(), a.view(), 1.0).is_err());
+    }
+}
+
+#[cfg(test)]
+mod strided_output {
+    use super::*;
+    use blas_array2::blas3::gemm::{GEMMDispatch, ShapeClass, GEMM_};
+
+    #[test]
+    fn test_checkerboard_c() {
+        // both strides of `c` are larger than 1: computed into a contiguous buffer and scattered back
+        for (layout_a, layout_c, layout) in
+            [('R', 'R', None), ('C', 'C', None), ('C', 'R', Some(BLASColMajor))]
+        {
+            let a = random_matrix::<c64>(5, 4, layout_a.into());
+            let b = random_matrix::<c64>(4, 6, layout_a.into());
+            let c_raw = random_matrix::<c64>(10, 12, layout_c.into());
+            let c_slc = s![..;2, 1..;2];
+            let alpha = c64::new(1.5, -0.5);
+            let beta = c64::new(0.5, 0.25);
+
+            let mut c_out = c_raw.clone();
+            let builder =
+                GEMM::default().a(a.view()).b(b.view()).c(c_out.slice_mut(c_slc)).alpha(alpha).beta(beta);
+            let builder = match layout {
+                Some(layout) => builder.layout(layout),
+                None => builder,
+            };
+            let c_ret = builder.run().unwrap();
+            assert!(matches!(c_ret, ArrayOut2::ViewMut(_)));
+
+            let mut c_naive = c_raw.clone();
+            let c_assign = gemm(&a.view(), &b.view()) * alpha + &c_raw.slice(c_slc) * beta;
+            c_naive.slice_mut(c_slc).assign(&c_assign);
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_checkerboard_c_inner_driver() {
+        // inner driver does not pass non-contiguous `c` to BLAS as-is, even with assumed layout
+        let a = random_matrix::<f64>(5, 4, 'C'.into());
+        let b = random_matrix::<f64>(4, 6, 'C'.into());
+        let c_raw = random_matrix::<f64>(10, 12, 'C'.into());
+        let c_slc = s![..;2, ..;2];
+
+        let mut c_out = c_raw.clone();
+        let obj = GEMM_ {
+            a: a.view(),
+            b: b.view(),
+            c: Some(c_out.slice_mut(c_slc)),
+            alpha: 2.0,
+            beta: 0.5,
+            transa: BLASNoTrans,
+            transb: BLASNoTrans,
+            layout: Some(BLASColMajor),
+            check_finite: false,
+            assume_layout: Some(BLASColMajor),
+            allow_aliasing_copy: false,
+            a_symmetric: None,
+            a_hermitian: None,
+            output_pool: None,
+            shape_hint: ShapeClass::Auto,
+            verify: None,
+            c_uninit: false,
+            transpose_output: false,
+            chunk_large_dims: false,
+            auto_detect_symmetry: false,
+            dispatch: GEMMDispatch::default(),
+        };
+        obj.driver().unwrap().run_blas().unwrap();
+
+        let mut c_naive = c_raw.clone();
+        let c_assign = gemm(&a.view(), &b.view()) * 2.0 + &c_raw.slice(c_slc) * 0.5;
+        c_naive.slice_mut(c_slc).assign(&c_assign);
+        check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod conj_trans {
+    use super::*;
+
+    #[test]
+    fn test_non_fpref_conj_trans() {
+        // conj(A)^T is formed exactly once, whether BLAS or the wrapper applies the conjugate
+        let a_raw = random_matrix::<c64>(12, 14, 'R'.into());
+        let b = random_matrix::<c64>(5, 4, 'C'.into());
+        let d = random_matrix::<c64>(3, 6, 'C'.into());
+        for a in [a_raw.slice(s![..5, ..6]), a_raw.slice(s![..;2, ..;2]).slice_move(s![..5, ..6])] {
+            let a_h = a.t().mapv(|x| x.conj());
+            let c_naive = gemm(&a_h.view(), &b.view());
+            for layout in [BLASColMajor, BLASRowMajor] {
+                let c = GEMM::default().a(a).b(b.view()).transa('C').layout(layout).run().unwrap();
+                check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+                let c = GEMM::default().a(d.view()).b(a).transb('C').layout(layout).run().unwrap();
+                check_same(&c.view(), &gemm(&d.view(), &a_h.view()).view(), 4.0 * f64::EPSILON);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod default_layout {
+    use super::*;
+    use blas_array2::config::{default_layout, set_default_layout};
+
+    #[test]
+    fn test_col_major_default_avoids_copy() {
+        // conj(A)^T with A col-major, B row-major: mixed layout, so computation layout falls back to default
+        let a = random_matrix::<c64>(5, 6, 'C'.into());
+        let b = random_matrix::<c64>(5, 4, 'R'.into());
+        let c_naive = gemm(&a.t().mapv(|x| x.conj()).view(), &b.view());
+        assert_eq!(default_layout(), BLASRowMajor);
+
+        // row-major default requires conjugate copy of A
+        reset_copy_stats();
+        let c = GEMM::default().a(a.view()).b(b.view()).transa('C').run().unwrap();
+        check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        assert!(c.view().is_standard_layout());
+        assert_eq!(copy_stats().copies, 1);
+
+        // col-major default: no copy, and output is col-major
+        set_default_layout(BLASColMajor).unwrap();
+        reset_copy_stats();
+        let c = GEMM::default().a(a.view()).b(b.view()).transa('C').run().unwrap();
+        check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        assert!(c.view().t().is_standard_layout());
+        assert_eq!(copy_stats().copies, 0);
+
+        // per-call layout takes priority over default
+        let c = GEMM::default().a(a.view()).b(b.view()).transa('C').layout(BLASRowMajor).run().unwrap();
+        check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        assert!(c.view().is_standard_layout());
+
+        set_default_layout(BLASRowMajor).unwrap();
+        assert!(set_default_layout(BLASLayout::Sequential).is_err());
+        assert_eq!(default_layout(), BLASRowMajor);
+    }
+
+    #[test]
+    fn test_default_is_thread_local() {
+        set_default_layout(BLASColMajor).unwrap();
+        std::thread::spawn(|| assert_eq!(default_layout(), BLASRowMajor)).join().unwrap();
+        assert_eq!(default_layout(), BLASColMajor);
+        set_default_layout(BLASRowMajor).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod run_with_report {
+    use super::*;
+
+    #[test]
+    fn test_report_copies() {
+        // conj(A)^T into col-major `c`: row-major `a` requires conjugate copy, col-major `a` does not
+        let b = random_matrix::<c64>(5, 4, 'C'.into());
+        for (layout_a, copied) in [('R', vec!["a"]), ('C', vec![])] {
+            let a = random_matrix::<c64>(5, 6, layout_a.into());
+            let mut c = Array2::<c64>::zeros((6, 4).f());
+            let c_naive = gemm(&a.t().mapv(|x| x.conj()).view(), &b.view());
+            let builder = GEMM::default().a(a.view()).b(b.view()).c(c.view_mut()).transa('C');
+            let (c_out, report) = builder.run_with_report().unwrap();
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+            assert_eq!(report.layout, BLASColMajor);
+            assert_eq!(report.copies, copied.len());
+            assert_eq!(report.elements, 30 * copied.len());
+            assert_eq!(report.copied, copied);
+        }
+    }
+}
+
+#[cfg(test)]
+mod chunk_large_dims {
+    use super::*;
+
+    #[test]
+    fn test_chunked_same_as_single_call() {
+        // small `max_dim` in place of `c_int::MAX`; chunking along m, n, k, and all trans combinations
+        let alpha = c64::new(0.7, -0.3);
+        let beta = c64::new(-1.2, 0.4);
+        for (transa, transb) in [('N', 'N'), ('T', 'N'), ('N', 'C'), ('C', 'T')] {
+            let (m, n, k) = (11, 9, 13);
+            let a = match transa {
+                'N' => random_matrix::<c64>(m, k, 'R'.into()),
+                _ => random_matrix::<c64>(k, m, 'C'.into()),
+            };
+            let b = match transb {
+                'N' => random_matrix::<c64>(k, n, 'C'.into()),
+                _ => random_matrix::<c64>(n, k, 'R'.into()),
+            };
+            let c_init = random_matrix::<c64>(m, n, 'R'.into());
+            macro_rules! builder {
+                () => {
+                    GEMM::default().a(a.view()).b(b.view()).transa(transa).transb(transb).alpha(alpha)
+                };
+            }
+            let c_single = builder!().run().unwrap().into_owned();
+
+            for max_dim in [1, 4, 5, 13, 100] {
+                let c_chunked = builder!().run_chunked(max_dim).unwrap().into_owned();
+                check_same(&c_chunked.view(), &c_single.view(), 16.0 * f64::EPSILON);
+
+                let mut c_expect = c_init.clone();
+                builder!().beta(beta).c(c_expect.view_mut()).run().unwrap();
+                let mut c = c_init.clone();
+                builder!().beta(beta).c(c.slice_mut(s![.., ..])).run_chunked(max_dim).unwrap();
+                check_same(&c.view(), &c_expect.view(), 16.0 * f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunked_layout_and_strided_output() {
+        let a_raw = random_matrix::<f64>(20, 20, 'C'.into());
+        let a = a_raw.slice(s![..14;2, 1..9]);
+        let b = random_matrix::<f64>(8, 6, 'R'.into());
+        let c_naive = gemm(&a, &b.view());
+        for layout in [BLASRowMajor, BLASColMajor] {
+            let c = GEMM::default().a(a).b(b.view()).layout(layout).run_chunked(3).unwrap().into_owned();
+            assert_eq!(c.is_standard_layout(), layout == BLASRowMajor);
+            check_same(&c.view(), &c_naive.view(), 8.0 * f64::EPSILON);
+        }
+
+        let mut c_raw = Array2::<f64>::zeros((14, 18));
+        GEMM::default().a(a).b(b.view()).c(c_raw.slice_mut(s![..;2, ..;3])).run_chunked(2).unwrap();
+        check_same(&c_raw.slice(s![..;2, ..;3]), &c_naive.view(), 8.0 * f64::EPSILON);
+        assert_eq!(c_raw.slice(s![1..;2, ..]).sum(), 0.0);
+
+        let c = GEMM::default().a(a).b(b.view()).transpose_output(true).run_chunked(4).unwrap();
+        check_same(&c.view(), &c_naive.t(), 8.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_chunk_large_dims_flag() {
+        // dimensions are within range here, so the flag falls back to the single call
+        let a = random_matrix::<f32>(5, 4, 'R'.into());
+        let b = random_matrix::<f32>(4, 3, 'R'.into());
+        let c = GEMM::default().a(a.view()).b(b.view()).chunk_large_dims(true).run().unwrap();
+        check_same(&c.view(), &gemm(&a.view(), &b.view()).view(), 4.0 * f32::EPSILON);
+
+        // empty inner dimension only scales output
+        let a = Array2::<f64>::zeros((5, 0));
+        let b = Array2::<f64>::zeros((0, 3));
+        let mut c = Array2::<f64>::ones((5, 3));
+        GEMM::default().a(a.view()).b(b.view()).c(c.view_mut()).beta(2.0).run_chunked(2).unwrap();
+        assert_eq!(c, Array2::from_elem((5, 3), 2.0));
+
+        // unsupported options and invalid dimensions
+        let a = random_matrix::<f64>(5, 5, 'R'.into());
+        let err = GEMM::default().a(a.view()).b(a.view()).a_symmetric('L').run_chunked(2).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+        let err = GEMM::default().a(a.view()).b(a.slice(s![..4, ..])).run_chunked(2).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+        let err = GEMM::default().a(a.view()).b(a.view()).run_chunked(0).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+    }
+}
+
+#[cfg(test)]
+mod single_column_views {
+    use super::*;
+
+    #[test]
+    fn test_sliced_single_row_column() {
+        // sliced blocks with length-one axes may have zero stride in ndarray
+        let a = random_matrix::<f64>(11, 13, 'R'.into());
+        let b = random_matrix::<f64>(13, 9, 'C'.into());
+        for (i, p, j) in [(0..1, 0..1, 0..1), (0..4, 12..13, 8..9), (10..11, 0..4, 0..1)] {
+            let (a, b) = (a.slice(s![i.clone(), p.clone()]), b.slice(s![p, j.clone()]));
+            let mut c = random_matrix::<f64>(11, 9, 'R'.into());
+            GEMM::default().a(a).b(b).c(c.slice_mut(s![i.clone(), j.clone()])).run().unwrap();
+            check_same(&c.slice(s![i, j]), &gemm(&a, &b).view(), 4.0 * f64::EPSILON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod auto_detect_symmetry {
+    use super::*;
+    use blas_array2::blas3::gemm::ShapeClass;
+
+    #[test]
+    fn test_symmetric_routed() {
+        let a_raw = random_matrix::<f64>(6, 6, 'C'.into());
+        let a = &a_raw + &a_raw.t();
+        let b = random_matrix::<f64>(6, 4, 'R'.into());
+        let c_naive = gemm(&a.view(), &b.view());
+        for transa in ['N', 'T'] {
+            let builder = GEMM::default().a(a.view()).b(b.view()).transa(transa).auto_detect_symmetry(true);
+            let plan = builder.clone().plan().unwrap();
+            assert_eq!((plan.routine, plan.m, plan.n, plan.k), ("dsymm", 6, 4, 6));
+            check_same(&builder.run().unwrap().view(), &c_naive.view(), 8.0 * f64::EPSILON);
+        }
+
+        // detection is off by default
+        let plan = GEMM::default().a(a.view()).b(b.view()).plan().unwrap();
+        assert_eq!(plan.routine, "dgemm");
+    }
+
+    #[test]
+    fn test_hermitian_routed() {
+        let a_raw = random_matrix::<c64>(5, 5, 'R'.into());
+        let a = &a_raw + &a_raw.t().mapv(|x| x.conj());
+        let b = random_matrix::<c64>(5, 3, 'C'.into());
+        let c_naive = gemm(&a.view(), &b.view());
+        for transa in ['N', 'C'] {
+            let mut c = random_matrix::<c64>(5, 3, 'C'.into());
+            let c_expect = &c_naive * c64::new(0.5, 1.0) + &c * c64::new(2.0, 0.0);
+            let builder = GEMM::default().a(a.view()).b(b.view()).transa(transa).auto_detect_symmetry(true);
+            let builder = builder.alpha(c64::new(0.5, 1.0)).beta(2.0).c(c.view_mut());
+            assert_eq!(builder.plan().unwrap().routine, "zhemm");
+            // HEMM can't take transpose of row-major `a` for col-major `c`, so `a` is copied
+            let (_, report) = builder.run_with_report().unwrap();
+            assert_eq!((report.layout, report.copied, report.copies), (BLASColMajor, vec!["a"], 1));
+            check_same(&c.view(), &c_expect.view(), 16.0 * f64::EPSILON);
+        }
+
+        // hermitian but not symmetric `a` can't be transposed without conjugate
+        let plan = GEMM::default().a(a.view()).b(b.view()).transa('T').auto_detect_symmetry(true).plan();
+        assert_eq!(plan.unwrap().routine, "zgemm");
+    }
+
+    #[test]
+    fn test_non_symmetric_fallback() {
+        let a_raw = random_matrix::<f32>(6, 6, 'R'.into());
+        let mut a = &a_raw + &a_raw.t();
+        a[[4, 1]] += 1.0;
+        let b = random_matrix::<f32>(6, 2, 'R'.into());
+        let c_naive = gemm(&a.view(), &b.view());
+        let builder = GEMM::default().a(a.view()).b(b.view()).auto_detect_symmetry(true);
+        assert_eq!(builder.clone().plan().unwrap().routine, "sgemm");
+        check_same(&builder.run().unwrap().view(), &c_naive.view(), 8.0 * f32::EPSILON);
+
+        // non-square `a` and transposed `b` are not checked
+        let a = random_matrix::<f32>(6, 4, 'R'.into());
+        let plan = GEMM::default().a(a.view()).b(b.view()).transa('T').auto_detect_symmetry(true).plan();
+        assert_eq!(plan.unwrap().routine, "sgemm");
+        let a = &a_raw + &a_raw.t();
+        let b = random_matrix::<f32>(2, 6, 'R'.into());
+        let plan = GEMM::default().a(a.view()).b(b.view()).transb('T').auto_detect_symmetry(true).plan();
+        assert_eq!(plan.unwrap().routine, "sgemm");
+    }
+
+    #[test]
+    fn test_gram_kept() {
+        let a_raw = random_matrix::<f64>(5, 5, 'R'.into());
+        let a = &a_raw + &a_raw.t();
+        let builder = GEMM::default().a(a.view()).b(a.view()).transa('T').auto_detect_symmetry(true);
+        let builder = builder.shape_hint(ShapeClass::Auto);
+        assert_eq!(builder.plan().unwrap().routine, "dsyrk");
+    }
+}
+
+#[cfg(test)]
+mod generic_bound {
+    use super::*;
+    use blas_array2::blas3::gemm::GEMMNum;
+
+    /// Generic callers only need the FFI trait to run plain GEMM.
+    fn gemm_generic<F>(a: ArrayView2<F>, b: ArrayView2<F>) -> Array2<F>
+    where
+        F: GEMMNum,
+    {
+        GEMM::default().a(a).b(b).run().unwrap().into_owned()
+    }
+
+    #[test]
+    fn test_gemm_num_only() {
+        let a = random_matrix::<c64>(5, 4, 'R'.into());
+        let b = random_matrix::<c64>(4, 3, 'C'.into());
+        let c_out = gemm_generic(a.view(), b.view());
+        check_same(&c_out.view(), &gemm(&a.view(), &b.view()).view(), 4.0 * f64::EPSILON);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_against_zgemm() {
+        for (transa, transb) in [('N', 'N'), ('T', 'N'), ('N', 'T'), ('T', 'C')] {
+            let (m, n, k) = (5, 6, 4);
+            let a_raw = random_matrix::<f64>(8, 8, 'R'.into());
+            let b_raw = random_matrix::<c64>(8, 8, 'C'.into());
+            let a = match transa {
+                'N' => a_raw.slice(s![1..1 + m, 2..2 + k]),
+                _ => a_raw.slice(s![1..1 + k, 2..2 + m]),
+            };
+            let b = match transb {
+                'N' => b_raw.slice(s![1..1 + k, ..n]),
+                _ => b_raw.slice(s![1..1 + n, ..k]),
+            };
+            let alpha = c64::rand();
+            let beta = c64::rand();
+            let mut c_raw = random_matrix::<c64>(10, 10, 'C'.into());
+            let c_slc = s![..m, 2..2 + n];
+
+            let a_wide = a.mapv(|x| c64::new(x, 0.0));
+            let mut c_naive = c_raw.clone();
+            ZGEMM::default()
+                .a(a_wide.view())
+                .b(b)
+                .c(c_naive.slice_mut(c_slc))
+                .alpha(alpha)
+                .beta(beta)
+                .transa(transa)
+                .transb(transb)
+                .run()
+                .unwrap();
+
+            let c_out =
+                GEMM_RC::default().a(a).b(b).alpha(alpha).transa(transa).transb(transb).run().unwrap();
+            let c_zgemm =
+                ZGEMM::default().a(a_wide.view()).b(b).alpha(alpha).transa(transa).transb(transb).run();
+            check_same(&c_out.view(), &c_zgemm.unwrap().view(), 4.0 * f64::EPSILON);
+
+            DGEMM_RC::default()
+                .a(a)
+                .b(b)
+                .c(c_raw.slice_mut(c_slc))
+                .alpha(alpha)
+                .beta(beta)
+                .transa(transa)
+                .transb(transb)
+                .run()
+                .unwrap();
+            check_same(&c_raw.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_beta_zero_ignores_nan() {
+        let a = random_matrix::<f32>(3, 4, 'R'.into());
+        let b = random_matrix::<c32>(4, 2, 'R'.into());
+        let mut c = Array2::from_elem((3, 2), c32::new(f32::NAN, 0.0));
+        SGEMM_RC::default().a(a.view()).b(b.view()).c(c.view_mut()).run().unwrap();
+        assert!(c.iter().all(|x| x.is_finite()));
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_dim_mismatch() {
+        let a = random_matrix::<f64>(3, 4, 'R'.into());
+        let b = random_matrix::<c64>(5, 2, 'R'.into());
+        DGEMM_RC::default().a(a.view()).b(b.view()).run().unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_output_mismatch() {
+        let a = random_matrix::<f64>(3, 4, 'R'.into());
+        let b = random_matrix::<c64>(4, 2, 'R'.into());
+        let mut c = Array2::<c64>::zeros((3, 3));
+        DGEMM_RC::default().a(a.view()).b(b.view()).c(c.view_mut()).run().unwrap();
+    }
+}
@@ -1,3 +1,4 @@
+use crate::util::hermitianize;
 use crate::util::*;
 use approx::*;
 use blas_array2::prelude::*;
@@ -260,3 +261,63 @@ mod valid_view {
     test_macro!(test_022: inline, c64, (9, 9, 1, 1), (7, 9, 3, 3), (7, 9, 3, 1), 'R', 'R', 'R', 'R', 'U', HEMM, hermitianize);
     test_macro!(test_023: inline, c64, (9, 9, 1, 3), (7, 9, 1, 1), (7, 9, 1, 3), 'C', 'C', 'C', 'R', 'L', HEMM, hermitianize);
 }
+
+#[cfg(test)]
+mod auto_uplo {
+    use super::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_symm() {
+        for (uplo_data, uplo_arg) in [('L', 'U'), ('U', 'L'), ('L', 'L')] {
+            let a_full = symmetrize(&random_matrix::<f64>(5, 5, 'C'.into()).view(), uplo_data);
+            let mut a = a_full.clone();
+            for i in 0..5 {
+                for j in 0..5 {
+                    if (uplo_data == 'L' && i < j) || (uplo_data == 'U' && i > j) {
+                        a[[i, j]] = 0.0;
+                    }
+                }
+            }
+            let b = random_matrix::<f64>(5, 3, 'R'.into());
+            let c_naive = gemm(&a_full.view(), &b.view());
+            let c = SYMM::default()
+                .a(a.view())
+                .b(b.view())
+                .uplo(uplo_arg)
+                .auto_uplo_from_data(true)
+                .run()
+                .unwrap();
+            check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_hemm() {
+        // data in upper triangle, default uplo is lower
+        let a_full = hermitianize(&random_matrix::<c64>(4, 4, 'R'.into()).view(), 'U');
+        let a =
+            Array2::from_shape_fn((4, 4), |(i, j)| if i <= j { a_full[[i, j]] } else { c64::new(0.0, 0.0) });
+        let b = random_matrix::<c64>(3, 4, 'C'.into());
+        let c_naive = gemm(&b.view(), &a_full.view());
+        let c = HEMM::default().a(a.view()).b(b.view()).side('R').auto_uplo_from_data(true).run().unwrap();
+        check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        let c = HEMM::default().a(a.view()).b(b.view()).side('R').run().unwrap();
+        assert!((&c.view() - &c_naive).mapv(|x| x.norm()).sum() > 1.0e-3);
+    }
+}
+
+#[cfg(test)]
+mod uplo_validation {
+    use super::*;
+
+    #[test]
+    fn test_invalid_uplo() {
+        let a = random_matrix::<c64>(4, 4, 'R'.into());
+        let b = random_matrix::<c64>(4, 3, 'R'.into());
+        let err = SYMM::default().a(a.view()).b(b.view()).uplo(BLASUpLo::Undefined).build().err().unwrap();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+        let err = HEMM::default().a(a.view()).b(b.view()).uplo('X').run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+    }
+}
@@ -299,3 +299,475 @@ mod valid_view {
     test_macro!(test_106: inline, f32, (0, 5, 1, 1), (5, 5, 1, 1), 'R', 'R', 'L', 'T', SYRK, 'T', f32);
     test_macro!(test_107: inline, f32, (5, 0, 1, 1), (5, 5, 1, 1), 'R', 'R', 'L', 'N', SYRK, 'T', f32);
 }
+
+#[cfg(test)]
+mod plan {
+    use super::*;
+
+    #[test]
+    fn test_plan() {
+        let a = random_matrix::<c64>(7, 5, 'C'.into());
+        let builder = SYRK::default().a(a.view()).trans('T');
+        let plan = builder.plan().unwrap();
+        assert_eq!(plan.routine, "zsyrk");
+        assert_eq!((plan.m, plan.n, plan.k), (5, 5, 7));
+        assert_eq!(plan.flops, 4 * 5 * 6 * 7);
+        assert_eq!(plan.layout, BLASColMajor);
+        assert!(plan.copies.is_empty());
+        let c_out = builder.run().unwrap().into_owned();
+        assert_eq!(c_out.dim(), (plan.n, plan.n));
+        assert!(c_out.t().is_standard_layout());
+
+        let mut c = Array2::<c64>::zeros((7, 7));
+        let plan = SYRK::default().a(a.view()).c(c.view_mut()).layout(BLASRowMajor).plan().unwrap();
+        assert_eq!(plan.layout, BLASRowMajor);
+        assert!(plan.copies.is_empty());
+
+        assert!(SYRK::default().a(a.view()).trans('C').plan().is_err());
+    }
+}
+
+#[cfg(test)]
+mod instrument {
+    use super::*;
+
+    #[test]
+    fn test_copy_stats_match_plan() {
+        for layout_a in ['R', 'C'] {
+            for layout_c in ['R', 'C'] {
+                let a = random_matrix::<f64>(7, 5, layout_a.into());
+                let mut c = random_matrix::<f64>(7, 7, layout_c.into());
+                let builder = SYRK::default().a(a.view()).c(c.view_mut());
+                let plan = builder.plan().unwrap();
+                reset_copy_stats();
+                builder.run().unwrap();
+                assert_eq!(copy_stats().copies, plan.copies.len());
+            }
+        }
+
+        let a = random_matrix::<f64>(7, 10, 'R'.into());
+        let builder = SYRK::default().a(a.slice(s![.., ..;2]));
+        let plan = builder.plan().unwrap();
+        assert_eq!(plan.copies, vec!["a"]);
+        reset_copy_stats();
+        let _ = builder.run().unwrap();
+        assert_eq!(copy_stats(), CopyStats { copies: 1, elements: 35 });
+    }
+
+    #[test]
+    fn test_run_with_report() {
+        // col-major `a` into col-major `c`: no copy
+        let a = random_matrix::<f64>(7, 5, 'C'.into());
+        let mut c = random_matrix::<f64>(7, 7, 'C'.into());
+        let (_, report) = SYRK::default().a(a.view()).c(c.view_mut()).run_with_report().unwrap();
+        assert_eq!(report, CopyReport { layout: BLASColMajor, copied: vec![], copies: 0, elements: 0 });
+
+        // non-contiguous `a` with row-major output: `a` copied
+        let a = random_matrix::<f64>(7, 10, 'R'.into());
+        let (c_out, report) = SYRK::default().a(a.slice(s![.., ..;2])).run_with_report().unwrap();
+        assert_eq!(report, CopyReport { layout: BLASRowMajor, copied: vec!["a"], copies: 1, elements: 35 });
+        assert!(c_out.view().is_standard_layout());
+    }
+}
+
+#[cfg(test)]
+mod driver {
+    use super::*;
+    use blas_array2::blas3::syrk::SYRK_;
+
+    fn syrk_obj<'a, 'c>(
+        a: ArrayView2<'a, c64>,
+        c: Option<ArrayViewMut2<'c, c64>>,
+        trans: BLASTranspose,
+    ) -> SYRK_<'a, 'c, c64> {
+        SYRK_ {
+            a,
+            c,
+            alpha: c64::new(1.0, 2.0),
+            beta: c64::from(0.0),
+            uplo: BLASLower,
+            trans,
+            layout: Some(BLASColMajor),
+            check_finite: false,
+            row_weights: None,
+        }
+    }
+
+    #[test]
+    fn test_leading_dimension() {
+        let a = random_matrix::<c64>(9, 4, 'C'.into());
+        let driver = syrk_obj(a.slice(s![..6, ..]), None, BLASNoTrans).driver().unwrap();
+        assert_eq!(driver.dims(), (6, 4));
+        assert_eq!((driver.lda(), driver.ldc()), (9, 6));
+        assert_eq!(driver.scalars(), (c64::new(1.0, 2.0), c64::from(0.0)));
+        assert_eq!(driver.a_view(), a.slice(s![..6, ..]));
+
+        // transposed row-major matrix with trans = T
+        let a = random_matrix::<c64>(6, 4, 'R'.into());
+        let mut c = random_matrix::<c64>(9, 6, 'C'.into());
+        let driver = syrk_obj(a.t(), Some(c.slice_mut(s![..6, ..])), BLASTrans).driver().unwrap();
+        assert_eq!(driver.dims(), (6, 4));
+        assert_eq!((driver.lda(), driver.ldc()), (4, 9));
+    }
+}
+
+#[cfg(test)]
+mod row_weights {
+    use super::*;
+
+    fn scale_rows<F>(a: &ArrayView2<F>, w: &ArrayView1<f64>) -> Array2<F>
+    where
+        F: BLASFloat + From<f64>,
+    {
+        let mut a = a.to_owned();
+        for (mut row, &wi) in a.rows_mut().into_iter().zip(w.iter()) {
+            row.mapv_inplace(|x| x * F::from(wi.sqrt()));
+        }
+        a
+    }
+
+    #[test]
+    fn test_weighted_gram_f64() {
+        for (layout, trans) in [('R', 'T'), ('C', 'T'), ('R', 'N'), ('C', 'N')] {
+            let a = random_matrix::<f64>(7, 4, 'C'.into());
+            let w = Array1::from_iter((0..7).map(|i| 0.5 * i as f64));
+
+            let c_out = SYRK::default()
+                .a(a.view())
+                .with_row_weights(w.view())
+                .trans(trans)
+                .layout(layout)
+                .run()
+                .unwrap()
+                .into_owned();
+            let c_ref = SYRK::default()
+                .a(scale_rows(&a.view(), &w.view()).view())
+                .trans(trans)
+                .layout(layout)
+                .run()
+                .unwrap()
+                .into_owned();
+            let err = (&c_out - &c_ref).mapv(|x| x.abs()).sum();
+            assert_abs_diff_eq!(err, 0.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_weighted_gram_c64() {
+        let a_raw = random_matrix::<c64>(9, 6, 'C'.into());
+        let a = a_raw.slice(s![1..8, ..;2]);
+        let w = Array1::from_iter((0..7).map(|i| 1.0 + i as f64));
+
+        let c_out = SYRK::default()
+            .a(a)
+            .with_row_weights(w.view())
+            .trans('T')
+            .alpha((2.0, 1.0))
+            .uplo('U')
+            .run()
+            .unwrap()
+            .into_owned();
+        let c_ref = SYRK::default()
+            .a(scale_rows(&a, &w.view()).view())
+            .trans('T')
+            .alpha((2.0, 1.0))
+            .uplo('U')
+            .run()
+            .unwrap()
+            .into_owned();
+        let err = (&c_out - &c_ref).mapv(|x| x.norm()).sum();
+        assert!(err < 1e-10);
+    }
+
+    #[test]
+    fn test_invalid_weights() {
+        let a = random_matrix::<f64>(5, 3, 'C'.into());
+
+        let w = Array1::<f64>::ones(4);
+        let err = SYRK::default().a(a.view()).with_row_weights(w.view()).trans('T').run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+
+        let w = Array1::from_vec(vec![1.0, 2.0, -1.0, 0.0, 3.0]);
+        let err = SYRK::default().a(a.view()).with_row_weights(w.view()).trans('T').run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+    }
+}
+
+#[cfg(test)]
+mod herk_beta {
+    use super::*;
+
+    #[test]
+    fn test_herk_explicit_c() {
+        for (uplo, trans, layout) in [('L', 'N', 'R'), ('U', 'N', 'C'), ('L', 'C', 'C'), ('U', 'C', 'R')] {
+            let a = random_matrix::<c64>(6, 6, layout.into());
+            let c = random_matrix::<c64>(6, 6, layout.into());
+
+            let mut c_out = c.clone();
+            HERK::default()
+                .a(a.view())
+                .c(c_out.view_mut())
+                .alpha(1.5)
+                .beta(0.5)
+                .uplo(uplo)
+                .trans(trans)
+                .run()
+                .unwrap();
+            let c_out = crate::util::hermitianize(&c_out.view(), uplo);
+
+            // reference: hermitian accumulation on hermitian part of `c` stored in `uplo`
+            let ah = transpose(&a.view(), BLASConjTrans);
+            let aah = match trans {
+                'N' => gemm(&a.view(), &ah.view()),
+                _ => gemm(&ah.view(), &a.view()),
+            };
+            let c_naive = crate::util::hermitianize(&c.view(), uplo) * c64::from(0.5) + aah * c64::from(1.5);
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_herk_owned_nonzero_beta() {
+        let a = random_matrix::<c64>(5, 7, 'R'.into());
+        let c_zero = HERK::default().a(a.view()).alpha(2.0).run().unwrap().into_owned();
+        let c_beta = HERK::default().a(a.view()).alpha(2.0).beta(0.5).run().unwrap().into_owned();
+        assert_eq!(c_zero, c_beta);
+        // diagonal is real, and result is hermitian once filled from lower triangle
+        assert!(c_beta.diag().iter().all(|x| x.im == 0.0));
+        let c_full = crate::util::hermitianize(&c_beta.view(), 'L');
+        let c_naive = gemm(&a.view(), &transpose(&a.view(), BLASConjTrans).view()) * c64::from(2.0);
+        check_same(&c_full.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod uplo_validation {
+    use super::*;
+
+    #[test]
+    fn test_invalid_uplo() {
+        let a = random_matrix::<f64>(4, 3, 'R'.into());
+        let err = SYRK::default().a(a.view()).uplo(BLASUpLo::Undefined).build().err().unwrap();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+        let err = SYRK::default().a(a.view()).uplo('X').run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+
+        // valid flags are accepted
+        for uplo in ['U', 'L', 'u', 'l'] {
+            assert!(SYRK::default().a(a.view()).uplo(uplo).build().is_ok());
+        }
+    }
+}
+
+#[cfg(test)]
+mod strided_output {
+    use super::*;
+
+    #[test]
+    fn test_c_contiguous_c_col_major_buffer() {
+        // C-contiguous `c` with col-major computation: result is computed in an F-contiguous buffer and
+        // assigned back by logical index
+        for uplo in ['L', 'U'] {
+            let a = random_matrix::<f64>(5, 3, 'R'.into());
+            let c_raw = random_matrix::<f64>(5, 5, 'R'.into());
+            let mut c_out = c_raw.clone();
+            SYRK::default()
+                .a(a.view())
+                .c(c_out.view_mut())
+                .beta(0.5)
+                .uplo(uplo)
+                .layout(BLASColMajor)
+                .run()
+                .unwrap();
+            assert!(c_out.is_standard_layout());
+
+            let mut c_naive = c_raw.clone();
+            let c_calc = gemm(&a.view(), &a.t()) + &c_raw * 0.5;
+            tril_assign(&mut c_naive.view_mut(), &c_calc.view(), uplo);
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_checkerboard_c_col_major() {
+        // both strides of `c` are larger than 1: computed into a contiguous buffer and scattered back
+        for uplo in ['L', 'U'] {
+            let a = random_matrix::<f64>(5, 3, 'C'.into());
+            let c_raw = random_matrix::<f64>(10, 10, 'C'.into());
+            let c_slc = s![..;2, 1..;2];
+            let mut c_out = c_raw.clone();
+            SYRK::default()
+                .a(a.view())
+                .c(c_out.slice_mut(c_slc))
+                .beta(0.5)
+                .uplo(uplo)
+                .layout(BLASColMajor)
+                .run()
+                .unwrap();
+
+            let mut c_naive = c_raw.clone();
+            let c_calc = gemm(&a.view(), &a.t()) + &c_raw.slice(c_slc) * 0.5;
+            tril_assign(&mut c_naive.slice_mut(c_slc), &c_calc.view(), uplo);
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_row_major_strided_c() {
+        // row-major SYRK reverses axes of output; a non-contiguous `c` is computed on a copy and assigned back
+        for (uplo, trans, layout_a, layout_c) in
+            [('L', 'N', 'R', 'R'), ('U', 'N', 'C', 'R'), ('L', 'T', 'R', 'C'), ('U', 'T', 'C', 'C')]
+        {
+            let a = random_matrix::<c64>(6, 6, layout_a.into());
+            let c_raw = random_matrix::<c64>(14, 16, layout_c.into());
+            let c_slc = s![1..13;2, 3..15;2];
+
+            let mut c_out = c_raw.clone();
+            let c_ret = SYRK::default()
+                .a(a.view())
+                .c(c_out.slice_mut(c_slc))
+                .alpha(1.5)
+                .beta(0.5)
+                .uplo(uplo)
+                .trans(trans)
+                .layout(BLASRowMajor)
+                .run()
+                .unwrap();
+            assert!(matches!(c_ret, ArrayOut2::ViewMut(_)));
+
+            let c_assign = match trans {
+                'N' => gemm(&a.view(), &a.t()),
+                _ => gemm(&a.t(), &a.view()),
+            } * c64::from(1.5)
+                + &c_raw.slice(c_slc) * c64::from(0.5);
+            let mut c_naive = c_raw.clone();
+            tril_assign(&mut c_naive.slice_mut(c_slc), &c_assign.view(), uplo);
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+            // elements outside of the referenced triangle of the sub-block are untouched
+            let mut mask = Array2::from_elem(c_raw.dim(), 1.0);
+            tril_assign(&mut mask.slice_mut(c_slc), &Array2::<f64>::zeros((6, 6)).view(), uplo);
+            for ((x, y), m) in c_out.iter().zip(c_raw.iter()).zip(mask.iter()) {
+                if *m == 1.0 {
+                    assert_eq!(x, y);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod arc_output {
+    use super::*;
+
+    #[test]
+    fn test_arc_array_unique() {
+        let a = random_matrix::<c64>(4, 7, 'C'.into());
+        let mut c = ArcArray::<c64, Ix2>::zeros((4, 4));
+        let ptr = c.as_ptr();
+        SYRK::default().a(a.view()).c(c.view_mut()).uplo('U').run().unwrap();
+        assert_eq!(c.as_ptr(), ptr);
+
+        let shared = c.clone();
+        let c_full = crate::util::symmetrize(&shared.view(), 'U');
+        check_same(&c_full.view(), &gemm(&a.view(), &a.t()).view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod conj_trans {
+    use super::*;
+
+    #[test]
+    fn test_herk_non_fpref_conj_trans() {
+        // HERK with trans = C gives A^H A, for row-major and non-contiguous `a` in either layout
+        let a_raw = random_matrix::<c64>(12, 10, 'R'.into());
+        for a in [a_raw.slice(s![..6, ..5]), a_raw.slice(s![..;2, ..;2])] {
+            let c_naive = gemm(&a.t().mapv(|x| x.conj()).view(), &a);
+            for (uplo, layout) in
+                [('L', BLASColMajor), ('U', BLASColMajor), ('L', BLASRowMajor), ('U', BLASRowMajor)]
+            {
+                let c = HERK::default().a(a).trans('C').uplo(uplo).layout(layout).run().unwrap().into_owned();
+                let mut c_expect = Array2::<c64>::zeros(c.dim());
+                tril_assign(&mut c_expect.view_mut(), &c_naive.view(), uplo);
+                check_same(&c.view(), &c_expect.view(), 4.0 * f64::EPSILON);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod conj_no_trans {
+    use super::*;
+
+    #[test]
+    fn test_syrk_conj_no_trans() {
+        // SYRK with trans = ConjNoTrans gives conj(A) conj(A)^T, for row- and col-major `a` in either layout
+        let a_raw = random_matrix::<c64>(12, 10, 'R'.into());
+        let mut a_f = Array2::<c64>::zeros((6, 5).f());
+        a_f.assign(&a_raw.slice(s![..6, ..5]));
+        for a in [a_raw.slice(s![..6, ..5]), a_f.view(), a_raw.slice(s![..;2, ..;2])] {
+            let a_conj = a.mapv(|x| x.conj());
+            let c_naive = gemm(&a_conj.view(), &a_conj.t());
+            for (uplo, layout) in
+                [('L', BLASColMajor), ('U', BLASColMajor), ('L', BLASRowMajor), ('U', BLASRowMajor)]
+            {
+                let c = SYRK::default().a(a).trans(BLASConjNoTrans).uplo(uplo).layout(layout).run().unwrap();
+                let mut c_expect = Array2::<c64>::zeros(c.view().dim());
+                tril_assign(&mut c_expect.view_mut(), &c_naive.view(), uplo);
+                let mut c_out = Array2::<c64>::zeros(c.view().dim());
+                tril_assign(&mut c_out.view_mut(), &c.view(), uplo);
+                check_same(&c_out.view(), &c_expect.view(), 4.0 * f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_syrk_conj_no_trans_given_c() {
+        let a = random_matrix::<c32>(6, 5, 'C'.into());
+        let mut c = Array2::<c32>::zeros((6, 6).f());
+        SYRK::default().a(a.view()).c(c.view_mut()).trans(BLASConjNoTrans).uplo('L').run().unwrap();
+        let a_conj = a.mapv(|x| x.conj());
+        let c_naive = gemm(&a_conj.view(), &a_conj.t());
+        for i in 0..6 {
+            for j in 0..=i {
+                assert!((c[[i, j]] - c_naive[[i, j]]).norm() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_syrk_conj_no_trans_plan() {
+        let a = random_matrix::<c64>(6, 5, 'R'.into());
+        let plan = SYRK::default().a(a.view()).trans(BLASConjNoTrans).plan().unwrap();
+        assert!(plan.copies.contains(&"a"));
+    }
+
+    #[test]
+    fn test_syrk_conj_no_trans_real() {
+        let a = random_matrix::<f64>(6, 5, 'R'.into());
+        let err = SYRK::default().a(a.view()).trans(BLASConjNoTrans).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+        let err = SYRK::default().a(a.view()).trans(BLASConjNoTrans).plan().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+    }
+
+    #[test]
+    fn test_herk_conj_no_trans() {
+        // HERK with trans = ConjNoTrans gives conj(A) A^T
+        let a_raw = random_matrix::<c64>(12, 10, 'R'.into());
+        for a in [a_raw.slice(s![..6, ..5]), a_raw.slice(s![..;2, ..;2])] {
+            let c_naive = gemm(&a.mapv(|x| x.conj()).view(), &a.t());
+            for (uplo, layout) in
+                [('L', BLASColMajor), ('U', BLASColMajor), ('L', BLASRowMajor), ('U', BLASRowMajor)]
+            {
+                let c = HERK::default().a(a).trans(BLASConjNoTrans).uplo(uplo).layout(layout).run().unwrap();
+                let mut c_expect = Array2::<c64>::zeros(c.view().dim());
+                tril_assign(&mut c_expect.view_mut(), &c_naive.view(), uplo);
+                let mut c_out = Array2::<c64>::zeros(c.view().dim());
+                tril_assign(&mut c_out.view_mut(), &c.view(), uplo);
+                check_same(&c_out.view(), &c_expect.view(), 4.0 * f64::EPSILON);
+            }
+        }
+    }
+}
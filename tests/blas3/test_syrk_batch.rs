@@ -0,0 +1,114 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+fn random_batch<F>(nbatch: usize, row: usize, col: usize) -> Array3<F>
+where
+    F: TestFloat,
+{
+    let mut arr = Array3::zeros((nbatch, row, col));
+    arr.iter_mut().for_each(|x| *x = F::rand());
+    arr
+}
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_syrk_batch_owned() {
+        let alpha = f64::rand();
+        let a = random_batch::<f64>(4, 7, 5);
+        for trans in ['N', 'T'] {
+            let c_out = SYRKBatch::default().a(a.view()).alpha(alpha).uplo('U').trans(trans).run().unwrap();
+            let c_out = c_out.into_owned();
+            let n = if trans == 'N' { 7 } else { 5 };
+            assert_eq!(c_out.dim(), (4, n, n));
+            for i in 0..4 {
+                let c_naive = SYRK::default()
+                    .a(a.index_axis(Axis(0), i))
+                    .alpha(alpha)
+                    .uplo('U')
+                    .trans(trans)
+                    .run()
+                    .unwrap();
+                check_same(&c_out.index_axis(Axis(0), i), &c_naive.view(), 4.0 * f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_syrk_batch_view_mut() {
+        let alpha = c32::rand();
+        let beta = c32::rand();
+        let a = random_batch::<c32>(3, 6, 4);
+        let mut c = random_batch::<c32>(3, 8, 8);
+        let c_orig = c.clone();
+        let c_slc = s![.., 1..7, 2..8];
+
+        SYRKBatch::default().a(a.view()).c(c.slice_mut(c_slc)).alpha(alpha).beta(beta).run().unwrap();
+
+        let mut c_naive = c_orig.clone();
+        for i in 0..3 {
+            let mut c_i = c_naive.index_axis_mut(Axis(0), i);
+            SYRK::default()
+                .a(a.index_axis(Axis(0), i))
+                .c(c_i.slice_mut(s![1..7, 2..8]))
+                .alpha(alpha)
+                .beta(beta)
+                .run()
+                .unwrap();
+        }
+        check_same(&c.view(), &c_naive.view(), 4.0 * f32::EPSILON);
+    }
+
+    #[test]
+    fn test_herk_batch() {
+        let alpha = f64::rand();
+        let beta = f64::rand();
+        let a = random_batch::<c64>(5, 4, 6);
+        let mut c = random_batch::<c64>(5, 6, 6);
+        let mut c_naive = c.clone();
+
+        HERKBatch::default().a(a.view()).c(c.view_mut()).alpha(alpha).beta(beta).trans('C').run().unwrap();
+
+        for i in 0..5 {
+            HERK::default()
+                .a(a.index_axis(Axis(0), i))
+                .c(c_naive.index_axis_mut(Axis(0), i))
+                .alpha(alpha)
+                .beta(beta)
+                .trans('C')
+                .run()
+                .unwrap();
+        }
+        check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_empty_batch() {
+        let a = Array3::<f64>::zeros((0, 3, 4));
+        let c_out = SYRKBatch::default().a(a.view()).run().unwrap();
+        assert_eq!(c_out.view().dim(), (0, 3, 3));
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_batch_mismatch() {
+        let a = random_batch::<f64>(3, 4, 4);
+        let mut c = random_batch::<f64>(2, 4, 4);
+        SYRKBatch::default().a(a.view()).c(c.view_mut()).run().unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_herk_trans() {
+        let a = random_batch::<c64>(3, 4, 4);
+        HERKBatch::default().a(a.view()).trans('T').run().unwrap();
+    }
+}
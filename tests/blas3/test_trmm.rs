@@ -180,3 +180,130 @@ mod valid {
     test_macro!(test_022: inline, c64, (8, 8, 3, 1), (8, 9, 3, 1), 'C', 'R', 'L', 'L', 'N', 'U');
     test_macro!(test_023: inline, c64, (9, 9, 3, 3), (8, 9, 1, 1), 'R', 'C', 'R', 'L', 'C', 'U');
 }
+
+#[cfg(test)]
+mod side_dim {
+    use super::*;
+
+    #[test]
+    fn test_side_dim() {
+        let b = random_matrix::<f64>(4, 6, 'R'.into());
+        for layout in ['R', 'C'] {
+            // correct cases
+            let a = random_matrix::<f64>(4, 4, layout.into());
+            let a_tri = Array2::from_shape_fn((4, 4), |(i, j)| if i >= j { a[[i, j]] } else { 0.0 });
+            let mut b_out = b.clone();
+            TRMM::default().a(a.view()).b(b_out.view_mut()).side('L').uplo('L').run().unwrap();
+            check_same(&b_out.view(), &gemm(&a_tri.view(), &b.view()).view(), 4.0 * f64::EPSILON);
+
+            let a = random_matrix::<f64>(6, 6, layout.into());
+            let a_tri = Array2::from_shape_fn((6, 6), |(i, j)| if i >= j { a[[i, j]] } else { 0.0 });
+            let mut b_out = b.clone();
+            TRMM::default().a(a.view()).b(b_out.view_mut()).side('R').uplo('L').run().unwrap();
+            check_same(&b_out.view(), &gemm(&b.view(), &a_tri.view()).view(), 4.0 * f64::EPSILON);
+
+            // deliberately wrong size of `a`
+            let a = random_matrix::<f64>(6, 6, layout.into());
+            let mut b_out = b.clone();
+            let err = TRMM::default().a(a.view()).b(b_out.view_mut()).side('L').run().unwrap_err();
+            let BLASError::InvalidDim(msg) = err else { panic!("unexpected error {err:?}") };
+            assert!(msg.contains("side = Left requires A to be m x m, where m = number of rows of B"));
+            assert!(msg.contains("found A of shape 6 x 6 and B of shape 4 x 6"));
+
+            let a = random_matrix::<f64>(4, 4, layout.into());
+            let err = TRMM::default().a(a.view()).b(b_out.view_mut()).side('R').run().unwrap_err();
+            let BLASError::InvalidDim(msg) = err else { panic!("unexpected error {err:?}") };
+            assert!(msg.contains("side = Right requires A to be n x n, where n = number of columns of B"));
+            assert!(msg.contains("found A of shape 4 x 4 and B of shape 4 x 6"));
+
+            // non-square `a`
+            let a = random_matrix::<f64>(4, 6, layout.into());
+            assert!(TRMM::default().a(a.view()).b(b_out.view_mut()).side('L').run().is_err());
+            assert_eq!(b_out, b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod gemm_fallback {
+    use super::*;
+    use itertools::iproduct;
+
+    #[test]
+    fn test_gemm_fallback() {
+        for (side, uplo, transa, diag, layout_a, layout_b) in
+            iproduct!(['L', 'R'], ['U', 'L'], ['N', 'T', 'C'], ['N', 'U'], ['R', 'C'], ['R', 'C'])
+        {
+            let (m, n) = (5, 7);
+            let a = random_matrix::<c64>(
+                if side == 'L' { m } else { n },
+                if side == 'L' { m } else { n },
+                layout_a.into(),
+            );
+            let b = random_matrix::<c64>(m, n, layout_b.into());
+
+            let mut b_native = b.clone();
+            TRMM::default()
+                .a(a.view())
+                .b(b_native.view_mut())
+                .alpha((1.5, -0.5))
+                .side(side)
+                .uplo(uplo)
+                .transa(transa)
+                .diag(diag)
+                .run()
+                .unwrap();
+
+            let mut b_fallback = b.clone();
+            TRMM::default()
+                .a(a.view())
+                .b(b_fallback.view_mut())
+                .alpha((1.5, -0.5))
+                .side(side)
+                .uplo(uplo)
+                .transa(transa)
+                .diag(diag)
+                .run_gemm_fallback()
+                .unwrap();
+            check_same(&b_fallback.view(), &b_native.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_gemm_fallback_strided() {
+        let a = random_matrix::<f64>(8, 8, 'C'.into());
+        let mut b = random_matrix::<f64>(6, 9, 'R'.into());
+        let b_orig = b.clone();
+        let a_slc = a.slice(s![..;2, ..4]);
+
+        TRMM::default()
+            .a(a_slc)
+            .b(b.slice_mut(s![1..5, ..;2]))
+            .uplo('L')
+            .diag('U')
+            .run_gemm_fallback()
+            .unwrap();
+        let mut b_native = b_orig.clone();
+        TRMM::default().a(a_slc).b(b_native.slice_mut(s![1..5, ..;2])).uplo('L').diag('U').run().unwrap();
+        check_same(&b.view(), &b_native.view(), 4.0 * f64::EPSILON);
+    }
+
+    /// Plain TRMM only needs the FFI trait; GEMM is only required by the fallback.
+    fn trmm_generic<F>(a: ArrayView2<F>, mut b: Array2<F>) -> Array2<F>
+    where
+        F: blas_array2::blas3::trmm::TRMMNum,
+    {
+        TRMM::default().a(a).b(b.view_mut()).uplo('L').run().unwrap();
+        b
+    }
+
+    #[test]
+    fn test_trmm_num_only() {
+        let a = random_matrix::<c32>(4, 4, 'R'.into());
+        let b = random_matrix::<c32>(4, 3, 'C'.into());
+        let b_out = trmm_generic(a.view(), b.clone());
+        let mut b_fallback = b.clone();
+        TRMM::default().a(a.view()).b(b_fallback.view_mut()).uplo('L').run_gemm_fallback().unwrap();
+        check_same(&b_out.view(), &b_fallback.view(), 4.0 * f32::EPSILON);
+    }
+}
@@ -170,3 +170,122 @@ mod valid {
     test_macro!(test_022: inline, c64, (8, 8, 3, 1), (8, 9, 3, 1), 'C', 'R', 'L', 'L', 'N', 'U');
     test_macro!(test_023: inline, c64, (9, 9, 3, 3), (8, 9, 1, 1), 'R', 'C', 'R', 'L', 'C', 'U');
 }
+
+#[cfg(test)]
+mod side_dim {
+    use super::*;
+
+    #[test]
+    fn test_side_dim() {
+        let b = random_matrix::<c64>(4, 6, 'C'.into());
+        for layout in ['R', 'C'] {
+            // correct cases
+            let mut a = random_matrix::<c64>(4, 4, layout.into());
+            a.diag_mut().mapv_inplace(|x| x + c64::from(4.0));
+            let a_tri =
+                Array2::from_shape_fn((4, 4), |(i, j)| if i <= j { a[[i, j]] } else { c64::from(0.0) });
+            let mut x = b.clone();
+            TRSM::default().a(a.view()).b(x.view_mut()).side('L').uplo('U').run().unwrap();
+            check_same(&gemm(&a_tri.view(), &x.view()).view(), &b.view(), 16.0 * f64::EPSILON);
+
+            let mut a = random_matrix::<c64>(6, 6, layout.into());
+            a.diag_mut().mapv_inplace(|x| x + c64::from(6.0));
+            let a_tri =
+                Array2::from_shape_fn((6, 6), |(i, j)| if i <= j { a[[i, j]] } else { c64::from(0.0) });
+            let mut x = b.clone();
+            TRSM::default().a(a.view()).b(x.view_mut()).side('R').uplo('U').run().unwrap();
+            check_same(&gemm(&x.view(), &a_tri.view()).view(), &b.view(), 16.0 * f64::EPSILON);
+
+            // deliberately wrong size of `a`
+            let a = random_matrix::<c64>(6, 6, layout.into());
+            let mut x = b.clone();
+            let err = TRSM::default().a(a.view()).b(x.view_mut()).side('L').run().unwrap_err();
+            let BLASError::InvalidDim(msg) = err else { panic!("unexpected error {err:?}") };
+            assert!(msg.contains("side = Left requires A to be m x m, where m = number of rows of B"));
+
+            let a = random_matrix::<c64>(4, 4, layout.into());
+            let err = TRSM::default().a(a.view()).b(x.view_mut()).side('R').run().unwrap_err();
+            let BLASError::InvalidDim(msg) = err else { panic!("unexpected error {err:?}") };
+            assert!(msg.contains("side = Right requires A to be n x n, where n = number of columns of B"));
+            assert!(msg.contains("found A of shape 4 x 4 and B of shape 4 x 6"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod warn_ill_conditioned {
+    use super::*;
+
+    #[test]
+    fn test_result_unchanged() {
+        let mut a = random_matrix::<f32>(4, 4, 'R'.into());
+        a[[0, 0]] = 1e-12;
+        assert_eq!(small_diagonal_index(a.diag()), Some(0));
+
+        let b = random_matrix::<f32>(4, 3, 'C'.into());
+        let mut x_ref = b.clone();
+        reset_trace_count();
+        TRSM::default().a(a.view()).b(x_ref.view_mut()).run().unwrap();
+        assert_eq!(trace_count(), 0);
+        let mut x_warn = b.clone();
+        reset_trace_count();
+        TRSM::default().a(a.view()).b(x_warn.view_mut()).warn_ill_conditioned(true).run().unwrap();
+        assert_eq!(trace_count(), 1);
+        assert_eq!(x_ref, x_warn);
+
+        // unit diagonal is not checked
+        let mut x_unit = b.clone();
+        reset_trace_count();
+        TRSM::default().a(a.view()).b(x_unit.view_mut()).diag('U').warn_ill_conditioned(true).run().unwrap();
+        assert_eq!(trace_count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod b_triangular {
+    use super::*;
+
+    #[test]
+    fn test_same_as_manual_zeroing() {
+        for (side, uplo_b, layout) in [('L', 'U', 'R'), ('L', 'L', 'C'), ('R', 'U', 'C'), ('R', 'L', 'R')] {
+            let mut a = random_matrix::<c64>(5, 5, 'C'.into());
+            a.diag_mut().mapv_inplace(|v| v + c64::from(5.0));
+            let mut b_raw = random_matrix::<c64>(5, 5, layout.into());
+
+            let mut b_zeroed = b_raw.clone();
+            zero_opposite_triangle(b_zeroed.view_mut(), uplo_b.into()).unwrap();
+            TRSM::default().a(a.view()).b(b_zeroed.view_mut()).side(side).uplo('L').run().unwrap();
+
+            // stale data in the other triangle is never read
+            zero_opposite_triangle(b_raw.view_mut(), uplo_b.into()).unwrap();
+            let mask = b_raw.mapv(|v| v == c64::from(0.0));
+            b_raw.zip_mut_with(&mask, |v, &m| {
+                if m {
+                    *v = c64::new(f64::NAN, f64::NAN);
+                }
+            });
+            TRSM::default()
+                .a(a.view())
+                .b(b_raw.view_mut())
+                .side(side)
+                .uplo('L')
+                .b_triangular(uplo_b)
+                .check_finite(true)
+                .run()
+                .unwrap();
+            assert_eq!(b_raw, b_zeroed);
+        }
+    }
+
+    #[test]
+    fn test_zero_opposite_triangle_trapezoid() {
+        let mut b = Array2::<f64>::ones((3, 5));
+        zero_opposite_triangle(b.view_mut(), BLASLower).unwrap();
+        let expected = array![[1., 0., 0., 0., 0.], [1., 1., 0., 0., 0.], [1., 1., 1., 0., 0.]];
+        assert_eq!(b, expected);
+        let mut b = Array2::<f64>::ones((5, 3));
+        zero_opposite_triangle(b.view_mut(), BLASUpper).unwrap();
+        assert_eq!(b, expected.t());
+        assert!(zero_opposite_triangle(b.view_mut(), BLASUpLo::Undefined).is_err());
+    }
+}
@@ -0,0 +1,12 @@
+pub mod test_condition;
+pub mod test_dot;
+pub mod test_elementwise;
+pub mod test_iter;
+pub mod test_matfree;
+pub mod test_norms;
+pub mod test_orthogonal;
+pub mod test_reduction;
+pub mod test_rotation;
+pub mod test_spectral;
+pub mod test_statistics;
+pub mod test_triangular;
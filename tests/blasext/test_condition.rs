@@ -0,0 +1,137 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+/// Exact 1-norm of inverse of triangular `t`, by solving for each column of identity.
+fn inv_norm_1_tri<F>(t: &ArrayView2<F>, uplo: char) -> f64
+where
+    F: TRSVNum,
+    F::RealFloat: Into<f64>,
+{
+    let n = t.nrows();
+    (0..n)
+        .map(|j| {
+            let mut e = Array1::<F>::zeros(n);
+            e[j] = F::one();
+            TRSV::default().a(t.view()).x(e.view_mut()).uplo(uplo).run().unwrap();
+            e.iter().map(|&v| F::modulus(v).into()).sum::<f64>()
+        })
+        .fold(0.0, f64::max)
+}
+
+fn norm_1<F>(a: &ArrayView2<F>) -> f64
+where
+    F: BLASFloat,
+    F::RealFloat: Into<f64>,
+{
+    a.columns().into_iter().map(|c| c.iter().map(|&v| F::modulus(v).into()).sum::<f64>()).fold(0.0, f64::max)
+}
+
+#[cfg(test)]
+mod cond_1_estimate {
+    use super::*;
+
+    #[test]
+    fn test_bidiagonal() {
+        // inverse of upper bidiagonal (1, -2) has elements 2^(j-i); exact condition number is 3 * (2^n - 1)
+        let n = 8;
+        let a = Array2::from_shape_fn((n, n), |(i, j)| match j as isize - i as isize {
+            0 => 1.0,
+            1 => -2.0,
+            _ => 0.0,
+        });
+        let factor = CondFactor::Triangular { uplo: BLASUpper, diag: BLASNonUnit };
+        let cond = cond_1_estimate(a.view(), factor).unwrap();
+        let cond_exact = 3.0 * 255.0;
+        assert!(cond <= cond_exact * (1.0 + 1e-10));
+        assert!(cond >= cond_exact / 10.0);
+
+        // only the triangle is referenced
+        let mut a_garbage = a.clone();
+        a_garbage[[5, 1]] = 1.0e10;
+        assert_eq!(cond_1_estimate(a_garbage.view(), factor).unwrap(), cond);
+    }
+
+    #[test]
+    fn test_triangular_random() {
+        for (uplo, layout) in [('L', 'R'), ('U', 'C')] {
+            let mut a = random_matrix::<c64>(12, 12, layout.into());
+            for i in 0..12 {
+                a[[i, i]] += c64::new(3.0, 0.0);
+            }
+            let a_tri = Array2::from_shape_fn((12, 12), |(i, j)| match (uplo, i >= j, i <= j) {
+                ('L', true, _) | ('U', _, true) => a[[i, j]],
+                _ => c64::new(0.0, 0.0),
+            });
+            let factor = CondFactor::Triangular { uplo: uplo.into(), diag: BLASNonUnit };
+            let cond = cond_1_estimate(a.view(), factor).unwrap();
+            let cond_exact = norm_1(&a_tri.view()) * inv_norm_1_tri(&a_tri.view(), uplo);
+            assert!(cond <= cond_exact * (1.0 + 1e-10));
+            assert!(cond >= cond_exact / 10.0);
+        }
+    }
+
+    #[test]
+    fn test_lu() {
+        let n = 10;
+        let l = Array2::from_shape_fn((n, n), |(i, j)| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => 0.3 * ((i * 7 + j * 3) % 5) as f64 - 0.6,
+            std::cmp::Ordering::Equal => 1.0,
+            std::cmp::Ordering::Less => 0.0,
+        });
+        let u = Array2::from_shape_fn((n, n), |(i, j)| match i.cmp(&j) {
+            std::cmp::Ordering::Less => 0.5 * ((i * 5 + j * 11) % 7) as f64 - 1.5,
+            std::cmp::Ordering::Equal => 0.5 + i as f64,
+            std::cmp::Ordering::Greater => 0.0,
+        });
+        let lu = &l + &u - Array2::<f64>::eye(n);
+        let lu_prod = gemm(&l.view(), &u.view());
+        let perm = [3, 0, 7, 1, 9, 2, 5, 8, 4, 6];
+        let mut a = Array2::<f64>::zeros((n, n));
+        for (i, &p) in perm.iter().enumerate() {
+            a.row_mut(p).assign(&lu_prod.row(i));
+        }
+
+        // row permutation does not change 1-norm of inverse (its columns are permuted)
+        let inv_norm = (0..n)
+            .map(|j| {
+                let mut e = Array1::<f64>::zeros(n);
+                e[j] = 1.0;
+                TRSV::default().a(l.view()).x(e.view_mut()).uplo('L').diag(BLASUnit).run().unwrap();
+                TRSV::default().a(u.view()).x(e.view_mut()).uplo('U').run().unwrap();
+                e.iter().map(|v| v.abs()).sum::<f64>()
+            })
+            .fold(0.0, f64::max);
+        let cond_exact = norm_1(&a.view()) * inv_norm;
+
+        let cond = cond_1_estimate(a.view(), CondFactor::LU { lu: lu.view(), perm: Some(&perm) }).unwrap();
+        assert!(cond <= cond_exact * (1.0 + 1e-10));
+        assert!(cond >= cond_exact / 10.0);
+
+        let cond_nopiv =
+            cond_1_estimate(lu_prod.view(), CondFactor::LU { lu: lu.view(), perm: None }).unwrap();
+        assert!(cond_nopiv <= norm_1(&lu_prod.view()) * inv_norm * (1.0 + 1e-10));
+        assert!(cond_nopiv >= norm_1(&lu_prod.view()) * inv_norm / 10.0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let a = Array2::<f64>::eye(3);
+        let err =
+            cond_1_estimate(a.view(), CondFactor::LU { lu: a.view(), perm: Some(&[0, 0, 1]) }).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+        let err =
+            cond_1_estimate(a.view(), CondFactor::LU { lu: a.view(), perm: Some(&[0, 1]) }).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+        let err =
+            cond_1_estimate(a.slice(s![.., ..2]), CondFactor::Triangular { uplo: BLASUpper, diag: BLASUnit })
+                .unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+
+        let mut a = Array2::<f64>::eye(3);
+        a[[1, 1]] = 0.0;
+        let cond =
+            cond_1_estimate(a.view(), CondFactor::Triangular { uplo: BLASLower, diag: BLASNonUnit }).unwrap();
+        assert!(cond.is_infinite());
+    }
+}
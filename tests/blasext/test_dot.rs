@@ -0,0 +1,397 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod blas_dot {
+    use super::*;
+
+    #[test]
+    fn test_mat_mat() {
+        for (layout_a, layout_b) in [('R', 'R'), ('R', 'C'), ('C', 'R'), ('C', 'C')] {
+            let a = random_matrix::<c64>(7, 5, layout_a.into());
+            let b = random_matrix::<c64>(5, 4, layout_b.into());
+            let c = a.blas_dot(&b).unwrap();
+            check_same(&c.view(), &gemm(&a.view(), &b.view()).view(), 4.0 * f64::EPSILON);
+            check_same(&c.view(), &a.dot(&b).view(), 4.0 * f64::EPSILON);
+        }
+
+        // views, with non-contiguous slice
+        let a = random_matrix::<f64>(9, 8, 'R'.into());
+        let b = random_matrix::<f64>(6, 4, 'C'.into());
+        let a_view = a.slice(s![1..8, ..;2]);
+        let c = a_view.blas_dot(&b.slice(s![..4, ..])).unwrap();
+        check_same(&c.view(), &a_view.dot(&b.slice(s![..4, ..])).view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_mat_vec() {
+        for layout in ['R', 'C'] {
+            let a = random_matrix::<c64>(7, 5, layout.into());
+            let x = random_array::<c64>(5);
+            let y = a.blas_dot(&x).unwrap();
+            let y_naive = gemm(&a.view(), &x.view().insert_axis(Axis(1))).remove_axis(Axis(1));
+            check_same(&y.view(), &y_naive.view(), 4.0 * f64::EPSILON);
+            check_same(&y.view(), &a.dot(&x).view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_vec_mat() {
+        for layout in ['R', 'C'] {
+            let a = random_matrix::<c64>(7, 5, layout.into());
+            let x = random_array::<c64>(7);
+            let y = x.blas_dot(&a).unwrap();
+            let y_naive = gemm(&x.view().insert_axis(Axis(0)), &a.view()).remove_axis(Axis(0));
+            check_same(&y.view(), &y_naive.view(), 4.0 * f64::EPSILON);
+            check_same(&y.view(), &x.dot(&a).view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_dim_mismatch() {
+        let a = random_matrix::<f64>(7, 5, 'R'.into());
+        assert!(a.blas_dot(&random_matrix::<f64>(4, 3, 'R'.into())).is_err());
+        assert!(a.blas_dot(&random_array::<f64>(7)).is_err());
+        assert!(random_array::<f64>(5).blas_dot(&a).is_err());
+    }
+}
+
+#[cfg(test)]
+mod gemm_diag {
+    use super::*;
+
+    #[test]
+    fn test_gemm_diag() {
+        // (m, k, n): scale `a` if it is smaller, otherwise `b`
+        for (m, k, n) in [(3, 5, 8), (8, 5, 3), (4, 6, 4)] {
+            for (layout_a, layout_b) in [('R', 'C'), ('C', 'R')] {
+                let a = random_matrix::<c64>(m, k, layout_a.into());
+                let b = random_matrix::<c64>(k, n, layout_b.into());
+                let d = random_array::<c64>(k);
+                let c = gemm_diag(a.view(), d.view(), b.view()).unwrap();
+                let c_naive = gemm(&gemm(&a.view(), &Array2::from_diag(&d).view()).view(), &b.view());
+                check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+            }
+        }
+
+        // non-contiguous operands
+        let a = random_matrix::<f64>(6, 10, 'R'.into());
+        let b = random_matrix::<f64>(5, 7, 'C'.into());
+        let d = random_array::<f64>(10);
+        let (a, b, d) = (a.slice(s![.., ..;2]), b.slice(s![.., 1..]), d.slice(s![1..;2]));
+        let c = gemm_diag(a, d, b).unwrap();
+        let c_naive = gemm(&gemm(&a, &Array2::from_diag(&d).view()).view(), &b);
+        check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_invalid_dim() {
+        let a = random_matrix::<f64>(3, 5, 'R'.into());
+        let b = random_matrix::<f64>(5, 2, 'R'.into());
+        let err = gemm_diag(a.view(), random_array::<f64>(4).view(), b.view()).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+        let err = gemm_diag(a.view(), random_array::<f64>(5).view(), a.view()).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+    }
+}
+
+#[cfg(test)]
+mod outer {
+    use super::*;
+
+    #[test]
+    fn test_outer() {
+        let x = random_array::<c64>(7);
+        let y = random_array::<c64>(10);
+        for (x, y) in [(x.view(), y.view()), (x.slice(s![..;-2]), y.slice(s![1..;3]))] {
+            let c = outer(x, y).unwrap();
+            let c_conj = outer_conj(x, y).unwrap();
+            assert_eq!(c.dim(), (x.len(), y.len()));
+            let mut c_loop = Array2::zeros(c.dim());
+            let mut c_conj_loop = Array2::zeros(c.dim());
+            for i in 0..x.len() {
+                for j in 0..y.len() {
+                    c_loop[[i, j]] = x[i] * y[j];
+                    c_conj_loop[[i, j]] = x[i] * y[j].conj();
+                }
+            }
+            check_same(&c.view(), &c_loop.view(), 4.0 * f64::EPSILON);
+            check_same(&c_conj.view(), &c_conj_loop.view(), 4.0 * f64::EPSILON);
+
+            // broadcasting outer product
+            let c_bcast = &x.insert_axis(Axis(1)) * &y.insert_axis(Axis(0));
+            check_same(&c.view(), &c_bcast.view(), 4.0 * f64::EPSILON);
+        }
+
+        let x = random_array::<f64>(4);
+        let y = random_array::<f64>(3);
+        let c_bcast = &x.view().insert_axis(Axis(1)) * &y.view().insert_axis(Axis(0));
+        check_same(&outer(x.view(), y.view()).unwrap().view(), &c_bcast.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_empty() {
+        let x = random_array::<f64>(4);
+        let e = Array1::<f64>::zeros(0);
+        assert!(matches!(outer(x.view(), e.view()).unwrap_err(), BLASError::InvalidDim(_)));
+        assert!(matches!(outer(e.view(), x.view()).unwrap_err(), BLASError::InvalidDim(_)));
+        let e = Array1::<c32>::zeros(0);
+        assert!(matches!(outer_conj(e.view(), e.view()).unwrap_err(), BLASError::InvalidDim(_)));
+    }
+}
+
+#[cfg(test)]
+mod kron {
+    use super::*;
+
+    fn kron_naive<F: BLASFloat>(a: &ArrayView2<F>, b: &ArrayView2<F>) -> Array2<F> {
+        let (ma, na) = a.dim();
+        let (mb, nb) = b.dim();
+        let mut c = Array2::zeros((ma * mb, na * nb));
+        for i in 0..ma {
+            for j in 0..na {
+                for k in 0..mb {
+                    for l in 0..nb {
+                        c[[i * mb + k, j * nb + l]] = a[[i, j]] * b[[k, l]];
+                    }
+                }
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn test_kron() {
+        let a = random_matrix::<c64>(3, 2, 'R'.into());
+        let b = random_matrix::<c64>(8, 10, 'C'.into());
+        for (a, b) in
+            [(a.view(), b.view()), (a.t(), b.slice(s![..;2, 1..;3])), (b.slice(s![..2, ..1]), a.view())]
+        {
+            let c = kron(a, b).unwrap();
+            assert_eq!(c.dim(), (a.nrows() * b.nrows(), a.ncols() * b.ncols()));
+            check_same(&c.view(), &kron_naive(&a, &b).view(), 4.0 * f64::EPSILON);
+        }
+
+        let a = random_matrix::<f32>(2, 3, 'C'.into());
+        let b = random_matrix::<f32>(4, 1, 'R'.into());
+        check_same(
+            &kron(a.view(), b.view()).unwrap().view(),
+            &kron_naive(&a.view(), &b.view()).view(),
+            4.0 * f32::EPSILON,
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let a = random_matrix::<f64>(2, 3, 'R'.into());
+        let e = Array2::<f64>::zeros((0, 3));
+        assert!(matches!(kron(a.view(), e.view()).unwrap_err(), BLASError::InvalidDim(_)));
+        assert!(matches!(kron(e.view(), a.view()).unwrap_err(), BLASError::InvalidDim(_)));
+    }
+}
+
+#[cfg(test)]
+mod dot_streaming {
+    use super::*;
+
+    #[test]
+    fn test_uneven_chunks() {
+        let x = random_array::<c64>(1000);
+        let y = random_array::<c64>(1000);
+        let whole = DOT::default().x(x.view()).y(y.view()).run().unwrap();
+        let bounds = [0, 1, 7, 256, 257, 600, 999, 1000];
+        let chunks = |v: &Array1<c64>| -> Vec<Array1<c64>> {
+            bounds.windows(2).map(|w| v.slice(s![w[0]..w[1]]).to_owned()).collect()
+        };
+        let (xs, ys) = (chunks(&x), chunks(&y));
+        let res = dot_streaming(xs.iter().map(|x| x.view()), ys.iter().map(|y| y.view())).unwrap();
+        assert!((res - whole).norm() < 1e-10);
+        assert!((res - x.dot(&y)).norm() < 1e-10);
+
+        // strided chunks
+        let res =
+            dot_streaming(x.axis_chunks_iter(Axis(0), 10), y.slice(s![..;-1]).axis_chunks_iter(Axis(0), 10));
+        assert!((res.unwrap() - x.dot(&y.slice(s![..;-1]))).norm() < 1e-10);
+
+        // no chunks
+        let empty = std::iter::empty::<ArrayView1<f64>>;
+        assert_eq!(dot_streaming(empty(), empty()).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_compensated() {
+        // many chunks with partial sums of 1 on a large running sum: naive f32 summation loses all of them
+        let x = Array1::<f32>::ones(1);
+        let big = Array1::<f32>::from_elem(1, 1.0e8);
+        let xs = std::iter::once(x.view()).chain(std::iter::repeat_n(x.view(), 1000));
+        let ys = std::iter::once(big.view()).chain(std::iter::repeat_n(x.view(), 1000));
+        let res = dot_streaming(xs, ys).unwrap();
+        assert_eq!(res, 1.0e8 + 1000.0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let x = random_array::<f64>(10);
+        let (x1, x2) = (x.slice(s![..4]), x.slice(s![4..]));
+        let res = dot_streaming([x1, x2].into_iter(), [x2, x1].into_iter());
+        assert!(matches!(res, Err(BLASError::InvalidDim(_))));
+        let res = dot_streaming([x1, x2].into_iter(), [x1].into_iter());
+        assert!(matches!(res, Err(BLASError::InvalidDim(_))));
+        let res = dot_streaming([x1].into_iter(), [x1, x2].into_iter());
+        assert!(matches!(res, Err(BLASError::InvalidDim(_))));
+    }
+}
+
+#[cfg(test)]
+mod tensordot {
+    use super::*;
+    use ndarray::{IxDyn, Slice};
+
+    fn random_tensor<F: TestFloat>(shape: &[usize]) -> ArrayD<F> {
+        random_array::<F>(shape.iter().product()).into_shape(shape).unwrap()
+    }
+
+    fn tensordot_naive<F: BLASFloat>(
+        a: &ArrayViewD<F>,
+        b: &ArrayViewD<F>,
+        axes_a: &[usize],
+        axes_b: &[usize],
+    ) -> ArrayD<F> {
+        let free_a: Vec<usize> = (0..a.ndim()).filter(|ax| !axes_a.contains(ax)).collect();
+        let free_b: Vec<usize> = (0..b.ndim()).filter(|ax| !axes_b.contains(ax)).collect();
+        let shape: Vec<usize> =
+            free_a.iter().map(|&ax| a.shape()[ax]).chain(free_b.iter().map(|&ax| b.shape()[ax])).collect();
+        let shape_k: Vec<usize> = axes_a.iter().map(|&ax| a.shape()[ax]).collect();
+        let mut c = ArrayD::zeros(shape);
+        for (idx, c_val) in c.indexed_iter_mut() {
+            for idx_k in ndarray::indices(IxDyn(&shape_k)) {
+                let mut ia = vec![0; a.ndim()];
+                let mut ib = vec![0; b.ndim()];
+                for (i, &ax) in free_a.iter().enumerate() {
+                    ia[ax] = idx[i];
+                }
+                for (i, &ax) in free_b.iter().enumerate() {
+                    ib[ax] = idx[free_a.len() + i];
+                }
+                for (i, (&ax_a, &ax_b)) in axes_a.iter().zip(axes_b).enumerate() {
+                    ia[ax_a] = idx_k[i];
+                    ib[ax_b] = idx_k[i];
+                }
+                *c_val = *c_val + a[IxDyn(&ia)] * b[IxDyn(&ib)];
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn test_tensordot() {
+        // (axes of a, axes of b, shape of a, shape of b)
+        type Case<'a> = (&'a [usize], &'a [usize], &'a [usize], &'a [usize]);
+        let cases: [Case; 5] = [
+            (&[1], &[0], &[3, 4], &[4, 5]),
+            (&[2, 0], &[0, 1], &[3, 4, 5], &[5, 3, 2]),
+            (&[1, 3], &[2, 0], &[2, 3, 4, 5], &[5, 6, 3]),
+            (&[0, 1], &[1, 0], &[3, 4], &[4, 3]),
+            (&[], &[], &[2, 3], &[4]),
+        ];
+        for (axes_a, axes_b, shape_a, shape_b) in cases {
+            let a = random_tensor::<c64>(shape_a);
+            let b = random_tensor::<c64>(shape_b);
+            let c = tensordot(a.view(), b.view(), (axes_a, axes_b)).unwrap();
+            let c_naive = tensordot_naive(&a.view(), &b.view(), axes_a, axes_b);
+            assert_eq!(c.shape(), c_naive.shape());
+            check_same(&c.view(), &c_naive.view(), 16.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_tensordot_non_contiguous() {
+        let a_raw = random_tensor::<f64>(&[6, 4, 10]);
+        let b_raw = random_tensor::<f64>(&[5, 8, 3]);
+        let a = a_raw.slice_axis(Axis(2), Slice::new(0, None, 2)).reversed_axes();
+        let b = b_raw.slice_axis(Axis(1), Slice::new(1, None, 2));
+        let (axes_a, axes_b): (&[usize], &[usize]) = (&[0, 1], &[0, 1]);
+        let c = tensordot(a.view(), b.view(), (axes_a, axes_b)).unwrap();
+        assert_eq!(c.shape(), &[6, 3]);
+        check_same(&c.view(), &tensordot_naive(&a, &b, axes_a, axes_b).view(), 16.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_tensordot_invalid() {
+        let a = random_tensor::<f32>(&[3, 4]);
+        let b = random_tensor::<f32>(&[4, 5]);
+        assert!(matches!(tensordot(a.view(), b.view(), (&[0], &[0])), Err(BLASError::InvalidDim(_))));
+        assert!(matches!(tensordot(a.view(), b.view(), (&[1], &[])), Err(BLASError::InvalidDim(_))));
+        assert!(matches!(tensordot(a.view(), b.view(), (&[2], &[0])), Err(BLASError::InvalidDim(_))));
+        assert!(matches!(tensordot(a.view(), a.view(), (&[1, 1], &[1, 1])), Err(BLASError::InvalidDim(_))));
+        // empty contracted axis gives zeros
+        let a = random_tensor::<f32>(&[3, 0]);
+        let b = random_tensor::<f32>(&[0, 5]);
+        assert_eq!(
+            tensordot(a.view(), b.view(), (&[1], &[0])).unwrap(),
+            ArrayD::<f32>::zeros(IxDyn(&[3, 5]))
+        );
+    }
+}
+
+#[cfg(test)]
+mod quadratic_form {
+    use super::*;
+
+    /// Dense hermitian matrix from the `uplo` triangle of `a`, with real diagonal.
+    fn hermitian_from<F: TestFloat>(a: &ArrayView2<F>, uplo: char) -> Array2<F> {
+        let n = a.nrows();
+        Array2::from_shape_fn((n, n), |(i, j)| match (i.cmp(&j), uplo) {
+            (core::cmp::Ordering::Equal, _) => F::from_real(F::real(a[[i, i]])),
+            (core::cmp::Ordering::Greater, 'L') | (core::cmp::Ordering::Less, 'U') => a[[i, j]],
+            _ => F::conj(a[[j, i]]),
+        })
+    }
+
+    #[test]
+    fn test_complex() {
+        let a_raw = random_matrix::<c64>(12, 12, 'R'.into());
+        let x_raw = random_array::<c64>(12);
+        for (a, x) in [
+            (a_raw.slice(s![..6, ..6]), x_raw.slice(s![..6])),
+            (a_raw.slice(s![..;2, ..;2]).reversed_axes(), x_raw.slice(s![..;2])),
+        ] {
+            for uplo in ['L', 'U'] {
+                let h = hermitian_from(&a, uplo);
+                let expect = x.mapv(|v| v.conj()).dot(&h.dot(&x));
+                assert!(expect.im.abs() < 1e-12);
+                let res = quadratic_form(a, x, uplo.into()).unwrap();
+                assert!((res - expect.re).abs() < 1e-12 * expect.norm().max(1.0));
+                let res = quadratic_form_checked(a, x, uplo.into(), 1e-12).unwrap();
+                assert!((res - expect.re).abs() < 1e-12 * expect.norm().max(1.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_real() {
+        let a = random_matrix::<f32>(7, 7, 'C'.into());
+        let x = random_array::<f32>(7);
+        let s = hermitian_from(&a.view(), 'U');
+        let expect = x.dot(&s.dot(&x));
+        let res = quadratic_form(a.view(), x.view(), BLASUpper).unwrap();
+        assert!((res - expect).abs() < 1e-5 * expect.abs().max(1.0));
+        let res = quadratic_form_checked(a.view(), x.view(), BLASUpper, 0.0).unwrap();
+        assert!((res - expect).abs() < 1e-5 * expect.abs().max(1.0));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let a = random_matrix::<c64>(4, 5, 'R'.into());
+        let x = random_array::<c64>(5);
+        assert!(matches!(quadratic_form(a.view(), x.view(), BLASLower), Err(BLASError::InvalidDim(_))));
+        let a = random_matrix::<c64>(4, 4, 'R'.into());
+        assert!(matches!(quadratic_form(a.view(), x.view(), BLASLower), Err(BLASError::InvalidDim(_))));
+
+        let mut x = random_array::<c64>(4);
+        x[1] = c64::new(f64::NAN, 0.0);
+        let err = quadratic_form_checked(a.view(), x.view(), BLASLower, 1e-12).unwrap_err();
+        assert!(matches!(err, BLASError::FailedCheck(_)));
+    }
+}
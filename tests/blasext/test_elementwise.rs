@@ -0,0 +1,182 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod mat_axpy {
+    use super::*;
+
+    #[test]
+    fn test_contiguous() {
+        for layout in ['R', 'C'] {
+            let alpha = c64::rand();
+            let b = random_matrix::<c64>(7, 5, layout.into());
+            let mut a = random_matrix::<c64>(7, 5, layout.into());
+            let a_naive = &a + &b.mapv(|v| alpha * v);
+            mat_axpy(alpha, b.view(), a.view_mut()).unwrap();
+            check_same(&a.view(), &a_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_mismatched_layout() {
+        for (layout_a, layout_b) in [('R', 'C'), ('C', 'R')] {
+            let alpha = 0.7;
+            let b = random_matrix::<f64>(7, 5, layout_b.into());
+            let mut a = random_matrix::<f64>(7, 5, layout_a.into());
+            let a_naive = &a + &b.mapv(|v| alpha * v);
+            mat_axpy(alpha, b.view(), a.view_mut()).unwrap();
+            check_same(&a.view(), &a_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_strided() {
+        let alpha = 2.0;
+        let b_raw = random_matrix::<f32>(10, 10, 'R'.into());
+        let mut a_raw = random_matrix::<f32>(10, 10, 'C'.into());
+        let b = b_raw.slice(s![1..8;2, ..;3]);
+        let a_slc = s![..;3, 2..6];
+        let a_naive = &a_raw.slice(a_slc) + &b.mapv(|v| alpha * v);
+        mat_axpy(alpha, b, a_raw.slice_mut(a_slc)).unwrap();
+        check_same(&a_raw.slice(a_slc), &a_naive.view(), 4.0 * f32::EPSILON);
+    }
+
+    #[test]
+    fn test_per_lane_matches_serial() {
+        // strided input takes the per-lane path, which is parallel with feature `rayon`
+        let alpha = c64::rand();
+        let b_raw = random_matrix::<c64>(64, 40, 'R'.into());
+        let mut a_raw = random_matrix::<c64>(64, 40, 'R'.into());
+        let b = b_raw.slice(s![.., ..;2]);
+        let mut a_serial = a_raw.slice(s![.., ..;2]).to_owned();
+        for (b_lane, a_lane) in b.outer_iter().zip(a_serial.outer_iter_mut()) {
+            AXPY::default().x(b_lane).y(a_lane).alpha(alpha).run().unwrap();
+        }
+        mat_axpy(alpha, b, a_raw.slice_mut(s![.., ..;2])).unwrap();
+        assert_eq!(a_raw.slice(s![.., ..;2]), a_serial);
+    }
+
+    #[test]
+    fn test_shape_mismatch() {
+        let b = random_matrix::<f64>(7, 5, 'R'.into());
+        let mut a = random_matrix::<f64>(5, 7, 'R'.into());
+        assert!(mat_axpy(1.0, b.view(), a.view_mut()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod axpy_real_alpha {
+    use super::*;
+
+    fn step<F: AXPYNum>(alpha: F::RealFloat, x: ArrayView1<F>, y: ArrayViewMut1<F>) {
+        // generic caller, where `F::RealFloat` is not known to convert into `F`
+        axpy_real_alpha(alpha, x, y).unwrap();
+    }
+
+    #[test]
+    fn test_complex() {
+        let x_raw = random_array::<c64>(20);
+        let y_orig = random_array::<c64>(20);
+        for x in [x_raw.slice(s![..10]), x_raw.slice(s![..;2]), x_raw.slice(s![10..;-1])] {
+            let mut y = y_orig.slice(s![..10]).to_owned();
+            step(-0.375, x, y.view_mut());
+            let mut y_expect = y_orig.slice(s![..10]).to_owned();
+            AXPY::default().x(x).y(y_expect.view_mut()).alpha(c64::new(-0.375, 0.0)).run().unwrap();
+            assert_eq!(y, y_expect);
+        }
+
+        let x = random_array::<c32>(5);
+        let mut y = random_array::<c32>(5);
+        let y_expect = &y + &x.mapv(|v| v * 2.5);
+        step(2.5, x.view(), y.view_mut());
+        assert!(y.iter().zip(y_expect.iter()).all(|(a, b)| (a - b).norm() < 1e-5));
+    }
+
+    #[test]
+    fn test_real() {
+        let x = random_array::<f64>(6);
+        let mut y = random_array::<f64>(6);
+        let y_expect = &y + &x.mapv(|v| v * 0.5);
+        step(0.5, x.view(), y.view_mut());
+        check_same(&y.view(), &y_expect.view(), 4.0 * f64::EPSILON);
+
+        let mut y = random_array::<f64>(5);
+        assert!(matches!(axpy_real_alpha(1.0, x.view(), y.view_mut()), Err(BLASError::InvalidDim(_))));
+    }
+}
+
+#[cfg(test)]
+mod scale {
+    use super::*;
+
+    #[test]
+    fn test_contiguous() {
+        for layout in ['R', 'C'] {
+            let alpha = c64::rand();
+            let mut a = random_matrix::<c64>(7, 5, layout.into());
+            let a_naive = a.mapv(|v| alpha * v);
+            scale(alpha, a.view_mut()).unwrap();
+            check_same(&a.view(), &a_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_strided() {
+        for layout in ['R', 'C'] {
+            let mut a_raw = random_matrix::<f32>(10, 10, layout.into());
+            let a_slc = s![1..8;2, ..;3];
+            let a_orig = a_raw.clone();
+            scale(2.5, a_raw.slice_mut(a_slc)).unwrap();
+            check_same(
+                &a_raw.slice(a_slc),
+                &a_orig.slice(a_slc).mapv(|v| 2.5 * v).view(),
+                4.0 * f32::EPSILON,
+            );
+            // elements out of slice are untouched
+            a_raw.slice_mut(a_slc).assign(&a_orig.slice(a_slc));
+            assert_eq!(a_raw, a_orig);
+        }
+    }
+
+    #[test]
+    fn test_negate() {
+        let mut a_raw = random_matrix::<f64>(6, 8, 'C'.into());
+        let a_orig = a_raw.clone();
+        negate(a_raw.view_mut()).unwrap();
+        assert_eq!(a_raw, a_orig.mapv(|v| -v));
+        negate(a_raw.slice_mut(s![.., ..;2])).unwrap();
+        assert_eq!(a_raw.slice(s![.., ..;2]), a_orig.slice(s![.., ..;2]));
+    }
+}
+
+#[cfg(test)]
+mod extract_block {
+    use super::*;
+
+    #[test]
+    fn test_extract_block() {
+        for layout in ['R', 'C'] {
+            let a = random_matrix::<c64>(20, 30, layout.into());
+            for a in [a.view(), a.slice(s![..;2, 1..;3])] {
+                for (rows, cols) in
+                    [(2..7, 3..9), (0..a.nrows(), 0..a.ncols()), (0..a.nrows(), 4..6), (3..4, 0..5)]
+                {
+                    let block = extract_block(a, rows.clone(), cols.clone()).unwrap();
+                    assert_eq!(block, a.slice(s![rows, cols]));
+                    assert!(block.t().is_standard_layout());
+                }
+            }
+        }
+
+        let a = random_matrix::<f64>(6, 5, 'C'.into());
+        assert_eq!(extract_block(a.view(), 2..2, 0..5).unwrap().dim(), (0, 5));
+    }
+
+    #[test]
+    fn test_out_of_bound() {
+        let a = random_matrix::<f64>(6, 5, 'R'.into());
+        assert!(matches!(extract_block(a.view(), 0..7, 0..5), Err(BLASError::InvalidDim(_))));
+        assert!(matches!(extract_block(a.view(), 0..6, 2..6), Err(BLASError::InvalidDim(_))));
+    }
+}
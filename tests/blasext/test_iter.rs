@@ -0,0 +1,51 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod gemv_over_rows {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        for (layout_a, layout_x) in [('R', 'R'), ('R', 'C'), ('C', 'R'), ('C', 'C')] {
+            let a = random_matrix::<c64>(5, 4, layout_a.into());
+            let xs = random_matrix::<c64>(7, 4, layout_x.into());
+            let ys_batch = gemm(&xs.view(), &a.t());
+
+            let iter = gemv_over_rows(a.view(), xs.view()).unwrap();
+            assert_eq!(iter.len(), 7);
+            let ys = iter.collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(ys.len(), 7);
+            for (y, y_batch) in ys.iter().zip(ys_batch.rows()) {
+                check_same(&y.view(), &y_batch, 4.0 * f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_combinators() {
+        let a = random_matrix::<f64>(5, 4, 'R'.into());
+        let xs = random_matrix::<f64>(10, 4, 'C'.into());
+        let ys_batch = gemm(&xs.slice(s![..;3, ..]), &a.t());
+
+        // only every third row is computed
+        let ys: Vec<Array1<f64>> =
+            gemv_over_rows(a.view(), xs.view()).unwrap().step_by(3).map(|y| y.unwrap()).collect();
+        assert_eq!(ys.len(), 4);
+        for (y, y_batch) in ys.iter().zip(ys_batch.rows()) {
+            check_same(&y.view(), &y_batch, 4.0 * f64::EPSILON);
+        }
+
+        let mut iter = gemv_over_rows(a.view(), xs.view()).unwrap();
+        assert_eq!(iter.nth(9).unwrap().unwrap().len(), 5);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let a = random_matrix::<f32>(5, 4, 'R'.into());
+        let xs = random_matrix::<f32>(7, 5, 'R'.into());
+        assert!(gemv_over_rows(a.view(), xs.view()).is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+
+#[cfg(test)]
+mod gemm_matfree {
+    use super::*;
+
+    #[test]
+    fn test_identity_columns() {
+        for layout in ['R', 'C'] {
+            let a = random_matrix::<c64>(5, 4, layout.into());
+            let c = gemm_matfree(a.view(), 4, |j, mut b_j| {
+                assert_eq!(b_j.len(), 4);
+                b_j.fill(c64::new(0.0, 0.0));
+                b_j[j] = c64::new(1.0, 0.0);
+            })
+            .unwrap();
+            assert_eq!(c.dim(), (5, 4));
+            check_same(&c.view(), &a.view(), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_against_gemm() {
+        let a = random_matrix::<f64>(6, 3, 'R'.into());
+        let b = random_matrix::<f64>(3, 7, 'C'.into());
+        let c = gemm_matfree(a.view(), 7, |j, mut b_j| b_j.assign(&b.column(j))).unwrap();
+        check_same(&c.view(), &gemm(&a.view(), &b.view()).view(), 4.0 * f64::EPSILON);
+
+        let c = gemm_matfree(a.view(), 0, |_, _| unreachable!()).unwrap();
+        assert_eq!(c.dim(), (6, 0));
+    }
+}
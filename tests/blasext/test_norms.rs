@@ -0,0 +1,159 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod blas_norms {
+    use super::*;
+
+    #[test]
+    fn test_vector() {
+        let x_raw = random_array::<c64>(20);
+        for x in [x_raw.view(), x_raw.slice(s![..;3]), x_raw.slice(s![..;-2])] {
+            assert_eq!(x.blas_nrm2().unwrap(), NRM2::default().x(x).run().unwrap());
+            assert_eq!(x.blas_asum().unwrap(), ASUM::default().x(x).run().unwrap());
+            assert_eq!(x.blas_iamax().unwrap(), IAMAX::default().x(x.to_owned().view()).run().unwrap());
+        }
+        let x_owned = x_raw.to_owned();
+        assert_eq!(x_owned.blas_iamax().unwrap(), IAMAX::default().x(x_raw.view()).run().unwrap());
+    }
+
+    #[test]
+    fn test_matrix() {
+        for layout in ['R', 'C'] {
+            let a_raw = random_matrix::<f64>(6, 8, layout.into());
+            for a in [a_raw.view(), a_raw.slice(s![1.., ..;2])] {
+                let flat = a.iter().cloned().collect::<Array1<f64>>();
+                let nrm2 = NRM2::default().x(flat.view()).run().unwrap();
+                let asum = ASUM::default().x(flat.view()).run().unwrap();
+                assert!((a.blas_nrm2().unwrap() - nrm2).abs() <= 4.0 * f64::EPSILON * nrm2);
+                assert!((a.blas_asum().unwrap() - asum).abs() <= 4.0 * f64::EPSILON * asum);
+
+                let idx = IAMAX::default().x(flat.view()).run().unwrap();
+                let ncols = a.ncols();
+                assert_eq!(a.blas_iamax().unwrap(), (idx / ncols, idx % ncols));
+            }
+        }
+        let a = Array2::<f64>::zeros((0, 3));
+        assert_eq!(a.blas_nrm2().unwrap(), 0.0);
+        assert_eq!(a.blas_iamax().unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn test_matrix_axis() {
+        for layout in ['R', 'C'] {
+            let a = random_matrix::<c64>(5, 7, layout.into());
+            let col_nrm2 = a.blas_nrm2_axis(Axis(0)).unwrap();
+            let row_asum = a.blas_asum_axis(Axis(1)).unwrap();
+            let col_iamax = a.blas_iamax_axis(Axis(0)).unwrap();
+            assert_eq!(col_nrm2.len(), 7);
+            assert_eq!(row_asum.len(), 5);
+            for j in 0..7 {
+                assert_eq!(col_nrm2[j], NRM2::default().x(a.column(j)).run().unwrap());
+                assert_eq!(col_iamax[j], IAMAX::default().x(a.column(j)).run().unwrap());
+            }
+            for i in 0..5 {
+                assert_eq!(row_asum[i], ASUM::default().x(a.row(i)).run().unwrap());
+            }
+        }
+        let a = Array2::<f64>::zeros((2, 2));
+        assert!(a.blas_nrm2_axis(Axis(2)).is_err());
+    }
+
+    #[test]
+    fn test_matrix_axis_matches_serial() {
+        // lanes are processed in parallel with feature `rayon`; results must equal per-lane serial calls
+        let a_raw = random_matrix::<f64>(50, 70, 'C'.into());
+        let a = a_raw.slice(s![..;2, ..]);
+        let serial =
+            a.columns().into_iter().map(|x| NRM2::default().x(x).run().unwrap()).collect::<Array1<f64>>();
+        assert_eq!(a.blas_nrm2_axis(Axis(0)).unwrap(), serial);
+        let serial =
+            a.rows().into_iter().map(|x| ASUM::default().x(x).run().unwrap()).collect::<Array1<f64>>();
+        assert_eq!(a.blas_asum_axis(Axis(1)).unwrap(), serial);
+    }
+}
+
+#[cfg(test)]
+mod matrix_norm {
+    use super::*;
+
+    #[test]
+    fn test_rectangular() {
+        for layout in ['R', 'C'] {
+            let a_raw = random_matrix::<f64>(9, 12, layout.into());
+            for a in [a_raw.view(), a_raw.slice(s![1..8, ..;3]), a_raw.slice(s![..;-2, 2..])] {
+                let norm_1 = a.columns().into_iter().map(|x| x.mapv(f64::abs).sum()).fold(0.0, f64::max);
+                let norm_inf = a.rows().into_iter().map(|x| x.mapv(f64::abs).sum()).fold(0.0, f64::max);
+                assert!((matrix_norm_1(a).unwrap() - norm_1).abs() <= 4.0 * f64::EPSILON * norm_1);
+                assert!((matrix_norm_inf(a).unwrap() - norm_inf).abs() <= 4.0 * f64::EPSILON * norm_inf);
+            }
+        }
+    }
+
+    #[test]
+    fn test_complex() {
+        // ASUM measures complex elements by |Re| + |Im|
+        let a = random_matrix::<c32>(4, 6, 'C'.into());
+        let modulus = |x: &c32| x.re.abs() + x.im.abs();
+        let norm_1 = a.columns().into_iter().map(|x| x.iter().map(modulus).sum::<f32>()).fold(0.0, f32::max);
+        let norm_inf = a.rows().into_iter().map(|x| x.iter().map(modulus).sum::<f32>()).fold(0.0, f32::max);
+        assert!((matrix_norm_1(a.view()).unwrap() - norm_1).abs() <= 8.0 * f32::EPSILON * norm_1);
+        assert!((matrix_norm_inf(a.view()).unwrap() - norm_inf).abs() <= 8.0 * f32::EPSILON * norm_inf);
+    }
+
+    #[test]
+    fn test_empty_and_nan() {
+        assert_eq!(matrix_norm_1(Array2::<f64>::zeros((0, 3)).view()).unwrap(), 0.0);
+        assert_eq!(matrix_norm_inf(Array2::<f64>::zeros((3, 0)).view()).unwrap(), 0.0);
+        let mut a = Array2::<f64>::ones((3, 3));
+        a[[1, 2]] = f64::NAN;
+        assert!(matrix_norm_1(a.view()).unwrap().is_nan());
+        assert!(matrix_norm_inf(a.view()).unwrap().is_nan());
+    }
+}
+
+#[cfg(test)]
+mod normalize_columns {
+    use super::*;
+
+    #[test]
+    fn test_unit_columns() {
+        for layout in ['R', 'C'] {
+            let mut a_raw = random_matrix::<c64>(7, 10, layout.into());
+            a_raw.column_mut(3).fill(c64::new(0.0, 0.0));
+            a_raw.column_mut(8).fill(c64::new(0.0, 0.0));
+            let a_orig = a_raw.clone();
+
+            let skipped = normalize_columns(a_raw.slice_mut(s![.., ..;-1])).unwrap();
+            // columns are reversed in the view
+            assert_eq!(skipped, vec![1, 6]);
+            for (j, col) in a_raw.columns().into_iter().enumerate() {
+                let norm = col.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+                if j == 3 || j == 8 {
+                    assert_eq!(col, a_orig.column(j));
+                } else {
+                    assert!((norm - 1.0).abs() <= 4.0 * f64::EPSILON);
+                    // direction is kept
+                    let orig_norm = a_orig.column(j).iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+                    check_same(&col, &(&a_orig.column(j) / c64::from(orig_norm)).view(), 4.0 * f64::EPSILON);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_strided_and_empty() {
+        let mut a_raw = random_matrix::<f32>(9, 6, 'R'.into());
+        let skipped = normalize_columns(a_raw.slice_mut(s![..;2, 1..])).unwrap();
+        assert!(skipped.is_empty());
+        for col in a_raw.slice(s![..;2, 1..]).columns() {
+            assert!((col.dot(&col).sqrt() - 1.0).abs() <= 4.0 * f32::EPSILON);
+        }
+
+        let mut e = Array2::<f64>::zeros((0, 3));
+        assert_eq!(normalize_columns(e.view_mut()).unwrap(), vec![0, 1, 2]);
+        let mut e = Array2::<f64>::zeros((3, 0));
+        assert!(normalize_columns(e.view_mut()).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+use num_complex::*;
+
+#[cfg(test)]
+mod mgs_step {
+    use super::*;
+
+    #[test]
+    fn test_orthogonal() {
+        for layout in ['R', 'C'] {
+            // orthonormal basis built column by column
+            let (n, k) = (12, 5);
+            let mut q = Array2::<c64>::zeros(match layout {
+                'R' => (n, k).into_shape(),
+                _ => (n, k).f().into_shape(),
+            });
+            for j in 0..k {
+                let v_raw = random_array::<c64>(n);
+                let mut v = v_raw.clone();
+                let (h, norm) = mgs_step(q.slice(s![.., ..j]), v.view_mut()).unwrap();
+
+                // v is orthogonal to previous columns, and normalized
+                let overlap = q.slice(s![.., ..j]).t().mapv(|x| x.conj()).dot(&v);
+                assert!(overlap.iter().all(|x| x.norm() < 1e-12));
+                assert!((v.dot(&v.mapv(|x| x.conj())).re - 1.0).abs() < 1e-12);
+
+                // original vector is recovered from projections and norm
+                let v_rebuilt = q.slice(s![.., ..j]).dot(&h) + &v * c64::from(norm);
+                check_same(&v_rebuilt.view(), &v_raw.view(), 16.0 * f64::EPSILON);
+                q.column_mut(j).assign(&v);
+            }
+            let gram = q.t().mapv(|x| x.conj()).dot(&q);
+            check_same(&gram.view(), &Array2::eye(k).view(), 16.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_real_and_dependent() {
+        let q_raw = random_matrix::<f64>(10, 3, 'R'.into());
+        let mut q = q_raw.clone();
+        for j in 0..3 {
+            let (q_prev, mut q_j) = q.view_mut().split_at(Axis(1), j);
+            mgs_step(q_prev.view(), q_j.column_mut(0)).unwrap();
+        }
+        let gram = q.t().dot(&q);
+        check_same(&gram.view(), &Array2::eye(3).view(), 16.0 * f64::EPSILON);
+
+        // vector in span of q is left unnormalized, with zero norm up to rounding
+        let mut v = q.dot(&array![1.0, -2.0, 0.5]);
+        let (h, norm) = mgs_step(q.view(), v.view_mut()).unwrap();
+        check_same(&h.view(), &array![1.0, -2.0, 0.5].view(), 16.0 * f64::EPSILON);
+        assert!(norm < 1e-14);
+
+        let mut v = Array1::<f64>::zeros(10);
+        let (_, norm) = mgs_step(q.view(), v.view_mut()).unwrap();
+        assert_eq!(norm, 0.0);
+        assert!(v.iter().all(|&x| x == 0.0));
+
+        let mut v = Array1::<f64>::zeros(9);
+        assert!(matches!(mgs_step(q.view(), v.view_mut()), Err(BLASError::InvalidDim(_))));
+    }
+}
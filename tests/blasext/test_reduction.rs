@@ -0,0 +1,40 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod max_min_abs {
+    use super::*;
+
+    macro_rules! test_macro {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                let x_raw = random_array::<$F>(40);
+                for x in [x_raw.view(), x_raw.slice(s![3..;3]), x_raw.slice(s![..;-2])] {
+                    let measure = x.mapv(|v| <$F as BLASFloat>::real(v).abs() + <$F as BLASFloat>::imag(v).abs());
+                    let max_naive = measure.iter().cloned().fold(0.0, <$F as BLASFloat>::RealFloat::max);
+                    let min_naive = measure.iter().cloned().fold(f64::INFINITY as _, <$F as BLASFloat>::RealFloat::min);
+                    assert_eq!(max_abs(x).unwrap(), max_naive);
+                    assert_eq!(min_abs(x).unwrap(), min_naive);
+                }
+
+                let x = Array1::<$F>::zeros(0);
+                assert!(matches!(max_abs(x.view()), Err(BLASError::InvalidDim(_))));
+                assert!(matches!(min_abs(x.view()), Err(BLASError::InvalidDim(_))));
+            }
+        };
+    }
+
+    test_macro!(test_f32, f32);
+    test_macro!(test_f64, f64);
+    test_macro!(test_c32, c32);
+    test_macro!(test_c64, c64);
+
+    #[test]
+    fn test_signed() {
+        let x = Array1::from_vec(vec![1.5, -7.0, 0.25, 3.0, -0.5]);
+        assert_eq!(max_abs(x.view()).unwrap(), 7.0);
+        assert_eq!(min_abs(x.view()).unwrap(), 0.25);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod givens_qr {
+    use super::*;
+
+    #[test]
+    fn test_givens_qr() {
+        for layout in ['R', 'C'] {
+            let (m, n) = (6, 4);
+            let a = random_matrix::<f64>(m, n, layout.into());
+            let mut r = a.clone();
+            let mut qt = Array2::<f64>::eye(m);
+            for i in 0..n {
+                for j in i + 1..m {
+                    let (c, s) = apply_givens_qr_step(r.view_mut(), i, j).unwrap();
+                    let (qt_i, qt_j) = qt.multi_slice_mut((s![i, ..], s![j, ..]));
+                    ROT::default().x(qt_i).y(qt_j).c(c).s(s).run().unwrap();
+                }
+            }
+
+            // upper triangular
+            for ((i, j), &x) in r.indexed_iter() {
+                if i > j {
+                    assert_eq!(x, 0.0);
+                }
+            }
+            // norm is preserved, and Q R = A
+            let norm = |x: &Array2<f64>| x.iter().map(|v| v * v).sum::<f64>().sqrt();
+            assert!((norm(&r) - norm(&a)).abs() < 1e-12 * norm(&a));
+            check_same(&gemm(&qt.t(), &r.view()).view(), &a.view(), 16.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_invalid_index() {
+        let mut r = random_matrix::<f64>(3, 2, 'R'.into());
+        assert!(matches!(apply_givens_qr_step(r.view_mut(), 2, 0), Err(BLASError::InvalidDim(_))));
+        assert!(matches!(apply_givens_qr_step(r.view_mut(), 0, 3), Err(BLASError::InvalidDim(_))));
+        assert!(matches!(apply_givens_qr_step(r.view_mut(), 1, 1), Err(BLASError::InvalidFlag(_))));
+    }
+}
+
+#[cfg(test)]
+mod givens_sequence {
+    use super::*;
+
+    fn rotation_matrix(n: usize, (i, j, c, s): (usize, usize, f64, f64)) -> Array2<f64> {
+        let mut g = Array2::<f64>::eye(n);
+        g[[i, i]] = c;
+        g[[j, j]] = c;
+        g[[i, j]] = s;
+        g[[j, i]] = -s;
+        g
+    }
+
+    fn random_rotations(n: usize, count: usize) -> Vec<(usize, usize, f64, f64)> {
+        let angles = random_array::<f64>(count);
+        (0..count)
+            .map(|k| {
+                let (i, j) = (k % n, (k * 3 + 1) % n);
+                let (i, j) = if i == j { (i, (j + 1) % n) } else { (i, j) };
+                let theta = 2.0 * std::f64::consts::PI * angles[k];
+                (i, j, theta.cos(), theta.sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let rotations = random_rotations(6, 10);
+        for layout in ['R', 'C'] {
+            let a = random_matrix::<f64>(6, 6, layout.into());
+
+            // rows: G_k ... G_1 A
+            let mut m = a.clone();
+            apply_givens_sequence(&rotations, m.view_mut(), BLASLeft).unwrap();
+            let mut expect = a.clone();
+            for &rot in &rotations {
+                expect = gemm(&rotation_matrix(6, rot).view(), &expect.view());
+            }
+            check_same(&m.view(), &expect.view(), 16.0 * f64::EPSILON);
+
+            // columns: A G_1^T ... G_k^T
+            let mut m = a.clone();
+            apply_givens_sequence(&rotations, m.view_mut(), BLASRight).unwrap();
+            let mut expect = a.clone();
+            for &rot in &rotations {
+                expect = gemm(&expect.view(), &rotation_matrix(6, rot).t());
+            }
+            check_same(&m.view(), &expect.view(), 16.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_rectangular_complex() {
+        // rotations of rows act on 4 x 7 matrix, rotations of columns on its transpose
+        let rotations = random_rotations(4, 7);
+        let a = random_matrix::<c64>(4, 7, 'R'.into());
+        let mut m_rows = a.clone();
+        apply_givens_sequence(&rotations, m_rows.view_mut(), BLASLeft).unwrap();
+        let mut m_cols = a.t().to_owned();
+        apply_givens_sequence(&rotations, m_cols.view_mut(), BLASRight).unwrap();
+        check_same(&m_cols.t(), &m_rows.view(), 16.0 * f64::EPSILON);
+
+        let g = rotations.iter().fold(Array2::<f64>::eye(4), |g, &rot| rotation_matrix(4, rot).dot(&g));
+        let expect = g.mapv(|v| c64::new(v, 0.0)).dot(&a);
+        check_same(&m_rows.view(), &expect.view(), 16.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_invalid_index() {
+        let mut m = random_matrix::<f64>(3, 5, 'R'.into());
+        let m_orig = m.clone();
+        let err = apply_givens_sequence(&[(0, 1, 1.0, 0.0), (0, 3, 1.0, 0.0)], m.view_mut(), BLASLeft);
+        assert!(matches!(err, Err(BLASError::InvalidDim(_))));
+        let err = apply_givens_sequence(&[(0, 1, 0.0, 1.0), (2, 2, 1.0, 0.0)], m.view_mut(), BLASRight);
+        assert!(matches!(err, Err(BLASError::InvalidFlag(_))));
+        // nothing is applied if any rotation is invalid
+        assert_eq!(m, m_orig);
+        apply_givens_sequence(&[(0, 3, 1.0, 0.0)], m.view_mut(), BLASRight).unwrap();
+        apply_givens_sequence::<f64>(&[], m.view_mut(), BLASLeft).unwrap();
+    }
+}
@@ -0,0 +1,97 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+/// Hermitian matrix Q diag(d) Q^H with Householder reflector Q, and first column of Q.
+fn householder_matrix<F>(d: &[F::RealFloat]) -> (Array2<F>, Array1<F>)
+where
+    F: TestFloat,
+{
+    let n = d.len();
+    let v = random_array::<F>(n);
+    let vv = v.iter().fold(F::zero(), |acc, &x| acc + F::conj(x) * x);
+    let q = Array2::from_shape_fn((n, n), |(i, j)| {
+        let eye = if i == j { F::one() } else { F::zero() };
+        eye - (F::one() + F::one()) * v[i] * F::conj(v[j]) / vv
+    });
+    let q_d = Array2::from_shape_fn((n, n), |(i, j)| q[[i, j]] * F::from_real(d[j]));
+    let q_h = q.t().mapv(F::conj);
+    (gemm(&q_d.view(), &q_h.view()), q.column(0).to_owned())
+}
+
+#[cfg(test)]
+mod power_iteration {
+    use super::*;
+
+    #[test]
+    fn test_real() {
+        let (a, q0) = householder_matrix::<f64>(&[5.0, 2.0, 1.0, -0.5, 0.25]);
+        let (eig, x) = power_iteration(a.view(), 1000, 1.0e-14).unwrap();
+        assert!((eig - 5.0).abs() < 1.0e-10);
+        assert!((x.dot(&q0).abs() - 1.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_negative_dominant() {
+        let (a, _) = householder_matrix::<f32>(&[-4.0, 1.0, 0.5]);
+        let (eig, _) = power_iteration(a.view(), 1000, 1.0e-6).unwrap();
+        assert!((eig + 4.0).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn test_hermitian() {
+        let (a, q0) = householder_matrix::<c64>(&[3.0, -1.0, 1.5, 0.5]);
+        let (eig, x) = power_iteration(a.t(), 1000, 1.0e-14).unwrap();
+        // a.t() is conjugate of a, whose eigenvector is conjugate of q0
+        assert!((eig - 3.0).abs() < 1.0e-10);
+        let overlap = x.iter().zip(q0.iter()).fold(c64::new(0.0, 0.0), |acc, (&xi, &qi)| acc + xi * qi);
+        assert!((overlap.norm() - 1.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let a = random_matrix::<f64>(3, 4, 'R'.into());
+        assert!(power_iteration(a.view(), 10, 1.0e-8).is_err());
+        let a = Array2::<f64>::zeros((0, 0));
+        assert!(power_iteration(a.view(), 10, 1.0e-8).is_err());
+    }
+}
+
+#[cfg(test)]
+mod matrix_power {
+    use super::*;
+
+    #[test]
+    fn test_naive() {
+        let a = random_matrix::<f64>(5, 5, 'R'.into());
+        let mut a_naive = a.clone();
+        for n in 1..=9 {
+            let a_pow = matrix_power(a.view(), n).unwrap();
+            check_same(&a_pow.view(), &a_naive.view(), 64.0 * f64::EPSILON);
+            a_naive = gemm(&a_naive.view(), &a.view());
+        }
+    }
+
+    #[test]
+    fn test_complex_col_major() {
+        let a = random_matrix::<c64>(4, 4, 'C'.into());
+        let a_naive = (1..13).fold(a.clone(), |acc, _| gemm(&acc.view(), &a.view()));
+        let a_pow = matrix_power(a.view(), 13).unwrap();
+        check_same(&a_pow.view(), &a_naive.view(), 64.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_identity() {
+        let a = random_matrix::<f32>(3, 3, 'R'.into());
+        assert_eq!(matrix_power(a.view(), 0).unwrap(), Array2::<f32>::eye(3));
+        assert_eq!(matrix_power(a.view(), 1).unwrap(), a);
+        let e = matrix_power(Array2::<f32>::zeros((0, 0)).view(), 5).unwrap();
+        assert_eq!(e.dim(), (0, 0));
+    }
+
+    #[test]
+    fn test_non_square() {
+        let a = random_matrix::<f64>(3, 4, 'R'.into());
+        assert!(matches!(matrix_power(a.view(), 2), Err(BLASError::InvalidDim(_))));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+use num_complex::*;
+
+#[cfg(test)]
+mod covariance {
+    use super::*;
+
+    /// Naive covariance $\sum_k (x_{ki} - \mu_i) \overline{(x_{kj} - \mu_j)} / (n - \mathrm{ddof})$.
+    fn covariance_naive(x: &ArrayView2<c64>, ddof: usize) -> Array2<c64> {
+        let (n, p) = x.dim();
+        let mean = x.mean_axis(Axis(0)).unwrap();
+        Array2::from_shape_fn((p, p), |(i, j)| {
+            (0..n).map(|k| (x[[k, i]] - mean[i]) * (x[[k, j]] - mean[j]).conj()).sum::<c64>()
+                / (n - ddof) as f64
+        })
+    }
+
+    #[test]
+    fn test_real() {
+        for layout in ['R', 'C'] {
+            let x_raw = random_matrix::<f64>(20, 10, layout.into());
+            for x in [x_raw.view(), x_raw.slice(s![..;2, 1..;3])] {
+                for ddof in [0, 1] {
+                    let c = covariance(x, ddof).unwrap();
+                    let c_naive = covariance_naive(&x.mapv(c64::from).view(), ddof).mapv(|v| v.re);
+                    check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+                    assert_eq!(c, c.t());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_complex() {
+        let x_raw = random_matrix::<c64>(15, 6, 'R'.into());
+        for x in [x_raw.view(), x_raw.slice(s![1.., ..;-1])] {
+            let c = covariance(x, 1).unwrap();
+            let c_naive = covariance_naive(&x, 1);
+            check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+            // hermitian with real diagonal
+            assert_eq!(c, c.t().mapv(|v| v.conj()));
+            assert!(c.diag().iter().all(|v| v.im == 0.0));
+        }
+    }
+
+    #[test]
+    fn test_complex_numpy() {
+        // numpy.cov([[1, 1j], [0, 0]], rowvar=False)
+        let x = array![[c64::new(1.0, 0.0), c64::new(0.0, 1.0)], [c64::new(0.0, 0.0), c64::new(0.0, 0.0)]];
+        let c = covariance(x.view(), 1).unwrap();
+        let c_numpy =
+            array![[c64::new(0.5, 0.0), c64::new(0.0, -0.5)], [c64::new(0.0, 0.5), c64::new(0.5, 0.0)]];
+        assert_eq!(c, c_numpy);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let x = random_matrix::<f32>(3, 4, 'R'.into());
+        assert!(covariance(x.view(), 2).is_ok());
+        assert!(matches!(covariance(x.view(), 3), Err(BLASError::InvalidDim(_))));
+        let x = Array2::<f64>::zeros((0, 4));
+        assert!(matches!(covariance(x.view(), 0), Err(BLASError::InvalidDim(_))));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod solve_triangular {
+    use super::*;
+
+    #[test]
+    fn test_storage_variants() {
+        let (n, k) = (7, 2);
+        for (uplo, trans, diag) in [('L', 'N', 'N'), ('U', 'T', 'N'), ('L', 'C', 'U'), ('U', 'N', 'U')] {
+            // banded triangular matrix, well-conditioned by dominant diagonal
+            let mut a = random_matrix::<c64>(n, n, 'R'.into());
+            for ((i, j), v) in a.indexed_iter_mut() {
+                let in_band = match uplo {
+                    'L' => i >= j && i - j <= k,
+                    _ => j >= i && j - i <= k,
+                };
+                if !in_band {
+                    *v = c64::new(0.0, 0.0);
+                } else if i == j {
+                    *v += c64::new(4.0, 0.0);
+                }
+            }
+            let ap = pack_tril(&a.view(), 'R', uplo);
+            let mut ab = Array2::<c64>::zeros((k + 1, n).f());
+            for ((i, j), &v) in a.indexed_iter() {
+                match uplo {
+                    'L' if i >= j && i - j <= k => ab[[i - j, j]] = v,
+                    'U' if j >= i && j - i <= k => ab[[k + i - j, j]] = v,
+                    _ => (),
+                }
+            }
+            let (kl, ku) = if uplo == 'L' { (k, 0) } else { (0, k) };
+            assert_eq!(densify_band(&ab.view(), kl, ku, n, n).unwrap(), a);
+
+            let b = random_array::<c64>(n);
+            let solve = |storage: TriangularStorage<c64>| {
+                let mut x = b.clone();
+                solve_triangular(storage, x.view_mut(), uplo.into(), trans.into(), diag.into()).unwrap();
+                x
+            };
+            let x_dense = solve(TriangularStorage::Dense(a.view()));
+            let x_packed = solve(TriangularStorage::Packed(ap.view(), n));
+            let x_banded = solve(TriangularStorage::Banded(ab.view(), k));
+            check_same(&x_packed.view(), &x_dense.view(), 4.0 * f64::EPSILON);
+            check_same(&x_banded.view(), &x_dense.view(), 4.0 * f64::EPSILON);
+
+            // solution satisfies op(A) x = b
+            let mut a_op = densify_triangular(&a.view(), uplo.into(), diag.into()).unwrap();
+            a_op = transpose(&a_op.view(), trans.into());
+            check_same(&gemv(&a_op.view(), &x_dense.view()).view(), &b.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_invalid_dim() {
+        let a = random_matrix::<f64>(4, 4, 'R'.into());
+        let ap = random_array::<f64>(10);
+        let mut b = random_array::<f64>(4);
+        let mut solve = |storage| {
+            solve_triangular(storage, b.view_mut(), BLASLower, BLASNoTrans, BLASNonUnit).map(|_| ())
+        };
+        assert!(matches!(solve(TriangularStorage::Packed(ap.view(), 5)), Err(BLASError::InvalidDim(_))));
+        assert!(matches!(
+            solve(TriangularStorage::Packed(ap.slice(s![..9]), 4)),
+            Err(BLASError::InvalidDim(_))
+        ));
+        assert!(matches!(
+            solve(TriangularStorage::Banded(a.slice(s![..2, ..]), 2)),
+            Err(BLASError::InvalidDim(_))
+        ));
+        assert!(solve(TriangularStorage::Banded(a.t().slice(s![..2, ..]), 1)).is_ok());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod test_default;
+pub mod test_setters;
+pub mod test_structured;
@@ -0,0 +1,107 @@
+//! Builders are constructed by `default()`, and only array fields are required.
+
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+fn assert_uninit<T>(res: Result<T, BLASError>, field: &str) {
+    match res {
+        Err(BLASError::UninitializedField(name)) => assert_eq!(name, field),
+        Err(err) => panic!("expected uninitialized field {field}, got {err:?}"),
+        Ok(_) => panic!("expected uninitialized field {field}, got Ok"),
+    }
+}
+
+#[cfg(test)]
+mod blas1 {
+    use super::*;
+
+    #[test]
+    fn test_minimal_setters() {
+        let x = random_array::<f64>(5);
+        let mut y = random_array::<f64>(5);
+        let y_orig = y.clone();
+        AXPY::default().x(x.view()).y(y.view_mut()).run().unwrap();
+        check_same(&y.view(), &(&y_orig + &x).view(), 4.0 * f64::EPSILON);
+        let dot = DOT::default().x(x.view()).y(y_orig.view()).run().unwrap();
+        assert!((dot - x.dot(&y_orig)).abs() < 1e-12);
+        let nrm = NRM2::default().x(x.view()).run().unwrap();
+        assert!((nrm - x.dot(&x).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_missing_array() {
+        let x = random_array::<f64>(5);
+        let mut y = random_array::<f64>(5);
+        assert_uninit(AXPY::<f64>::default().x(x.view()).run(), "y");
+        assert_uninit(AXPY::default().y(y.view_mut()).run(), "x");
+        assert_uninit(DOT::<f64>::default().y(x.view()).run(), "x");
+        assert_uninit(NRM2::<f64>::default().run(), "x");
+    }
+}
+
+#[cfg(test)]
+mod blas2 {
+    use super::*;
+
+    #[test]
+    fn test_minimal_setters() {
+        let a = random_matrix::<c64>(4, 3, 'R'.into());
+        let x = random_array::<c64>(3);
+        let y = GEMV::default().a(a.view()).x(x.view()).run().unwrap();
+        check_same(&y.view(), &a.dot(&x).view(), 4.0 * f64::EPSILON);
+
+        let x = random_array::<c64>(4);
+        let z = random_array::<c64>(3);
+        let c = GER::default().x(x.view()).y(z.view()).run().unwrap();
+        check_same(
+            &c.view(),
+            &gemm(&x.view().insert_axis(Axis(1)), &z.view().insert_axis(Axis(0))).view(),
+            4.0 * f64::EPSILON,
+        );
+    }
+
+    #[test]
+    fn test_missing_array() {
+        let a = random_matrix::<c64>(4, 4, 'R'.into());
+        let mut x = random_array::<c64>(4);
+        assert_uninit(GEMV::default().x(x.view()).run(), "a");
+        assert_uninit(GER::<c64>::default().x(x.view()).run(), "y");
+        assert_uninit(TRSV::<c64>::default().a(a.view()).run(), "x");
+        assert_uninit(TRSV::default().x(x.view_mut()).run(), "a");
+    }
+}
+
+#[cfg(test)]
+mod blas3 {
+    use super::*;
+
+    #[test]
+    fn test_minimal_setters() {
+        let a = random_matrix::<f32>(4, 3, 'C'.into());
+        let b = random_matrix::<f32>(3, 5, 'R'.into());
+        let c = GEMM::default().a(a.view()).b(b.view()).run().unwrap();
+        check_same(&c.view(), &gemm(&a.view(), &b.view()).view(), 4.0 * f32::EPSILON);
+
+        // SYRK output is referenced in the lower triangle by default
+        let c = SYRK::default().a(a.view()).run().unwrap();
+        let c_naive = gemm(&a.view(), &a.t());
+        for i in 0..4 {
+            for j in 0..=i {
+                assert!((c.view()[[i, j]] - c_naive[[i, j]]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_missing_array() {
+        let a = random_matrix::<f32>(4, 4, 'C'.into());
+        let mut b = random_matrix::<f32>(4, 4, 'C'.into());
+        assert_uninit(GEMM::default().a(a.view()).run(), "b");
+        assert_uninit(GEMM::default().b(a.view()).run(), "a");
+        assert_uninit(SYRK::<f32>::default().run(), "a");
+        assert_uninit(SYMM::default().a(a.view()).run(), "b");
+        assert_uninit(TRSM::default().b(b.view_mut()).run(), "a");
+        assert_uninit(TRSM::<f32>::default().a(a.view()).run(), "b");
+    }
+}
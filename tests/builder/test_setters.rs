@@ -0,0 +1,266 @@
+//! Setters of owned builders: values set explicitly are honored, and setting a field again overwrites it.
+
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod gemm {
+    use super::*;
+
+    #[test]
+    fn test_arrays_and_scalars() {
+        let a1 = random_matrix::<f64>(4, 3, 'R'.into());
+        let a2 = random_matrix::<f64>(4, 3, 'C'.into());
+        let b = random_matrix::<f64>(3, 5, 'R'.into());
+        let c_naive = gemm(&a2.view(), &b.view());
+
+        // `a` set twice, last one is used
+        let c = GEMM::default().a(a1.view()).b(b.view()).a(a2.view()).run().unwrap();
+        check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+        // `c` set twice: only the last one is written, and returned as view into it
+        let c_orig = random_matrix::<f64>(4, 5, 'R'.into());
+        let (mut c1, mut c2) = (c_orig.clone(), c_orig.clone());
+        let builder = GEMM::default().a(a2.view()).b(b.view()).c(c1.view_mut()).c(c2.view_mut());
+        let out = builder.alpha(3.0).beta(7.0).alpha(2.0).beta(0.5).run().unwrap();
+        assert!(matches!(out, ArrayOut::ViewMut(_)));
+        assert_eq!(out.view().as_ptr(), c2.as_ptr());
+        assert_eq!(c1, c_orig);
+        check_same(&c2.view(), &(2.0 * &c_naive + 0.5 * &c_orig).view(), 8.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_flags() {
+        let a = random_matrix::<c64>(3, 4, 'R'.into());
+        let b = random_matrix::<c64>(5, 3, 'C'.into());
+        let c_naive = gemm(&a.t().mapv(|x| x.conj()).view(), &b.t());
+
+        let c = GEMM::default().a(a.view()).b(b.view()).transa('T').transa('C').transb('N').transb('T').run();
+        check_same(&c.unwrap().view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+        // invalid string marks the flag as undefined; setting it again replaces that
+        let builder = GEMM::default().a(a.view()).b(b.view()).transa_str("X").transb_str("t");
+        assert!(matches!(builder.run(), Err(BLASError::InvalidFlag(_))));
+        let builder = GEMM::default().a(a.view()).b(b.view()).transa_str("X").transa_str("c").transb_str("t");
+        check_same(&builder.run().unwrap().view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+        // layout of owned output follows `layout`, the last one set
+        for (first, last) in [(BLASRowMajor, BLASColMajor), (BLASColMajor, BLASRowMajor)] {
+            let c =
+                GEMM::default().a(a.view()).b(b.view()).transa('C').transb('T').layout(first).layout(last);
+            let c = c.run().unwrap().into_owned();
+            assert_eq!(c.is_standard_layout(), last == BLASRowMajor);
+            check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+
+        // `transpose_output` and `chunk_large_dims` are honored
+        let c = GEMM::default().a(a.view()).b(b.view()).transa('C').transb('T').transpose_output(true).run();
+        check_same(&c.unwrap().view(), &c_naive.t(), 4.0 * f64::EPSILON);
+        let c = GEMM::default().a(a.view()).b(b.view()).transa('C').transb('T').chunk_large_dims(true).run();
+        check_same(&c.unwrap().view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_boolean_options() {
+        let mut a = random_matrix::<f64>(4, 4, 'R'.into());
+        let b = random_matrix::<f64>(4, 4, 'R'.into());
+        a[[1, 2]] = f64::NAN;
+        let err = GEMM::default().a(a.view()).b(b.view()).check_finite(true).run().unwrap_err();
+        assert!(matches!(err, BLASError::NonFinite(_)));
+        assert!(GEMM::default().a(a.view()).b(b.view()).check_finite(true).check_finite(false).run().is_ok());
+
+        // output overlapping input is rejected, unless copy is allowed
+        let a = random_matrix::<f64>(4, 4, 'R'.into());
+        let c_naive = gemm(&a.view(), &b.view());
+        let mut c = a.clone();
+        let a_view = unsafe { ArrayView2::from_shape_ptr((4, 4), c.as_ptr()) };
+        let err = GEMM::default().a(a_view).b(b.view()).c(c.view_mut()).run().unwrap_err();
+        assert!(matches!(err, BLASError::Aliasing(_)));
+        let builder = GEMM::default().a(a_view).b(b.view()).c(c.view_mut()).allow_aliasing_copy(true);
+        builder.run().unwrap();
+        check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_structure_and_plan_options() {
+        let a = random_matrix::<f64>(5, 5, 'C'.into());
+        let b = random_matrix::<f64>(5, 3, 'C'.into());
+
+        // only lower triangle of `a` is referenced with `a_symmetric(Lower)`
+        let mut a_sym = a.clone();
+        for i in 0..5 {
+            for j in 0..i {
+                a_sym[[j, i]] = a_sym[[i, j]];
+            }
+        }
+        let c = GEMM::default().a(a.view()).b(b.view()).a_symmetric('U').a_symmetric('L').run().unwrap();
+        check_same(&c.view(), &gemm(&a_sym.view(), &b.view()).view(), 8.0 * f64::EPSILON);
+        let c = GEMM::default().a(a.view()).b(b.view()).a_hermitian('L').run().unwrap();
+        check_same(&c.view(), &gemm(&a_sym.view(), &b.view()).view(), 8.0 * f64::EPSILON);
+
+        // `shape_hint` and `assume_layout` are honored by plan
+        let builder = GEMM::default().a(a.view()).b(a.view()).transb('T').shape_hint(ShapeClass::Auto);
+        assert_eq!(builder.plan().unwrap().routine, "dsyrk");
+        let builder = builder.shape_hint(ShapeClass::TallSkinny);
+        assert_eq!(builder.plan().unwrap().routine, "dgemm");
+        let builder = GEMM::default().a(a.view()).b(b.view()).assume_layout(BLASRowMajor);
+        assert!(matches!(builder.plan(), Err(BLASError::InvalidDim(_))));
+        let builder =
+            GEMM::default().a(a.view()).b(b.view()).assume_layout(BLASRowMajor).assume_layout(BLASColMajor);
+        assert_eq!(builder.plan().unwrap().layout, BLASColMajor);
+
+        // output written into pool, and checked by verify
+        let mut pool = GemmOutputPool::new();
+        let c = GEMM::default().a(a.view()).b(b.view()).output_pool(&mut pool).verify(1e-12, 1e-12).run();
+        check_same(&c.unwrap().view(), &gemm(&a.view(), &b.view()).view(), 8.0 * f64::EPSILON);
+        assert_eq!(pool.allocations(), 1);
+    }
+
+    #[test]
+    fn test_c_replaces_c_uninit() {
+        // `c` given after `c_uninit` is ordinary initialized output, so nonzero beta is allowed
+        let a = random_matrix::<f64>(3, 3, 'R'.into());
+        let mut buf = vec![core::mem::MaybeUninit::<f64>::uninit(); 9];
+        let c_orig = random_matrix::<f64>(3, 3, 'R'.into());
+        let mut c = c_orig.clone();
+        let builder = unsafe {
+            GEMM::default().a(a.view()).b(a.view()).c_uninit(buf.as_mut_ptr(), (3, 3), BLASRowMajor)
+        };
+        builder.c(c.view_mut()).beta(1.0).run().unwrap();
+        check_same(&c.view(), &(gemm(&a.view(), &a.view()) + &c_orig).view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_clone_drops_output() {
+        // mutable output can not be cloned, so clone of builder allocates output instead
+        let a = random_matrix::<f64>(3, 3, 'R'.into());
+        let mut c = Array2::<f64>::zeros((3, 3));
+        let builder = GEMM::default().a(a.view()).b(a.view()).alpha(2.0).layout(BLASColMajor).c(c.view_mut());
+        let out = builder.clone().run().unwrap().into_owned();
+        assert!(out.t().is_standard_layout());
+        check_same(&out.view(), &(2.0 * gemm(&a.view(), &a.view())).view(), 4.0 * f64::EPSILON);
+        assert!(matches!(builder.run().unwrap(), ArrayOut::ViewMut(_)));
+        check_same(&c.view(), &out.view(), 0.0);
+    }
+}
+
+#[cfg(test)]
+mod syrk {
+    use super::*;
+
+    fn lower(c: ArrayView2<f64>) -> Array2<f64> {
+        let mut l = Array2::zeros(c.dim());
+        tril_assign(&mut l.view_mut(), &c, 'L');
+        l
+    }
+
+    #[test]
+    fn test_setters() {
+        let a = random_matrix::<f64>(4, 6, 'R'.into());
+        let c_naive = gemm(&a.t(), &a.view());
+
+        // uplo and trans overwritten, c given twice
+        let c_orig = random_matrix::<f64>(6, 6, 'C'.into());
+        let (mut c1, mut c2) = (c_orig.clone(), c_orig.clone());
+        let builder = SYRK::default().a(a.view()).uplo('U').uplo('L').trans('N').trans_str("T");
+        let builder = builder.c(c1.view_mut()).c(c2.view_mut()).alpha(2.0).beta(0.5).layout(BLASRowMajor);
+        builder.check_finite(true).run().unwrap();
+        assert_eq!(c1, c_orig);
+        check_same(&lower(c2.view()).view(), &lower((2.0 * &c_naive + 0.5 * &c_orig).view()).view(), 1e-14);
+        // upper triangle is not referenced
+        for i in 0..6 {
+            for j in i + 1..6 {
+                assert_eq!(c2[[i, j]], c_orig[[i, j]]);
+            }
+        }
+
+        // row weights, set twice
+        let w1 = Array1::from_elem(4, 4.0);
+        let w2 = array![1.0, 0.0, 2.0, 0.5];
+        let c =
+            SYRK::default().a(a.view()).trans('T').with_row_weights(w1.view()).with_row_weights(w2.view());
+        let c = c.run().unwrap();
+        let aw = &a * &w2.mapv(f64::sqrt).insert_axis(Axis(1));
+        check_same(&lower(c.view()).view(), &lower(gemm(&aw.t(), &aw.view()).view()).view(), 1e-14);
+
+        // layout of owned output
+        for layout in [BLASRowMajor, BLASColMajor] {
+            let c = SYRK::default().a(a.view()).trans('T').layout(layout).run().unwrap().into_owned();
+            assert_eq!(c.is_standard_layout(), layout == BLASRowMajor);
+        }
+
+        // invalid flags are rejected, but replaced by a later valid one
+        assert!(matches!(SYRK::default().a(a.view()).trans_str("Q").run(), Err(BLASError::InvalidFlag(_))));
+        assert!(matches!(SYRK::default().a(a.view()).uplo('X').run(), Err(BLASError::InvalidFlag(_))));
+        assert!(SYRK::default().a(a.view()).uplo('X').uplo('U').trans_str("Q").trans('T').run().is_ok());
+    }
+}
+
+#[cfg(test)]
+mod tpsv {
+    use super::*;
+
+    /// Upper triangular matrix packed in col-major (BLAS upper) order, and its dense form.
+    fn packed_upper(n: usize) -> (Array1<f64>, Array2<f64>) {
+        let mut dense = Array2::zeros((n, n));
+        let mut ap = Vec::new();
+        for j in 0..n {
+            for i in 0..=j {
+                let v = if i == j { 2.0 + i as f64 } else { 0.1 * (i + 2 * j) as f64 };
+                dense[[i, j]] = v;
+                ap.push(v);
+            }
+        }
+        (Array1::from(ap), dense)
+    }
+
+    #[test]
+    fn test_setters() {
+        let n = 5;
+        let (ap, dense) = packed_upper(n);
+        let x_orig = random_array::<f64>(n);
+        let solve_check = |dense: &Array2<f64>, x: &Array1<f64>| {
+            check_same(&dense.dot(x).view(), &x_orig.view(), 1e-13);
+        };
+
+        // col-major upper, with flags set twice
+        let (mut x1, mut x2) = (x_orig.clone(), x_orig.clone());
+        let builder = TPSV::default().ap(ap.view()).x(x1.view_mut()).x(x2.view_mut());
+        let builder = builder.uplo('L').uplo('U').trans('T').trans_str("n").diag('U').diag('N');
+        builder.layout(BLASRowMajor).layout(BLASColMajor).warn_ill_conditioned(true).run().unwrap();
+        assert_eq!(x1, x_orig);
+        solve_check(&dense, &x2);
+
+        // transpose of col-major upper is row-major lower in the same packed order
+        let mut x = x_orig.clone();
+        TPSV::default().ap(ap.view()).x(x.view_mut()).uplo('L').layout(BLASRowMajor).run().unwrap();
+        solve_check(&dense.t().to_owned(), &x);
+
+        // trans
+        let mut x = x_orig.clone();
+        TPSV::default().ap(ap.view()).x(x.view_mut()).layout(BLASColMajor).trans('T').run().unwrap();
+        solve_check(&dense.t().to_owned(), &x);
+
+        // unit diagonal ignores stored diagonal
+        let mut x = x_orig.clone();
+        TPSV::default().ap(ap.view()).x(x.view_mut()).layout(BLASColMajor).diag('U').run().unwrap();
+        let mut dense_unit = dense.clone();
+        dense_unit.diag_mut().fill(1.0);
+        solve_check(&dense_unit, &x);
+
+        // packed order differing from uplo: col-major upper order read as lower triangle of the transpose
+        let mut x = x_orig.clone();
+        let builder = TPSV::default().ap(ap.view()).x(x.view_mut()).layout(BLASColMajor).uplo('L');
+        builder.packed_order(PackedOrder::Lower).packed_order(PackedOrder::Upper).run().unwrap();
+        solve_check(&dense.t().to_owned(), &x);
+
+        // invalid flags
+        let mut x = x_orig.clone();
+        let err = TPSV::default().ap(ap.view()).x(x.view_mut()).trans_str("?").run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+        let err = TPSV::default().ap(ap.view()).x(x.view_mut()).uplo('?').run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+        assert_eq!(x, x_orig);
+    }
+}
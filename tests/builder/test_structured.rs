@@ -0,0 +1,77 @@
+//! Validated structured matrix wrappers given to builders of symmetric and triangular routines.
+
+use crate::util::*;
+use blas_array2::prelude::*;
+
+#[cfg(test)]
+mod symmetric {
+    use super::*;
+
+    #[test]
+    fn test_symm() {
+        let a = random_matrix::<f64>(4, 4, 'R'.into());
+        let b = random_matrix::<f64>(4, 3, 'C'.into());
+        for uplo in [BLASUpper, BLASLower] {
+            let s = SymmetricMatrix::new(a.view(), uplo).unwrap();
+            let c = SYMM::default().symmetric(s).b(b.view()).run().unwrap();
+            let c_ref = SYMM::default().a(a.view()).uplo(uplo).b(b.view()).run().unwrap();
+            check_same(&c.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+        }
+
+        // default of `try_from` is lower triangle, same as SYMM
+        let s = SymmetricMatrix::try_from(&a.view()).unwrap();
+        let c = SYMM::default().symmetric(s).b(b.view()).run().unwrap();
+        let c_ref = SYMM::default().a(a.view()).b(b.view()).run().unwrap();
+        check_same(&c.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rejected() {
+        let a = random_matrix::<f64>(4, 3, 'R'.into());
+        let err = SymmetricMatrix::try_from(&a.view()).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+        let a = random_matrix::<f64>(4, 4, 'R'.into());
+        let err = SymmetricMatrix::new(a.view(), BLASUpLo::Undefined).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+    }
+}
+
+#[cfg(test)]
+mod triangular {
+    use super::*;
+
+    #[test]
+    fn test_trsv() {
+        let mut a = random_matrix::<c64>(5, 5, 'C'.into());
+        for i in 0..5 {
+            a[[i, i]] += c64::new(5.0, 0.0);
+        }
+        let x = random_array::<c64>(5);
+        for (uplo, diag) in [(BLASUpper, BLASNonUnit), (BLASLower, BLASUnit)] {
+            let t = TriangularMatrix::new(a.view(), uplo, diag).unwrap();
+            let mut x1 = x.clone();
+            TRSV::default().triangular(t).x(x1.view_mut()).run().unwrap();
+            let mut x2 = x.clone();
+            TRSV::default().a(a.view()).uplo(uplo).diag(diag).x(x2.view_mut()).run().unwrap();
+            check_same(&x1.view(), &x2.view(), 4.0 * f64::EPSILON);
+        }
+
+        // `trans` is still set independently
+        let t = TriangularMatrix::try_from(&a.view()).unwrap();
+        let mut x1 = x.clone();
+        TRSV::default().triangular(t).trans('C').x(x1.view_mut()).run().unwrap();
+        let mut x2 = x.clone();
+        TRSV::default().a(a.view()).trans('C').x(x2.view_mut()).run().unwrap();
+        check_same(&x1.view(), &x2.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rejected() {
+        let a = random_matrix::<f64>(3, 4, 'R'.into());
+        let err = TriangularMatrix::try_from(&a.view()).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+        let a = random_matrix::<f64>(4, 4, 'R'.into());
+        let err = TriangularMatrix::new(a.view(), BLASLower, BLASDiag::Undefined).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidFlag(_)));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::util::*;
+use blas_array2::compat::ndarray_linalg::*;
+use blas_array2::util::*;
+use ndarray::linalg;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_mat_mul() {
+        for (a_layout, b_layout) in [('R', 'R'), ('R', 'C'), ('C', 'R'), ('C', 'C')] {
+            let a = random_matrix::<f64>(7, 5, a_layout.into());
+            let b = random_matrix::<f64>(5, 9, b_layout.into());
+            let c = mat_mul(&a, &b);
+            let c_naive = a.dot(&b);
+            check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+            assert_eq!(c.strides(), c_naive.strides());
+        }
+    }
+
+    #[test]
+    fn test_mat_mul_strided() {
+        let a_raw = random_matrix::<c32>(10, 12, 'C'.into());
+        let b_raw = random_matrix::<c32>(12, 10, 'R'.into());
+        let a = a_raw.slice(s![..;2, 1..7]);
+        let b = b_raw.slice(s![..6;-1, ..;3]);
+        let c = mat_mul(&a, &b);
+        let c_naive = a.dot(&b);
+        check_same(&c.view(), &c_naive.view(), 4.0 * f32::EPSILON);
+        assert_eq!(c.strides(), c_naive.strides());
+    }
+
+    #[test]
+    fn test_general_mat_mul() {
+        let a = random_matrix::<c64>(4, 6, 'R'.into());
+        let b = random_matrix::<c64>(6, 3, 'C'.into());
+        let mut c = random_matrix::<c64>(4, 3, 'C'.into());
+        let mut c_naive = c.clone();
+        let (alpha, beta) = (c64::new(1.5, -0.5), c64::new(0.25, 2.0));
+        general_mat_mul(alpha, &a, &b, beta, &mut c);
+        linalg::general_mat_mul(alpha, &a, &b, beta, &mut c_naive);
+        check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_mat_vec_mul() {
+        let a = random_matrix::<f32>(8, 5, 'C'.into());
+        let x = random_array::<f32>(5);
+        let w = random_array::<f32>(8);
+        check_same(&mat_vec_mul(&a, &x).view(), &a.dot(&x).view(), 4.0 * f32::EPSILON);
+        check_same(&vec_mat_mul(&w, &a).view(), &w.dot(&a).view(), 4.0 * f32::EPSILON);
+
+        let mut y = random_array::<f32>(8);
+        let mut y_naive = y.clone();
+        general_mat_vec_mul(2.0, &a, &x.slice(s![..;-1]), -1.0, &mut y.slice_mut(s![..;-1]));
+        linalg::general_mat_vec_mul(2.0, &a, &x.slice(s![..;-1]), -1.0, &mut y_naive.slice_mut(s![..;-1]));
+        check_same(&y.view(), &y_naive.view(), 4.0 * f32::EPSILON);
+    }
+
+    #[test]
+    fn test_broadcast() {
+        let row = random_array::<f64>(5);
+        let a = row.broadcast((7, 5)).unwrap();
+        let b = random_matrix::<f64>(5, 3, 'C'.into());
+        check_same(&mat_mul(&a, &b).view(), &a.dot(&b).view(), 4.0 * f64::EPSILON);
+        let x = Array1::from_elem(1, 2.5);
+        let x = x.broadcast(5).unwrap();
+        check_same(&mat_vec_mul(&b.t(), &x).view(), &b.t().dot(&x).view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_mat_mul_shape() {
+        let a = random_matrix::<f64>(3, 4, 'R'.into());
+        mat_mul(&a, &a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_general_mat_vec_mul_shape() {
+        let a = random_matrix::<f64>(3, 4, 'R'.into());
+        let x = random_array::<f64>(4);
+        let mut y = random_array::<f64>(4);
+        general_mat_vec_mul(1.0, &a, &x, 0.0, &mut y);
+    }
+}
@@ -1,4 +1,10 @@
 pub mod blas1;
 pub mod blas2;
 pub mod blas3;
+pub mod blasext;
+pub mod builder;
+pub mod compat;
+#[cfg(feature = "record")]
+pub mod record;
+pub mod threads;
 pub mod util;
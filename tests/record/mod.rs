@@ -0,0 +1 @@
+pub mod test_record;
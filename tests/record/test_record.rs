@@ -0,0 +1,74 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use blas_array2::record::{self, checksum, CallRecord};
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod record_calls {
+    use super::*;
+
+    #[test]
+    fn test_sequence() {
+        let a = random_matrix::<f64>(3, 4, 'C'.into());
+        let b = random_matrix::<f64>(4, 5, 'C'.into());
+        let x = random_array::<f64>(4);
+        record::drain();
+
+        let c = GEMM::default().a(a.view()).b(b.view()).alpha(2.0).run().unwrap().into_owned();
+        let y = GEMV::default().a(a.view()).x(x.view()).run().unwrap().into_owned();
+        let mut z = x.clone();
+        SCAL::default().x(z.view_mut()).alpha(0.5).run().unwrap();
+        // empty output does not call BLAS
+        GEMV::default().a(a.slice(s![..0, ..])).x(x.view()).run().unwrap();
+
+        let records = record::drain();
+        let c0 = Array2::<f64>::zeros((3, 5).f());
+        let y0 = Array1::<f64>::zeros(3);
+        let expected = vec![
+            CallRecord {
+                routine: "GEMM",
+                dims: vec![("m", 3), ("n", 5), ("k", 4)],
+                flags: vec![("transa", 'N'), ("transb", 'N')],
+                scalars: vec![("alpha", c64::new(2.0, 0.0)), ("beta", c64::new(0.0, 0.0))],
+                checksums: vec![
+                    ("a", checksum(&a.view())),
+                    ("b", checksum(&b.view())),
+                    ("c", checksum(&c0.view())),
+                ],
+            },
+            CallRecord {
+                routine: "GEMV",
+                dims: vec![("m", 3), ("n", 4)],
+                flags: vec![("trans", 'N')],
+                scalars: vec![("alpha", c64::new(1.0, 0.0)), ("beta", c64::new(0.0, 0.0))],
+                checksums: vec![
+                    ("a", checksum(&a.view())),
+                    ("x", checksum(&x.view())),
+                    ("y", checksum(&y0.view())),
+                ],
+            },
+            CallRecord {
+                routine: "SCAL",
+                dims: vec![("n", 4)],
+                flags: vec![],
+                scalars: vec![("alpha", c64::new(0.5, 0.0))],
+                checksums: vec![("x", checksum(&x.view()))],
+            },
+        ];
+        assert_eq!(records, expected);
+        assert!(record::drain().is_empty());
+        assert_eq!(c.dim(), (3, 5));
+        assert_eq!(y.dim(), 3);
+    }
+
+    #[test]
+    fn test_checksum() {
+        let a = random_matrix::<c64>(5, 6, 'R'.into());
+        let a_f = a.as_standard_layout().reversed_axes().as_standard_layout().reversed_axes().into_owned();
+        assert_eq!(checksum(&a.view()), checksum(&a_f.view()));
+        assert_ne!(checksum(&a.view()), checksum(&a.t()));
+        let mut b = a.clone();
+        b[[2, 3]] += c64::new(0.0, 1e-12);
+        assert_ne!(checksum(&a.view()), checksum(&b.view()));
+    }
+}
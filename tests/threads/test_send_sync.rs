@@ -0,0 +1,96 @@
+use crate::util::*;
+use blas_array2::blas3::gemm::{GEMM_Driver, GemmOutputPool};
+use blas_array2::prelude::generic::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+/// Pin that builders, inner structs and drivers are `Send + Sync` for every float type.
+macro_rules! assert_all_send_sync {
+    ($($ty: ident),* $(,)?) => {
+        assert_send_sync::<ArrayOut2<'static, $($ty)*>>();
+        assert_send_sync::<ArrayOut1<'static, $($ty)*>>();
+        assert_send_sync::<GemmOutputPool<$($ty)*>>();
+        assert_send_sync::<Accumulator<$($ty)*>>();
+
+        assert_send_sync::<ASUM<'static, $($ty)*>>();
+        assert_send_sync::<AXPY<'static, 'static, $($ty)*>>();
+        assert_send_sync::<IAMAX<'static, $($ty)*>>();
+        assert_send_sync::<NRM2<'static, $($ty)*>>();
+        assert_send_sync::<SCAL<'static, $($ty)*>>();
+
+        assert_send_sync::<GBMV<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<GEMV<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<GER<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<HBMV<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<HEMV<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<HER<'static, 'static, $($ty)*>>();
+        assert_send_sync::<HER2<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<HPMV<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<HPR<'static, 'static, $($ty)*>>();
+        assert_send_sync::<HPR2<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<TBMV<'static, 'static, $($ty)*>>();
+        assert_send_sync::<TBSV<'static, 'static, $($ty)*>>();
+        assert_send_sync::<TPMV<'static, 'static, $($ty)*>>();
+        assert_send_sync::<TPSV<'static, 'static, $($ty)*>>();
+        assert_send_sync::<TRMV<'static, 'static, $($ty)*>>();
+        assert_send_sync::<TRSV<'static, 'static, $($ty)*>>();
+
+        assert_send_sync::<GEMM<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<GEMM_<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<GEMM_Driver<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<SYMM<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<SYR2K<'static, 'static, 'static, $($ty)*>>();
+        assert_send_sync::<SYRK<'static, 'static, $($ty)*>>();
+        assert_send_sync::<SYRKBatch<'static, 'static, $($ty)*>>();
+        assert_send_sync::<TRMM<'static, 'static, $($ty)*>>();
+        assert_send_sync::<TRSM<'static, 'static, $($ty)*>>();
+    };
+}
+
+#[cfg(test)]
+mod send_sync {
+    use super::*;
+
+    #[test]
+    fn test_static_assertions() {
+        assert_all_send_sync!(f32);
+        assert_all_send_sync!(f64);
+        assert_all_send_sync!(c32);
+        assert_all_send_sync!(c64);
+
+        assert_send_sync::<GERC<'static, 'static, 'static, c64>>();
+        assert_send_sync::<HEMM<'static, 'static, 'static, c64>>();
+        assert_send_sync::<HER2K<'static, 'static, 'static, c64>>();
+        assert_send_sync::<HERK<'static, 'static, c64>>();
+        assert_send_sync::<HERKBatch<'static, 'static, c64>>();
+        assert_send_sync::<ROT<'static, 'static, f64>>();
+        assert_send_sync::<ROT<'static, 'static, c64>>();
+        assert_send_sync::<GEMM_RC<'static, 'static, 'static, f64>>();
+        assert_send_sync::<BLASError>();
+        assert_send_sync::<BlasPlan>();
+    }
+
+    #[test]
+    fn test_builder_with_mutable_view_across_threads() {
+        // builders holding mutable views are built in one thread and run in another
+        let a = random_matrix::<f64>(8, 6, 'R'.into());
+        let b = random_matrix::<f64>(6, 10, 'C'.into());
+        let mut c = Array2::<f64>::zeros((8, 20));
+        let (c_left, c_right) = c.view_mut().split_at(Axis(1), 10);
+        let builders = [
+            GEMM::default().a(a.view()).b(b.view()).c(c_left),
+            GEMM::default().a(a.view()).b(b.view()).c(c_right).alpha(2.0),
+        ];
+        std::thread::scope(|s| {
+            let handles = builders.map(|builder| s.spawn(move || builder.run().map(|_| ())));
+            for handle in handles {
+                handle.join().unwrap().unwrap();
+            }
+        });
+        let c_naive = gemm(&a.view(), &b.view());
+        check_same(&c.slice(s![.., ..10]), &c_naive.view(), 4.0 * f64::EPSILON);
+        check_same(&c.slice(s![.., 10..]), &(c_naive * 2.0).view(), 4.0 * f64::EPSILON);
+    }
+}
@@ -282,6 +282,34 @@ where
     }
 }
 
+pub fn pack_tril<F>(a: &ArrayView2<F>, layout: char, uplo: char) -> Array1<F>
+where
+    F: BLASFloat,
+{
+    let n = a.len_of(Axis(0));
+    let mut ap = vec![];
+    for outer in 0..n {
+        for inner in 0..n {
+            let (i, j) = if layout == 'R' { (outer, inner) } else { (inner, outer) };
+            if (uplo == 'L' && i >= j) || (uplo == 'U' && i <= j) {
+                ap.push(a[[i, j]]);
+            }
+        }
+    }
+    Array1::from(ap)
+}
+
+/// Copies of `ap` with stride 3 (column of a 2-D buffer) and with negative stride.
+pub fn strided_copies<F>(ap: &Array1<F>) -> (Array2<F>, Array1<F>)
+where
+    F: BLASFloat,
+{
+    let mut buf = Array2::<F>::zeros((ap.len(), 3));
+    buf.column_mut(1).assign(ap);
+    let rev = ap.slice(s![..;-1]).to_owned();
+    (buf, rev)
+}
+
 pub fn check_same<F, D>(a: &ArrayView<F, D>, b: &ArrayView<F, D>, eps: <F::RealFloat as AbsDiffEq>::Epsilon)
 where
     F: TestFloat,